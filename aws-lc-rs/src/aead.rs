@@ -47,6 +47,47 @@
 //! # }
 //! ```
 //!
+//! # Key-Committing API
+//!
+//! [`CommittingSealingKey`] and [`CommittingOpeningKey`] prepend a commitment to the key to each
+//! ciphertext, so that a ciphertext can only be opened with the key that sealed it.
+//!
+//! ```rust
+//! # use std::error::Error;
+//! #
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! use aws_lc_rs::aead::{
+//!     Aad, CommittingOpeningKey, CommittingSealingKey, Nonce, UnboundKey, AES_256_GCM,
+//!     COMMITMENT_LEN,
+//! };
+//!
+//! let key_bytes = [0x42u8; 32];
+//! let sealing_key = CommittingSealingKey::new(UnboundKey::new(&AES_256_GCM, &key_bytes)?);
+//! let opening_key = CommittingOpeningKey::new(UnboundKey::new(&AES_256_GCM, &key_bytes)?);
+//!
+//! let message = "test message";
+//! let mut in_out = Vec::from(message);
+//! let nonce = [0x24u8; 12];
+//! sealing_key.seal_in_place_append_tag(
+//!     Nonce::assume_unique_for_key(nonce),
+//!     Aad::empty(),
+//!     &mut in_out,
+//! )?;
+//! assert_eq!(
+//!     message.len() + COMMITMENT_LEN + AES_256_GCM.tag_len(),
+//!     in_out.len()
+//! );
+//!
+//! let plaintext = opening_key.open_in_place(
+//!     Nonce::assume_unique_for_key(nonce),
+//!     Aad::empty(),
+//!     &mut in_out,
+//! )?;
+//! assert_eq!(message.as_bytes(), plaintext);
+//! #   Ok(())
+//! # }
+//! ```
+//!
 //! # TLS AEAD APIs
 //!
 //! Systems developers creating TLS protocol implementations should use
@@ -187,23 +228,28 @@ use core::fmt::Debug;
 use core::ops::RangeFrom;
 use core::stringify;
 use paste::paste;
+use zeroize::ZeroizeOnDrop;
 
 mod aead_ctx;
 mod aes_gcm;
 mod chacha;
 pub mod chacha20_poly1305_openssh;
+mod committing;
 mod nonce;
 pub mod nonce_sequence;
-mod poly1305;
+pub(crate) mod poly1305;
 pub mod quic;
 mod rand_nonce;
+mod stream;
 mod tls;
 mod unbound_key;
 
 pub use self::aes_gcm::{AES_128_GCM, AES_128_GCM_SIV, AES_192_GCM, AES_256_GCM, AES_256_GCM_SIV};
 pub use self::chacha::CHACHA20_POLY1305;
+pub use self::committing::{CommittingOpeningKey, CommittingSealingKey, COMMITMENT_LEN};
 pub use self::nonce::{Nonce, NONCE_LEN};
 pub use self::rand_nonce::RandomizedNonceKey;
+pub use self::stream::{OpeningStream, SealingStream};
 pub use self::tls::{TlsProtocolId, TlsRecordOpeningKey, TlsRecordSealingKey};
 pub use self::unbound_key::UnboundKey;
 
@@ -249,6 +295,8 @@ pub struct OpeningKey<N: NonceSequence> {
     nonce_sequence: N,
 }
 
+impl<N: NonceSequence> ZeroizeOnDrop for OpeningKey<N> {}
+
 impl<N: NonceSequence> BoundKey<N> for OpeningKey<N> {
     fn new(key: UnboundKey, nonce_sequence: N) -> Self {
         Self {
@@ -388,6 +436,24 @@ impl<N: NonceSequence> OpeningKey<N> {
     pub fn prepare_nonce(&mut self) -> Result<OpeningKeyPreparedNonce<'_, N>, Unspecified> {
         OpeningKeyPreparedNonce::new(self)
     }
+
+    /// Replaces the `NonceSequence` bound to this key, returning a new `OpeningKey` that uses
+    /// the same underlying key with `nonce_sequence`.
+    ///
+    /// This avoids re-initializing the key from an [`UnboundKey`].
+    ///
+    /// # Security Considerations
+    /// The caller is responsible for ensuring that `nonce_sequence` never produces a nonce that
+    /// has already been used with this key. Resetting a sequence to a previously used position
+    /// results in nonce reuse, which breaks the confidentiality and integrity guarantees of the
+    /// AEAD.
+    #[must_use]
+    pub fn reset_nonce_sequence<M: NonceSequence>(self, nonce_sequence: M) -> OpeningKey<M> {
+        OpeningKey {
+            key: self.key,
+            nonce_sequence,
+        }
+    }
 }
 
 /// An AEAD key for encrypting and signing ("sealing"), bound to a nonce
@@ -402,6 +468,8 @@ pub struct SealingKey<N: NonceSequence> {
     nonce_sequence: N,
 }
 
+impl<N: NonceSequence> ZeroizeOnDrop for SealingKey<N> {}
+
 impl<N: NonceSequence> BoundKey<N> for SealingKey<N> {
     fn new(key: UnboundKey, nonce_sequence: N) -> Self {
         Self {
@@ -526,6 +594,24 @@ impl<N: NonceSequence> SealingKey<N> {
     pub fn prepare_nonce(&mut self) -> Result<SealingKeyPreparedNonce<'_, N>, Unspecified> {
         SealingKeyPreparedNonce::new(self)
     }
+
+    /// Replaces the `NonceSequence` bound to this key, returning a new `SealingKey` that uses
+    /// the same underlying key with `nonce_sequence`.
+    ///
+    /// This avoids re-initializing the key from an [`UnboundKey`].
+    ///
+    /// # Security Considerations
+    /// The caller is responsible for ensuring that `nonce_sequence` never produces a nonce that
+    /// has already been used with this key. Resetting a sequence to a previously used position
+    /// results in nonce reuse, which breaks the confidentiality and integrity guarantees of the
+    /// AEAD.
+    #[must_use]
+    pub fn reset_nonce_sequence<M: NonceSequence>(self, nonce_sequence: M) -> SealingKey<M> {
+        SealingKey {
+            key: self.key,
+            nonce_sequence,
+        }
+    }
 }
 
 macro_rules! nonce_seq_key_op_mut {
@@ -709,6 +795,8 @@ pub struct LessSafeKey {
     key: UnboundKey,
 }
 
+impl ZeroizeOnDrop for LessSafeKey {}
+
 impl LessSafeKey {
     /// Constructs a `LessSafeKey` from an `UnboundKey`.
     #[must_use]
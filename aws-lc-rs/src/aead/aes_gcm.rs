@@ -32,6 +32,15 @@ pub const AES_256_GCM: Algorithm = Algorithm {
 };
 
 /// AES-256 in GCM mode with nonce reuse resistance, 128-bit tags and 96 bit nonces.
+///
+/// Unlike AES-GCM, sealing the same plaintext with the same nonce and AAD always produces the
+/// same ciphertext, and reusing a nonce does not compromise the key or the authenticity of
+/// other messages ([RFC 8452]). Since the keystream is derived from the tag, which depends on
+/// the plaintext, sealing distinct plaintexts under one nonce does not reveal their XOR as it
+/// would with AES-GCM. An observer can still tell when identical inputs were sealed, so nonces
+/// should still be unique where that matters.
+///
+/// [RFC 8452]: https://www.rfc-editor.org/rfc/rfc8452
 pub const AES_256_GCM_SIV: Algorithm = Algorithm {
     init: init_256_aead_siv,
     key_len: AES_256_KEY_LEN,
@@ -40,6 +49,8 @@ pub const AES_256_GCM_SIV: Algorithm = Algorithm {
 };
 
 /// AES-128 in GCM mode with nonce reuse resistance, 128-bit tags and 96 bit nonces.
+///
+/// See [`AES_256_GCM_SIV`] for how nonce reuse affects AES-GCM-SIV.
 pub const AES_128_GCM_SIV: Algorithm = Algorithm {
     init: init_128_aead_siv,
     key_len: AES_128_KEY_LEN,
@@ -35,6 +35,12 @@ pub struct SealingKey {
     key: Key,
 }
 
+impl core::fmt::Debug for SealingKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SealingKey").finish_non_exhaustive()
+    }
+}
+
 impl SealingKey {
     /// Constructs a new `SealingKey`.
     #[must_use]
@@ -85,6 +91,12 @@ pub struct OpeningKey {
     key: Key,
 }
 
+impl core::fmt::Debug for OpeningKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("OpeningKey").finish_non_exhaustive()
+    }
+}
+
 impl OpeningKey {
     /// Constructs a new `OpeningKey`.
     #[must_use]
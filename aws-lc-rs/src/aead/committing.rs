@@ -0,0 +1,325 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+use super::{Aad, Algorithm, Nonce, UnboundKey};
+use crate::constant_time;
+use crate::error::Unspecified;
+use crate::hkdf;
+use core::fmt::Debug;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// The length of the key commitment that committing keys prepend to each ciphertext.
+pub const COMMITMENT_LEN: usize = 32;
+
+const COMMITMENT_LABEL: &[u8] = b"aws-lc-rs committing AEAD commitment";
+const KEY_LABEL: &[u8] = b"aws-lc-rs committing AEAD key";
+
+/// An AEAD key that commits to the key used to seal each ciphertext.
+///
+/// An AEAD such as AES-GCM does not commit to its key: an adversary who knows two keys can
+/// construct a ciphertext that opens successfully under both, to different plaintexts. This
+/// enables attacks on protocols, such as envelope encryption, that assume a ciphertext can only
+/// be opened by the key that sealed it.
+///
+/// Following the "UtC" transform of Bellare and Hoang, [Efficient Schemes for Committing
+/// Authenticated Encryption](https://eprint.iacr.org/2022/268), each message is sealed under a
+/// subkey derived with HKDF-SHA256 from the key and the nonce, and a [`COMMITMENT_LEN`]-byte
+/// commitment derived in the same way is prepended to the ciphertext. The resulting ciphertext
+/// is [`COMMITMENT_LEN`] bytes longer than that of the underlying algorithm, and can only be
+/// opened by a [`CommittingOpeningKey`] constructed from the same key.
+///
+/// Every algorithm is supported; the subkey has the length of the `UnboundKey`'s algorithm key.
+// # FIPS
+// This type must not be used.
+pub struct CommittingSealingKey {
+    key: CommittingKey,
+}
+
+impl ZeroizeOnDrop for CommittingSealingKey {}
+
+impl CommittingSealingKey {
+    /// Constructs a `CommittingSealingKey` from an `UnboundKey`.
+    #[must_use]
+    pub fn new(key: UnboundKey) -> Self {
+        Self {
+            key: CommittingKey::new(&key),
+        }
+    }
+
+    /// Encrypts and signs (“seals”) data in place, prepending the key commitment and appending
+    /// the tag.
+    ///
+    /// On output, `in_out` contains the commitment, followed by the ciphertext, followed by the
+    /// tag, and is [`COMMITMENT_LEN`] + `self.algorithm().tag_len()` bytes longer than on input.
+    ///
+    /// `nonce` must be unique for every use of the key to seal data.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if encryption operation fails.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn seal_in_place_append_tag<A, InOut>(
+        &self,
+        nonce: Nonce,
+        aad: Aad<A>,
+        in_out: &mut InOut,
+    ) -> Result<(), Unspecified>
+    where
+        A: AsRef<[u8]>,
+        InOut: AsMut<[u8]> + for<'in_out> Extend<&'in_out u8>,
+    {
+        let (commitment, subkey) = self.key.derive(&nonce)?;
+        let plaintext_len = in_out.as_mut().len();
+        let (_, tag) =
+            subkey.seal_in_place_separate_tag(Some(nonce), aad.as_ref(), in_out.as_mut())?;
+        in_out.extend(commitment.iter());
+        in_out.as_mut()[..plaintext_len + COMMITMENT_LEN].rotate_right(COMMITMENT_LEN);
+        in_out.extend(tag.as_ref().iter());
+        Ok(())
+    }
+
+    /// The key's AEAD algorithm.
+    #[inline]
+    #[must_use]
+    pub fn algorithm(&self) -> &'static Algorithm {
+        self.key.algorithm
+    }
+}
+
+impl Debug for CommittingSealingKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.debug_struct("CommittingSealingKey")
+            .field("algorithm", self.algorithm())
+            .finish()
+    }
+}
+
+/// An AEAD key that opens ciphertexts sealed by a [`CommittingSealingKey`].
+// # FIPS
+// This type must not be used.
+pub struct CommittingOpeningKey {
+    key: CommittingKey,
+}
+
+impl ZeroizeOnDrop for CommittingOpeningKey {}
+
+impl CommittingOpeningKey {
+    /// Constructs a `CommittingOpeningKey` from an `UnboundKey`.
+    #[must_use]
+    pub fn new(key: UnboundKey) -> Self {
+        Self {
+            key: CommittingKey::new(&key),
+        }
+    }
+
+    /// Verifies the key commitment, then authenticates and decrypts (“opens”) data in place.
+    ///
+    /// On input, `in_out` must be the commitment, followed by the ciphertext, followed by the
+    /// tag. When `open_in_place()` returns `Ok(plaintext)`, the ciphertext has been overwritten
+    /// by the plaintext; `plaintext` refers to the plaintext without the commitment or the tag.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if the commitment does not match this key, or when the ciphertext is
+    /// invalid. The ciphertext is not decrypted if the commitment does not match.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn open_in_place<'in_out, A>(
+        &self,
+        nonce: Nonce,
+        aad: Aad<A>,
+        in_out: &'in_out mut [u8],
+    ) -> Result<&'in_out mut [u8], Unspecified>
+    where
+        A: AsRef<[u8]>,
+    {
+        if in_out.len() < COMMITMENT_LEN {
+            return Err(Unspecified);
+        }
+        let (commitment, ciphertext_and_tag) = in_out.split_at_mut(COMMITMENT_LEN);
+        let (expected_commitment, subkey) = self.key.derive(&nonce)?;
+        constant_time::verify_slices_are_equal(commitment, &expected_commitment)?;
+        subkey.open_within(nonce, aad.as_ref(), ciphertext_and_tag, 0..)
+    }
+
+    /// The key's AEAD algorithm.
+    #[inline]
+    #[must_use]
+    pub fn algorithm(&self) -> &'static Algorithm {
+        self.key.algorithm
+    }
+}
+
+impl Debug for CommittingOpeningKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.debug_struct("CommittingOpeningKey")
+            .field("algorithm", self.algorithm())
+            .finish()
+    }
+}
+
+struct CommittingKey {
+    prk: hkdf::Prk,
+    algorithm: &'static Algorithm,
+}
+
+impl CommittingKey {
+    fn new(key: &UnboundKey) -> Self {
+        Self {
+            // An empty salt is equivalent to the `HashLen` zero bytes of an unsalted extract.
+            prk: hkdf::Salt::new(hkdf::HKDF_SHA256, &[]).extract(key.key_bytes()),
+            algorithm: key.algorithm(),
+        }
+    }
+
+    // Derives the commitment and the subkey that seals the message with `nonce`.
+    fn derive(&self, nonce: &Nonce) -> Result<([u8; COMMITMENT_LEN], UnboundKey), Unspecified> {
+        let mut commitment = [0u8; COMMITMENT_LEN];
+        self.expand(COMMITMENT_LABEL, nonce, &mut commitment)?;
+
+        let mut subkey = [0u8; super::MAX_KEY_LEN];
+        let subkey = &mut subkey[..self.algorithm.key_len()];
+        self.expand(KEY_LABEL, nonce, subkey)?;
+        let result = UnboundKey::new(self.algorithm, subkey);
+        subkey.zeroize();
+        Ok((commitment, result?))
+    }
+
+    fn expand(&self, label: &[u8], nonce: &Nonce, out: &mut [u8]) -> Result<(), Unspecified> {
+        hkdf::expand(&self.prk, &[label, nonce.as_ref()].concat(), out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CommittingKey, CommittingOpeningKey, CommittingSealingKey, KEY_LABEL};
+    use crate::aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM};
+    use crate::cipher::{EncryptingKey, EncryptionContext, UnboundCipherKey, AES_256};
+    use crate::iv::FixedLength;
+
+    const NONCE: [u8; 12] = [0x24; 12];
+
+    // Multiplication in GF(2^128) with GCM's bit order.
+    fn gf_mul(x: u128, y: u128) -> u128 {
+        let mut z = 0;
+        let mut v = y;
+        for i in 0..128 {
+            if x >> (127 - i) & 1 == 1 {
+                z ^= v;
+            }
+            v = if v & 1 == 1 {
+                (v >> 1) ^ (0xE1 << 120)
+            } else {
+                v >> 1
+            };
+        }
+        z
+    }
+
+    fn gf_inv(x: u128) -> u128 {
+        // x^(2^128 - 2)
+        let mut result = 1 << 127;
+        let mut power = x;
+        for _ in 1..128 {
+            power = gf_mul(power, power);
+            result = gf_mul(result, power);
+        }
+        result
+    }
+
+    fn aes_256_block(key: &[u8], block: u128) -> u128 {
+        let key = UnboundCipherKey::new(&AES_256, key).unwrap();
+        let key = EncryptingKey::ctr(key).unwrap();
+        let mut out = [0u8; 16];
+        key.less_safe_encrypt(
+            &mut out,
+            EncryptionContext::Iv128(FixedLength::from(block.to_be_bytes())),
+        )
+        .unwrap();
+        u128::from_be_bytes(out)
+    }
+
+    // Constructs a two-block AES-256-GCM ciphertext and tag, with an empty AAD, that are valid
+    // under both `key1` and `key2`.
+    fn dual_key_ciphertext(key1: &[u8], key2: &[u8]) -> Vec<u8> {
+        let mut j0 = [0u8; 16];
+        j0[..12].copy_from_slice(&NONCE);
+        j0[15] = 1;
+        let j0 = u128::from_be_bytes(j0);
+        let (h1, h2) = (aes_256_block(key1, 0), aes_256_block(key2, 0));
+        let (e1, e2) = (aes_256_block(key1, j0), aes_256_block(key2, j0));
+        let lengths = 256u128;
+
+        // tag = c1·H^3 + c2·H^2 + lengths·H + E(J0); solve tag1 = tag2 for c2.
+        let c1 = 0x0123_4567_89ab_cdef_0123_4567_89ab_cdef_u128;
+        let (h1_2, h2_2) = (gf_mul(h1, h1), gf_mul(h2, h2));
+        let (h1_3, h2_3) = (gf_mul(h1_2, h1), gf_mul(h2_2, h2));
+        let c2 = gf_mul(
+            gf_mul(c1, h1_3 ^ h2_3) ^ gf_mul(lengths, h1 ^ h2) ^ e1 ^ e2,
+            gf_inv(h1_2 ^ h2_2),
+        );
+        let tag = gf_mul(c1, h1_3) ^ gf_mul(c2, h1_2) ^ gf_mul(lengths, h1) ^ e1;
+
+        [c1.to_be_bytes(), c2.to_be_bytes(), tag.to_be_bytes()].concat()
+    }
+
+    fn subkey(key: &[u8]) -> Vec<u8> {
+        let key = CommittingKey::new(&UnboundKey::new(&AES_256_GCM, key).unwrap());
+        let mut subkey = vec![0u8; 32];
+        key.expand(KEY_LABEL, &Nonce::assume_unique_for_key(NONCE), &mut subkey)
+            .unwrap();
+        subkey
+    }
+
+    #[test]
+    fn test_gf_inv() {
+        let x = 0x66e9_4bd4_ef8a_2c3b_884c_fa59_ca34_2b2e_u128;
+        assert_eq!(1 << 127, gf_mul(x, gf_inv(x)));
+    }
+
+    #[test]
+    fn test_dual_key_ciphertext_rejected() {
+        let key1 = [0x11u8; 32];
+        let key2 = [0x22u8; 32];
+
+        // The adversary crafts a body that the underlying AEAD opens under both subkeys.
+        let (subkey1, subkey2) = (subkey(&key1), subkey(&key2));
+        let body = dual_key_ciphertext(&subkey1, &subkey2);
+        for subkey in [&subkey1, &subkey2] {
+            let key = LessSafeKey::new(UnboundKey::new(&AES_256_GCM, subkey).unwrap());
+            let mut in_out = body.clone();
+            key.open_in_place(
+                Nonce::assume_unique_for_key(NONCE),
+                Aad::empty(),
+                &mut in_out,
+            )
+            .unwrap();
+        }
+
+        // With `key1`'s commitment prepended, only `key1` opens it.
+        let mut sealed = Vec::new();
+        CommittingSealingKey::new(UnboundKey::new(&AES_256_GCM, &key1).unwrap())
+            .seal_in_place_append_tag(
+                Nonce::assume_unique_for_key(NONCE),
+                Aad::empty(),
+                &mut sealed,
+            )
+            .unwrap();
+        let mut ciphertext = sealed[..super::COMMITMENT_LEN].to_vec();
+        ciphertext.extend_from_slice(&body);
+
+        let opening_key1 = CommittingOpeningKey::new(UnboundKey::new(&AES_256_GCM, &key1).unwrap());
+        let opening_key2 = CommittingOpeningKey::new(UnboundKey::new(&AES_256_GCM, &key2).unwrap());
+        assert!(opening_key1
+            .open_in_place(
+                Nonce::assume_unique_for_key(NONCE),
+                Aad::empty(),
+                &mut ciphertext.clone()
+            )
+            .is_ok());
+        assert!(opening_key2
+            .open_in_place(
+                Nonce::assume_unique_for_key(NONCE),
+                Aad::empty(),
+                &mut ciphertext
+            )
+            .is_err());
+    }
+}
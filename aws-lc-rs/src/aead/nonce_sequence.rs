@@ -5,6 +5,36 @@
 
 mod counter32;
 mod counter64;
+mod unpredictable;
 
 pub use counter32::{Counter32, Counter32Builder};
 pub use counter64::{Counter64, Counter64Builder};
+pub use unpredictable::{NsAlgorithm, UnpredictableNonceSequence};
+
+/// A checkpoint of the position of a nonce sequence, from which the sequence can be resumed,
+/// e.g. after a process restart.
+///
+/// The state holds no key material, so the key of the sequence must be stored separately.
+/// The state must be persisted before any nonce generated after the checkpoint is used;
+/// resuming from an earlier checkpoint repeats nonces.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NonceSequenceState {
+    /// The position at which the next nonce will be generated.
+    pub position: u64,
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for NonceSequenceState {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.position, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for NonceSequenceState {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let position = <u64 as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(Self { position })
+    }
+}
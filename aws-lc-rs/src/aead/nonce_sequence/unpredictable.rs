@@ -0,0 +1,243 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+use crate::aead::nonce_sequence::NonceSequenceState;
+use crate::aead::{Nonce, NonceSequence, NONCE_LEN};
+use crate::cipher::block::{Block, BLOCK_LEN};
+use crate::cipher::chacha::{self, encrypt_block_chacha20};
+use crate::cipher::key::SymmetricCipherKey;
+use crate::error::Unspecified;
+use crate::iv::FixedLength;
+
+/// The pseudorandom function used by an [`UnpredictableNonceSequence`].
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NsAlgorithm {
+    /// AES-128, encrypting the big-endian position as a single block.
+    Aes128,
+
+    /// ChaCha20, using the position as the 64-bit block counter.
+    ChaCha20,
+}
+
+/// `UnpredictableNonceSequence` is an implementation of the `NonceSequence` trait.
+///
+/// The internal state is a 64-bit position that increments on each call to `advance`. Each nonce
+/// is the first `NONCE_LEN` bytes of a pseudorandom function of the position, keyed with a
+/// secret that is independent of the AEAD key, so that nonces cannot be predicted by an observer
+/// who does not know that secret:
+/// * [`NsAlgorithm::Aes128`] encrypts the position, as a 128-bit big-endian integer, with AES-128.
+/// * [`NsAlgorithm::ChaCha20`] generates the ChaCha20 keystream block at the position, using the
+///   original 64-bit block counter and a zero nonce.
+///
+/// Since the output is truncated, distinct positions are not guaranteed to produce distinct
+/// nonces. As with randomly generated nonces, no more than 2<sup>32</sup> nonces should be
+/// generated with a single AEAD key.
+#[allow(clippy::module_name_repetitions)]
+pub struct UnpredictableNonceSequence {
+    key: SymmetricCipherKey,
+    position: u64,
+}
+
+impl UnpredictableNonceSequence {
+    /// Constructs an `UnpredictableNonceSequence` using `algorithm` keyed with `key`, with the
+    /// first nonce generated at `position`.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if `key` is not the correct length for `algorithm`.
+    pub fn new(algorithm: NsAlgorithm, key: &[u8], position: u64) -> Result<Self, Unspecified> {
+        let key = match algorithm {
+            NsAlgorithm::Aes128 => SymmetricCipherKey::aes128(key)?,
+            NsAlgorithm::ChaCha20 => SymmetricCipherKey::chacha20(key)?,
+        };
+        Ok(Self { key, position })
+    }
+
+    /// Constructs an `UnpredictableNonceSequence` using ChaCha20 keyed with `key`, with the
+    /// first nonce generated at `position`.
+    #[must_use]
+    pub fn with_chacha20(key: &[u8; chacha::KEY_LEN], position: u64) -> Self {
+        Self {
+            key: SymmetricCipherKey::ChaCha20 {
+                raw_key: (*key).into(),
+            },
+            position,
+        }
+    }
+
+    /// The algorithm used to generate nonces.
+    #[must_use]
+    pub fn algorithm(&self) -> NsAlgorithm {
+        match self.key {
+            SymmetricCipherKey::ChaCha20 { .. } => NsAlgorithm::ChaCha20,
+            _ => NsAlgorithm::Aes128,
+        }
+    }
+
+    /// The position at which the next nonce will be generated.
+    #[must_use]
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// The number of nonces that can still be generated before the sequence is exhausted, after
+    /// which `advance` returns an error rather than repeating a position.
+    #[must_use]
+    pub fn remaining(&self) -> u64 {
+        u64::MAX - self.position
+    }
+
+    /// Checkpoints the position of the sequence, so that it can be resumed with
+    /// [`UnpredictableNonceSequence::from_state`].
+    #[must_use]
+    pub fn state(&self) -> NonceSequenceState {
+        NonceSequenceState {
+            position: self.position,
+        }
+    }
+
+    /// Resumes the sequence using `algorithm` keyed with `key` from `state`, as returned by
+    /// [`UnpredictableNonceSequence::state`].
+    ///
+    /// # Errors
+    /// `error::Unspecified` if `key` is not the correct length for `algorithm`.
+    pub fn from_state(
+        algorithm: NsAlgorithm,
+        key: &[u8],
+        state: NonceSequenceState,
+    ) -> Result<Self, Unspecified> {
+        Self::new(algorithm, key, state.position)
+    }
+
+    fn compute_block(&self) -> Result<Block, Unspecified> {
+        match &self.key {
+            SymmetricCipherKey::ChaCha20 { raw_key } => {
+                // The 64-bit block counter is split between the 32-bit counter and the first
+                // word of the nonce, both of which are little-endian.
+                let mut nonce = [0u8; chacha::NONCE_LEN];
+                #[allow(clippy::cast_possible_truncation)]
+                let (high, low) = ((self.position >> 32) as u32, self.position as u32);
+                nonce[..4].copy_from_slice(&high.to_le_bytes());
+                encrypt_block_chacha20(raw_key, Block::zero(), &nonce, low)
+            }
+            key => {
+                let mut block = [0u8; BLOCK_LEN];
+                block[BLOCK_LEN - 8..].copy_from_slice(&self.position.to_be_bytes());
+                Ok(key.encrypt_block(Block::from(block)))
+            }
+        }
+    }
+}
+
+impl NonceSequence for UnpredictableNonceSequence {
+    fn advance(&mut self) -> Result<Nonce, Unspecified> {
+        let next_position = self.position.checked_add(1).ok_or(Unspecified)?;
+        let block = self.compute_block()?;
+        let mut nonce_bytes = [0u8; NONCE_LEN];
+        nonce_bytes.copy_from_slice(&block.as_ref()[..NONCE_LEN]);
+        self.position = next_position;
+        Ok(Nonce(FixedLength::from(nonce_bytes)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aead::nonce_sequence::{
+        NonceSequenceState, NsAlgorithm, UnpredictableNonceSequence,
+    };
+    use crate::aead::NonceSequence;
+    use crate::test::from_hex;
+
+    #[test]
+    fn test_chacha20_keystream() {
+        // RFC 8439, Appendix A.1, Test Vectors #1 and #2
+        let mut ns = UnpredictableNonceSequence::with_chacha20(&[0u8; 32], 0);
+        assert_eq!(NsAlgorithm::ChaCha20, ns.algorithm());
+        assert_eq!(
+            from_hex("76b8e0ada0f13d90405d6ae5").unwrap().as_slice(),
+            ns.advance().unwrap().as_ref()
+        );
+        assert_eq!(
+            from_hex("9f07e7be5551387a98ba977c").unwrap().as_slice(),
+            ns.advance().unwrap().as_ref()
+        );
+        assert_eq!(2, ns.position());
+
+        // Block counter 2^32 + 5 carries into the first word of the nonce.
+        let mut ns = UnpredictableNonceSequence::with_chacha20(&[0u8; 32], (1 << 32) + 5);
+        assert_eq!(
+            from_hex("e8c70e7c19c1d66e3f902bea").unwrap().as_slice(),
+            ns.advance().unwrap().as_ref()
+        );
+    }
+
+    #[test]
+    fn test_aes128() {
+        let key = from_hex("000102030405060708090a0b0c0d0e0f").unwrap();
+        let mut ns = UnpredictableNonceSequence::new(NsAlgorithm::Aes128, &key, 0).unwrap();
+        assert_eq!(NsAlgorithm::Aes128, ns.algorithm());
+        // Generated with `openssl enc -aes-128-ecb -nopad`.
+        assert_eq!(
+            from_hex("c6a13b37878f5b826f4f8162").unwrap().as_slice(),
+            ns.advance().unwrap().as_ref()
+        );
+        assert_eq!(
+            from_hex("7346139595c0b41e497bbde3").unwrap().as_slice(),
+            ns.advance().unwrap().as_ref()
+        );
+
+        assert!(UnpredictableNonceSequence::new(NsAlgorithm::Aes128, &[0u8; 32], 0).is_err());
+        assert!(UnpredictableNonceSequence::new(NsAlgorithm::ChaCha20, &key, 0).is_err());
+    }
+
+    #[test]
+    fn test_same_key_and_position() {
+        let key = [0x42u8; 32];
+        let mut ns1 = UnpredictableNonceSequence::with_chacha20(&key, 1000);
+        let mut ns2 = UnpredictableNonceSequence::new(NsAlgorithm::ChaCha20, &key, 1000).unwrap();
+        let mut ns3 = UnpredictableNonceSequence::with_chacha20(&[0x24u8; 32], 1000);
+        let mut previous = Vec::new();
+        for _ in 0..16 {
+            let nonce = ns1.advance().unwrap();
+            assert_eq!(nonce.as_ref(), ns2.advance().unwrap().as_ref());
+            assert_ne!(nonce.as_ref(), ns3.advance().unwrap().as_ref());
+            assert!(!previous.contains(nonce.as_ref()));
+            previous.push(*nonce.as_ref());
+        }
+    }
+
+    #[test]
+    fn test_resume_from_state() {
+        let key = [0x42u8; 16];
+        let mut ns = UnpredictableNonceSequence::new(NsAlgorithm::Aes128, &key, 0).unwrap();
+        assert_eq!(u64::MAX, ns.remaining());
+        for _ in 0..1000 {
+            ns.advance().unwrap();
+        }
+        let state = ns.state();
+        assert_eq!(NonceSequenceState { position: 1000 }, state);
+
+        let mut resumed =
+            UnpredictableNonceSequence::from_state(NsAlgorithm::Aes128, &key, state).unwrap();
+        assert_eq!(
+            ns.advance().unwrap().as_ref(),
+            resumed.advance().unwrap().as_ref()
+        );
+        assert_eq!(ns.state(), resumed.state());
+
+        assert!(
+            UnpredictableNonceSequence::from_state(NsAlgorithm::ChaCha20, &key, state).is_err()
+        );
+    }
+
+    #[test]
+    fn test_exhausted() {
+        let mut ns = UnpredictableNonceSequence::with_chacha20(&[0u8; 32], u64::MAX - 1);
+        assert_eq!(1, ns.remaining());
+        assert!(ns.advance().is_ok());
+        assert_eq!(0, ns.remaining());
+        assert!(ns.advance().is_err());
+        assert_eq!(u64::MAX, ns.position());
+        assert_eq!(0, ns.remaining());
+    }
+}
@@ -9,18 +9,24 @@ use super::{Tag, TAG_LEN};
 use crate::aws_lc::{CRYPTO_poly1305_finish, CRYPTO_poly1305_init, CRYPTO_poly1305_update};
 use crate::cipher::block::BLOCK_LEN;
 use core::mem::MaybeUninit;
+use zeroize::Zeroize;
 
 /// A Poly1305 key.
-pub(super) struct Key {
+pub(crate) struct Key {
     pub(super) key_and_nonce: [u8; KEY_LEN],
 }
 
-const KEY_LEN: usize = 2 * BLOCK_LEN;
+pub(crate) const KEY_LEN: usize = 2 * BLOCK_LEN;
+
+impl Drop for Key {
+    fn drop(&mut self) {
+        self.key_and_nonce.zeroize();
+    }
+}
 
 impl Key {
     #[inline]
-    #[allow(dead_code)]
-    pub(super) fn new(key_and_nonce: [u8; KEY_LEN]) -> Self {
+    pub(crate) fn new(key_and_nonce: [u8; KEY_LEN]) -> Self {
         Self { key_and_nonce }
     }
 }
@@ -38,11 +44,13 @@ pub struct Context {
 struct poly1305_state(aws_lc::poly1305_state);
 
 impl Context {
+    // Takes `key` by value without destructuring it, so that its only copy is zeroized when it
+    // is dropped at the end of this function.
     #[inline]
-    pub(super) fn from_key(Key { key_and_nonce }: Key) -> Self {
+    pub(crate) fn from_key(key: Key) -> Self {
         unsafe {
             let mut state = MaybeUninit::<poly1305_state>::uninit();
-            CRYPTO_poly1305_init(state.as_mut_ptr().cast(), key_and_nonce.as_ptr());
+            CRYPTO_poly1305_init(state.as_mut_ptr().cast(), key.key_and_nonce.as_ptr());
             Self {
                 state: state.assume_init(),
             }
@@ -61,7 +69,7 @@ impl Context {
     }
 
     #[inline]
-    pub(super) fn finish(mut self) -> Tag {
+    pub(crate) fn finish(mut self) -> Tag {
         unsafe {
             let mut tag = MaybeUninit::<[u8; TAG_LEN]>::uninit();
             CRYPTO_poly1305_finish(self.state.0.as_mut_ptr().cast(), tag.as_mut_ptr().cast());
@@ -71,6 +79,13 @@ impl Context {
     }
 }
 
+// The state holds the key's `r` and `s` values until the tag is computed.
+impl Drop for Context {
+    fn drop(&mut self) {
+        self.state.0.zeroize();
+    }
+}
+
 /// Implements the original, non-IETF padding semantics.
 ///
 /// This is used by `chacha20_poly1305_openssh` and the standalone
@@ -106,4 +121,15 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn context_state_zeroized_on_drop() {
+        let mut ctx = Context::from_key(Key::new([0x5a; KEY_LEN]));
+        ctx.update(b"message");
+        assert!(ctx.state.0.iter().any(|b| *b != 0));
+
+        unsafe { core::ptr::drop_in_place(&mut ctx) };
+        assert!(ctx.state.0.iter().all(|b| *b == 0));
+        core::mem::forget(ctx);
+    }
 }
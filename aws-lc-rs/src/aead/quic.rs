@@ -20,6 +20,14 @@ pub struct HeaderProtectionKey {
     algorithm: &'static Algorithm,
 }
 
+impl core::fmt::Debug for HeaderProtectionKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("HeaderProtectionKey")
+            .field("algorithm", self.algorithm)
+            .finish_non_exhaustive()
+    }
+}
+
 impl From<hkdf::Okm<'_, &'static Algorithm>> for HeaderProtectionKey {
     fn from(okm: hkdf::Okm<&'static Algorithm>) -> Self {
         let mut key_bytes = [0; super::MAX_KEY_LEN];
@@ -3,9 +3,11 @@
 
 use crate::error::Unspecified;
 use core::fmt::Debug;
+use zeroize::ZeroizeOnDrop;
 
 use super::aead_ctx::AeadCtx;
-use super::{Aad, Algorithm, AlgorithmID, Nonce, Tag, UnboundKey};
+use super::unbound_key::AeadKey;
+use super::{Aad, Algorithm, AlgorithmID, Nonce, Tag};
 
 /// AEAD Cipher key using a randomized nonce.
 ///
@@ -17,10 +19,12 @@ use super::{Aad, Algorithm, AlgorithmID, Nonce, Tag, UnboundKey};
 ///
 /// Prefer this type in place of `LessSafeKey`, `OpeningKey`, `SealingKey`.
 pub struct RandomizedNonceKey {
-    key: UnboundKey,
+    key: AeadKey,
     algorithm: &'static Algorithm,
 }
 
+impl ZeroizeOnDrop for RandomizedNonceKey {}
+
 impl RandomizedNonceKey {
     /// New Random Nonce Sequence
     /// # Errors
@@ -42,7 +46,7 @@ impl RandomizedNonceKey {
             | AlgorithmID::CHACHA20_POLY1305 => return Err(Unspecified),
         }?;
         Ok(Self {
-            key: UnboundKey::from(ctx),
+            key: AeadKey::from(ctx),
             algorithm,
         })
     }
@@ -0,0 +1,121 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+use super::{Aad, Algorithm, BoundKey, NonceSequence, OpeningKey, SealingKey};
+use crate::error::Unspecified;
+use core::fmt::Debug;
+
+/// Seals a stream of data as a sequence of independently sealed chunks.
+///
+/// Each chunk is sealed with the next nonce of the `NonceSequence` bound to the
+/// `SealingKey`, so the chunks must be opened by an [`OpeningStream`] whose
+/// `NonceSequence` produces the same nonces, in the same order.
+///
+/// The stream does not protect against truncation: a reader that stops before
+/// the last chunk cannot detect that chunks are missing. Callers that need this
+/// must authenticate the end of the stream, e.g. by marking the final chunk in its
+/// `aad`.
+pub struct SealingStream<N: NonceSequence> {
+    key: SealingKey<N>,
+}
+
+impl<N: NonceSequence> SealingStream<N> {
+    /// Constructs a new `SealingStream` from `key`.
+    #[must_use]
+    pub fn new(key: SealingKey<N>) -> Self {
+        Self { key }
+    }
+
+    /// Seals the next chunk of the stream, returning the ciphertext followed by the tag.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if the `NonceSequence` is exhausted, or if sealing fails.
+    pub fn seal_chunk(&mut self, aad: &[u8], plaintext: &[u8]) -> Result<Vec<u8>, Unspecified> {
+        let mut in_out = Vec::with_capacity(plaintext.len() + self.algorithm().tag_len());
+        in_out.extend_from_slice(plaintext);
+        self.key
+            .seal_in_place_append_tag(Aad::from(aad), &mut in_out)?;
+        Ok(in_out)
+    }
+
+    /// The key's AEAD algorithm.
+    #[must_use]
+    pub fn algorithm(&self) -> &'static Algorithm {
+        self.key.algorithm()
+    }
+
+    /// Returns the underlying `SealingKey`.
+    #[must_use]
+    pub fn into_inner(self) -> SealingKey<N> {
+        self.key
+    }
+}
+
+impl<N: NonceSequence> Debug for SealingStream<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.debug_struct("SealingStream")
+            .field("algorithm", &self.algorithm())
+            .finish()
+    }
+}
+
+/// Opens a stream of chunks sealed by a [`SealingStream`].
+///
+/// Chunks must be opened in the order they were sealed. A chunk that is
+/// reordered, replayed or skipped is opened with the wrong nonce, and is
+/// rejected. Once a chunk has been rejected, the nonce it consumed is not
+/// reused, so all subsequent chunks are rejected too.
+pub struct OpeningStream<N: NonceSequence> {
+    key: OpeningKey<N>,
+}
+
+impl<N: NonceSequence> OpeningStream<N> {
+    /// Constructs a new `OpeningStream` from `key`.
+    #[must_use]
+    pub fn new(key: OpeningKey<N>) -> Self {
+        Self { key }
+    }
+
+    /// Opens the next chunk of the stream in place.
+    ///
+    /// On input, `ciphertext` must be the ciphertext followed by the tag, as
+    /// returned by [`SealingStream::seal_chunk`]. On success, `ciphertext` is
+    /// truncated to the plaintext, which is also returned.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if the chunk is not authentic, is out of order, or if
+    /// the `NonceSequence` is exhausted. In this case, `ciphertext` may have been
+    /// overwritten in an unspecified way.
+    pub fn open_chunk<'in_out>(
+        &mut self,
+        aad: &[u8],
+        ciphertext: &'in_out mut Vec<u8>,
+    ) -> Result<&'in_out [u8], Unspecified> {
+        let plaintext_len = self
+            .key
+            .open_in_place(Aad::from(aad), ciphertext.as_mut_slice())?
+            .len();
+        ciphertext.truncate(plaintext_len);
+        Ok(ciphertext.as_slice())
+    }
+
+    /// The key's AEAD algorithm.
+    #[must_use]
+    pub fn algorithm(&self) -> &'static Algorithm {
+        self.key.algorithm()
+    }
+
+    /// Returns the underlying `OpeningKey`.
+    #[must_use]
+    pub fn into_inner(self) -> OpeningKey<N> {
+        self.key
+    }
+}
+
+impl<N: NonceSequence> Debug for OpeningStream<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.debug_struct("OpeningStream")
+            .field("algorithm", &self.algorithm())
+            .finish()
+    }
+}
@@ -2,10 +2,12 @@
 // SPDX-License-Identifier: Apache-2.0 OR ISC
 
 use super::aead_ctx::{self, AeadCtx};
-use super::{Aad, Algorithm, AlgorithmID, Nonce, Tag, UnboundKey};
+use super::unbound_key::AeadKey;
+use super::{Aad, Algorithm, AlgorithmID, Nonce, Tag};
 use crate::error::Unspecified;
 use core::fmt::Debug;
 use core::ops::RangeFrom;
+use zeroize::ZeroizeOnDrop;
 
 /// The Transport Layer Security (TLS) protocol version.
 #[allow(clippy::module_name_repetitions)]
@@ -35,10 +37,12 @@ pub struct TlsRecordSealingKey {
     // The choice here was either wrap the underlying EVP_AEAD_CTX in a Mutex as done here,
     // or force this type to !Sync. Since this is an implementation detail of AWS-LC
     // we have optex to manage this behavior internally.
-    key: UnboundKey,
+    key: AeadKey,
     protocol: TlsProtocolId,
 }
 
+impl ZeroizeOnDrop for TlsRecordSealingKey {}
+
 impl TlsRecordSealingKey {
     /// New TLS record sealing key. Only supports `AES_128_GCM` and `AES_256_GCM`.
     ///
@@ -80,7 +84,7 @@ impl TlsRecordSealingKey {
             ) => Err(Unspecified),
         }?;
         Ok(Self {
-            key: UnboundKey::from(ctx),
+            key: AeadKey::from(ctx),
             protocol,
         })
     }
@@ -181,10 +185,12 @@ pub struct TlsRecordOpeningKey {
     // The choice here was either wrap the underlying EVP_AEAD_CTX in a Mutex as done here,
     // or force this type to !Sync. Since this is an implementation detail of AWS-LC
     // we have optex to manage this behavior internally.
-    key: UnboundKey,
+    key: AeadKey,
     protocol: TlsProtocolId,
 }
 
+impl ZeroizeOnDrop for TlsRecordOpeningKey {}
+
 impl TlsRecordOpeningKey {
     /// New TLS record opening key. Only supports `AES_128_GCM` and `AES_256_GCM` Algorithms.
     ///
@@ -226,7 +232,7 @@ impl TlsRecordOpeningKey {
             ) => Err(Unspecified),
         }?;
         Ok(Self {
-            key: UnboundKey::from(ctx),
+            key: AeadKey::from(ctx),
             protocol,
         })
     }
@@ -9,6 +9,7 @@ use super::{
 use crate::aws_lc::{
     EVP_AEAD_CTX_open, EVP_AEAD_CTX_open_gather, EVP_AEAD_CTX_seal, EVP_AEAD_CTX_seal_scatter,
 };
+use crate::buffer::Buffer;
 use crate::error::Unspecified;
 use crate::fips::indicator_check;
 use crate::hkdf;
@@ -17,6 +18,7 @@ use core::fmt::Debug;
 use core::mem::MaybeUninit;
 use core::ops::RangeFrom;
 use core::ptr::null;
+use zeroize::ZeroizeOnDrop;
 
 /// The maximum length of a nonce returned by our AEAD API.
 const MAX_NONCE_LEN: usize = NONCE_LEN;
@@ -25,16 +27,25 @@ const MAX_NONCE_LEN: usize = NONCE_LEN;
 const MAX_TAG_NONCE_BUFFER_LEN: usize = MAX_TAG_LEN + MAX_NONCE_LEN;
 
 /// An AEAD key without a designated role or nonce sequence.
+///
+/// The key material is zeroed when the key is dropped.
 pub struct UnboundKey {
-    ctx: AeadCtx,
-    algorithm: &'static Algorithm,
+    inner: AeadKey,
+    // AWS-LC cannot copy an `EVP_AEAD_CTX`, so the key bytes are retained to
+    // construct the context of a copy and to derive committing subkeys.
+    key_bytes: Buffer<'static, &'static [u8]>,
 }
 
+// The key bytes are held in a `Buffer`, which zeroes them on drop, and AWS-LC cleanses the
+// `EVP_AEAD_CTX` when it is freed.
+impl ZeroizeOnDrop for UnboundKey {}
+
 #[allow(clippy::missing_fields_in_debug)]
 impl Debug for UnboundKey {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
         f.debug_struct("UnboundKey")
-            .field("algorithm", &self.algorithm)
+            .field("algorithm", &self.inner.algorithm)
+            .field("key_len", &self.inner.algorithm.key_len())
             .finish()
     }
 }
@@ -45,11 +56,119 @@ impl UnboundKey {
     /// `error::Unspecified` if `key_bytes.len() != algorithm.key_len()`.
     pub fn new(algorithm: &'static Algorithm, key_bytes: &[u8]) -> Result<Self, Unspecified> {
         Ok(Self {
-            ctx: (algorithm.init)(key_bytes, algorithm.tag_len())?,
-            algorithm,
+            inner: AeadKey {
+                ctx: (algorithm.init)(key_bytes, algorithm.tag_len())?,
+                algorithm,
+            },
+            key_bytes: Buffer::new(key_bytes.to_vec()),
         })
     }
 
+    /// Returns an independent copy of this key, with its own AEAD context.
+    ///
+    /// Each copy zeroizes its key material when dropped, regardless of the other.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if the AEAD context of the copy cannot be initialized.
+    pub fn try_clone(&self) -> Result<Self, Unspecified> {
+        Self::new(self.inner.algorithm, self.key_bytes.as_ref())
+    }
+
+    #[inline]
+    pub(crate) fn open_within<'in_out>(
+        &self,
+        nonce: Nonce,
+        aad: &[u8],
+        in_out: &'in_out mut [u8],
+        ciphertext_and_tag: RangeFrom<usize>,
+    ) -> Result<&'in_out mut [u8], Unspecified> {
+        self.inner
+            .open_within(nonce, aad, in_out, ciphertext_and_tag)
+    }
+
+    #[inline]
+    pub(crate) fn open_separate_gather(
+        &self,
+        nonce: &Nonce,
+        aad: &[u8],
+        in_ciphertext: &[u8],
+        in_tag: &[u8],
+        out_plaintext: &mut [u8],
+    ) -> Result<(), Unspecified> {
+        self.inner
+            .open_separate_gather(nonce, aad, in_ciphertext, in_tag, out_plaintext)
+    }
+
+    #[inline]
+    pub(crate) fn seal_in_place_append_tag<'a, InOut>(
+        &self,
+        nonce: Option<Nonce>,
+        aad: &[u8],
+        in_out: &'a mut InOut,
+    ) -> Result<Nonce, Unspecified>
+    where
+        InOut: AsMut<[u8]> + for<'in_out> Extend<&'in_out u8>,
+    {
+        self.inner.seal_in_place_append_tag(nonce, aad, in_out)
+    }
+
+    #[inline]
+    pub(crate) fn seal_in_place_separate_tag(
+        &self,
+        nonce: Option<Nonce>,
+        aad: &[u8],
+        in_out: &mut [u8],
+    ) -> Result<(Nonce, Tag), Unspecified> {
+        self.inner.seal_in_place_separate_tag(nonce, aad, in_out)
+    }
+
+    #[inline]
+    pub(crate) fn seal_in_place_separate_scatter(
+        &self,
+        nonce: Nonce,
+        aad: &[u8],
+        in_out: &mut [u8],
+        extra_in: &[u8],
+        extra_out_and_tag: &mut [u8],
+    ) -> Result<(), Unspecified> {
+        self.inner
+            .seal_in_place_separate_scatter(nonce, aad, in_out, extra_in, extra_out_and_tag)
+    }
+
+    /// The key's AEAD algorithm.
+    #[inline]
+    #[must_use]
+    pub fn algorithm(&self) -> &'static Algorithm {
+        self.inner.algorithm
+    }
+
+    #[inline]
+    pub(crate) fn key_bytes(&self) -> &[u8] {
+        self.key_bytes.as_ref()
+    }
+}
+
+/// The AEAD context of a key and its algorithm.
+///
+/// The TLS record and randomized nonce keys construct their context directly from an `AeadCtx`
+/// and use this type instead of an `UnboundKey`: they retain no key bytes, so they can't be
+/// cloned or committed to.
+pub(crate) struct AeadKey {
+    ctx: AeadCtx,
+    algorithm: &'static Algorithm,
+}
+
+#[allow(clippy::missing_fields_in_debug)]
+impl Debug for AeadKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.debug_struct("AeadKey")
+            .field("algorithm", &self.algorithm)
+            .field("key_len", &self.algorithm.key_len())
+            .finish()
+    }
+}
+
+impl AeadKey {
     #[inline]
     pub(crate) fn open_within<'in_out>(
         &self,
@@ -199,10 +318,8 @@ impl UnboundKey {
         Ok(())
     }
 
-    /// The key's AEAD algorithm.
     #[inline]
-    #[must_use]
-    pub fn algorithm(&self) -> &'static Algorithm {
+    pub(crate) fn algorithm(&self) -> &'static Algorithm {
         self.algorithm
     }
 
@@ -479,7 +596,7 @@ impl UnboundKey {
     }
 }
 
-impl From<AeadCtx> for UnboundKey {
+impl From<AeadCtx> for AeadKey {
     fn from(value: AeadCtx) -> Self {
         let algorithm = match value {
             AeadCtx::AES_128_GCM(_)
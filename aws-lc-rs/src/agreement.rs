@@ -52,18 +52,21 @@
 mod ephemeral;
 
 use crate::ec::encoding::sec1::{
-    marshal_sec1_private_key, marshal_sec1_public_point, marshal_sec1_public_point_into_buffer,
-    parse_sec1_private_bn,
+    marshal_sec1_private_key, marshal_sec1_public_coordinates, marshal_sec1_public_point,
+    marshal_sec1_public_point_into_buffer, parse_sec1_private_bn,
 };
 use crate::ec::{encoding, evp_key_generate};
-use crate::error::{KeyRejected, Unspecified};
+use crate::error::{CryptoError, KeyRejected, Unspecified};
 use crate::hex;
 use crate::ptr::ConstPointer;
 pub use ephemeral::{agree_ephemeral, EphemeralPrivateKey};
 
+#[cfg(not(feature = "fips"))]
+use crate::aws_lc::NID_secp256k1;
 use crate::aws_lc::{
     EVP_PKEY_derive, EVP_PKEY_derive_init, EVP_PKEY_derive_set_peer, EVP_PKEY_get0_EC_KEY,
-    NID_X9_62_prime256v1, NID_secp384r1, NID_secp521r1, EVP_PKEY, EVP_PKEY_X25519, NID_X25519,
+    NID_X9_62_prime256v1, NID_secp224r1, NID_secp384r1, NID_secp521r1, EVP_PKEY, EVP_PKEY_EC,
+    EVP_PKEY_X25519, NID_X25519,
 };
 
 use crate::buffer::Buffer;
@@ -71,10 +74,11 @@ use crate::ec;
 use crate::ec::encoding::rfc5915::parse_rfc5915_private_key;
 use crate::encoding::{
     AsBigEndian, AsDer, Curve25519SeedBin, EcPrivateKeyBin, EcPrivateKeyRfc5915Der,
-    EcPublicKeyCompressedBin, EcPublicKeyUncompressedBin, PublicKeyX509Der,
+    EcPublicKeyCompressedBin, EcPublicKeyUncompressedBin, Pkcs8V1Der, PublicKeyX509Der,
 };
 use crate::evp_pkey::No_EVP_PKEY_CTX_consumer;
 use crate::fips::indicator_check;
+use crate::pkcs8::Version;
 use crate::ptr::LcPtr;
 use core::fmt;
 use core::fmt::{Debug, Formatter};
@@ -83,9 +87,12 @@ use core::ptr::null_mut;
 #[allow(non_camel_case_types)]
 #[derive(PartialEq, Eq)]
 enum AlgorithmID {
+    ECDH_P224,
     ECDH_P256,
     ECDH_P384,
     ECDH_P521,
+    #[cfg(not(feature = "fips"))]
+    ECDH_P256K1,
     X25519,
 }
 
@@ -93,9 +100,12 @@ impl AlgorithmID {
     #[inline]
     const fn nid(&self) -> i32 {
         match self {
+            AlgorithmID::ECDH_P224 => NID_secp224r1,
             AlgorithmID::ECDH_P256 => NID_X9_62_prime256v1,
             AlgorithmID::ECDH_P384 => NID_secp384r1,
             AlgorithmID::ECDH_P521 => NID_secp521r1,
+            #[cfg(not(feature = "fips"))]
+            AlgorithmID::ECDH_P256K1 => NID_secp256k1,
             AlgorithmID::X25519 => NID_X25519,
         }
     }
@@ -104,9 +114,12 @@ impl AlgorithmID {
     #[inline]
     const fn pub_key_len(&self) -> usize {
         match self {
+            AlgorithmID::ECDH_P224 => ec::uncompressed_public_key_size_bytes(224),
             AlgorithmID::ECDH_P256 => ec::uncompressed_public_key_size_bytes(256),
             AlgorithmID::ECDH_P384 => ec::uncompressed_public_key_size_bytes(384),
             AlgorithmID::ECDH_P521 => ec::uncompressed_public_key_size_bytes(521),
+            #[cfg(not(feature = "fips"))]
+            AlgorithmID::ECDH_P256K1 => ec::uncompressed_public_key_size_bytes(256),
             AlgorithmID::X25519 => 32,
         }
     }
@@ -114,9 +127,12 @@ impl AlgorithmID {
     #[inline]
     const fn private_key_len(&self) -> usize {
         match self {
+            AlgorithmID::ECDH_P224 => 28,
             AlgorithmID::ECDH_P256 | AlgorithmID::X25519 => 32,
             AlgorithmID::ECDH_P384 => 48,
             AlgorithmID::ECDH_P521 => 66,
+            #[cfg(not(feature = "fips"))]
+            AlgorithmID::ECDH_P256K1 => 32,
         }
     }
 }
@@ -124,9 +140,12 @@ impl AlgorithmID {
 impl Debug for AlgorithmID {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
         let output = match self {
+            AlgorithmID::ECDH_P224 => "curve: P224",
             AlgorithmID::ECDH_P256 => "curve: P256",
             AlgorithmID::ECDH_P384 => "curve: P384",
             AlgorithmID::ECDH_P521 => "curve: P521",
+            #[cfg(not(feature = "fips"))]
+            AlgorithmID::ECDH_P256K1 => "curve: secp256k1",
             AlgorithmID::X25519 => "curve: Curve25519",
         };
         f.write_str(output)
@@ -145,6 +164,14 @@ impl Debug for Algorithm {
     }
 }
 
+/// ECDH using the NIST P-224 (secp224r1) curve.
+///
+/// P-224 is only provided for interoperability with legacy systems.
+#[deprecated(note = "Use P-256 or higher for new designs")]
+pub const ECDH_P224: Algorithm = Algorithm {
+    id: AlgorithmID::ECDH_P224,
+};
+
 /// ECDH using the NSA Suite B P-256 (secp256r1) curve.
 pub const ECDH_P256: Algorithm = Algorithm {
     id: AlgorithmID::ECDH_P256,
@@ -160,6 +187,21 @@ pub const ECDH_P521: Algorithm = Algorithm {
     id: AlgorithmID::ECDH_P521,
 };
 
+/// ECDH using the secp256k1 curve of [SEC 2], as used by Bitcoin and Ethereum.
+///
+/// As specified by [SEC 1, Section 3.3.1], the shared secret is the x-coordinate of the shared
+/// point, encoded in 32 bytes.
+///
+/// secp256k1 is not a FIPS-approved curve, so this algorithm is unavailable when the "fips"
+/// feature is enabled.
+///
+/// [SEC 2]: https://www.secg.org/sec2-v2.pdf
+/// [SEC 1, Section 3.3.1]: https://www.secg.org/sec1-v2.pdf
+#[cfg(not(feature = "fips"))]
+pub const ECDH_P256K1: Algorithm = Algorithm {
+    id: AlgorithmID::ECDH_P256K1,
+};
+
 /// X25519 (ECDH using Curve25519) as described in [RFC 7748].
 ///
 /// Everything is as described in RFC 7748. Key agreement will fail if the
@@ -174,18 +216,24 @@ pub const X25519: Algorithm = Algorithm {
 
 #[allow(non_camel_case_types)]
 enum KeyInner {
+    ECDH_P224(LcPtr<EVP_PKEY>),
     ECDH_P256(LcPtr<EVP_PKEY>),
     ECDH_P384(LcPtr<EVP_PKEY>),
     ECDH_P521(LcPtr<EVP_PKEY>),
+    #[cfg(not(feature = "fips"))]
+    ECDH_P256K1(LcPtr<EVP_PKEY>),
     X25519(LcPtr<EVP_PKEY>),
 }
 
 impl Clone for KeyInner {
     fn clone(&self) -> KeyInner {
         match self {
+            KeyInner::ECDH_P224(evp_pkey) => KeyInner::ECDH_P224(evp_pkey.clone()),
             KeyInner::ECDH_P256(evp_pkey) => KeyInner::ECDH_P256(evp_pkey.clone()),
             KeyInner::ECDH_P384(evp_pkey) => KeyInner::ECDH_P384(evp_pkey.clone()),
             KeyInner::ECDH_P521(evp_pkey) => KeyInner::ECDH_P521(evp_pkey.clone()),
+            #[cfg(not(feature = "fips"))]
+            KeyInner::ECDH_P256K1(evp_pkey) => KeyInner::ECDH_P256K1(evp_pkey.clone()),
             KeyInner::X25519(evp_pkey) => KeyInner::X25519(evp_pkey.clone()),
         }
     }
@@ -200,21 +248,28 @@ pub struct PrivateKey {
 
 impl KeyInner {
     #[inline]
+    #[allow(deprecated)]
     fn algorithm(&self) -> &'static Algorithm {
         match self {
+            KeyInner::ECDH_P224(..) => &ECDH_P224,
             KeyInner::ECDH_P256(..) => &ECDH_P256,
             KeyInner::ECDH_P384(..) => &ECDH_P384,
             KeyInner::ECDH_P521(..) => &ECDH_P521,
+            #[cfg(not(feature = "fips"))]
+            KeyInner::ECDH_P256K1(..) => &ECDH_P256K1,
             KeyInner::X25519(..) => &X25519,
         }
     }
 
     fn get_evp_pkey(&self) -> &LcPtr<EVP_PKEY> {
         match self {
-            KeyInner::ECDH_P256(evp_pkey)
+            KeyInner::ECDH_P224(evp_pkey)
+            | KeyInner::ECDH_P256(evp_pkey)
             | KeyInner::ECDH_P384(evp_pkey)
             | KeyInner::ECDH_P521(evp_pkey)
             | KeyInner::X25519(evp_pkey) => evp_pkey,
+            #[cfg(not(feature = "fips"))]
+            KeyInner::ECDH_P256K1(evp_pkey) => evp_pkey,
         }
     }
 }
@@ -244,6 +299,9 @@ impl PrivateKey {
             AlgorithmID::X25519 => Self {
                 inner_key: KeyInner::X25519(evp_pkey),
             },
+            AlgorithmID::ECDH_P224 => Self {
+                inner_key: KeyInner::ECDH_P224(evp_pkey),
+            },
             AlgorithmID::ECDH_P256 => Self {
                 inner_key: KeyInner::ECDH_P256(evp_pkey),
             },
@@ -253,6 +311,10 @@ impl PrivateKey {
             AlgorithmID::ECDH_P521 => Self {
                 inner_key: KeyInner::ECDH_P521(evp_pkey),
             },
+            #[cfg(not(feature = "fips"))]
+            AlgorithmID::ECDH_P256K1 => Self {
+                inner_key: KeyInner::ECDH_P256K1(evp_pkey),
+            },
         }
     }
 
@@ -260,6 +322,7 @@ impl PrivateKey {
     /// Generate a new private key for the given algorithm.
     // # FIPS
     // Use this function with one of the following algorithms:
+    // * `ECDH_P224`
     // * `ECDH_P256`
     // * `ECDH_P384`
     // * `ECDH_P521`
@@ -280,7 +343,7 @@ impl PrivateKey {
     /// attempt to automatically detect other key formats. This function supports unencrypted
     /// PKCS#8 `PrivateKeyInfo` structures as well as key type specific formats.
     ///
-    /// X25519 keys are not supported. See `PrivateKey::as_der`.
+    /// X25519 keys are only supported as PKCS#8 `PrivateKeyInfo` structures (RFC 8410).
     ///
     /// # Errors
     /// `error::KeyRejected` if parsing failed or key otherwise unacceptable.
@@ -290,10 +353,14 @@ impl PrivateKey {
         alg: &'static Algorithm,
         key_bytes: &[u8],
     ) -> Result<Self, KeyRejected> {
-        if AlgorithmID::X25519 == alg.id {
-            return Err(KeyRejected::invalid_encoding());
-        }
-        let evp_pkey = parse_rfc5915_private_key(key_bytes, alg.id.nid())?;
+        let evp_pkey = if AlgorithmID::X25519 == alg.id {
+            LcPtr::<EVP_PKEY>::parse_rfc5208_private_key(key_bytes, EVP_PKEY_X25519)?
+        } else {
+            let evp_pkey = LcPtr::<EVP_PKEY>::parse_rfc5208_private_key(key_bytes, EVP_PKEY_EC)
+                .or_else(|_| parse_rfc5915_private_key(key_bytes, alg.id.nid()))?;
+            ec::validate_evp_key(&evp_pkey.as_const(), alg.id.nid())?;
+            evp_pkey
+        };
         Ok(Self::new(alg, evp_pkey))
     }
 
@@ -332,6 +399,14 @@ impl PrivateKey {
                 rng.fill(&mut priv_key)?;
                 Self::from_x25519_private_key(&priv_key)
             }
+            AlgorithmID::ECDH_P224 => {
+                let mut priv_key = [0u8; AlgorithmID::ECDH_P224.private_key_len()];
+                rng.fill(&mut priv_key)?;
+                let pkey = parse_sec1_private_bn(&priv_key, NID_secp224r1)?;
+                Ok(PrivateKey {
+                    inner_key: KeyInner::ECDH_P224(pkey),
+                })
+            }
             AlgorithmID::ECDH_P256 => {
                 let mut priv_key = [0u8; AlgorithmID::ECDH_P256.private_key_len()];
                 rng.fill(&mut priv_key)?;
@@ -347,6 +422,15 @@ impl PrivateKey {
                 rng.fill(&mut priv_key)?;
                 Self::from_p521_private_key(&priv_key)
             }
+            #[cfg(not(feature = "fips"))]
+            AlgorithmID::ECDH_P256K1 => {
+                let mut priv_key = [0u8; AlgorithmID::ECDH_P256K1.private_key_len()];
+                rng.fill(&mut priv_key)?;
+                let pkey = parse_sec1_private_bn(&priv_key, ECDH_P256K1.id.nid())?;
+                Ok(PrivateKey {
+                    inner_key: KeyInner::ECDH_P256K1(pkey),
+                })
+            }
         }
     }
 
@@ -391,17 +475,12 @@ impl PrivateKey {
     /// `error::Unspecified` when operation fails due to internal error.
     pub fn compute_public_key(&self) -> Result<PublicKey, Unspecified> {
         match &self.inner_key {
-            KeyInner::ECDH_P256(evp_pkey)
+            KeyInner::ECDH_P224(evp_pkey)
+            | KeyInner::ECDH_P256(evp_pkey)
             | KeyInner::ECDH_P384(evp_pkey)
-            | KeyInner::ECDH_P521(evp_pkey) => {
-                let mut public_key = [0u8; MAX_PUBLIC_KEY_LEN];
-                let len = marshal_sec1_public_point_into_buffer(&mut public_key, evp_pkey, false)?;
-                Ok(PublicKey {
-                    inner_key: self.inner_key.clone(),
-                    public_key,
-                    len,
-                })
-            }
+            | KeyInner::ECDH_P521(evp_pkey) => self.compute_ec_public_key(evp_pkey),
+            #[cfg(not(feature = "fips"))]
+            KeyInner::ECDH_P256K1(evp_pkey) => self.compute_ec_public_key(evp_pkey),
             KeyInner::X25519(priv_key) => {
                 let mut buffer = [0u8; MAX_PUBLIC_KEY_LEN];
                 let out_len = priv_key.marshal_raw_public_to_buffer(&mut buffer)?;
@@ -414,6 +493,16 @@ impl PrivateKey {
         }
     }
 
+    fn compute_ec_public_key(&self, evp_pkey: &LcPtr<EVP_PKEY>) -> Result<PublicKey, Unspecified> {
+        let mut public_key = [0u8; MAX_PUBLIC_KEY_LEN];
+        let len = marshal_sec1_public_point_into_buffer(&mut public_key, evp_pkey, false)?;
+        Ok(PublicKey {
+            inner_key: self.inner_key.clone(),
+            public_key,
+            len,
+        })
+    }
+
     /// The algorithm for the private key.
     #[inline]
     #[must_use]
@@ -449,6 +538,20 @@ impl AsDer<EcPrivateKeyRfc5915Der<'static>> for PrivateKey {
     }
 }
 
+impl AsDer<Pkcs8V1Der<'static>> for PrivateKey {
+    /// Serializes the key as a DER-encoded PKCS#8 v1 `PrivateKeyInfo` structure.
+    ///
+    /// # Errors
+    /// `error::Unspecified`  if serialization failed.
+    fn as_der(&self) -> Result<Pkcs8V1Der<'static>, Unspecified> {
+        Ok(Pkcs8V1Der::new(
+            self.inner_key
+                .get_evp_pkey()
+                .marshal_rfc5208_private_key(Version::V1)?,
+        ))
+    }
+}
+
 impl AsBigEndian<EcPrivateKeyBin<'static>> for PrivateKey {
     /// Exposes the private key encoded as a big-endian fixed-length integer.
     ///
@@ -500,6 +603,20 @@ impl PublicKey {
     pub fn algorithm(&self) -> &'static Algorithm {
         self.inner_key.algorithm()
     }
+
+    /// Returns the affine coordinates `(x, y)` of an ECDH public key, each encoded in big-endian
+    /// bytes and zero-padded to the size of a field element of the curve.
+    ///
+    /// X25519 is not supported.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if the key is an X25519 key, or on internal error.
+    pub fn public_key_x_y(&self) -> Result<(Vec<u8>, Vec<u8>), Unspecified> {
+        if AlgorithmID::X25519 == self.inner_key.algorithm().id {
+            return Err(Unspecified);
+        }
+        marshal_sec1_public_coordinates(self.inner_key.get_evp_pkey())
+    }
 }
 
 unsafe impl Send for PublicKey {}
@@ -539,15 +656,8 @@ impl AsDer<PublicKeyX509Der<'static>> for PublicKey {
     /// # Errors
     /// Returns an error if the public key fails to marshal to X.509.
     fn as_der(&self) -> Result<PublicKeyX509Der<'static>, crate::error::Unspecified> {
-        match &self.inner_key {
-            KeyInner::ECDH_P256(evp_pkey)
-            | KeyInner::ECDH_P384(evp_pkey)
-            | KeyInner::ECDH_P521(evp_pkey)
-            | KeyInner::X25519(evp_pkey) => {
-                let der = evp_pkey.marshal_rfc5280_public_key()?;
-                Ok(PublicKeyX509Der::from(Buffer::new(der)))
-            }
-        }
+        let der = self.inner_key.get_evp_pkey().marshal_rfc5280_public_key()?;
+        Ok(PublicKeyX509Der::from(Buffer::new(der)))
     }
 }
 
@@ -557,9 +667,12 @@ impl AsBigEndian<EcPublicKeyCompressedBin<'static>> for PublicKey {
     /// Returns an error if the underlying implementation is unable to marshal the public key to this format.
     fn as_be_bytes(&self) -> Result<EcPublicKeyCompressedBin<'static>, crate::error::Unspecified> {
         let evp_pkey = match &self.inner_key {
-            KeyInner::ECDH_P256(evp_pkey)
+            KeyInner::ECDH_P224(evp_pkey)
+            | KeyInner::ECDH_P256(evp_pkey)
             | KeyInner::ECDH_P384(evp_pkey)
             | KeyInner::ECDH_P521(evp_pkey) => evp_pkey,
+            #[cfg(not(feature = "fips"))]
+            KeyInner::ECDH_P256K1(evp_pkey) => evp_pkey,
             KeyInner::X25519(_) => return Err(Unspecified),
         };
         let pub_point = marshal_sec1_public_point(evp_pkey, true)?;
@@ -648,6 +761,7 @@ impl<B: AsRef<[u8]>> UnparsedPublicKey<B> {
 /// returns.
 // # FIPS
 // Use this function with one of the following key algorithms:
+// * `ECDH_P224`
 // * `ECDH_P256`
 // * `ECDH_P384`
 // * `ECDH_P521`
@@ -665,28 +779,62 @@ pub fn agree<B: AsRef<[u8]>, F, R, E>(
 where
     F: FnOnce(&[u8]) -> Result<R, E>,
 {
+    let mut buffer = [0u8; MAX_AGREEMENT_SECRET_LEN];
+    let secret = agree_secret(&mut buffer, my_private_key, peer_public_key).or(Err(error_value))?;
+    kdf(secret)
+}
+
+/// Like [`agree`], but returns a [`CryptoError`] describing the failure instead of a
+/// caller-provided error value.
+///
+/// # Errors
+/// * `CryptoError::AlgorithmMismatch` if `peer_public_key` does not have the algorithm of
+///   `my_private_key`.
+/// * `CryptoError::Unspecified` if `peer_public_key` is invalid, or on internal failure.
+/// * The error returned by `kdf`.
+#[inline]
+pub fn agree_with_error<B: AsRef<[u8]>, F, R>(
+    my_private_key: &PrivateKey,
+    peer_public_key: &UnparsedPublicKey<B>,
+    kdf: F,
+) -> Result<R, CryptoError>
+where
+    F: FnOnce(&[u8]) -> Result<R, CryptoError>,
+{
+    let mut buffer = [0u8; MAX_AGREEMENT_SECRET_LEN];
+    let secret = agree_secret(&mut buffer, my_private_key, peer_public_key)?;
+    kdf(secret)
+}
+
+fn agree_secret<'a, B: AsRef<[u8]>>(
+    buffer: &'a mut [u8; MAX_AGREEMENT_SECRET_LEN],
+    my_private_key: &PrivateKey,
+    peer_public_key: &UnparsedPublicKey<B>,
+) -> Result<&'a [u8], CryptoError> {
     let expected_alg = my_private_key.algorithm();
     let expected_nid = expected_alg.id.nid();
 
     if peer_public_key.alg != expected_alg {
-        return Err(error_value);
+        return Err(CryptoError::AlgorithmMismatch);
     }
 
     let peer_pub_bytes = peer_public_key.bytes.as_ref();
 
-    let mut buffer = [0u8; MAX_AGREEMENT_SECRET_LEN];
-
-    let secret: &[u8] = match &my_private_key.inner_key {
+    Ok(match &my_private_key.inner_key {
         KeyInner::X25519(priv_key) => {
-            x25519_diffie_hellman(&mut buffer, priv_key, peer_pub_bytes).or(Err(error_value))?
+            x25519_diffie_hellman(buffer, priv_key, peer_pub_bytes).map_err(|()| Unspecified)?
         }
-        KeyInner::ECDH_P256(priv_key)
+        KeyInner::ECDH_P224(priv_key)
+        | KeyInner::ECDH_P256(priv_key)
         | KeyInner::ECDH_P384(priv_key)
         | KeyInner::ECDH_P521(priv_key) => {
-            ec_key_ecdh(&mut buffer, priv_key, peer_pub_bytes, expected_nid).or(Err(error_value))?
+            ec_key_ecdh(buffer, priv_key, peer_pub_bytes, expected_nid)?
         }
-    };
-    kdf(secret)
+        #[cfg(not(feature = "fips"))]
+        KeyInner::ECDH_P256K1(priv_key) => {
+            ec_key_ecdh(buffer, priv_key, peer_pub_bytes, expected_nid)?
+        }
+    })
 }
 
 // Current max secret length is P-521's.
@@ -779,13 +927,15 @@ fn try_parse_x25519_public_key_raw_bytes(key_bytes: &[u8]) -> Result<LcPtr<EVP_P
 
 #[cfg(test)]
 mod tests {
+    #[cfg(not(feature = "fips"))]
+    use crate::agreement::ECDH_P256K1;
     use crate::agreement::{
         agree, Algorithm, PrivateKey, PublicKey, UnparsedPublicKey, ECDH_P256, ECDH_P384,
         ECDH_P521, X25519,
     };
     use crate::encoding::{
         AsBigEndian, AsDer, Curve25519SeedBin, EcPrivateKeyBin, EcPrivateKeyRfc5915Der,
-        EcPublicKeyCompressedBin, EcPublicKeyUncompressedBin, PublicKeyX509Der,
+        EcPublicKeyCompressedBin, EcPublicKeyUncompressedBin, Pkcs8V1Der, PublicKeyX509Der,
     };
     use crate::{rand, test};
 
@@ -828,6 +978,25 @@ mod tests {
             assert_eq!(result, Ok(()));
         }
 
+        // RFC 8410, Section 7
+        let pkcs8_private_key: Pkcs8V1Der = my_private.as_der().unwrap();
+        assert_eq!(
+            pkcs8_private_key.as_ref(),
+            &test::from_dirty_hex(
+                "302e020100300506032b656e04220420\
+                 a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac4",
+            )[..]
+        );
+        let pkcs8_private_key =
+            PrivateKey::from_private_key_der(&X25519, pkcs8_private_key.as_ref()).unwrap();
+        {
+            let result = agree(&pkcs8_private_key, &peer_public, (), |key_material| {
+                assert_eq!(key_material, &output[..]);
+                Ok(())
+            });
+            assert_eq!(result, Ok(()));
+        }
+
         let computed_public = my_private.compute_public_key().unwrap();
         assert_eq!(computed_public.as_ref(), &my_public[..]);
 
@@ -878,6 +1047,74 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_agreement_ecdh_peer_validation() {
+        for alg in [&ECDH_P256, &ECDH_P384, &ECDH_P521] {
+            let my_private = PrivateKey::generate(alg).unwrap();
+            let peer_public = PrivateKey::generate(alg)
+                .unwrap()
+                .compute_public_key()
+                .unwrap();
+            let peer_x509: PublicKeyX509Der = peer_public.as_der().unwrap();
+
+            // The SEC1 encoding of the point at infinity.
+            assert!(agree(
+                &my_private,
+                &UnparsedPublicKey::new(alg, [0u8]),
+                (),
+                |_| Ok(())
+            )
+            .is_err());
+
+            // Points that are not on the curve.
+            let mut off_curve = peer_public.as_ref().to_vec();
+            *off_curve.last_mut().unwrap() ^= 1;
+            assert!(agree(
+                &my_private,
+                &UnparsedPublicKey::new(alg, &off_curve),
+                (),
+                |_| Ok(())
+            )
+            .is_err());
+            let mut off_curve_x509 = peer_x509.as_ref().to_vec();
+            *off_curve_x509.last_mut().unwrap() ^= 1;
+            assert!(agree(
+                &my_private,
+                &UnparsedPublicKey::new(alg, &off_curve_x509),
+                (),
+                |_| Ok(())
+            )
+            .is_err());
+
+            // The same secret is agreed with either encoding of the peer key, and after the
+            // private key is round-tripped through PKCS#8.
+            let pkcs8: Pkcs8V1Der = my_private.as_der().unwrap();
+            let my_private_from_pkcs8 =
+                PrivateKey::from_private_key_der(alg, pkcs8.as_ref()).unwrap();
+            let other_alg = if alg == &ECDH_P256 {
+                &ECDH_P384
+            } else {
+                &ECDH_P256
+            };
+            assert!(PrivateKey::from_private_key_der(other_alg, pkcs8.as_ref()).is_err());
+            let expected = agree(
+                &my_private,
+                &UnparsedPublicKey::new(alg, peer_public.as_ref()),
+                (),
+                |secret| Ok(secret.to_vec()),
+            )
+            .unwrap();
+            let from_x509 = agree(
+                &my_private_from_pkcs8,
+                &UnparsedPublicKey::new(alg, peer_x509.as_ref()),
+                (),
+                |secret| Ok(secret.to_vec()),
+            )
+            .unwrap();
+            assert_eq!(expected, from_x509);
+        }
+    }
+
     #[test]
     fn test_agreement_ecdh_p256() {
         let alg = &ECDH_P256;
@@ -1086,6 +1323,89 @@ mod tests {
         }
     }
 
+    // The private keys are the secret keys of test vectors 1 and 2 of BIP 340.
+    #[cfg(not(feature = "fips"))]
+    #[test]
+    fn test_agreement_ecdh_p256k1() {
+        let alg = &ECDH_P256K1;
+        let peer_public = UnparsedPublicKey::new(
+            alg,
+            test::from_dirty_hex(
+                "04dd308afec5777e13121fa72b9cc1b7cc0139715309b086c960e18fd969774eb8f594bb5f72b37faae396a4259ea64ed5e6fdeb2a51c6467582b275925fab1394",
+            ),
+        );
+
+        let my_private = test::from_dirty_hex(
+            "b7e151628aed2a6abf7158809cf4f3c762e7160f38b4da56a784d9045190cfef",
+        );
+
+        let my_private = {
+            let rng = test::rand::FixedSliceRandom { bytes: &my_private };
+            PrivateKey::generate_for_test(alg, &rng).unwrap()
+        };
+
+        let my_public = test::from_dirty_hex(
+            "04dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba6592ce19b946c4ee58546f5251d441a065ea50735606985e5b228788bec4e582898",
+        );
+        let output = test::from_dirty_hex(
+            "ca77ad739864d3f6f599f93842f48c89d5a9a7d0c867b767d53bf96f03557627",
+        );
+
+        assert_eq!(my_private.algorithm(), alg);
+        assert_eq!(
+            "Algorithm { curve: secp256k1 }",
+            format!("{:?}", my_private.algorithm())
+        );
+
+        let be_private_key_buffer: EcPrivateKeyBin = my_private.as_be_bytes().unwrap();
+        let be_private_key =
+            PrivateKey::from_private_key(&ECDH_P256K1, be_private_key_buffer.as_ref()).unwrap();
+        {
+            let result = agree(&be_private_key, &peer_public, (), |key_material| {
+                assert_eq!(key_material, &output[..]);
+                Ok(())
+            });
+            assert_eq!(result, Ok(()));
+        }
+
+        let der_private_key_buffer: EcPrivateKeyRfc5915Der = my_private.as_der().unwrap();
+        let der_private_key =
+            PrivateKey::from_private_key_der(&ECDH_P256K1, der_private_key_buffer.as_ref())
+                .unwrap();
+        {
+            let result = agree(&der_private_key, &peer_public, (), |key_material| {
+                assert_eq!(key_material, &output[..]);
+                Ok(())
+            });
+            assert_eq!(result, Ok(()));
+        }
+
+        let computed_public = my_private.compute_public_key().unwrap();
+        assert_eq!(computed_public.as_ref(), &my_public[..]);
+
+        assert_eq!(computed_public.algorithm(), alg);
+        {
+            let result = agree(&my_private, &peer_public, (), |key_material| {
+                assert_eq!(key_material, &output[..]);
+                Ok(())
+            });
+            assert_eq!(result, Ok(()));
+        }
+
+        // A P-256 public key is rejected.
+        let p256_public = PrivateKey::generate(&ECDH_P256)
+            .unwrap()
+            .compute_public_key()
+            .unwrap();
+        assert!(agree(
+            &my_private,
+            &UnparsedPublicKey::new(alg, p256_public.as_ref()),
+            (),
+            |_| Ok(())
+        )
+        .is_err());
+    }
+
     #[test]
     fn agreement_traits() {
         use crate::test;
@@ -1178,6 +1498,10 @@ mod tests {
         for alg in test_algorithms {
             test_agreement_random_helper(alg);
         }
+        #[allow(deprecated)]
+        test_agreement_random_helper(&crate::agreement::ECDH_P224);
+        #[cfg(not(feature = "fips"))]
+        test_agreement_random_helper(&ECDH_P256K1);
     }
 
     fn test_agreement_random_helper(alg: &'static Algorithm) {
@@ -1210,9 +1534,12 @@ mod tests {
         }
 
         let key_types_tested = match alg.id {
-            crate::agreement::AlgorithmID::ECDH_P256
+            crate::agreement::AlgorithmID::ECDH_P224
+            | crate::agreement::AlgorithmID::ECDH_P256
             | crate::agreement::AlgorithmID::ECDH_P384
             | crate::agreement::AlgorithmID::ECDH_P521 => 4,
+            #[cfg(not(feature = "fips"))]
+            crate::agreement::AlgorithmID::ECDH_P256K1 => 4,
             crate::agreement::AlgorithmID::X25519 => 2,
         };
 
@@ -1222,12 +1549,8 @@ mod tests {
     }
 
     fn public_key_formats_helper(public_key: &PublicKey) -> Vec<Vec<u8>> {
-        let verify_ec_raw_traits = matches!(
-            public_key.algorithm().id,
-            crate::agreement::AlgorithmID::ECDH_P256
-                | crate::agreement::AlgorithmID::ECDH_P384
-                | crate::agreement::AlgorithmID::ECDH_P521
-        );
+        let verify_ec_raw_traits =
+            public_key.algorithm().id != crate::agreement::AlgorithmID::X25519;
 
         let mut public_keys = Vec::<Vec<u8>>::new();
         public_keys.push(public_key.as_ref().into());
@@ -1245,6 +1568,37 @@ mod tests {
         public_keys
     }
 
+    #[test]
+    fn public_key_x_y() {
+        fn check(alg: &'static Algorithm, elem_len: usize) {
+            let public_key = PrivateKey::generate(alg)
+                .unwrap()
+                .compute_public_key()
+                .unwrap();
+            let (x, y) = public_key.public_key_x_y().unwrap();
+            assert_eq!(elem_len, x.len());
+            assert_eq!(elem_len, y.len());
+            assert_eq!(
+                public_key.as_ref(),
+                [&[0x04][..], &x, &y].concat().as_slice()
+            );
+        }
+
+        #[allow(deprecated)]
+        check(&crate::agreement::ECDH_P224, 28);
+        check(&ECDH_P256, 32);
+        check(&ECDH_P384, 48);
+        check(&ECDH_P521, 66);
+        #[cfg(not(feature = "fips"))]
+        check(&ECDH_P256K1, 32);
+
+        let public_key = PrivateKey::generate(&X25519)
+            .unwrap()
+            .compute_public_key()
+            .unwrap();
+        assert!(public_key.public_key_x_y().is_err());
+    }
+
     #[test]
     fn private_key_drop() {
         let private_key = PrivateKey::generate(&ECDH_P256).unwrap();
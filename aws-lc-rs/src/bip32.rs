@@ -0,0 +1,319 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+//! Hierarchical deterministic key derivation for secp256k1 as specified in
+//! [BIP 32](https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki).
+//!
+//! An [`ExtendedPrivateKey`] is a secp256k1 private key together with a chain code. Child keys are
+//! derived from it by index; indices of [`HARDENED_INDEX`] and above select hardened derivation,
+//! whose children cannot be derived from the parent's public key.
+//!
+//! # Example
+//!
+//! ```rust
+//! use aws_lc_rs::bip32::{ExtendedPrivateKey, HARDENED_INDEX};
+//! use aws_lc_rs::rand::SystemRandom;
+//! use aws_lc_rs::signature::{UnparsedPublicKey, ECDSA_P256K1_SHA256_ASN1};
+//! use aws_lc_rs::signature::ECDSA_P256K1_SHA256_ASN1_SIGNING;
+//!
+//! let seed = [0x42u8; 32];
+//! let master = ExtendedPrivateKey::from_seed(&seed)?;
+//!
+//! // m/44'/0'/0'/0/0
+//! let key = master
+//!     .derive_child(HARDENED_INDEX + 44)?
+//!     .derive_child(HARDENED_INDEX)?
+//!     .derive_child(HARDENED_INDEX)?
+//!     .derive_child(0)?
+//!     .derive_child(0)?;
+//! assert_eq!(5, key.depth());
+//!
+//! let key_pair = key.signing_key(&ECDSA_P256K1_SHA256_ASN1_SIGNING)?;
+//! let signature = key_pair.sign(&SystemRandom::new(), b"hello, world")?;
+//!
+//! // The compressed public key verifies the signature.
+//! let public_key = key.public_key();
+//! UnparsedPublicKey::new(&ECDSA_P256K1_SHA256_ASN1, public_key.public_key())
+//!     .verify(b"hello, world", signature.as_ref())?;
+//! # Ok::<(), aws_lc_rs::error::Unspecified>(())
+//! ```
+
+use crate::aws_lc::{
+    BN_CTX_new, BN_bn2bin_padded, BN_cmp, BN_is_zero, BN_mod_add, BN_new, EC_GROUP_get0_order,
+    NID_secp256k1, RIPEMD160,
+};
+use crate::digest;
+use crate::ec::ec_group_from_nid;
+use crate::ec::encoding::sec1::{marshal_sec1_public_point_into_buffer, parse_sec1_private_bn};
+use crate::ec::key_pair::EcdsaKeyPair;
+use crate::ec::signature::EcdsaSigningAlgorithm;
+use crate::error::Unspecified;
+use crate::hex;
+use crate::hmac;
+use crate::ptr::{ConstPointer, LcPtr, SecretBnPtr};
+use core::fmt::{self, Debug, Formatter};
+use zeroize::Zeroize;
+
+/// The first child index that selects hardened derivation, 2<sup>31</sup>.
+pub const HARDENED_INDEX: u32 = 0x8000_0000;
+
+const MASTER_KEY: &[u8] = b"Bitcoin seed";
+const MIN_SEED_LEN: usize = 16;
+const MAX_SEED_LEN: usize = 64;
+const PRIVATE_KEY_LEN: usize = 32;
+const PUBLIC_KEY_LEN: usize = 33;
+const CHAIN_CODE_LEN: usize = 32;
+const FINGERPRINT_LEN: usize = 4;
+
+/// A BIP 32 extended secp256k1 private key.
+///
+/// The private key is zeroized when dropped.
+pub struct ExtendedPrivateKey {
+    private_key: [u8; PRIVATE_KEY_LEN],
+    public: ExtendedPublicKey,
+}
+
+/// A BIP 32 extended secp256k1 public key.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ExtendedPublicKey {
+    public_key: [u8; PUBLIC_KEY_LEN],
+    chain_code: [u8; CHAIN_CODE_LEN],
+    depth: u8,
+    parent_fingerprint: [u8; FINGERPRINT_LEN],
+    child_number: u32,
+}
+
+impl ExtendedPrivateKey {
+    /// Derives the master key from `seed`.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if `seed` is shorter than 16 bytes or longer than 64 bytes, or in the
+    /// negligibly unlikely case that the seed does not produce a valid private key.
+    pub fn from_seed(seed: &[u8]) -> Result<Self, Unspecified> {
+        if seed.len() < MIN_SEED_LEN || seed.len() > MAX_SEED_LEN {
+            return Err(Unspecified);
+        }
+        let key = hmac::Key::new(hmac::HMAC_SHA512, MASTER_KEY);
+        let mut tag = hmac::sign(&key, seed);
+        let (private_key, chain_code) = tag.as_ref().split_at(PRIVATE_KEY_LEN);
+        let result = Self::new(private_key, chain_code, 0, [0; FINGERPRINT_LEN], 0);
+        tag.zeroize();
+        result
+    }
+
+    /// Derives the child key with the given `index`. Indices of [`HARDENED_INDEX`] and above
+    /// select hardened derivation.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if this key is at the maximum depth of 255, or in the negligibly
+    /// unlikely case that `index` does not produce a valid key. BIP 32 specifies that the caller
+    /// should then proceed with the next index.
+    pub fn derive_child(&self, index: u32) -> Result<Self, Unspecified> {
+        let depth = self.public.depth.checked_add(1).ok_or(Unspecified)?;
+
+        let key = hmac::Key::new(hmac::HMAC_SHA512, &self.public.chain_code);
+        let mut context = hmac::Context::with_key(&key);
+        if index >= HARDENED_INDEX {
+            context.update(&[0]);
+            context.update(&self.private_key);
+        } else {
+            context.update(&self.public.public_key);
+        }
+        context.update(&index.to_be_bytes());
+        let mut tag = context.sign();
+        let (tweak, chain_code) = tag.as_ref().split_at(PRIVATE_KEY_LEN);
+
+        let child = add_scalars(tweak, &self.private_key).and_then(|mut private_key| {
+            let child = Self::new(
+                &private_key,
+                chain_code,
+                depth,
+                self.public.fingerprint(),
+                index,
+            );
+            private_key.zeroize();
+            child
+        });
+        tag.zeroize();
+        child
+    }
+
+    /// Returns a key pair for signing with this key's private key.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if `alg` is not an algorithm for the secp256k1 curve.
+    pub fn signing_key(
+        &self,
+        alg: &'static EcdsaSigningAlgorithm,
+    ) -> Result<EcdsaKeyPair, Unspecified> {
+        if alg.id.nid() != NID_secp256k1 {
+            return Err(Unspecified);
+        }
+        let evp_pkey = parse_sec1_private_bn(&self.private_key, NID_secp256k1)?;
+        Ok(EcdsaKeyPair::new(alg, evp_pkey)?)
+    }
+
+    /// Returns the extended public key corresponding to this key.
+    #[must_use]
+    pub fn public_key(&self) -> ExtendedPublicKey {
+        self.public.clone()
+    }
+
+    /// The number of derivation steps from the master key, which has depth 0.
+    #[must_use]
+    pub fn depth(&self) -> u8 {
+        self.public.depth
+    }
+
+    /// The index with which this key was derived from its parent, or 0 for the master key.
+    #[must_use]
+    pub fn child_number(&self) -> u32 {
+        self.public.child_number
+    }
+
+    /// The fingerprint of the parent key, or zeros for the master key.
+    #[must_use]
+    pub fn parent_fingerprint(&self) -> [u8; FINGERPRINT_LEN] {
+        self.public.parent_fingerprint
+    }
+
+    /// The chain code of this key.
+    #[must_use]
+    pub fn chain_code(&self) -> &[u8] {
+        &self.public.chain_code
+    }
+
+    fn new(
+        private_key: &[u8],
+        chain_code: &[u8],
+        depth: u8,
+        parent_fingerprint: [u8; FINGERPRINT_LEN],
+        child_number: u32,
+    ) -> Result<Self, Unspecified> {
+        // Rejects private keys of zero or not less than the order of the curve.
+        let evp_pkey = parse_sec1_private_bn(private_key, NID_secp256k1)?;
+        let mut public_key = [0u8; PUBLIC_KEY_LEN];
+        let public_key_len =
+            marshal_sec1_public_point_into_buffer(&mut public_key, &evp_pkey, true)?;
+        debug_assert_eq!(PUBLIC_KEY_LEN, public_key_len);
+
+        let mut key = Self {
+            private_key: [0; PRIVATE_KEY_LEN],
+            public: ExtendedPublicKey {
+                public_key,
+                chain_code: [0; CHAIN_CODE_LEN],
+                depth,
+                parent_fingerprint,
+                child_number,
+            },
+        };
+        key.private_key.copy_from_slice(private_key);
+        key.public.chain_code.copy_from_slice(chain_code);
+        Ok(key)
+    }
+}
+
+impl Drop for ExtendedPrivateKey {
+    fn drop(&mut self) {
+        self.private_key.zeroize();
+    }
+}
+
+impl Debug for ExtendedPrivateKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExtendedPrivateKey")
+            .field("public", &self.public)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ExtendedPublicKey {
+    /// The public key in SEC 1 compressed form.
+    #[must_use]
+    pub fn public_key(&self) -> &[u8] {
+        &self.public_key
+    }
+
+    /// The number of derivation steps from the master key, which has depth 0.
+    #[must_use]
+    pub fn depth(&self) -> u8 {
+        self.depth
+    }
+
+    /// The index with which this key was derived from its parent, or 0 for the master key.
+    #[must_use]
+    pub fn child_number(&self) -> u32 {
+        self.child_number
+    }
+
+    /// The fingerprint of the parent key, or zeros for the master key.
+    #[must_use]
+    pub fn parent_fingerprint(&self) -> [u8; FINGERPRINT_LEN] {
+        self.parent_fingerprint
+    }
+
+    /// The chain code of this key.
+    #[must_use]
+    pub fn chain_code(&self) -> &[u8] {
+        &self.chain_code
+    }
+
+    /// The fingerprint of this key: the first four bytes of `RIPEMD160(SHA256(public_key))`.
+    #[must_use]
+    pub fn fingerprint(&self) -> [u8; FINGERPRINT_LEN] {
+        let sha256 = digest::digest(&digest::SHA256, &self.public_key);
+        let mut ripemd160 = [0u8; 20];
+        unsafe {
+            RIPEMD160(
+                sha256.as_ref().as_ptr(),
+                sha256.as_ref().len(),
+                ripemd160.as_mut_ptr(),
+            )
+        };
+        let mut fingerprint = [0u8; FINGERPRINT_LEN];
+        fingerprint.copy_from_slice(&ripemd160[..FINGERPRINT_LEN]);
+        fingerprint
+    }
+}
+
+impl Debug for ExtendedPublicKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExtendedPublicKey")
+            .field("public_key", &hex::encode(self.public_key))
+            .field("depth", &self.depth)
+            .field("child_number", &self.child_number)
+            .finish_non_exhaustive()
+    }
+}
+
+// Computes `(tweak + private_key) mod n`, rejecting a `tweak` not less than `n` or a sum of zero.
+fn add_scalars(tweak: &[u8], private_key: &[u8]) -> Result<[u8; PRIVATE_KEY_LEN], Unspecified> {
+    let group = ec_group_from_nid(NID_secp256k1)?;
+    let order = ConstPointer::new(unsafe { EC_GROUP_get0_order(*group) })?;
+    let tweak = SecretBnPtr::try_from(tweak)?;
+    if unsafe { BN_cmp(*tweak.as_const(), *order) } >= 0 {
+        return Err(Unspecified);
+    }
+    let private_key = SecretBnPtr::try_from(private_key)?;
+
+    let mut sum = SecretBnPtr::new(unsafe { BN_new() })?;
+    let mut bn_ctx = LcPtr::new(unsafe { BN_CTX_new() })?;
+    if 1 != unsafe {
+        BN_mod_add(
+            *sum.as_mut(),
+            *tweak.as_const(),
+            *private_key.as_const(),
+            *order,
+            *bn_ctx.as_mut(),
+        )
+    } || 1 == unsafe { BN_is_zero(*sum.as_const()) }
+    {
+        return Err(Unspecified);
+    }
+
+    let mut out = [0u8; PRIVATE_KEY_LEN];
+    if 1 != unsafe { BN_bn2bin_padded(out.as_mut_ptr(), out.len(), *sum.as_const()) } {
+        return Err(Unspecified);
+    }
+    Ok(out)
+}
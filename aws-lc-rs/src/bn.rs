@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0 OR ISC
 
 use crate::aws_lc::{BN_bin2bn, BN_bn2bin, BN_new, BN_num_bytes, BN_set_u64, BIGNUM};
-use crate::ptr::{ConstPointer, DetachableLcPtr, LcPtr};
+use crate::ptr::{ConstPointer, DetachableLcPtr, DetachableSecretBnPtr, LcPtr, SecretBnPtr};
 use core::ptr::null_mut;
 
 impl TryFrom<&[u8]> for LcPtr<BIGNUM> {
@@ -13,6 +13,14 @@ impl TryFrom<&[u8]> for LcPtr<BIGNUM> {
     }
 }
 
+impl TryFrom<&[u8]> for SecretBnPtr {
+    type Error = ();
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        unsafe { SecretBnPtr::new(BN_bin2bn(bytes.as_ptr(), bytes.len(), null_mut())) }
+    }
+}
+
 impl TryFrom<u64> for LcPtr<BIGNUM> {
     type Error = ();
 
@@ -35,6 +43,14 @@ impl TryFrom<&[u8]> for DetachableLcPtr<BIGNUM> {
     }
 }
 
+impl TryFrom<&[u8]> for DetachableSecretBnPtr {
+    type Error = ();
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        unsafe { DetachableSecretBnPtr::new(BN_bin2bn(bytes.as_ptr(), bytes.len(), null_mut())) }
+    }
+}
+
 impl TryFrom<u64> for DetachableLcPtr<BIGNUM> {
     type Error = ();
 
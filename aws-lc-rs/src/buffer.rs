@@ -52,6 +52,51 @@ impl<T> AsRef<[u8]> for Buffer<'_, T> {
     }
 }
 
+/// Marker type for the contents of a [`SecureBuffer`].
+pub struct SecureBufferType {
+    _priv: (),
+}
+
+/// A buffer holding secret bytes, such as derived key material.
+///
+/// Unlike a `Vec<u8>`, the contents are zeroed when the buffer is dropped, and its `Debug`
+/// implementation does not reveal them, so a derived key is not accidentally left in freed memory
+/// or written to a log.
+pub struct SecureBuffer(Buffer<'static, SecureBufferType>);
+
+impl SecureBuffer {
+    pub(crate) fn new(owned: Vec<u8>) -> Self {
+        Self(Buffer::new(owned))
+    }
+
+    /// The number of bytes in the buffer.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.as_ref().len()
+    }
+
+    /// Returns `true` if the buffer is empty.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.as_ref().is_empty()
+    }
+}
+
+impl fmt::Debug for SecureBuffer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        f.write_str("SecureBuffer(<derived key>)")
+    }
+}
+
+impl AsRef<[u8]> for SecureBuffer {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -62,6 +107,15 @@ mod tests {
         assert_eq!(buffer.as_ref(), &[1, 2, 3]);
     }
 
+    #[test]
+    fn test_secure_buffer() {
+        let buffer = SecureBuffer::new(vec![1, 2, 3]);
+        assert_eq!(buffer.as_ref(), &[1, 2, 3]);
+        assert_eq!(3, buffer.len());
+        assert!(!buffer.is_empty());
+        assert_eq!("SecureBuffer(<derived key>)", format!("{buffer:?}"));
+    }
+
     #[test]
     fn test_take_from_slice() {
         let mut slice = [1, 2, 3];
@@ -219,19 +219,27 @@
 #![allow(clippy::module_name_repetitions)]
 
 pub(crate) mod aes;
-pub(crate) mod block;
+// Single-block AES is not part of the documented API; see `block::AesBlockCipher`.
+#[doc(hidden)]
+pub mod block;
 pub(crate) mod chacha;
 pub(crate) mod key;
 mod padded;
 mod streaming;
+mod xts;
 
 pub use padded::{PaddedBlockDecryptingKey, PaddedBlockEncryptingKey};
-pub use streaming::{BufferUpdate, StreamingDecryptingKey, StreamingEncryptingKey};
+pub use streaming::{
+    BufferUpdate, EncryptingContext, PaddedBlockEncryptingContext, StreamingDecryptingKey,
+    StreamingEncryptingKey,
+};
+pub use xts::{XtsDecryptingKey, XtsEncryptingKey, XTS_MAX_SECTOR_LEN};
 
 use crate::aws_lc::{
-    EVP_aes_128_cbc, EVP_aes_128_cfb128, EVP_aes_128_ctr, EVP_aes_128_ecb, EVP_aes_192_cbc,
-    EVP_aes_192_cfb128, EVP_aes_192_ctr, EVP_aes_192_ecb, EVP_aes_256_cbc, EVP_aes_256_cfb128,
-    EVP_aes_256_ctr, EVP_aes_256_ecb, EVP_CIPHER,
+    EVP_aes_128_cbc, EVP_aes_128_cfb128, EVP_aes_128_cfb8, EVP_aes_128_ctr, EVP_aes_128_ecb,
+    EVP_aes_128_ofb, EVP_aes_192_cbc, EVP_aes_192_cfb128, EVP_aes_192_cfb8, EVP_aes_192_ctr,
+    EVP_aes_192_ecb, EVP_aes_192_ofb, EVP_aes_256_cbc, EVP_aes_256_cfb128, EVP_aes_256_cfb8,
+    EVP_aes_256_ctr, EVP_aes_256_ecb, EVP_aes_256_ofb, EVP_CIPHER,
 };
 use crate::buffer::Buffer;
 use crate::error::Unspecified;
@@ -241,6 +249,8 @@ use crate::iv::{FixedLength, IV_LEN_128_BIT};
 use crate::ptr::ConstPointer;
 use core::fmt::Debug;
 use key::SymmetricCipherKey;
+use streaming::KeyedCipherCtx;
+use zeroize::ZeroizeOnDrop;
 
 /// The number of bytes in an AES 128-bit key
 pub use crate::cipher::aes::AES_128_KEY_LEN;
@@ -251,7 +261,10 @@ pub use crate::cipher::aes::AES_192_KEY_LEN;
 /// The number of bytes in an AES 256-bit key
 pub use crate::cipher::aes::AES_256_KEY_LEN;
 
-const MAX_CIPHER_KEY_LEN: usize = AES_256_KEY_LEN;
+/// The number of bytes in an AES-256-XTS key, i.e. two AES 256-bit keys
+pub use crate::cipher::aes::AES_256_XTS_KEY_LEN;
+
+const MAX_CIPHER_KEY_LEN: usize = AES_256_XTS_KEY_LEN;
 
 /// The number of bytes for an AES-CBC initialization vector (IV)
 pub use crate::cipher::aes::AES_CBC_IV_LEN;
@@ -262,6 +275,9 @@ pub use crate::cipher::aes::AES_CTR_IV_LEN;
 /// The number of bytes for an AES-CFB initialization vector (IV)
 pub use crate::cipher::aes::AES_CFB_IV_LEN;
 
+/// The number of bytes for an AES-OFB initialization vector (IV)
+pub use crate::cipher::aes::AES_OFB_IV_LEN;
+
 use crate::cipher::aes::AES_BLOCK_LEN;
 
 const MAX_CIPHER_BLOCK_LEN: usize = AES_BLOCK_LEN;
@@ -279,27 +295,40 @@ pub enum OperatingMode {
     /// CFB 128-bit mode.
     CFB128,
 
+    /// CFB 8-bit mode.
+    CFB8,
+
+    /// Output feedback (OFB) mode.
+    OFB,
+
     /// Electronic Code Book (ECB) mode.
     ECB,
 }
 
 impl OperatingMode {
-    fn evp_cipher(&self, algorithm: &Algorithm) -> ConstPointer<EVP_CIPHER> {
-        ConstPointer::new(match (self, algorithm.id) {
+    fn evp_cipher(&self, algorithm: &Algorithm) -> Result<ConstPointer<EVP_CIPHER>, Unspecified> {
+        Ok(ConstPointer::new(match (self, algorithm.id) {
             (OperatingMode::CBC, AlgorithmId::Aes128) => unsafe { EVP_aes_128_cbc() },
             (OperatingMode::CTR, AlgorithmId::Aes128) => unsafe { EVP_aes_128_ctr() },
             (OperatingMode::CFB128, AlgorithmId::Aes128) => unsafe { EVP_aes_128_cfb128() },
+            (OperatingMode::CFB8, AlgorithmId::Aes128) => unsafe { EVP_aes_128_cfb8() },
+            (OperatingMode::OFB, AlgorithmId::Aes128) => unsafe { EVP_aes_128_ofb() },
             (OperatingMode::ECB, AlgorithmId::Aes128) => unsafe { EVP_aes_128_ecb() },
             (OperatingMode::CBC, AlgorithmId::Aes192) => unsafe { EVP_aes_192_cbc() },
             (OperatingMode::CTR, AlgorithmId::Aes192) => unsafe { EVP_aes_192_ctr() },
             (OperatingMode::CFB128, AlgorithmId::Aes192) => unsafe { EVP_aes_192_cfb128() },
+            (OperatingMode::CFB8, AlgorithmId::Aes192) => unsafe { EVP_aes_192_cfb8() },
+            (OperatingMode::OFB, AlgorithmId::Aes192) => unsafe { EVP_aes_192_ofb() },
             (OperatingMode::ECB, AlgorithmId::Aes192) => unsafe { EVP_aes_192_ecb() },
             (OperatingMode::CBC, AlgorithmId::Aes256) => unsafe { EVP_aes_256_cbc() },
             (OperatingMode::CTR, AlgorithmId::Aes256) => unsafe { EVP_aes_256_ctr() },
             (OperatingMode::CFB128, AlgorithmId::Aes256) => unsafe { EVP_aes_256_cfb128() },
+            (OperatingMode::CFB8, AlgorithmId::Aes256) => unsafe { EVP_aes_256_cfb8() },
+            (OperatingMode::OFB, AlgorithmId::Aes256) => unsafe { EVP_aes_256_ofb() },
             (OperatingMode::ECB, AlgorithmId::Aes256) => unsafe { EVP_aes_256_ecb() },
-        })
-        .unwrap()
+            // XTS keys can only be used with `XtsEncryptingKey` and `XtsDecryptingKey`.
+            (_, AlgorithmId::Aes256Xts) => return Err(Unspecified),
+        })?)
     }
 }
 
@@ -361,6 +390,9 @@ pub enum AlgorithmId {
 
     /// AES 192-bit
     Aes192,
+
+    /// AES 256-bit in XTS mode, using two AES 256-bit keys
+    Aes256Xts,
 }
 
 /// A cipher algorithm.
@@ -392,6 +424,15 @@ pub static AES_256: Algorithm = Algorithm {
     block_len: AES_BLOCK_LEN,
 };
 
+/// AES 256-bit cipher in XTS mode, for use with [`XtsEncryptingKey`] and [`XtsDecryptingKey`].
+///
+/// The key is the concatenation of the data encryption key and the tweak encryption key.
+pub static AES_256_XTS: Algorithm = Algorithm {
+    id: AlgorithmId::Aes256Xts,
+    key_len: AES_256_XTS_KEY_LEN,
+    block_len: AES_BLOCK_LEN,
+};
+
 impl Algorithm {
     fn id(&self) -> &AlgorithmId {
         &self.id
@@ -408,41 +449,54 @@ impl Algorithm {
         mode: OperatingMode,
     ) -> Result<EncryptionContext, Unspecified> {
         match self.id {
-            // TODO: Hopefully support CFB1, and CFB8
+            // TODO: Hopefully support CFB1
             AlgorithmId::Aes128 | AlgorithmId::Aes192 | AlgorithmId::Aes256 => match mode {
-                OperatingMode::CBC | OperatingMode::CTR | OperatingMode::CFB128 => {
-                    Ok(EncryptionContext::Iv128(FixedLength::new()?))
-                }
+                OperatingMode::CBC
+                | OperatingMode::CTR
+                | OperatingMode::CFB128
+                | OperatingMode::CFB8
+                | OperatingMode::OFB => Ok(EncryptionContext::Iv128(FixedLength::new()?)),
                 OperatingMode::ECB => Ok(EncryptionContext::None),
             },
+            AlgorithmId::Aes256Xts => Err(Unspecified),
         }
     }
 
     fn is_valid_encryption_context(&self, mode: OperatingMode, input: &EncryptionContext) -> bool {
         match self.id {
-            // TODO: Hopefully support CFB1, and CFB8
+            // TODO: Hopefully support CFB1
             AlgorithmId::Aes128 | AlgorithmId::Aes192 | AlgorithmId::Aes256 => match mode {
-                OperatingMode::CBC | OperatingMode::CTR | OperatingMode::CFB128 => {
+                OperatingMode::CBC
+                | OperatingMode::CTR
+                | OperatingMode::CFB128
+                | OperatingMode::CFB8
+                | OperatingMode::OFB => {
                     matches!(input, EncryptionContext::Iv128(_))
                 }
                 OperatingMode::ECB => {
                     matches!(input, EncryptionContext::None)
                 }
             },
+            AlgorithmId::Aes256Xts => false,
         }
     }
 
     fn is_valid_decryption_context(&self, mode: OperatingMode, input: &DecryptionContext) -> bool {
-        // TODO: Hopefully support CFB1, and CFB8
+        // TODO: Hopefully support CFB1
         match self.id {
             AlgorithmId::Aes128 | AlgorithmId::Aes192 | AlgorithmId::Aes256 => match mode {
-                OperatingMode::CBC | OperatingMode::CTR | OperatingMode::CFB128 => {
+                OperatingMode::CBC
+                | OperatingMode::CTR
+                | OperatingMode::CFB128
+                | OperatingMode::CFB8
+                | OperatingMode::OFB => {
                     matches!(input, DecryptionContext::Iv128(_))
                 }
                 OperatingMode::ECB => {
                     matches!(input, DecryptionContext::None)
                 }
             },
+            AlgorithmId::Aes256Xts => false,
         }
     }
 }
@@ -473,6 +527,8 @@ impl KeyType for &'static Algorithm {
 }
 
 /// A key bound to a particular cipher algorithm.
+///
+/// The key material is zeroed when the key is dropped.
 pub struct UnboundCipherKey {
     algorithm: &'static Algorithm,
     key_bytes: Buffer<'static, &'static [u8]>,
@@ -485,6 +541,9 @@ impl UnboundCipherKey {
     ///
     /// * [`Unspecified`] if `key_bytes.len()` does not match the length required by `algorithm`.
     pub fn new(algorithm: &'static Algorithm, key_bytes: &[u8]) -> Result<Self, Unspecified> {
+        if key_bytes.len() != algorithm.key_len {
+            return Err(Unspecified);
+        }
         let key_bytes = Buffer::new(key_bytes.to_vec());
         Ok(UnboundCipherKey {
             algorithm,
@@ -500,6 +559,18 @@ impl UnboundCipherKey {
     }
 }
 
+// The key bytes are held in a `Buffer`, which zeroes them on drop.
+impl ZeroizeOnDrop for UnboundCipherKey {}
+
+impl Clone for UnboundCipherKey {
+    fn clone(&self) -> Self {
+        Self {
+            algorithm: self.algorithm,
+            key_bytes: Buffer::new(self.key_bytes.as_ref().to_vec()),
+        }
+    }
+}
+
 impl TryInto<SymmetricCipherKey> for UnboundCipherKey {
     type Error = Unspecified;
 
@@ -508,6 +579,7 @@ impl TryInto<SymmetricCipherKey> for UnboundCipherKey {
             AlgorithmId::Aes128 => SymmetricCipherKey::aes128(self.key_bytes.as_ref()),
             AlgorithmId::Aes192 => SymmetricCipherKey::aes192(self.key_bytes.as_ref()),
             AlgorithmId::Aes256 => SymmetricCipherKey::aes256(self.key_bytes.as_ref()),
+            AlgorithmId::Aes256Xts => Err(Unspecified),
         }
     }
 }
@@ -517,6 +589,7 @@ pub struct EncryptingKey {
     algorithm: &'static Algorithm,
     key: SymmetricCipherKey,
     mode: OperatingMode,
+    keyed_ctx: KeyedCipherCtx,
 }
 
 impl EncryptingKey {
@@ -546,6 +619,22 @@ impl EncryptingKey {
         Self::new(key, OperatingMode::CFB128)
     }
 
+    /// Constructs an `EncryptingKey` operating in cipher feedback 8-bit mode (CFB8) using the provided key.
+    ///
+    /// # Errors
+    /// * [`Unspecified`]: Returned if there is an error constructing the `EncryptingKey`.
+    pub fn cfb8(key: UnboundCipherKey) -> Result<Self, Unspecified> {
+        Self::new(key, OperatingMode::CFB8)
+    }
+
+    /// Constructs an `EncryptingKey` operating in output feedback (OFB) mode using the provided key.
+    ///
+    /// # Errors
+    /// * [`Unspecified`]: Returned if there is an error constructing the `EncryptingKey`.
+    pub fn ofb(key: UnboundCipherKey) -> Result<Self, Unspecified> {
+        Self::new(key, OperatingMode::OFB)
+    }
+
     /// Constructs an `EncryptingKey` operating in electronic code book mode (ECB) using the provided key.
     ///
     /// # ☠️ ️️️DANGER ☠️
@@ -566,11 +655,13 @@ impl EncryptingKey {
     #[allow(clippy::unnecessary_wraps)]
     fn new(key: UnboundCipherKey, mode: OperatingMode) -> Result<Self, Unspecified> {
         let algorithm = key.algorithm();
+        let keyed_ctx = KeyedCipherCtx::new(algorithm, mode, key.key_bytes.as_ref())?;
         let key = key.try_into()?;
         Ok(Self {
             algorithm,
             key,
             mode,
+            keyed_ctx,
         })
     }
 
@@ -625,6 +716,33 @@ impl EncryptingKey {
         }
         encrypt(self.algorithm(), &self.key, self.mode, in_out, context)
     }
+
+    /// Begins an incremental encryption operation with a randomly generated IV.
+    ///
+    /// Input is provided with [`EncryptingContext::update`], and the operation is completed by
+    /// [`EncryptingContext::finalize`], which returns the [`DecryptionContext`]. The ciphertext
+    /// is identical to that produced by [`Self::less_safe_encrypt`] for the concatenated input
+    /// with the same IV.
+    ///
+    /// # Errors
+    /// * [`Unspecified`]: Returned if the IV cannot be generated.
+    pub fn new_encrypt_context(&self) -> Result<EncryptingContext, Unspecified> {
+        let context = self.algorithm.new_encryption_context(self.mode)?;
+        self.less_safe_new_encrypt_context(context)
+    }
+
+    /// Begins an incremental encryption operation using the provided `EncryptionContext`.
+    /// This is considered "less safe" because the caller could potentially construct
+    /// a `EncryptionContext` from a previously used IV (initialization vector).
+    ///
+    /// # Errors
+    /// * [`Unspecified`]: Returned if `context` is not valid for the key's algorithm and mode.
+    pub fn less_safe_new_encrypt_context(
+        &self,
+        context: EncryptionContext,
+    ) -> Result<EncryptingContext, Unspecified> {
+        EncryptingContext::new(self.algorithm, &self.keyed_ctx, self.mode, context)
+    }
 }
 
 impl Debug for EncryptingKey {
@@ -670,6 +788,22 @@ impl DecryptingKey {
         Self::new(key, OperatingMode::CFB128)
     }
 
+    /// Constructs a cipher decrypting key operating in cipher feedback 8-bit mode (CFB8) using the provided key and context.
+    ///
+    /// # Errors
+    /// * [`Unspecified`]: Returned if there is an error during decryption.
+    pub fn cfb8(key: UnboundCipherKey) -> Result<Self, Unspecified> {
+        Self::new(key, OperatingMode::CFB8)
+    }
+
+    /// Constructs a cipher decrypting key operating in output feedback (OFB) mode using the provided key and context.
+    ///
+    /// # Errors
+    /// * [`Unspecified`]: Returned if there is an error during decryption.
+    pub fn ofb(key: UnboundCipherKey) -> Result<Self, Unspecified> {
+        Self::new(key, OperatingMode::OFB)
+    }
+
     /// Constructs an `DecryptingKey` operating in electronic code book (ECB) mode using the provided key.
     ///
     /// # ☠️ ️️️DANGER ☠️
@@ -760,22 +894,32 @@ fn encrypt(
             AlgorithmId::Aes128 | AlgorithmId::Aes192 | AlgorithmId::Aes256 => {
                 aes::encrypt_cbc_mode(key, context, in_out)
             }
+            AlgorithmId::Aes256Xts => Err(Unspecified),
         },
         OperatingMode::CTR => match algorithm.id() {
             AlgorithmId::Aes128 | AlgorithmId::Aes192 | AlgorithmId::Aes256 => {
                 aes::encrypt_ctr_mode(key, context, in_out)
             }
+            AlgorithmId::Aes256Xts => Err(Unspecified),
         },
-        // TODO: Hopefully support CFB1, and CFB8
-        OperatingMode::CFB128 => match algorithm.id() {
+        OperatingMode::OFB => match algorithm.id() {
+            AlgorithmId::Aes128 | AlgorithmId::Aes192 | AlgorithmId::Aes256 => {
+                aes::encrypt_ofb_mode(key, context, in_out)
+            }
+            AlgorithmId::Aes256Xts => Err(Unspecified),
+        },
+        // TODO: Hopefully support CFB1
+        OperatingMode::CFB128 | OperatingMode::CFB8 => match algorithm.id() {
             AlgorithmId::Aes128 | AlgorithmId::Aes192 | AlgorithmId::Aes256 => {
                 aes::encrypt_cfb_mode(key, mode, context, in_out)
             }
+            AlgorithmId::Aes256Xts => Err(Unspecified),
         },
         OperatingMode::ECB => match algorithm.id() {
             AlgorithmId::Aes128 | AlgorithmId::Aes192 | AlgorithmId::Aes256 => {
                 aes::encrypt_ecb_mode(key, context, in_out)
             }
+            AlgorithmId::Aes256Xts => Err(Unspecified),
         },
     }
 }
@@ -803,22 +947,32 @@ fn decrypt<'in_out>(
             AlgorithmId::Aes128 | AlgorithmId::Aes192 | AlgorithmId::Aes256 => {
                 aes::decrypt_cbc_mode(key, context, in_out)
             }
+            AlgorithmId::Aes256Xts => Err(Unspecified),
         },
         OperatingMode::CTR => match algorithm.id() {
             AlgorithmId::Aes128 | AlgorithmId::Aes192 | AlgorithmId::Aes256 => {
                 aes::decrypt_ctr_mode(key, context, in_out)
             }
+            AlgorithmId::Aes256Xts => Err(Unspecified),
+        },
+        OperatingMode::OFB => match algorithm.id() {
+            AlgorithmId::Aes128 | AlgorithmId::Aes192 | AlgorithmId::Aes256 => {
+                aes::decrypt_ofb_mode(key, context, in_out)
+            }
+            AlgorithmId::Aes256Xts => Err(Unspecified),
         },
-        // TODO: Hopefully support CFB1, and CFB8
-        OperatingMode::CFB128 => match algorithm.id() {
+        // TODO: Hopefully support CFB1
+        OperatingMode::CFB128 | OperatingMode::CFB8 => match algorithm.id() {
             AlgorithmId::Aes128 | AlgorithmId::Aes192 | AlgorithmId::Aes256 => {
                 aes::decrypt_cfb_mode(key, mode, context, in_out)
             }
+            AlgorithmId::Aes256Xts => Err(Unspecified),
         },
         OperatingMode::ECB => match algorithm.id() {
             AlgorithmId::Aes128 | AlgorithmId::Aes192 | AlgorithmId::Aes256 => {
                 aes::decrypt_ecb_mode(key, context, in_out)
             }
+            AlgorithmId::Aes256Xts => Err(Unspecified),
         },
     }
 }
@@ -933,6 +1087,40 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_aes_128_cfb8() {
+        let key = from_hex("000102030405060708090a0b0c0d0e0f").unwrap();
+        for i in 0..=50 {
+            helper_test_cipher_n_bytes(key.as_slice(), &AES_128, OperatingMode::CFB8, i);
+        }
+    }
+
+    #[test]
+    fn test_aes_256_cfb8() {
+        let key =
+            from_hex("000102030405060708090a0b0c0d0e0f000102030405060708090a0b0c0d0e0f").unwrap();
+        for i in 0..=50 {
+            helper_test_cipher_n_bytes(key.as_slice(), &AES_256, OperatingMode::CFB8, i);
+        }
+    }
+
+    #[test]
+    fn test_aes_128_ofb() {
+        let key = from_hex("000102030405060708090a0b0c0d0e0f").unwrap();
+        for i in 0..=50 {
+            helper_test_cipher_n_bytes(key.as_slice(), &AES_128, OperatingMode::OFB, i);
+        }
+    }
+
+    #[test]
+    fn test_aes_256_ofb() {
+        let key =
+            from_hex("000102030405060708090a0b0c0d0e0f000102030405060708090a0b0c0d0e0f").unwrap();
+        for i in 0..=50 {
+            helper_test_cipher_n_bytes(key.as_slice(), &AES_256, OperatingMode::OFB, i);
+        }
+    }
+
     #[test]
     fn test_aes_256_ctr() {
         let key =
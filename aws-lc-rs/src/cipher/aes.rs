@@ -4,8 +4,8 @@
 // SPDX-License-Identifier: Apache-2.0 OR ISC
 
 use crate::aws_lc::{
-    AES_cbc_encrypt, AES_cfb128_encrypt, AES_ctr128_encrypt, AES_ecb_encrypt, AES_DECRYPT,
-    AES_ENCRYPT, AES_KEY,
+    AES_cbc_encrypt, AES_cfb128_encrypt, AES_cfb8_encrypt, AES_ctr128_encrypt, AES_ecb_encrypt,
+    AES_ofb128_encrypt, AES_DECRYPT, AES_ENCRYPT, AES_KEY,
 };
 use crate::cipher::block::Block;
 use crate::error::Unspecified;
@@ -23,6 +23,9 @@ pub const AES_192_KEY_LEN: usize = 24;
 /// Length of an AES-256 key in bytes.
 pub const AES_256_KEY_LEN: usize = 32;
 
+/// Length of an AES-256-XTS key in bytes.
+pub const AES_256_XTS_KEY_LEN: usize = 2 * AES_256_KEY_LEN;
+
 /// The number of bytes for an AES-CBC initialization vector (IV)
 pub const AES_CBC_IV_LEN: usize = 16;
 
@@ -32,6 +35,9 @@ pub const AES_CTR_IV_LEN: usize = 16;
 /// The number of bytes for an AES-CFB initialization vector (IV)
 pub const AES_CFB_IV_LEN: usize = 16;
 
+/// The number of bytes for an AES-OFB initialization vector (IV)
+pub const AES_OFB_IV_LEN: usize = 16;
+
 pub const AES_BLOCK_LEN: usize = 16;
 
 #[inline]
@@ -44,6 +50,15 @@ pub(crate) fn encrypt_block(aes_key: &AES_KEY, mut block: Block) -> Block {
     block
 }
 
+pub(crate) fn decrypt_block(aes_key: &AES_KEY, mut block: Block) -> Block {
+    {
+        let block_ref = block.as_mut();
+        debug_assert_eq!(block_ref.len(), AES_BLOCK_LEN);
+        aes_ecb_decrypt(aes_key, block_ref);
+    }
+    block
+}
+
 pub(super) fn encrypt_ctr_mode(
     key: &SymmetricCipherKey,
     context: EncryptionContext,
@@ -80,6 +95,40 @@ pub(super) fn decrypt_ctr_mode<'in_out>(
     encrypt_ctr_mode(key, context.into(), in_out).map(|_| in_out)
 }
 
+pub(super) fn encrypt_ofb_mode(
+    key: &SymmetricCipherKey,
+    context: EncryptionContext,
+    in_out: &mut [u8],
+) -> Result<DecryptionContext, Unspecified> {
+    #[allow(clippy::match_wildcard_for_single_variants)]
+    let key = match &key {
+        SymmetricCipherKey::Aes128 { enc_key, .. }
+        | SymmetricCipherKey::Aes192 { enc_key, .. }
+        | SymmetricCipherKey::Aes256 { enc_key, .. } => enc_key,
+        _ => unreachable!(),
+    };
+
+    let mut iv = {
+        let mut iv = [0u8; AES_OFB_IV_LEN];
+        iv.copy_from_slice((&context).try_into()?);
+        iv
+    };
+
+    aes_ofb128_encrypt(key, &mut iv, in_out);
+    iv.zeroize();
+
+    Ok(context.into())
+}
+
+pub(super) fn decrypt_ofb_mode<'in_out>(
+    key: &SymmetricCipherKey,
+    context: DecryptionContext,
+    in_out: &'in_out mut [u8],
+) -> Result<&'in_out mut [u8], Unspecified> {
+    // OFB decryption is the same operation as encryption
+    encrypt_ofb_mode(key, context.into(), in_out).map(|_| in_out)
+}
+
 pub(super) fn encrypt_cbc_mode(
     key: &SymmetricCipherKey,
     context: EncryptionContext,
@@ -153,8 +202,9 @@ pub(super) fn encrypt_cfb_mode(
     };
 
     let cfb_encrypt: fn(&AES_KEY, &mut [u8], &mut [u8]) = match mode {
-        // TODO: Hopefully support CFB1, and CFB8
+        // TODO: Hopefully support CFB1
         OperatingMode::CFB128 => aes_cfb128_encrypt,
+        OperatingMode::CFB8 => aes_cfb8_encrypt,
         _ => unreachable!(),
     };
 
@@ -186,8 +236,9 @@ pub(super) fn decrypt_cfb_mode<'in_out>(
     };
 
     let cfb_decrypt: fn(&AES_KEY, &mut [u8], &mut [u8]) = match mode {
-        // TODO: Hopefully support CFB1, and CFB8
+        // TODO: Hopefully support CFB1
         OperatingMode::CFB128 => aes_cfb128_decrypt,
+        OperatingMode::CFB8 => aes_cfb8_decrypt,
         _ => unreachable!(),
     };
 
@@ -292,6 +343,20 @@ fn aes_ctr128_encrypt(key: &AES_KEY, iv: &mut [u8], block_buffer: &mut [u8], in_
     Zeroize::zeroize(block_buffer);
 }
 
+fn aes_ofb128_encrypt(key: &AES_KEY, iv: &mut [u8], in_out: &mut [u8]) {
+    let mut num: i32 = 0;
+    indicator_check!(unsafe {
+        AES_ofb128_encrypt(
+            in_out.as_ptr(),
+            in_out.as_mut_ptr(),
+            in_out.len(),
+            key,
+            iv.as_mut_ptr(),
+            &mut num,
+        );
+    });
+}
+
 fn aes_cbc_encrypt(key: &AES_KEY, iv: &mut [u8], in_out: &mut [u8]) {
     indicator_check!(unsafe {
         AES_cbc_encrypt(
@@ -347,3 +412,33 @@ fn aes_cfb128_decrypt(key: &AES_KEY, iv: &mut [u8], in_out: &mut [u8]) {
         );
     });
 }
+
+fn aes_cfb8_encrypt(key: &AES_KEY, iv: &mut [u8], in_out: &mut [u8]) {
+    let mut num: i32 = 0;
+    indicator_check!(unsafe {
+        AES_cfb8_encrypt(
+            in_out.as_ptr(),
+            in_out.as_mut_ptr(),
+            in_out.len(),
+            key,
+            iv.as_mut_ptr(),
+            &mut num,
+            AES_ENCRYPT,
+        );
+    });
+}
+
+fn aes_cfb8_decrypt(key: &AES_KEY, iv: &mut [u8], in_out: &mut [u8]) {
+    let mut num: i32 = 0;
+    indicator_check!(unsafe {
+        AES_cfb8_encrypt(
+            in_out.as_ptr(),
+            in_out.as_mut_ptr(),
+            in_out.len(),
+            key,
+            iv.as_mut_ptr(),
+            &mut num,
+            AES_DECRYPT,
+        );
+    });
+}
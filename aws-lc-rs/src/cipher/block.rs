@@ -3,6 +3,20 @@
 // Modifications copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0 OR ISC
 
+//! Single-block AES encryption and decryption.
+//!
+//! # 🛑 Read Before Using
+//!
+//! [`AesBlockCipher`] applies the raw AES permutation to one 16-byte block at a time, which is
+//! equivalent to AES in ECB mode. It provides neither semantic security nor integrity: equal
+//! plaintext blocks encrypt to equal ciphertext blocks. It is intended only as a building block
+//! for implementing other constructions and for interoperating with legacy systems. Protocols
+//! should use an AEAD from [`crate::aead`] instead.
+
+use crate::cipher::key::SymmetricCipherKey;
+use crate::error::Unspecified;
+use core::fmt::Debug;
+
 /// An array of 16 bytes that can (in the `x86_64` and `AAarch64` ABIs, at least)
 /// be efficiently passed by value and returned by value (i.e. in registers),
 /// and which meets the alignment requirements of `u32` and `u64` (at least)
@@ -46,6 +60,59 @@ impl AsMut<[u8; BLOCK_LEN]> for Block {
     }
 }
 
+/// A raw AES block cipher, operating on a single 16-byte block.
+///
+/// # ⚠️ Warning
+///
+/// **Do not use this type directly to encrypt data in a protocol.** Encrypting blocks
+/// independently with the same key (i.e., ECB mode) reveals which blocks are equal and provides
+/// no integrity. Use an AEAD from [`crate::aead`], or one of the cipher modes in
+/// [`crate::cipher`], instead.
+#[must_use]
+pub struct AesBlockCipher {
+    key: SymmetricCipherKey,
+}
+
+impl AesBlockCipher {
+    /// Constructs an AES-128 block cipher from `key`.
+    ///
+    /// # Errors
+    /// `Unspecified` if the key could not be expanded.
+    pub fn new_128(key: &[u8; 16]) -> Result<Self, Unspecified> {
+        Ok(Self {
+            key: SymmetricCipherKey::aes128(key)?,
+        })
+    }
+
+    /// Constructs an AES-256 block cipher from `key`.
+    ///
+    /// # Errors
+    /// `Unspecified` if the key could not be expanded.
+    pub fn new_256(key: &[u8; 32]) -> Result<Self, Unspecified> {
+        Ok(Self {
+            key: SymmetricCipherKey::aes256(key)?,
+        })
+    }
+
+    /// Encrypts a single block.
+    #[must_use]
+    pub fn encrypt_block(&self, block: &[u8; BLOCK_LEN]) -> [u8; BLOCK_LEN] {
+        *self.key.encrypt_block(Block::from(*block)).as_ref()
+    }
+
+    /// Decrypts a single block.
+    #[must_use]
+    pub fn decrypt_block(&self, block: &[u8; BLOCK_LEN]) -> [u8; BLOCK_LEN] {
+        *self.key.decrypt_block(Block::from(*block)).as_ref()
+    }
+}
+
+impl Debug for AesBlockCipher {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("AesBlockCipher").finish_non_exhaustive()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
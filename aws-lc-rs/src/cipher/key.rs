@@ -125,6 +125,18 @@ impl SymmetricCipherKey {
             SymmetricCipherKey::ChaCha20 { .. } => panic!("Unsupported algorithm!"),
         }
     }
+
+    #[inline]
+    pub(crate) fn decrypt_block(&self, block: Block) -> Block {
+        match self {
+            SymmetricCipherKey::Aes128 { dec_key, .. }
+            | SymmetricCipherKey::Aes192 { dec_key, .. }
+            | SymmetricCipherKey::Aes256 { dec_key, .. } => {
+                super::aes::decrypt_block(dec_key, block)
+            }
+            SymmetricCipherKey::ChaCha20 { .. } => panic!("Unsupported algorithm!"),
+        }
+    }
 }
 
 #[cfg(test)]
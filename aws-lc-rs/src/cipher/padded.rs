@@ -2,11 +2,13 @@
 // SPDX-License-Identifier: Apache-2.0 OR ISC
 use crate::cipher;
 use crate::cipher::key::SymmetricCipherKey;
+use crate::cipher::streaming::KeyedCipherCtx;
 use crate::cipher::{
-    Algorithm, DecryptionContext, EncryptionContext, OperatingMode, UnboundCipherKey,
-    MAX_CIPHER_BLOCK_LEN,
+    Algorithm, DecryptionContext, EncryptionContext, OperatingMode, PaddedBlockEncryptingContext,
+    UnboundCipherKey, MAX_CIPHER_BLOCK_LEN,
 };
 use crate::error::Unspecified;
+use crate::rand;
 use core::fmt::Debug;
 
 /// The cipher block padding strategy.
@@ -15,6 +17,10 @@ use core::fmt::Debug;
 pub(crate) enum PaddingStrategy {
     /// PKCS#7 Padding. ([See RFC 5652](https://datatracker.ietf.org/doc/html/rfc5652#section-6.3))
     PKCS7,
+    /// ISO/IEC 7816-4 Padding: a single `0x80` byte followed by `0x00` bytes.
+    ISO7816,
+    /// ANSI X9.23 Padding: random bytes followed by a byte containing the padding length.
+    X923,
 }
 
 impl PaddingStrategy {
@@ -22,32 +28,52 @@ impl PaddingStrategy {
     where
         InOut: AsMut<[u8]> + for<'in_out> Extend<&'in_out u8>,
     {
+        let mut padding_buffer = [0u8; MAX_CIPHER_BLOCK_LEN];
+        let in_out_len = in_out.as_mut().len();
+        let padding_size = self.padding(block_len, in_out_len, &mut padding_buffer)?;
+        // Possible heap allocation here :(
+        in_out.extend(padding_buffer[0..padding_size].iter());
+        Ok(())
+    }
+
+    // Writes the padding of `in_out_len` bytes of input into `padding_buffer`, returning its
+    // length.
+    pub(super) fn padding(
+        self,
+        block_len: usize,
+        in_out_len: usize,
+        padding_buffer: &mut [u8; MAX_CIPHER_BLOCK_LEN],
+    ) -> Result<usize, Unspecified> {
+        let remainder = in_out_len % block_len;
+        let padding_size = block_len - remainder;
+        let v: u8 = padding_size.try_into().map_err(|_| Unspecified)?;
+        let padding = &mut padding_buffer[0..padding_size];
+
         match self {
             PaddingStrategy::PKCS7 => {
-                let mut padding_buffer = [0u8; MAX_CIPHER_BLOCK_LEN];
-
-                let in_out_len = in_out.as_mut().len();
                 // This implements PKCS#7 padding scheme, used by aws-lc if we were using EVP_CIPHER API's
-                let remainder = in_out_len % block_len;
-                let padding_size = block_len - remainder;
-                let v: u8 = padding_size.try_into().map_err(|_| Unspecified)?;
-                padding_buffer.fill(v);
-                // Possible heap allocation here :(
-                in_out.extend(padding_buffer[0..padding_size].iter());
+                padding.fill(v);
+            }
+            PaddingStrategy::ISO7816 => {
+                padding[0] = 0x80;
+            }
+            PaddingStrategy::X923 => {
+                rand::fill(padding)?;
+                padding[padding_size - 1] = v;
             }
         }
-        Ok(())
+        Ok(padding_size)
     }
 
     fn remove_padding(self, block_len: usize, in_out: &mut [u8]) -> Result<&mut [u8], Unspecified> {
-        match self {
-            PaddingStrategy::PKCS7 => {
-                let block_size: u8 = block_len.try_into().map_err(|_| Unspecified)?;
+        let block_size: u8 = block_len.try_into().map_err(|_| Unspecified)?;
 
-                if in_out.is_empty() || in_out.len() < block_len {
-                    return Err(Unspecified);
-                }
+        if in_out.is_empty() || in_out.len() < block_len {
+            return Err(Unspecified);
+        }
 
+        let padding_len = match self {
+            PaddingStrategy::PKCS7 => {
                 let padding: u8 = in_out[in_out.len() - 1];
                 if padding == 0 || padding > block_size {
                     return Err(Unspecified);
@@ -58,11 +84,64 @@ impl PaddingStrategy {
                         return Err(Unspecified);
                     }
                 }
-
-                let final_len = in_out.len() - padding as usize;
-                Ok(&mut in_out[0..final_len])
+                padding as usize
             }
-        }
+            PaddingStrategy::ISO7816 => {
+                iso7816_padding_len(&in_out[in_out.len() - block_len..]).ok_or(Unspecified)?
+            }
+            PaddingStrategy::X923 => {
+                x923_padding_len(block_size, in_out[in_out.len() - 1]).ok_or(Unspecified)?
+            }
+        };
+
+        let final_len = in_out.len() - padding_len;
+        Ok(&mut in_out[0..final_len])
+    }
+}
+
+/// Returns `0xff` if `a == b`, otherwise `0x00`, without branching on the values.
+#[inline]
+#[allow(clippy::cast_possible_truncation)]
+fn ct_eq_mask(a: u8, b: u8) -> u8 {
+    // `x - 1` underflows into the high byte only if `x` is zero.
+    let x = u16::from(a ^ b);
+    (x.wrapping_sub(1) >> 8) as u8
+}
+
+/// Returns the length of the ISO/IEC 7816-4 padding of `last_block`, or `None` if the padding is
+/// invalid. Every byte of the block is examined, regardless of the position of the `0x80` marker.
+fn iso7816_padding_len(last_block: &[u8]) -> Option<usize> {
+    let mut found = 0u8;
+    let mut invalid = 0u8;
+    let mut padding_len = 0usize;
+    for (i, &byte) in last_block.iter().enumerate().rev() {
+        let is_marker = ct_eq_mask(byte, 0x80);
+        let is_zero = ct_eq_mask(byte, 0x00);
+        let take = !found & is_marker;
+        // Select `last_block.len() - i` if `take` is set, otherwise keep `padding_len`.
+        let take_mask = usize::from(take & 1).wrapping_neg();
+        padding_len = (padding_len & !take_mask) | ((last_block.len() - i) & take_mask);
+        invalid |= !found & !is_zero & !is_marker;
+        found |= take;
+    }
+    if found & !invalid == 0xff {
+        Some(padding_len)
+    } else {
+        None
+    }
+}
+
+/// Returns the length of the ANSI X9.23 padding ending with `last_byte`, or `None` if the padding
+/// is invalid. The filler bytes are not examined.
+#[allow(clippy::cast_possible_truncation)]
+fn x923_padding_len(block_size: u8, last_byte: u8) -> Option<usize> {
+    // Valid if `1 <= last_byte <= block_size`, computed without branching on `last_byte`.
+    let is_zero = ct_eq_mask(last_byte, 0);
+    let too_large = (u16::from(block_size).wrapping_sub(u16::from(last_byte)) >> 8) as u8;
+    if is_zero | too_large == 0 {
+        Some(last_byte as usize)
+    } else {
+        None
     }
 }
 
@@ -72,6 +151,7 @@ pub struct PaddedBlockEncryptingKey {
     key: SymmetricCipherKey,
     mode: OperatingMode,
     padding: PaddingStrategy,
+    keyed_ctx: KeyedCipherCtx,
 }
 
 impl PaddedBlockEncryptingKey {
@@ -102,6 +182,26 @@ impl PaddedBlockEncryptingKey {
         Self::new(key, OperatingMode::ECB, PaddingStrategy::PKCS7)
     }
 
+    /// Constructs a new `PaddedBlockEncryptingKey` cipher with chaining block cipher (CBC) mode.
+    /// Plaintext data is padded following the ISO/IEC 7816-4 scheme: a single `0x80` byte
+    /// followed by as many `0x00` bytes as needed to complete the block.
+    ///
+    /// # Errors
+    /// * [`Unspecified`]: Returned if there is an error constructing a `PaddedBlockEncryptingKey`.
+    pub fn cbc_iso7816(key: UnboundCipherKey) -> Result<Self, Unspecified> {
+        Self::new(key, OperatingMode::CBC, PaddingStrategy::ISO7816)
+    }
+
+    /// Constructs a new `PaddedBlockEncryptingKey` cipher with chaining block cipher (CBC) mode.
+    /// Plaintext data is padded following the ANSI X9.23 scheme: random bytes followed by a byte
+    /// containing the length of the padding.
+    ///
+    /// # Errors
+    /// * [`Unspecified`]: Returned if there is an error constructing a `PaddedBlockEncryptingKey`.
+    pub fn cbc_x923(key: UnboundCipherKey) -> Result<Self, Unspecified> {
+        Self::new(key, OperatingMode::CBC, PaddingStrategy::X923)
+    }
+
     #[allow(clippy::unnecessary_wraps)]
     fn new(
         key: UnboundCipherKey,
@@ -109,12 +209,14 @@ impl PaddedBlockEncryptingKey {
         padding: PaddingStrategy,
     ) -> Result<PaddedBlockEncryptingKey, Unspecified> {
         let algorithm = key.algorithm();
+        let keyed_ctx = KeyedCipherCtx::new(algorithm, mode, key.key_bytes.as_ref())?;
         let key = key.try_into()?;
         Ok(Self {
             algorithm,
             key,
             mode,
             padding,
+            keyed_ctx,
         })
     }
 
@@ -173,6 +275,39 @@ impl PaddedBlockEncryptingKey {
             context,
         )
     }
+
+    /// Begins an incremental encryption operation with a randomly generated IV.
+    ///
+    /// Input is provided with [`PaddedBlockEncryptingContext::update`], and the operation is
+    /// completed by [`PaddedBlockEncryptingContext::finalize`], which pads the input and returns
+    /// the [`DecryptionContext`]. The ciphertext is identical to that produced by
+    /// [`Self::less_safe_encrypt`] for the concatenated input with the same IV.
+    ///
+    /// # Errors
+    /// * [`Unspecified`]: Returned if the IV cannot be generated.
+    pub fn new_encrypt_context(&self) -> Result<PaddedBlockEncryptingContext, Unspecified> {
+        let context = self.algorithm.new_encryption_context(self.mode)?;
+        self.less_safe_new_encrypt_context(context)
+    }
+
+    /// Begins an incremental encryption operation using the provided `EncryptionContext`.
+    /// This is considered "less safe" because the caller could potentially construct
+    /// a `EncryptionContext` from a previously used IV (initialization vector).
+    ///
+    /// # Errors
+    /// * [`Unspecified`]: Returned if `context` is not valid for the key's algorithm and mode.
+    pub fn less_safe_new_encrypt_context(
+        &self,
+        context: EncryptionContext,
+    ) -> Result<PaddedBlockEncryptingContext, Unspecified> {
+        PaddedBlockEncryptingContext::new(
+            self.algorithm,
+            &self.keyed_ctx,
+            self.mode,
+            self.padding,
+            context,
+        )
+    }
 }
 
 impl Debug for PaddedBlockEncryptingKey {
@@ -226,6 +361,27 @@ impl PaddedBlockDecryptingKey {
         Self::new(key, OperatingMode::ECB, PaddingStrategy::PKCS7)
     }
 
+    /// Constructs a new `PaddedBlockDecryptingKey` cipher with chaining block cipher (CBC) mode.
+    /// Decrypted data is unpadded following the ISO/IEC 7816-4 scheme.
+    ///
+    /// The padding is checked in constant time with respect to the values of the padding bytes.
+    ///
+    /// # Errors
+    /// * [`Unspecified`]: Returned if there is an error constructing the `PaddedBlockDecryptingKey`.
+    pub fn cbc_iso7816(key: UnboundCipherKey) -> Result<Self, Unspecified> {
+        Self::new(key, OperatingMode::CBC, PaddingStrategy::ISO7816)
+    }
+
+    /// Constructs a new `PaddedBlockDecryptingKey` cipher with chaining block cipher (CBC) mode.
+    /// Decrypted data is unpadded following the ANSI X9.23 scheme. Only the final length byte of
+    /// the padding is checked, so padding with either random or zero filler bytes is accepted.
+    ///
+    /// # Errors
+    /// * [`Unspecified`]: Returned if there is an error constructing the `PaddedBlockDecryptingKey`.
+    pub fn cbc_x923(key: UnboundCipherKey) -> Result<Self, Unspecified> {
+        Self::new(key, OperatingMode::CBC, PaddingStrategy::X923)
+    }
+
     #[allow(clippy::unnecessary_wraps)]
     fn new(
         key: UnboundCipherKey,
@@ -291,9 +447,9 @@ impl Debug for PaddedBlockDecryptingKey {
 
 #[cfg(test)]
 mod tests {
-    use crate::cipher::padded::PaddingStrategy;
+    use crate::cipher::padded::{iso7816_padding_len, x923_padding_len, PaddingStrategy};
     use crate::cipher::{
-        Algorithm, EncryptionContext, OperatingMode, PaddedBlockDecryptingKey,
+        Algorithm, DecryptionContext, EncryptionContext, OperatingMode, PaddedBlockDecryptingKey,
         PaddedBlockEncryptingKey, UnboundCipherKey, AES_128, AES_256,
     };
     use crate::iv::FixedLength;
@@ -359,6 +515,85 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_aes_cbc_iso7816_x923() {
+        let key_128 = from_hex("000102030405060708090a0b0c0d0e0f").unwrap();
+        let key_256 =
+            from_hex("000102030405060708090a0b0c0d0e0f000102030405060708090a0b0c0d0e0f").unwrap();
+        for (key, alg) in [(&key_128, &AES_128), (&key_256, &AES_256)] {
+            for padding in [PaddingStrategy::ISO7816, PaddingStrategy::X923] {
+                for i in 0..=50 {
+                    helper_test_padded_cipher_n_bytes(
+                        key.as_slice(),
+                        alg,
+                        OperatingMode::CBC,
+                        padding,
+                        i,
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_iso7816_padding_len() {
+        let mut block = [0u8; 16];
+        block[15] = 0x80;
+        assert_eq!(Some(1), iso7816_padding_len(&block));
+        block[15] = 0;
+        block[8] = 0x80;
+        assert_eq!(Some(8), iso7816_padding_len(&block));
+        block[0] = 0x80;
+        assert_eq!(Some(8), iso7816_padding_len(&block));
+        block[8] = 0;
+        assert_eq!(Some(16), iso7816_padding_len(&block));
+
+        // No marker, or a non-zero byte after the marker.
+        assert_eq!(None, iso7816_padding_len(&[0u8; 16]));
+        block[15] = 0x01;
+        assert_eq!(None, iso7816_padding_len(&block));
+        block[15] = 0x80;
+        block[14] = 0x80;
+        assert_eq!(Some(1), iso7816_padding_len(&block));
+    }
+
+    #[test]
+    fn test_x923_padding_len() {
+        for v in 1..=16u8 {
+            assert_eq!(Some(usize::from(v)), x923_padding_len(16, v));
+        }
+        for v in [0u8, 17, 0x80, 0xff] {
+            assert_eq!(None, x923_padding_len(16, v));
+        }
+    }
+
+    #[test]
+    fn test_cbc_invalid_padding() {
+        let key = from_hex("000102030405060708090a0b0c0d0e0f").unwrap();
+        let iv = [0u8; 16];
+        // Ciphertexts of the block `00112233445566778899aabbccddeeff` under `key`, and of the same
+        // block followed by a PKCS#7 padding block. The latter is also valid X9.23 padding.
+        let single_block = from_hex("69c4e0d86a7b0430d8cdb78070b4c55a").unwrap();
+        let pkcs7 =
+            from_hex("69c4e0d86a7b0430d8cdb78070b4c55a9e978e6d16b086570ef794ef97984232").unwrap();
+
+        for (padding, ciphertext) in [
+            (PaddingStrategy::ISO7816, &single_block),
+            (PaddingStrategy::ISO7816, &pkcs7),
+            (PaddingStrategy::X923, &single_block),
+        ] {
+            let decrypting_key = PaddedBlockDecryptingKey::new(
+                UnboundCipherKey::new(&AES_128, &key).unwrap(),
+                OperatingMode::CBC,
+                padding,
+            )
+            .unwrap();
+            let mut in_out = ciphertext.clone();
+            let context = DecryptionContext::Iv128(FixedLength::from(iv));
+            assert!(decrypting_key.decrypt(&mut in_out, context).is_err());
+        }
+    }
+
     macro_rules! padded_cipher_kat {
         ($name:ident, $alg:expr, $mode:expr, $padding:expr, $key:literal, $iv: literal, $plaintext:literal, $ciphertext:literal) => {
             #[test]
@@ -467,4 +702,92 @@ mod tests {
         "a39c1fdf77ea3e1f18178c0ec237c70a",
         "f1af484830a149ee0387b854d65fe87ca0e62efc1c8e6909d4b9ab8666470453"
     );
+
+    padded_cipher_kat!(
+        test_iv_aes_128_cbc_iso7816_16_bytes,
+        &AES_128,
+        OperatingMode::CBC,
+        PaddingStrategy::ISO7816,
+        "000102030405060708090a0b0c0d0e0f",
+        "00000000000000000000000000000000",
+        "00112233445566778899aabbccddeeff",
+        "69c4e0d86a7b0430d8cdb78070b4c55a8e17c55e98d0f4cb1a31eb14b39c547a"
+    );
+
+    padded_cipher_kat!(
+        test_iv_aes_256_cbc_iso7816_15_bytes,
+        &AES_256,
+        OperatingMode::CBC,
+        PaddingStrategy::ISO7816,
+        "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f",
+        "00000000000000000000000000000000",
+        "00112233445566778899aabbccddee",
+        "4ca8c956695b6870e5c94c943eecb88c"
+    );
+
+    padded_cipher_kat!(
+        test_iv_aes_128_cbc_iso7816_0_bytes,
+        &AES_128,
+        OperatingMode::CBC,
+        PaddingStrategy::ISO7816,
+        "000102030405060708090a0b0c0d0e0f",
+        "00000000000000000000000000000000",
+        "",
+        "4399572cd6ea5341b8d35876a7098af7"
+    );
+
+    // X9.23 padding is random, so these vectors only check decryption.
+    macro_rules! padded_cipher_decrypt_kat {
+        ($name:ident, $alg:expr, $mode:expr, $padding:expr, $key:literal, $iv: literal, $plaintext:literal, $ciphertext:literal) => {
+            #[test]
+            fn $name() {
+                let key = from_hex($key).unwrap();
+                let expected_plaintext = from_hex($plaintext).unwrap();
+                let mut in_out = from_hex($ciphertext).unwrap();
+                let mut iv = [0u8; 16];
+                iv.copy_from_slice(&from_hex($iv).unwrap());
+                let context = DecryptionContext::Iv128(FixedLength::from(iv));
+
+                let unbound_key = UnboundCipherKey::new($alg, &key).unwrap();
+                let decrypting_key =
+                    PaddedBlockDecryptingKey::new(unbound_key, $mode, $padding).unwrap();
+
+                let plaintext = decrypting_key.decrypt(&mut in_out, context).unwrap();
+                assert_eq!(expected_plaintext.as_slice(), plaintext);
+            }
+        };
+    }
+
+    padded_cipher_decrypt_kat!(
+        test_iv_aes_128_cbc_x923_zero_filler_15_bytes,
+        &AES_128,
+        OperatingMode::CBC,
+        PaddingStrategy::X923,
+        "000102030405060708090a0b0c0d0e0f",
+        "00000000000000000000000000000000",
+        "00112233445566778899aabbccddee",
+        "77a0785a36a150ed8831ce8aef66ded4"
+    );
+
+    padded_cipher_decrypt_kat!(
+        test_iv_aes_128_cbc_x923_5_bytes,
+        &AES_128,
+        OperatingMode::CBC,
+        PaddingStrategy::X923,
+        "000102030405060708090a0b0c0d0e0f",
+        "00000000000000000000000000000000",
+        "0011223344",
+        "dda56d6908757526f876b13ade7977c9"
+    );
+
+    padded_cipher_decrypt_kat!(
+        test_iv_aes_256_cbc_x923_16_bytes,
+        &AES_256,
+        OperatingMode::CBC,
+        PaddingStrategy::X923,
+        "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f",
+        "00000000000000000000000000000000",
+        "00112233445566778899aabbccddeeff",
+        "8ea2b7ca516745bfeafc49904b49608949fde943fd6cb1965761b35c9c2e69c0"
+    );
 }
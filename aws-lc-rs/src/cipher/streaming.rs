@@ -2,12 +2,14 @@
 // SPDX-License-Identifier: Apache-2.0 OR ISC
 
 use crate::aws_lc::{
-    EVP_CIPHER_CTX_new, EVP_CIPHER_iv_length, EVP_CIPHER_key_length, EVP_DecryptFinal_ex,
-    EVP_DecryptInit_ex, EVP_DecryptUpdate, EVP_EncryptFinal_ex, EVP_EncryptInit_ex,
-    EVP_EncryptUpdate, EVP_CIPHER, EVP_CIPHER_CTX,
+    EVP_CIPHER_CTX_copy, EVP_CIPHER_CTX_new, EVP_CIPHER_CTX_set_padding, EVP_CIPHER_iv_length,
+    EVP_CIPHER_key_length, EVP_DecryptFinal_ex, EVP_DecryptInit_ex, EVP_DecryptUpdate,
+    EVP_EncryptFinal_ex, EVP_EncryptInit_ex, EVP_EncryptUpdate, EVP_CIPHER, EVP_CIPHER_CTX,
 };
+use crate::cipher::padded::PaddingStrategy;
 use crate::cipher::{
     Algorithm, DecryptionContext, EncryptionContext, OperatingMode, UnboundCipherKey,
+    MAX_CIPHER_BLOCK_LEN,
 };
 use crate::error::Unspecified;
 use crate::fips::indicator_check;
@@ -114,6 +116,15 @@ fn evp_decrypt_init(
     Ok(())
 }
 
+impl core::fmt::Debug for StreamingEncryptingKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("StreamingEncryptingKey")
+            .field("algorithm", self.algorithm)
+            .field("mode", &self.mode)
+            .finish_non_exhaustive()
+    }
+}
+
 impl StreamingEncryptingKey {
     #[allow(clippy::needless_pass_by_value)]
     fn new(
@@ -123,7 +134,7 @@ impl StreamingEncryptingKey {
     ) -> Result<Self, Unspecified> {
         let algorithm = key.algorithm();
         let mut cipher_ctx = LcPtr::new(unsafe { EVP_CIPHER_CTX_new() })?;
-        let cipher = mode.evp_cipher(key.algorithm);
+        let cipher = mode.evp_cipher(key.algorithm)?;
         let key_bytes = key.key_bytes.as_ref();
         debug_assert_eq!(
             key_bytes.len(),
@@ -283,6 +294,28 @@ impl StreamingEncryptingKey {
         Self::less_safe_cfb128(key, context)
     }
 
+    /// Constructs a `StreamingEncryptingKey` for encrypting data using the CFB8 cipher mode.
+    /// The resulting ciphertext will be the same length as the plaintext.
+    ///
+    /// # Errors
+    /// Returns an error on an internal failure.
+    pub fn cfb8(key: UnboundCipherKey) -> Result<Self, Unspecified> {
+        let context = key
+            .algorithm()
+            .new_encryption_context(OperatingMode::CFB8)?;
+        Self::less_safe_cfb8(key, context)
+    }
+
+    /// Constructs a `StreamingEncryptingKey` for encrypting data using the OFB cipher mode.
+    /// The resulting ciphertext will be the same length as the plaintext.
+    ///
+    /// # Errors
+    /// Returns an error on an internal failure.
+    pub fn ofb(key: UnboundCipherKey) -> Result<Self, Unspecified> {
+        let context = key.algorithm().new_encryption_context(OperatingMode::OFB)?;
+        Self::less_safe_ofb(key, context)
+    }
+
     /// Constructs a `StreamingEncryptingKey` for encrypting using ECB cipher mode with PKCS7 padding.
     /// The resulting plaintext will be the same length as the ciphertext.
     ///
@@ -312,6 +345,36 @@ impl StreamingEncryptingKey {
         Self::new(key, OperatingMode::CFB128, context)
     }
 
+    /// Constructs a `StreamingEncryptingKey` for encrypting data using the CFB8 cipher mode.
+    /// The resulting ciphertext will be the same length as the plaintext.
+    ///
+    /// This is considered less safe because the caller could potentially construct
+    /// an `EncryptionContext` from a previously used initialization vector (IV).
+    ///
+    /// # Errors
+    /// Returns an error on an internal failure.
+    pub fn less_safe_cfb8(
+        key: UnboundCipherKey,
+        context: EncryptionContext,
+    ) -> Result<Self, Unspecified> {
+        Self::new(key, OperatingMode::CFB8, context)
+    }
+
+    /// Constructs a `StreamingEncryptingKey` for encrypting data using the OFB cipher mode.
+    /// The resulting ciphertext will be the same length as the plaintext.
+    ///
+    /// This is considered less safe because the caller could potentially construct
+    /// an `EncryptionContext` from a previously used initialization vector (IV).
+    ///
+    /// # Errors
+    /// Returns an error on an internal failure.
+    pub fn less_safe_ofb(
+        key: UnboundCipherKey,
+        context: EncryptionContext,
+    ) -> Result<Self, Unspecified> {
+        Self::new(key, OperatingMode::OFB, context)
+    }
+
     /// Constructs a `StreamingEncryptingKey` for encrypting data using the CBC cipher mode
     /// with pkcs7 padding.
     /// The resulting ciphertext will be longer than the plaintext; padding is added
@@ -336,6 +399,15 @@ pub struct StreamingDecryptingKey {
     mode: OperatingMode,
     cipher_ctx: LcPtr<EVP_CIPHER_CTX>,
 }
+impl core::fmt::Debug for StreamingDecryptingKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("StreamingDecryptingKey")
+            .field("algorithm", self.algorithm)
+            .field("mode", &self.mode)
+            .finish_non_exhaustive()
+    }
+}
+
 impl StreamingDecryptingKey {
     #[allow(clippy::needless_pass_by_value)]
     fn new(
@@ -345,7 +417,7 @@ impl StreamingDecryptingKey {
     ) -> Result<Self, Unspecified> {
         let mut cipher_ctx = LcPtr::new(unsafe { EVP_CIPHER_CTX_new() })?;
         let algorithm = key.algorithm();
-        let cipher = mode.evp_cipher(key.algorithm);
+        let cipher = mode.evp_cipher(key.algorithm)?;
         let key_bytes = key.key_bytes.as_ref();
         debug_assert_eq!(
             key_bytes.len(),
@@ -481,6 +553,24 @@ impl StreamingDecryptingKey {
         Self::new(key, OperatingMode::CFB128, context)
     }
 
+    /// Constructs a `StreamingDecryptingKey` for decrypting using the CFB8 cipher mode.
+    /// The resulting plaintext will be the same length as the ciphertext.
+    ///
+    /// # Errors
+    /// Returns an error on an internal failure.
+    pub fn cfb8(key: UnboundCipherKey, context: DecryptionContext) -> Result<Self, Unspecified> {
+        Self::new(key, OperatingMode::CFB8, context)
+    }
+
+    /// Constructs a `StreamingDecryptingKey` for decrypting using the OFB cipher mode.
+    /// The resulting plaintext will be the same length as the ciphertext.
+    ///
+    /// # Errors
+    /// Returns an error on an internal failure.
+    pub fn ofb(key: UnboundCipherKey, context: DecryptionContext) -> Result<Self, Unspecified> {
+        Self::new(key, OperatingMode::OFB, context)
+    }
+
     /// Constructs a `StreamingDecryptingKey` for decrypting using the ECB cipher mode.
     /// The resulting plaintext will be the same length as the ciphertext.
     ///
@@ -498,6 +588,228 @@ impl StreamingDecryptingKey {
     }
 }
 
+/// An `EVP_CIPHER_CTX` initialized with the key, but no IV, of a [`PaddedBlockEncryptingKey`] or
+/// [`EncryptingKey`]. The context of each incremental encryption operation is copied from it.
+///
+/// [`PaddedBlockEncryptingKey`]: super::PaddedBlockEncryptingKey
+/// [`EncryptingKey`]: super::EncryptingKey
+pub(super) struct KeyedCipherCtx(LcPtr<EVP_CIPHER_CTX>);
+
+// The context is only read, by `EVP_CIPHER_CTX_copy`.
+unsafe impl Send for KeyedCipherCtx {}
+unsafe impl Sync for KeyedCipherCtx {}
+
+impl KeyedCipherCtx {
+    pub(super) fn new(
+        algorithm: &'static Algorithm,
+        mode: OperatingMode,
+        key_bytes: &[u8],
+    ) -> Result<Self, Unspecified> {
+        let mut cipher_ctx = LcPtr::new(unsafe { EVP_CIPHER_CTX_new() })?;
+        let cipher = mode.evp_cipher(algorithm)?;
+        evp_encrypt_init(&mut cipher_ctx, &cipher, key_bytes, None)?;
+        // `PaddedBlockEncryptingContext` applies the padding of its key, and the input of an
+        // `EncryptingContext` in ECB mode must be a whole number of blocks.
+        if 1 != unsafe { EVP_CIPHER_CTX_set_padding(*cipher_ctx.as_mut(), 0) } {
+            return Err(Unspecified);
+        }
+        Ok(Self(cipher_ctx))
+    }
+}
+
+impl StreamingEncryptingKey {
+    // Constructs a `StreamingEncryptingKey` from a copy of `keyed_ctx`, setting the IV of
+    // `context`.
+    fn from_keyed_ctx(
+        algorithm: &'static Algorithm,
+        mode: OperatingMode,
+        keyed_ctx: &KeyedCipherCtx,
+        context: EncryptionContext,
+    ) -> Result<Self, Unspecified> {
+        if !algorithm.is_valid_encryption_context(mode, &context) {
+            return Err(Unspecified);
+        }
+        let mut cipher_ctx = LcPtr::new(unsafe { EVP_CIPHER_CTX_new() })?;
+        if 1 != unsafe { EVP_CIPHER_CTX_copy(*cipher_ctx.as_mut(), *keyed_ctx.0.as_const()) } {
+            return Err(Unspecified);
+        }
+        if let EncryptionContext::Iv128(..) = &context {
+            let iv = <&[u8]>::try_from(&context)?;
+            // Without a cipher or key, only the IV of the context is set.
+            if 1 != unsafe {
+                EVP_EncryptInit_ex(
+                    *cipher_ctx.as_mut(),
+                    null(),
+                    null_mut(),
+                    null(),
+                    iv.as_ptr(),
+                )
+            } {
+                return Err(Unspecified);
+            }
+        }
+        Ok(Self {
+            algorithm,
+            mode,
+            cipher_ctx,
+            context,
+        })
+    }
+
+    // Encrypts `data` with `update`, appending the ciphertext to `output`.
+    fn update_vec(&mut self, data: &[u8], output: &mut Vec<u8>) -> Result<(), Unspecified> {
+        let start = output.len();
+        let max_len = data
+            .len()
+            .checked_add(self.algorithm.block_len())
+            .ok_or(Unspecified)?;
+        output.resize(start + max_len, 0);
+        let written_len = self
+            .update(data, &mut output[start..])
+            .map(|update| update.written().len());
+        output.truncate(start + written_len.unwrap_or(0));
+        written_len.map(|_| ())
+    }
+
+    // Finishes the operation with `finish`, appending the final ciphertext to `output`.
+    fn finish_vec(self, output: &mut Vec<u8>) -> Result<DecryptionContext, Unspecified> {
+        let start = output.len();
+        output.resize(start + self.algorithm.block_len(), 0);
+        let result = self
+            .finish(&mut output[start..])
+            .map(|(context, update)| (context, update.written().len()));
+        output.truncate(start + result.as_ref().map_or(0, |(_, len)| *len));
+        result.map(|(context, _)| context)
+    }
+}
+
+/// An incremental encryption operation of a [`PaddedBlockEncryptingKey`](super::PaddedBlockEncryptingKey).
+///
+/// Created by [`PaddedBlockEncryptingKey::new_encrypt_context`](super::PaddedBlockEncryptingKey::new_encrypt_context).
+/// The ciphertext produced by a sequence of calls to `update` followed by `finalize` is identical
+/// to that of encrypting the concatenated input in a single call.
+pub struct PaddedBlockEncryptingContext {
+    inner: StreamingEncryptingKey,
+    padding: PaddingStrategy,
+    // The length of the input, modulo the block length, which determines the padding.
+    partial_block_len: usize,
+}
+
+impl PaddedBlockEncryptingContext {
+    pub(super) fn new(
+        algorithm: &'static Algorithm,
+        keyed_ctx: &KeyedCipherCtx,
+        mode: OperatingMode,
+        padding: PaddingStrategy,
+        context: EncryptionContext,
+    ) -> Result<Self, Unspecified> {
+        Ok(Self {
+            inner: StreamingEncryptingKey::from_keyed_ctx(algorithm, mode, keyed_ctx, context)?,
+            padding,
+            partial_block_len: 0,
+        })
+    }
+
+    /// Encrypts `data`, appending the ciphertext of every complete block to `output`.
+    ///
+    /// Up to one block of input is buffered until the next call to `update` or `finalize`.
+    ///
+    /// # Errors
+    /// * [`Unspecified`]: Returned if encryption fails, in which case `output` is unchanged.
+    pub fn update(&mut self, data: &[u8], output: &mut Vec<u8>) -> Result<(), Unspecified> {
+        self.inner.update_vec(data, output)?;
+        self.partial_block_len = (self.partial_block_len + data.len()) % self.block_len();
+        Ok(())
+    }
+
+    /// Pads and encrypts the buffered input, appending the final ciphertext to `output`.
+    /// Returns the [`DecryptionContext`] needed to decrypt the ciphertext.
+    ///
+    /// # Errors
+    /// * [`Unspecified`]: Returned if encryption fails, in which case `output` is unchanged.
+    pub fn finalize(mut self, output: &mut Vec<u8>) -> Result<DecryptionContext, Unspecified> {
+        let start = output.len();
+        let mut padding = [0u8; MAX_CIPHER_BLOCK_LEN];
+        let padding_len =
+            self.padding
+                .padding(self.block_len(), self.partial_block_len, &mut padding)?;
+        let result = self
+            .inner
+            .update_vec(&padding[..padding_len], output)
+            .and_then(|()| self.inner.finish_vec(output));
+        if result.is_err() {
+            output.truncate(start);
+        }
+        result
+    }
+
+    fn block_len(&self) -> usize {
+        self.inner.algorithm.block_len()
+    }
+}
+
+impl core::fmt::Debug for PaddedBlockEncryptingContext {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PaddedBlockEncryptingContext")
+            .field("algorithm", self.inner.algorithm)
+            .field("mode", &self.inner.mode)
+            .field("padding", &self.padding)
+            .finish_non_exhaustive()
+    }
+}
+
+/// An incremental encryption operation of an [`EncryptingKey`](super::EncryptingKey).
+///
+/// Created by [`EncryptingKey::new_encrypt_context`](super::EncryptingKey::new_encrypt_context).
+/// The ciphertext produced by a sequence of calls to `update` followed by `finalize` is identical
+/// to that of encrypting the concatenated input in a single call.
+pub struct EncryptingContext {
+    inner: StreamingEncryptingKey,
+}
+
+impl EncryptingContext {
+    pub(super) fn new(
+        algorithm: &'static Algorithm,
+        keyed_ctx: &KeyedCipherCtx,
+        mode: OperatingMode,
+        context: EncryptionContext,
+    ) -> Result<Self, Unspecified> {
+        Ok(Self {
+            inner: StreamingEncryptingKey::from_keyed_ctx(algorithm, mode, keyed_ctx, context)?,
+        })
+    }
+
+    /// Encrypts `data`, appending the ciphertext to `output`.
+    ///
+    /// In `OperatingMode::ECB`, up to one block of input is buffered until the next call to
+    /// `update` or `finalize`.
+    ///
+    /// # Errors
+    /// * [`Unspecified`]: Returned if encryption fails, in which case `output` is unchanged.
+    pub fn update(&mut self, data: &[u8], output: &mut Vec<u8>) -> Result<(), Unspecified> {
+        self.inner.update_vec(data, output)
+    }
+
+    /// Encrypts the buffered input, appending the final ciphertext to `output`.
+    /// Returns the [`DecryptionContext`] needed to decrypt the ciphertext.
+    ///
+    /// # Errors
+    /// * [`Unspecified`]: Returned if the key is operating in `OperatingMode::ECB` and the total
+    ///   input length is not a multiple of the block length, or if encryption fails.
+    pub fn finalize(self, output: &mut Vec<u8>) -> Result<DecryptionContext, Unspecified> {
+        self.inner.finish_vec(output)
+    }
+}
+
+impl core::fmt::Debug for EncryptingContext {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("EncryptingContext")
+            .field("algorithm", self.inner.algorithm)
+            .field("mode", &self.inner.mode)
+            .finish_non_exhaustive()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::cipher::{
@@ -5,7 +5,8 @@
 
 use crate::cipher::{
     DecryptingKey, EncryptingKey, PaddedBlockDecryptingKey, PaddedBlockEncryptingKey,
-    StreamingDecryptingKey, StreamingEncryptingKey, UnboundCipherKey, AES_128, AES_192, AES_256,
+    StreamingDecryptingKey, StreamingEncryptingKey, UnboundCipherKey, XtsDecryptingKey,
+    XtsEncryptingKey, AES_128, AES_192, AES_256, AES_256_XTS,
 };
 use crate::fips::{assert_fips_status_indicator, FipsServiceStatus};
 
@@ -27,25 +28,28 @@ const TEST_MESSAGE: &str = "test message";
 
 macro_rules! block_api {
     ($name:ident, $alg:expr, $encrypt_mode:path, $decrypt_mode:path, $key:expr) => {
+        block_api!(
+            $name,
+            $alg,
+            $encrypt_mode,
+            $decrypt_mode,
+            $key,
+            FipsServiceStatus::Approved
+        );
+    };
+    ($name:ident, $alg:expr, $encrypt_mode:path, $decrypt_mode:path, $key:expr, $status:path) => {
         #[test]
         fn $name() {
             let key = $encrypt_mode(UnboundCipherKey::new($alg, $key).unwrap()).unwrap();
 
             let mut in_out = Vec::from(TEST_MESSAGE);
 
-            let context = assert_fips_status_indicator!(
-                key.encrypt(&mut in_out),
-                FipsServiceStatus::Approved
-            )
-            .unwrap();
+            let context = assert_fips_status_indicator!(key.encrypt(&mut in_out), $status).unwrap();
 
             let key = $decrypt_mode(UnboundCipherKey::new($alg, $key).unwrap()).unwrap();
 
-            let in_out = assert_fips_status_indicator!(
-                key.decrypt(&mut in_out, context),
-                FipsServiceStatus::Approved
-            )
-            .unwrap();
+            let in_out =
+                assert_fips_status_indicator!(key.decrypt(&mut in_out, context), $status).unwrap();
 
             assert_eq!(TEST_MESSAGE.as_bytes(), in_out);
         }
@@ -54,6 +58,16 @@ macro_rules! block_api {
 
 macro_rules! streaming_api {
     ($name:ident, $alg:expr, $encrypt_mode:path, $decrypt_mode:path, $key:expr) => {
+        streaming_api!(
+            $name,
+            $alg,
+            $encrypt_mode,
+            $decrypt_mode,
+            $key,
+            FipsServiceStatus::Approved
+        );
+    };
+    ($name:ident, $alg:expr, $encrypt_mode:path, $decrypt_mode:path, $key:expr, $status:path) => {
         #[test]
         fn $name() {
             let mut key = $encrypt_mode(UnboundCipherKey::new($alg, $key).unwrap()).unwrap();
@@ -64,11 +78,9 @@ macro_rules! streaming_api {
             let mut buffer_update = key.update(&input, &mut encrypt_output).unwrap();
 
             let outlen = buffer_update.written().len();
-            let (context, buffer_update) = assert_fips_status_indicator!(
-                key.finish(buffer_update.remainder_mut()),
-                FipsServiceStatus::Approved
-            )
-            .unwrap();
+            let (context, buffer_update) =
+                assert_fips_status_indicator!(key.finish(buffer_update.remainder_mut()), $status)
+                    .unwrap();
 
             let outlen = outlen + buffer_update.written().len();
 
@@ -80,11 +92,9 @@ macro_rules! streaming_api {
             let mut buffer_update = key.update(ciphertext, &mut decrypt_output).unwrap();
 
             let outlen = buffer_update.written().len();
-            let buffer_update = assert_fips_status_indicator!(
-                key.finish(buffer_update.remainder_mut()),
-                FipsServiceStatus::Approved
-            )
-            .unwrap();
+            let buffer_update =
+                assert_fips_status_indicator!(key.finish(buffer_update.remainder_mut()), $status)
+                    .unwrap();
 
             let outlen = outlen + buffer_update.written().len();
             let plaintext = &decrypt_output[0..outlen];
@@ -187,3 +197,80 @@ block_api!(
     DecryptingKey::ctr,
     &TEST_KEY_256_BIT
 );
+
+block_api!(
+    block_aes_128_ecb_pkcs7,
+    &AES_128,
+    PaddedBlockEncryptingKey::ecb_pkcs7,
+    PaddedBlockDecryptingKey::ecb_pkcs7,
+    &TEST_KEY_128_BIT
+);
+
+block_api!(
+    block_aes_256_ecb_pkcs7,
+    &AES_256,
+    PaddedBlockEncryptingKey::ecb_pkcs7,
+    PaddedBlockDecryptingKey::ecb_pkcs7,
+    &TEST_KEY_256_BIT
+);
+
+// CFB and OFB are not within the FIPS boundary of AWS-LC.
+block_api!(
+    block_aes_128_cfb128,
+    &AES_128,
+    EncryptingKey::cfb128,
+    DecryptingKey::cfb128,
+    &TEST_KEY_128_BIT,
+    FipsServiceStatus::NonApproved
+);
+
+block_api!(
+    block_aes_256_cfb8,
+    &AES_256,
+    EncryptingKey::cfb8,
+    DecryptingKey::cfb8,
+    &TEST_KEY_256_BIT,
+    FipsServiceStatus::NonApproved
+);
+
+block_api!(
+    block_aes_256_ofb,
+    &AES_256,
+    EncryptingKey::ofb,
+    DecryptingKey::ofb,
+    &TEST_KEY_256_BIT,
+    FipsServiceStatus::NonApproved
+);
+
+streaming_api!(
+    streaming_aes_256_cfb128,
+    &AES_256,
+    StreamingEncryptingKey::cfb128,
+    StreamingDecryptingKey::cfb128,
+    &TEST_KEY_256_BIT,
+    FipsServiceStatus::NonApproved
+);
+
+#[test]
+fn xts_aes_256() {
+    let key_bytes = [TEST_KEY_256_BIT, [0x11; 32]].concat();
+    let mut sector = [0x42u8; 512];
+
+    let mut key =
+        XtsEncryptingKey::new(UnboundCipherKey::new(&AES_256_XTS, &key_bytes).unwrap()).unwrap();
+    assert_fips_status_indicator!(
+        key.encrypt_sector(1, &mut sector),
+        FipsServiceStatus::Approved
+    )
+    .unwrap();
+
+    let mut key =
+        XtsDecryptingKey::new(UnboundCipherKey::new(&AES_256_XTS, &key_bytes).unwrap()).unwrap();
+    assert_fips_status_indicator!(
+        key.decrypt_sector(1, &mut sector),
+        FipsServiceStatus::Approved
+    )
+    .unwrap();
+
+    assert_eq!([0x42u8; 512], sector);
+}
@@ -0,0 +1,283 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+use crate::aws_lc::{
+    EVP_CIPHER_CTX_new, EVP_Cipher, EVP_DecryptInit_ex, EVP_EncryptInit_ex, EVP_aes_256_xts,
+    EVP_CIPHER_CTX,
+};
+use crate::cipher::aes::AES_BLOCK_LEN;
+use crate::cipher::{Algorithm, AlgorithmId, UnboundCipherKey, AES_256_XTS};
+use crate::error::Unspecified;
+use crate::fips::indicator_check;
+use crate::ptr::LcPtr;
+use core::fmt::Debug;
+use core::ptr::{null, null_mut};
+
+/// The maximum length of a sector (data unit) encrypted with AES-XTS, in bytes.
+pub const XTS_MAX_SECTOR_LEN: usize = 1 << 20;
+
+const XTS_TWEAK_LEN: usize = 16;
+
+/// A key for encrypting storage sectors with AES-256-XTS, as specified in
+/// [IEEE 1619] and [NIST SP 800-38E].
+///
+/// Rather than a random IV, each sector is encrypted with a tweak derived from its sector
+/// number, so that the ciphertext is the same length as the plaintext and can be written
+/// in place. XTS provides no integrity protection.
+///
+/// [IEEE 1619]: https://standards.ieee.org/ieee/1619/4205/
+/// [NIST SP 800-38E]: https://nvlpubs.nist.gov/nistpubs/Legacy/SP/nistspecialpublication800-38e.pdf
+pub struct XtsEncryptingKey {
+    cipher_ctx: LcPtr<EVP_CIPHER_CTX>,
+}
+
+/// A key for decrypting storage sectors encrypted with AES-256-XTS.
+///
+/// See [`XtsEncryptingKey`].
+pub struct XtsDecryptingKey {
+    cipher_ctx: LcPtr<EVP_CIPHER_CTX>,
+}
+
+impl XtsEncryptingKey {
+    /// Constructs an `XtsEncryptingKey` from a key constructed with [`AES_256_XTS`].
+    ///
+    // # FIPS
+    // Use this function with an `UnboundCipherKey` constructed with `AES_256_XTS`.
+    //
+    /// # Errors
+    /// * [`Unspecified`]: Returned if `key` was not constructed with [`AES_256_XTS`], or if
+    ///   the two halves of the key are equal.
+    pub fn new(key: UnboundCipherKey) -> Result<Self, Unspecified> {
+        Ok(Self {
+            cipher_ctx: new_cipher_ctx(&key, true)?,
+        })
+    }
+
+    /// Returns the cipher algorithm.
+    #[must_use]
+    pub fn algorithm(&self) -> &'static Algorithm {
+        &AES_256_XTS
+    }
+
+    /// Encrypts the sector `buf` in-place, using `sector_number` as the tweak.
+    ///
+    /// # Errors
+    /// * [`Unspecified`]: Returned if `buf` is shorter than the AES block length or longer
+    ///   than [`XTS_MAX_SECTOR_LEN`], or if encryption fails.
+    pub fn encrypt_sector(
+        &mut self,
+        sector_number: u64,
+        buf: &mut [u8],
+    ) -> Result<(), Unspecified> {
+        let tweak = sector_tweak(sector_number);
+        if 1 != unsafe {
+            EVP_EncryptInit_ex(
+                *self.cipher_ctx.as_mut(),
+                null(),
+                null_mut(),
+                null(),
+                tweak.as_ptr(),
+            )
+        } {
+            return Err(Unspecified);
+        }
+        xts_cipher(&mut self.cipher_ctx, buf)
+    }
+}
+
+impl XtsDecryptingKey {
+    /// Constructs an `XtsDecryptingKey` from a key constructed with [`AES_256_XTS`].
+    ///
+    // # FIPS
+    // Use this function with an `UnboundCipherKey` constructed with `AES_256_XTS`.
+    //
+    /// # Errors
+    /// * [`Unspecified`]: Returned if `key` was not constructed with [`AES_256_XTS`], or if
+    ///   the two halves of the key are equal.
+    pub fn new(key: UnboundCipherKey) -> Result<Self, Unspecified> {
+        Ok(Self {
+            cipher_ctx: new_cipher_ctx(&key, false)?,
+        })
+    }
+
+    /// Returns the cipher algorithm.
+    #[must_use]
+    pub fn algorithm(&self) -> &'static Algorithm {
+        &AES_256_XTS
+    }
+
+    /// Decrypts the sector `buf` in-place, using `sector_number` as the tweak.
+    ///
+    /// # Errors
+    /// * [`Unspecified`]: Returned if `buf` is shorter than the AES block length or longer
+    ///   than [`XTS_MAX_SECTOR_LEN`], or if decryption fails.
+    pub fn decrypt_sector(
+        &mut self,
+        sector_number: u64,
+        buf: &mut [u8],
+    ) -> Result<(), Unspecified> {
+        let tweak = sector_tweak(sector_number);
+        if 1 != unsafe {
+            EVP_DecryptInit_ex(
+                *self.cipher_ctx.as_mut(),
+                null(),
+                null_mut(),
+                null(),
+                tweak.as_ptr(),
+            )
+        } {
+            return Err(Unspecified);
+        }
+        xts_cipher(&mut self.cipher_ctx, buf)
+    }
+}
+
+impl Debug for XtsEncryptingKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("XtsEncryptingKey")
+            .field("algorithm", self.algorithm())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Debug for XtsDecryptingKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("XtsDecryptingKey")
+            .field("algorithm", self.algorithm())
+            .finish_non_exhaustive()
+    }
+}
+
+fn new_cipher_ctx(
+    key: &UnboundCipherKey,
+    encrypt: bool,
+) -> Result<LcPtr<EVP_CIPHER_CTX>, Unspecified> {
+    if AlgorithmId::Aes256Xts != *key.algorithm().id() {
+        return Err(Unspecified);
+    }
+    let mut cipher_ctx = LcPtr::new(unsafe { EVP_CIPHER_CTX_new() })?;
+    let key_bytes = key.key_bytes.as_ref();
+    // AWS-LC copies the key into the EVP_CIPHER_CTX, and rejects keys whose halves are equal.
+    let result = unsafe {
+        if encrypt {
+            EVP_EncryptInit_ex(
+                *cipher_ctx.as_mut(),
+                EVP_aes_256_xts(),
+                null_mut(),
+                key_bytes.as_ptr(),
+                null(),
+            )
+        } else {
+            EVP_DecryptInit_ex(
+                *cipher_ctx.as_mut(),
+                EVP_aes_256_xts(),
+                null_mut(),
+                key_bytes.as_ptr(),
+                null(),
+            )
+        }
+    };
+    if 1 != result {
+        return Err(Unspecified);
+    }
+    Ok(cipher_ctx)
+}
+
+/// The tweak is the sector number encoded as a 128-bit little-endian integer.
+fn sector_tweak(sector_number: u64) -> [u8; XTS_TWEAK_LEN] {
+    let mut tweak = [0u8; XTS_TWEAK_LEN];
+    tweak[..8].copy_from_slice(&sector_number.to_le_bytes());
+    tweak
+}
+
+fn xts_cipher(cipher_ctx: &mut LcPtr<EVP_CIPHER_CTX>, buf: &mut [u8]) -> Result<(), Unspecified> {
+    if buf.len() < AES_BLOCK_LEN || buf.len() > XTS_MAX_SECTOR_LEN {
+        return Err(Unspecified);
+    }
+    if 1 != indicator_check!(unsafe {
+        EVP_Cipher(
+            *cipher_ctx.as_mut(),
+            buf.as_mut_ptr(),
+            buf.as_ptr(),
+            buf.len(),
+        )
+    }) {
+        return Err(Unspecified);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cipher::{
+        UnboundCipherKey, XtsDecryptingKey, XtsEncryptingKey, AES_256, AES_256_XTS,
+        XTS_MAX_SECTOR_LEN,
+    };
+
+    fn key_bytes() -> Vec<u8> {
+        (0..64).collect()
+    }
+
+    #[test]
+    fn round_trip() {
+        let key = UnboundCipherKey::new(&AES_256_XTS, &key_bytes()).unwrap();
+        let mut encrypting_key = XtsEncryptingKey::new(key).unwrap();
+        let key = UnboundCipherKey::new(&AES_256_XTS, &key_bytes()).unwrap();
+        let mut decrypting_key = XtsDecryptingKey::new(key).unwrap();
+
+        for len in [16, 17, 31, 32, 512, 4096] {
+            #[allow(clippy::cast_possible_truncation)]
+            let plaintext: Vec<u8> = (0..len).map(|i| i as u8).collect();
+            let mut sector = plaintext.clone();
+            encrypting_key.encrypt_sector(7, &mut sector).unwrap();
+            assert_ne!(plaintext, sector);
+
+            // The same plaintext encrypts differently in another sector.
+            let mut other_sector = plaintext.clone();
+            encrypting_key.encrypt_sector(8, &mut other_sector).unwrap();
+            assert_ne!(sector, other_sector);
+
+            decrypting_key.decrypt_sector(7, &mut sector).unwrap();
+            assert_eq!(plaintext, sector);
+        }
+    }
+
+    #[test]
+    fn invalid_keys() {
+        // Only AES_256_XTS keys are accepted.
+        let key = UnboundCipherKey::new(&AES_256, &[1u8; 32]).unwrap();
+        assert!(XtsEncryptingKey::new(key).is_err());
+        let key = UnboundCipherKey::new(&AES_256, &[1u8; 32]).unwrap();
+        assert!(XtsDecryptingKey::new(key).is_err());
+
+        // The two halves of the key must differ.
+        let key = UnboundCipherKey::new(&AES_256_XTS, &[1u8; 64]).unwrap();
+        assert!(XtsEncryptingKey::new(key).is_err());
+        let key = UnboundCipherKey::new(&AES_256_XTS, &[1u8; 64]).unwrap();
+        assert!(XtsDecryptingKey::new(key).is_err());
+    }
+
+    #[test]
+    fn invalid_sector_len() {
+        let key = UnboundCipherKey::new(&AES_256_XTS, &key_bytes()).unwrap();
+        let mut encrypting_key = XtsEncryptingKey::new(key).unwrap();
+        assert!(encrypting_key.encrypt_sector(0, &mut []).is_err());
+        assert!(encrypting_key.encrypt_sector(0, &mut [0u8; 15]).is_err());
+        assert!(encrypting_key
+            .encrypt_sector(0, &mut vec![0u8; XTS_MAX_SECTOR_LEN + 1])
+            .is_err());
+        assert!(encrypting_key
+            .encrypt_sector(0, &mut vec![0u8; XTS_MAX_SECTOR_LEN])
+            .is_ok());
+    }
+
+    #[test]
+    fn test_debug() {
+        let key = UnboundCipherKey::new(&AES_256_XTS, &key_bytes()).unwrap();
+        let encrypting_key = XtsEncryptingKey::new(key).unwrap();
+        assert_eq!(
+            "XtsEncryptingKey { algorithm: Algorithm { id: Aes256Xts, key_len: 64, block_len: 16 }, .. }",
+            format!("{encrypting_key:?}")
+        );
+    }
+}
@@ -0,0 +1,273 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+//! CMAC with AES is specified in [NIST SP 800-38B] and [RFC 4493].
+//!
+//! As with `hmac`, a `CmacKey` can be used for multiple signing or verification operations.
+//! The module-level `cmac` function calculates the tag of a message in a single contiguous
+//! piece, while `CmacContext` should be used if the input is in multiple parts.
+//!
+//! # Example
+//!
+//! ```
+//! use aws_lc_rs::cmac;
+//!
+//! let key = cmac::CmacKey::new_aes128(&[0x2b; 16]);
+//!
+//! let tag = cmac::cmac(&key, b"hello, world");
+//!
+//! let mut ctx = cmac::CmacContext::with_key(&key);
+//! ctx.update(b"hello, ");
+//! ctx.update(b"world");
+//! assert_eq!(tag, ctx.sign());
+//!
+//! cmac::verify(&key, b"hello, world", tag.as_ref())?;
+//!
+//! # Ok::<(), aws_lc_rs::error::Unspecified>(())
+//! ```
+//!
+//! [NIST SP 800-38B]: https://nvlpubs.nist.gov/nistpubs/SpecialPublications/NIST.SP.800-38b.pdf
+//! [RFC 4493]: https://www.rfc-editor.org/rfc/rfc4493
+
+use crate::aws_lc::{
+    CMAC_CTX_copy, CMAC_CTX_new, CMAC_Final, CMAC_Init, CMAC_Update, EVP_aes_128_cbc,
+    EVP_aes_256_cbc, CMAC_CTX, EVP_CIPHER,
+};
+use crate::constant_time;
+use crate::error::Unspecified;
+use crate::fips::indicator_check;
+use crate::ptr::LcPtr;
+use core::ptr::null_mut;
+
+/// The length of a CMAC tag, which is the AES block length.
+pub const TAG_LEN: usize = 16;
+
+/// A CMAC tag.
+///
+/// Comparing two tags with `==` takes time independent of their contents.
+#[derive(Clone, Copy, Debug)]
+pub struct Tag([u8; TAG_LEN]);
+
+impl AsRef<[u8]> for Tag {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl PartialEq for Tag {
+    fn eq(&self, other: &Self) -> bool {
+        constant_time::verify_slices_are_equal(&self.0, &other.0).is_ok()
+    }
+}
+
+impl Eq for Tag {}
+
+struct LcCmacCtx(LcPtr<CMAC_CTX>);
+
+impl LcCmacCtx {
+    fn new(cipher: *const EVP_CIPHER, key_value: &[u8]) -> Result<Self, Unspecified> {
+        let mut ctx = LcPtr::new(unsafe { CMAC_CTX_new() })?;
+        if 1 != unsafe {
+            CMAC_Init(
+                *ctx.as_mut(),
+                key_value.as_ptr().cast(),
+                key_value.len(),
+                cipher,
+                null_mut(),
+            )
+        } {
+            return Err(Unspecified);
+        }
+        Ok(Self(ctx))
+    }
+
+    fn try_clone(&self) -> Result<Self, Unspecified> {
+        let mut ctx = LcPtr::new(unsafe { CMAC_CTX_new() })?;
+        if 1 != unsafe { CMAC_CTX_copy(*ctx.as_mut(), *self.0.as_const()) } {
+            return Err(Unspecified);
+        }
+        Ok(Self(ctx))
+    }
+}
+
+unsafe impl Send for LcCmacCtx {}
+
+impl Clone for LcCmacCtx {
+    fn clone(&self) -> Self {
+        self.try_clone().expect("Unable to clone LcCmacCtx")
+    }
+}
+
+/// An AES key to use for CMAC signing.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone)]
+pub struct CmacKey {
+    key_len: usize,
+    ctx: LcCmacCtx,
+}
+
+unsafe impl Send for CmacKey {}
+// All uses of *mut CMAC_CTX require the creation of a CmacContext, which will clone the key.
+unsafe impl Sync for CmacKey {}
+
+#[allow(clippy::missing_fields_in_debug)]
+impl core::fmt::Debug for CmacKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.debug_struct("CmacKey")
+            .field("key_len", &self.key_len)
+            .finish()
+    }
+}
+
+impl CmacKey {
+    /// Constructs a CMAC key using AES-128.
+    ///
+    /// # Panics
+    /// Panics if the CMAC context cannot be constructed
+    #[must_use]
+    pub fn new_aes128(key_value: &[u8; 16]) -> Self {
+        Self::new(unsafe { EVP_aes_128_cbc() }, key_value)
+    }
+
+    /// Constructs a CMAC key using AES-256.
+    ///
+    /// # Panics
+    /// Panics if the CMAC context cannot be constructed
+    #[must_use]
+    pub fn new_aes256(key_value: &[u8; 32]) -> Self {
+        Self::new(unsafe { EVP_aes_256_cbc() }, key_value)
+    }
+
+    fn new(cipher: *const EVP_CIPHER, key_value: &[u8]) -> Self {
+        Self {
+            key_len: key_value.len(),
+            ctx: LcCmacCtx::new(cipher, key_value).expect("Unable to create CmacContext"),
+        }
+    }
+}
+
+/// A context for multi-step (Init-Update-Finish) CMAC signing.
+///
+/// Use `cmac` for single-step CMAC signing.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone)]
+pub struct CmacContext {
+    ctx: LcCmacCtx,
+}
+
+unsafe impl Send for CmacContext {}
+
+impl core::fmt::Debug for CmacContext {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.debug_struct("CmacContext").finish_non_exhaustive()
+    }
+}
+
+impl CmacContext {
+    /// Constructs a new CMAC signing context using the given key.
+    #[inline]
+    #[must_use]
+    pub fn with_key(key: &CmacKey) -> Self {
+        Self {
+            ctx: key.ctx.clone(),
+        }
+    }
+
+    /// Updates the CMAC with all the data in `data`. `update` may be called
+    /// zero or more times until `sign` is called.
+    ///
+    /// # Panics
+    /// Panics if the CMAC cannot be updated
+    #[inline]
+    pub fn update(&mut self, data: &[u8]) {
+        self.try_update(data).expect("CMAC_Update failed");
+    }
+
+    fn try_update(&mut self, data: &[u8]) -> Result<(), Unspecified> {
+        if 1 != unsafe { CMAC_Update(*self.ctx.0.as_mut(), data.as_ptr(), data.len()) } {
+            return Err(Unspecified);
+        }
+        Ok(())
+    }
+
+    /// Finalizes the CMAC calculation and returns the tag. `sign` consumes the
+    /// context so it cannot be (mis-)used after `sign` has been called.
+    ///
+    /// # Panics
+    /// Panics if the CMAC calculation cannot be finalized
+    #[inline]
+    #[must_use]
+    pub fn sign(self) -> Tag {
+        self.try_sign().expect("CMAC_Final failed")
+    }
+
+    fn try_sign(mut self) -> Result<Tag, Unspecified> {
+        let mut output = [0u8; TAG_LEN];
+        let mut out_len = TAG_LEN;
+        if 1 != indicator_check!(unsafe {
+            CMAC_Final(*self.ctx.0.as_mut(), output.as_mut_ptr(), &mut out_len)
+        }) {
+            return Err(Unspecified);
+        }
+        debug_assert_eq!(TAG_LEN, out_len);
+        Ok(Tag(output))
+    }
+}
+
+/// Calculates the CMAC of `data` using the key `key` in one step.
+///
+/// Use `CmacContext` to calculate CMACs where the input is in multiple parts.
+///
+/// To verify a tag, use `verify` rather than comparing the bytes of the
+/// returned tag.
+#[inline]
+#[must_use]
+pub fn cmac(key: &CmacKey, data: &[u8]) -> Tag {
+    let mut ctx = CmacContext::with_key(key);
+    ctx.update(data);
+    ctx.sign()
+}
+
+/// Calculates the CMAC of `data` using the key `key`, and verifies whether
+/// the resultant value equals `tag`, in one step.
+///
+/// The verification will be done in constant time to prevent timing attacks.
+///
+/// # Errors
+/// `error::Unspecified` if the inputs are not verified.
+#[inline]
+pub fn verify(key: &CmacKey, data: &[u8], tag: &[u8]) -> Result<(), Unspecified> {
+    constant_time::verify_slices_are_equal(cmac(key, data).as_ref(), tag)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cmac::{cmac, CmacContext, CmacKey};
+    use crate::test;
+
+    #[cfg(feature = "fips")]
+    mod fips;
+
+    #[test]
+    fn cmac_key_and_context_traits() {
+        test::compile_time_assert_send::<CmacKey>();
+        test::compile_time_assert_sync::<CmacKey>();
+        test::compile_time_assert_send::<CmacContext>();
+
+        let key = CmacKey::new_aes256(&[0u8; 32]);
+        assert_eq!("CmacKey { key_len: 32 }", format!("{key:?}"));
+
+        let mut ctx = CmacContext::with_key(&key);
+        ctx.update(b"hello");
+        assert_eq!("CmacContext { .. }", format!("{ctx:?}"));
+        let ctx_clone = ctx.clone();
+        ctx.update(b", world");
+        assert_ne!(ctx.sign(), ctx_clone.sign());
+        assert_eq!(cmac(&key, b"hello, world"), {
+            let mut ctx = CmacContext::with_key(&key);
+            ctx.update(b"hello, world");
+            ctx.sign()
+        });
+    }
+}
@@ -0,0 +1,35 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+#![cfg(debug_assertions)]
+
+use crate::cmac::{cmac, verify, CmacKey};
+use crate::fips::{assert_fips_status_indicator, FipsServiceStatus};
+
+const TEST_MESSAGE: &str = "test message";
+
+#[test]
+fn cmac_aes128() {
+    let key = CmacKey::new_aes128(&[42u8; 16]);
+    let tag = assert_fips_status_indicator!(
+        cmac(&key, TEST_MESSAGE.as_bytes()),
+        FipsServiceStatus::Approved
+    );
+    assert_fips_status_indicator!(
+        verify(&key, TEST_MESSAGE.as_bytes(), tag.as_ref()).unwrap(),
+        FipsServiceStatus::Approved
+    );
+}
+
+#[test]
+fn cmac_aes256() {
+    let key = CmacKey::new_aes256(&[42u8; 32]);
+    let tag = assert_fips_status_indicator!(
+        cmac(&key, TEST_MESSAGE.as_bytes()),
+        FipsServiceStatus::Approved
+    );
+    assert_fips_status_indicator!(
+        verify(&key, TEST_MESSAGE.as_bytes(), tag.as_ref()).unwrap(),
+        FipsServiceStatus::Approved
+    );
+}
@@ -14,9 +14,14 @@ use crate::error;
 /// contents of each, but NOT in constant time with respect to the lengths of
 /// `a` and `b`.
 ///
+/// The result should only be used to decide whether to accept or reject the
+/// input as a whole. Branching on it to do further work that depends on the
+/// contents of `a` or `b` can reveal their contents through timing.
+///
 /// # Errors
-/// `error::Unspecified` when `a` and `b` differ.
-#[inline]
+/// `error::Unspecified` when `a` and `b` differ, including when their lengths
+/// differ.
+#[inline(never)]
 pub fn verify_slices_are_equal(a: &[u8], b: &[u8]) -> Result<(), error::Unspecified> {
     if a.len() != b.len() {
         return Err(error::Unspecified);
@@ -27,3 +32,52 @@ pub fn verify_slices_are_equal(a: &[u8], b: &[u8]) -> Result<(), error::Unspecif
         _ => Err(error::Unspecified),
     }
 }
+
+/// Returns `true_val` if `condition` is `true` and `false_val` otherwise.
+///
+/// The selection is done with masking arithmetic, without branching on
+/// `condition`, so it takes the same time regardless of `condition`. As with
+/// `verify_slices_are_equal`, callers must not then branch on the selected
+/// value in a way that reveals `condition` through timing.
+#[inline(never)]
+#[must_use]
+pub fn select_u8(condition: bool, true_val: u8, false_val: u8) -> u8 {
+    // 0xff if `condition` is `true`, 0x00 otherwise.
+    let mask = 0u8.wrapping_sub(u8::from(condition));
+    (true_val & mask) | (false_val & !mask)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constant_time::{select_u8, verify_slices_are_equal};
+
+    #[test]
+    fn test_verify_slices_are_equal() {
+        assert!(verify_slices_are_equal(&[], &[]).is_ok());
+        assert!(verify_slices_are_equal(b"hello", b"hello").is_ok());
+        assert!(verify_slices_are_equal(b"hello", b"hellO").is_err());
+        assert!(verify_slices_are_equal(b"hello", b"jello").is_err());
+
+        let a = [0x5au8; 64];
+        for i in 0..a.len() {
+            let mut b = a;
+            b[i] ^= 1;
+            assert!(verify_slices_are_equal(&a, &b).is_err());
+        }
+    }
+
+    #[test]
+    fn test_verify_slices_are_equal_length_mismatch() {
+        assert!(verify_slices_are_equal(b"hello", b"hello!").is_err());
+        assert!(verify_slices_are_equal(b"hello", b"hell").is_err());
+        assert!(verify_slices_are_equal(b"", b"\0").is_err());
+    }
+
+    #[test]
+    fn test_select_u8() {
+        for (true_val, false_val) in [(0x00, 0xff), (0xff, 0x00), (0x12, 0x34), (0x80, 0x80)] {
+            assert_eq!(true_val, select_u8(true, true_val, false_val));
+            assert_eq!(false_val, select_u8(false, true_val, false_val));
+        }
+    }
+}
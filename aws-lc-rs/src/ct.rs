@@ -0,0 +1,243 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+//! Certificate Transparency as specified in [RFC 6962].
+//!
+//! A Signed Certificate Timestamp (SCT) is a log's promise to include a certificate in its
+//! public log. This module verifies v1 SCTs signed with ECDSA P-256 and SHA-256, as used by
+//! current logs, or with Ed25519.
+//!
+//! SCTs embedded in a certificate sign a [`LogEntry::Precertificate`]: the certificate's
+//! `TBSCertificate` with the SCT list extension removed, together with the SHA-256 hash of the
+//! issuer's `SubjectPublicKeyInfo`. SCTs delivered in a TLS extension or an OCSP response sign
+//! a [`LogEntry::X509Certificate`]: the certificate itself.
+//!
+//! # Example
+//!
+//! ```rust
+//! # use std::error::Error;
+//! #
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! use aws_lc_rs::ct::{self, LogEntry};
+//! use aws_lc_rs::signature::{EcdsaKeyPair, KeyPair, ECDSA_P256_SHA256_ASN1_SIGNING};
+//!
+//! let log_key = EcdsaKeyPair::from_pkcs8(
+//!     &ECDSA_P256_SHA256_ASN1_SIGNING,
+//!     include_bytes!("../tests/data/ct_log_p256.p8"),
+//! )?;
+//! let certificate = include_bytes!("../tests/data/x509_must_staple.der");
+//! let sct = aws_lc_rs::test::from_hex(
+//!     "00a9e4ecbcdcee7fca8cdbc4d315e7a0af747e57a6034cf37ea4789d3431fd79f10000018bcfe5687b000004\
+//!      030046304402201e3138ab178dde20116da57e770f9ebd820a017c432819a13b73d06541db89380220011892\
+//!      17e18d2668543aa64164eab03b323046f18d57de17820d2edd80deb6e7",
+//! )?;
+//!
+//! let entry = LogEntry::X509Certificate(certificate);
+//! let info = ct::verify_sct_ecdsa(log_key.public_key(), &sct, &entry)?;
+//! assert_eq!(1_700_000_000_123, info.timestamp_ms);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [RFC 6962]: https://www.rfc-editor.org/rfc/rfc6962
+
+use crate::digest;
+use crate::ec::signature::AlgorithmID;
+use crate::encoding::AsDer;
+use crate::error::Unspecified;
+use crate::signature::{
+    EcdsaPublicKey, Ed25519PublicKey, UnparsedPublicKey, VerificationAlgorithm,
+    ECDSA_P256_SHA256_ASN1, ED25519,
+};
+
+/// The length of a log ID, the SHA-256 hash of the log's public key.
+pub const LOG_ID_LEN: usize = 32;
+
+const SCT_VERSION_V1: u8 = 0;
+const SIGNATURE_TYPE_CERTIFICATE_TIMESTAMP: u8 = 0;
+const LOG_ENTRY_TYPE_X509: u16 = 0;
+const LOG_ENTRY_TYPE_PRECERT: u16 = 1;
+
+// `SignatureAndHashAlgorithm` values (RFC 5246 section 7.4.1.4.1), with Ed25519 represented by
+// the two bytes of its `SignatureScheme` (RFC 8446 section 4.2.3).
+const ECDSA_SHA256: [u8; 2] = [4, 3];
+const ED25519_SCHEME: [u8; 2] = [8, 7];
+
+/// The certificate that an SCT is for.
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub enum LogEntry<'a> {
+    /// A DER-encoded X.509 certificate, for SCTs delivered in a TLS extension or an OCSP
+    /// response.
+    X509Certificate(&'a [u8]),
+
+    /// A precertificate, for SCTs embedded in a certificate.
+    Precertificate {
+        /// The SHA-256 hash of the DER-encoded `SubjectPublicKeyInfo` of the issuer's key.
+        issuer_key_hash: &'a [u8; 32],
+
+        /// The DER-encoded `TBSCertificate` of the certificate, with the SCT list extension
+        /// removed.
+        tbs_certificate: &'a [u8],
+    },
+}
+
+/// The fields of a verified SCT.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SctInfo {
+    /// The ID of the log that issued the SCT, the SHA-256 hash of its `SubjectPublicKeyInfo`.
+    pub log_id: [u8; LOG_ID_LEN],
+
+    /// The time at which the log issued the SCT, in milliseconds since the Unix epoch.
+    pub timestamp_ms: u64,
+}
+
+/// Verifies a v1 SCT issued by the log with the Ed25519 public key `log_public_key` for `entry`.
+///
+/// # Errors
+/// `error::Unspecified` if `sct` is malformed or not a v1 SCT, if its log ID does not match
+/// `log_public_key`, if it is not signed with Ed25519, or if its signature is invalid.
+pub fn verify_sct(
+    log_public_key: &Ed25519PublicKey,
+    sct: &[u8],
+    entry: &LogEntry<'_>,
+) -> Result<SctInfo, Unspecified> {
+    verify(
+        &ED25519,
+        ED25519_SCHEME,
+        log_public_key.as_ref(),
+        log_public_key.as_der()?.as_ref(),
+        sct,
+        entry,
+    )
+}
+
+/// Verifies a v1 SCT issued by the log with the ECDSA P-256 public key `log_public_key` for
+/// `entry`.
+///
+/// # Errors
+/// `error::Unspecified` if `log_public_key` is not a P-256 key, if `sct` is malformed or not a v1
+/// SCT, if its log ID does not match `log_public_key`, if it is not signed with ECDSA and
+/// SHA-256, or if its signature is invalid.
+pub fn verify_sct_ecdsa(
+    log_public_key: &EcdsaPublicKey,
+    sct: &[u8],
+    entry: &LogEntry<'_>,
+) -> Result<SctInfo, Unspecified> {
+    if *log_public_key.algorithm().id != AlgorithmID::ECDSA_P256 {
+        return Err(Unspecified);
+    }
+    verify(
+        &ECDSA_P256_SHA256_ASN1,
+        ECDSA_SHA256,
+        log_public_key.as_ref(),
+        log_public_key.as_der()?.as_ref(),
+        sct,
+        entry,
+    )
+}
+
+fn verify(
+    algorithm: &'static dyn VerificationAlgorithm,
+    signature_algorithm: [u8; 2],
+    public_key: &[u8],
+    spki: &[u8],
+    sct: &[u8],
+    entry: &LogEntry<'_>,
+) -> Result<SctInfo, Unspecified> {
+    let sct = Sct::parse(sct)?;
+    let log_id = digest::digest(&digest::SHA256, spki);
+    if sct.log_id != log_id.as_ref() || sct.signature_algorithm != signature_algorithm {
+        return Err(Unspecified);
+    }
+
+    let mut signed = Vec::new();
+    signed.push(SCT_VERSION_V1);
+    signed.push(SIGNATURE_TYPE_CERTIFICATE_TIMESTAMP);
+    signed.extend_from_slice(&sct.timestamp_ms.to_be_bytes());
+    match entry {
+        LogEntry::X509Certificate(certificate) => {
+            signed.extend_from_slice(&LOG_ENTRY_TYPE_X509.to_be_bytes());
+            write_u24_prefixed(&mut signed, certificate)?;
+        }
+        LogEntry::Precertificate {
+            issuer_key_hash,
+            tbs_certificate,
+        } => {
+            signed.extend_from_slice(&LOG_ENTRY_TYPE_PRECERT.to_be_bytes());
+            signed.extend_from_slice(&issuer_key_hash[..]);
+            write_u24_prefixed(&mut signed, tbs_certificate)?;
+        }
+    }
+    let extensions_len = u16::try_from(sct.extensions.len()).map_err(|_| Unspecified)?;
+    signed.extend_from_slice(&extensions_len.to_be_bytes());
+    signed.extend_from_slice(sct.extensions);
+
+    UnparsedPublicKey::new(algorithm, public_key).verify(&signed, sct.signature)?;
+
+    let mut info = SctInfo {
+        log_id: [0; LOG_ID_LEN],
+        timestamp_ms: sct.timestamp_ms,
+    };
+    info.log_id.copy_from_slice(sct.log_id);
+    Ok(info)
+}
+
+fn write_u24_prefixed(out: &mut Vec<u8>, value: &[u8]) -> Result<(), Unspecified> {
+    if value.is_empty() || value.len() >= 1 << 24 {
+        return Err(Unspecified);
+    }
+    let len = u32::try_from(value.len()).map_err(|_| Unspecified)?;
+    out.extend_from_slice(&len.to_be_bytes()[1..]);
+    out.extend_from_slice(value);
+    Ok(())
+}
+
+// A `SignedCertificateTimestamp` (RFC 6962 section 3.2).
+struct Sct<'a> {
+    log_id: &'a [u8],
+    timestamp_ms: u64,
+    extensions: &'a [u8],
+    signature_algorithm: [u8; 2],
+    signature: &'a [u8],
+}
+
+impl<'a> Sct<'a> {
+    fn parse(input: &'a [u8]) -> Result<Self, Unspecified> {
+        let mut input = input;
+        if read(&mut input, 1)? != [SCT_VERSION_V1] {
+            return Err(Unspecified);
+        }
+        let log_id = read(&mut input, LOG_ID_LEN)?;
+        let mut timestamp_ms = [0u8; 8];
+        timestamp_ms.copy_from_slice(read(&mut input, 8)?);
+        let extensions = read_u16_prefixed(&mut input)?;
+        let mut signature_algorithm = [0u8; 2];
+        signature_algorithm.copy_from_slice(read(&mut input, 2)?);
+        let signature = read_u16_prefixed(&mut input)?;
+        if !input.is_empty() {
+            return Err(Unspecified);
+        }
+        Ok(Self {
+            log_id,
+            timestamp_ms: u64::from_be_bytes(timestamp_ms),
+            extensions,
+            signature_algorithm,
+            signature,
+        })
+    }
+}
+
+fn read<'a>(input: &mut &'a [u8], len: usize) -> Result<&'a [u8], Unspecified> {
+    if input.len() < len {
+        return Err(Unspecified);
+    }
+    let (value, rest) = input.split_at(len);
+    *input = rest;
+    Ok(value)
+}
+
+fn read_u16_prefixed<'a>(input: &mut &'a [u8]) -> Result<&'a [u8], Unspecified> {
+    let len = read(input, 2)?;
+    read(input, usize::from(u16::from_be_bytes([len[0], len[1]])))
+}
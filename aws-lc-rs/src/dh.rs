@@ -0,0 +1,351 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+//! Finite-field Diffie-Hellman (FFDHE) key agreement using the named groups of
+//! [RFC 7919](https://www.rfc-editor.org/rfc/rfc7919).
+//!
+//! FFDHE is provided for protocols that require it, such as IKE and SSH. New designs should use
+//! elliptic curve key agreement from the [`agreement`](crate::agreement) module instead.
+//!
+//! # Example
+//!
+//! ```rust
+//! use aws_lc_rs::dh::{FfdheGroup, FfdhePair};
+//! use aws_lc_rs::rand::SystemRandom;
+//!
+//! let rng = SystemRandom::new();
+//! let alice = FfdhePair::generate(&FfdheGroup::Ffdhe2048, &rng)?;
+//! let bob = FfdhePair::generate(&FfdheGroup::Ffdhe2048, &rng)?;
+//!
+//! // The public keys are exchanged with the peer.
+//! let alice_public_key = alice.public_key().to_vec();
+//! let bob_public_key = bob.public_key().to_vec();
+//!
+//! let alice_secret = alice.agree(&bob_public_key)?;
+//! let bob_secret = bob.agree(&alice_public_key)?;
+//!
+//! let alice_key = alice_secret.derive(|secret| secret.to_vec());
+//! let bob_key = bob_secret.derive(|secret| secret.to_vec());
+//! assert_eq!(alice_key, bob_key);
+//! # Ok::<(), aws_lc_rs::error::Unspecified>(())
+//! ```
+
+use crate::aws_lc::{
+    BN_bn2bin_padded, DH_check_pub_key, DH_compute_key_padded, DH_generate_key, DH_get0_pub_key,
+    DH_new_by_nid, DH_size, NID_ffdhe2048, NID_ffdhe3072, NID_ffdhe4096, NID_ffdhe8192, BIGNUM, DH,
+};
+use crate::error::Unspecified;
+use crate::ptr::{ConstPointer, LcPtr};
+use crate::rand::SecureRandom;
+use crate::secret::SecretBox;
+use core::fmt::{self, Debug, Formatter};
+use zeroize::Zeroize;
+
+/// A finite-field Diffie-Hellman group from RFC 7919.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FfdheGroup {
+    /// The 2048-bit group `ffdhe2048`.
+    Ffdhe2048,
+    /// The 3072-bit group `ffdhe3072`.
+    Ffdhe3072,
+    /// The 4096-bit group `ffdhe4096`.
+    Ffdhe4096,
+    /// The 8192-bit group `ffdhe8192`.
+    Ffdhe8192,
+}
+
+impl FfdheGroup {
+    /// The length in bytes of the prime of the group, which is also the length of public keys
+    /// and of shared secrets.
+    #[must_use]
+    pub fn prime_len(&self) -> usize {
+        match self {
+            Self::Ffdhe2048 => 2048 / 8,
+            Self::Ffdhe3072 => 3072 / 8,
+            Self::Ffdhe4096 => 4096 / 8,
+            Self::Ffdhe8192 => 8192 / 8,
+        }
+    }
+
+    fn nid(self) -> i32 {
+        match self {
+            Self::Ffdhe2048 => NID_ffdhe2048,
+            Self::Ffdhe3072 => NID_ffdhe3072,
+            Self::Ffdhe4096 => NID_ffdhe4096,
+            Self::Ffdhe8192 => NID_ffdhe8192,
+        }
+    }
+
+    fn new_dh(self) -> Result<LcPtr<DH>, Unspecified> {
+        Ok(LcPtr::new(unsafe { DH_new_by_nid(self.nid()) })?)
+    }
+}
+
+/// An ephemeral FFDHE key pair.
+pub struct FfdhePair {
+    group: FfdheGroup,
+    dh: LcPtr<DH>,
+    public_key: Box<[u8]>,
+}
+
+impl FfdhePair {
+    /// Generates a new key pair in `group`.
+    ///
+    /// # Errors
+    /// `error::Unspecified` when operation fails due to internal error.
+    pub fn generate(group: &FfdheGroup, _rng: &dyn SecureRandom) -> Result<Self, Unspecified> {
+        let mut dh = group.new_dh()?;
+        if 1 != unsafe { DH_generate_key(*dh.as_mut()) } {
+            return Err(Unspecified);
+        }
+        Self::new(*group, dh)
+    }
+
+    fn new(group: FfdheGroup, dh: LcPtr<DH>) -> Result<Self, Unspecified> {
+        let pub_key = ConstPointer::new(unsafe { DH_get0_pub_key(*dh.as_const()) })?;
+        let mut public_key = vec![0u8; group.prime_len()];
+        if 1 != unsafe { BN_bn2bin_padded(public_key.as_mut_ptr(), public_key.len(), *pub_key) } {
+            return Err(Unspecified);
+        }
+        Ok(Self {
+            group,
+            dh,
+            public_key: public_key.into_boxed_slice(),
+        })
+    }
+
+    /// The group of the key pair.
+    #[must_use]
+    pub fn group(&self) -> FfdheGroup {
+        self.group
+    }
+
+    /// The public key, as a big-endian integer padded to [`FfdheGroup::prime_len`] bytes.
+    #[must_use]
+    pub fn public_key(&self) -> &[u8] {
+        &self.public_key
+    }
+
+    /// Computes the secret shared with the holder of `peer_public_key`, consuming the key pair.
+    ///
+    /// `peer_public_key` is a big-endian integer, which must lie in the range `[2, p-2]` for the
+    /// prime `p` of the group and be an element of its prime-order subgroup. The secret is padded
+    /// to [`FfdheGroup::prime_len`] bytes, as required by TLS 1.3.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if `peer_public_key` is not a valid public key for the group.
+    pub fn agree(self, peer_public_key: &[u8]) -> Result<SharedSecret, Unspecified> {
+        if peer_public_key.len() > self.group.prime_len() {
+            return Err(Unspecified);
+        }
+        let peer_public_key = LcPtr::<BIGNUM>::try_from(peer_public_key)?;
+
+        let mut flags = 0;
+        if 1 != unsafe {
+            DH_check_pub_key(*self.dh.as_const(), *peer_public_key.as_const(), &mut flags)
+        } || flags != 0
+        {
+            return Err(Unspecified);
+        }
+
+        let mut dh = self.dh;
+        let secret_len = usize::try_from(unsafe { DH_size(*dh.as_const()) })?;
+        let mut secret = vec![0u8; secret_len];
+        let result = unsafe {
+            DH_compute_key_padded(
+                secret.as_mut_ptr(),
+                *peer_public_key.as_const(),
+                *dh.as_mut(),
+            )
+        };
+        if usize::try_from(result) != Ok(secret_len) {
+            secret.zeroize();
+            return Err(Unspecified);
+        }
+        Ok(SharedSecret(SecretBox::from(secret)))
+    }
+}
+
+impl Debug for FfdhePair {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FfdhePair")
+            .field("group", &self.group)
+            .finish_non_exhaustive()
+    }
+}
+
+unsafe impl Send for FfdhePair {}
+unsafe impl Sync for FfdhePair {}
+
+/// A secret established by [`FfdhePair::agree`].
+///
+/// The secret is only exposed to the key derivation function passed to [`SharedSecret::derive`].
+pub struct SharedSecret(SecretBox<Vec<u8>>);
+
+impl SharedSecret {
+    /// Consumes the secret, calling `kdf` with its bytes and returning what `kdf` returns.
+    ///
+    /// As with any key agreement, the secret should be passed through a key derivation function
+    /// rather than used directly as a key.
+    #[inline]
+    pub fn derive<F, R>(self, kdf: F) -> R
+    where
+        F: FnOnce(&[u8]) -> R,
+    {
+        kdf(self.0.expose())
+    }
+}
+
+impl Debug for SharedSecret {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SharedSecret").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aws_lc::{DH_generate_key, DH_get0_p, DH_set0_key, BIGNUM};
+    use crate::dh::{FfdheGroup, FfdhePair};
+    use crate::ptr::{ConstPointer, DetachableLcPtr};
+    use crate::rand::SystemRandom;
+    use crate::test::from_dirty_hex;
+    use core::ptr::null_mut;
+
+    // RFC 7919, Appendix A.1.
+    const FFDHE2048_P: &str = "
+        FFFFFFFF FFFFFFFF ADF85458 A2BB4A9A AFDC5620 273D3CF1
+        D8B9C583 CE2D3695 A9E13641 146433FB CC939DCE 249B3EF9
+        7D2FE363 630C75D8 F681B202 AEC4617A D3DF1ED5 D5FD6561
+        2433F51F 5F066ED0 85636555 3DED1AF3 B557135E 7F57C935
+        984F0C70 E0E68B77 E2A689DA F3EFE872 1DF158A1 36ADE735
+        30ACCA4F 483A797A BC0AB182 B324FB61 D108A94B B2C8E3FB
+        B96ADAB7 60D7F468 1D4F42A3 DE394DF4 AE56EDE7 6372BB19
+        0B07A7C8 EE0A6D70 9E02FCE1 CDF7E2EC C03404CD 28342F61
+        9172FE9C E98583FF 8E4F1232 EEF28183 C3FE3B1B 4C6FAD73
+        3BB5FCBC 2EC22005 C58EF183 7D1683B2 C6F34A26 C1B2EFFA
+        886B4238 61285C97 FFFFFFFF FFFFFFFF";
+
+    // Private keys chosen for this test; the public keys and shared secret were computed with
+    // Python's `pow`.
+    const FFDHE2048_XA: &str = "
+        15abe273 ed8bbb39 df82e25c 379efdd9 15a86234 e5343e5b 89e2a0f5 dcde67a7";
+    const FFDHE2048_YA: &str = "
+        feb3e6d9 74ddfe4f 6ee8af02 002a6438 3d032107 bf1995f5 abd84770 0026e1d4
+        f46c11bf 2a375b29 fed6b927 2ad65ea7 31679247 15149cdd c173deda cb697390
+        9e7075c7 94848def bf1f5175 d969b6b1 548e5a45 83fc37ca a094aad1 1be8b3f6
+        ed91a9a4 4e565a2c c37683a6 9ab94e94 aaa703fa 79ecd3f6 598b88c4 0cd265ed
+        076551db 3ee8b7f1 27dc11c7 c6c90f0f 88aeb358 cc2ddb43 e1a96be0 b9d7f6b2
+        33cfd8d0 551c94c6 8513f2e7 ad832979 67d58173 afd38141 b1d06320 006988f7
+        362c798f be4e8bed 279c3a77 77cde2fc 9cac3b2a d1fec7de fbec04a7 ad4837eb
+        bbccd434 19044551 a285e8dd 6ffd2c41 3da1428c a0fe1bc4 248c9d07 f02707d3";
+    const FFDHE2048_XB: &str = "
+        a59cec5d c86428ef 013905e0 d5be9619 d4ad0a2a 1669db50 b79fe425 d8a6fa83";
+    const FFDHE2048_YB: &str = "
+        697d1ae7 a17ff209 d5c2912c c10d25eb 0b1ce15f a5d92347 e290c0b0 c4ff5363
+        1214bdf6 2625a95a bd97db5e 1e156c18 db4dee74 060bf0d2 0ad68bdb 859aa812
+        ccb8a65c 834131ad ace08fc6 3b19120b 4e1ddb14 35a800ff a01e6b88 981debe5
+        0dc507ec bceeb996 45f71dd6 6bf590e3 3e760c86 cc853adf adea45c9 1a6deea9
+        2b8887f0 d6cc55a5 a7ecdf4e e8635026 bb7ae76b 37491124 6e8fcec5 9255fc31
+        8d84bfb9 c6f613cb 47baa384 5c4a194a 36fa82d1 13e46456 e0af51b2 9cf78e27
+        39f2dce2 876c72d1 9997e0ec 1d1f412d 53ad5ea6 70e37d75 02f1db27 614b8e33
+        836137bb 77fcceff 61b204fc a7e8ab19 f7466860 d030fe06 6f0d09b0 a3e24418";
+    const FFDHE2048_Z: &str = "
+        96661f3e c23bfec1 d0b136c7 1ee1dcee ddbe9d35 ea7d054e b283e30d 08fe343b
+        116d85f0 d661af80 b9921d89 faffe664 d7a2ca07 d452c114 3ec07aba 9d31ada2
+        e3fa1b8e 4457805c 37843d8b d3c34c81 c6c0cfe6 3d79f516 ead915bb ed093155
+        3dbde1f8 346f041d 3472c14e aefbfe42 c434f342 e82b97b5 cd31f124 a76e5125
+        32547897 b85a9ca9 661b884c fd1a7908 e8a7a961 3a5747e0 d2b74c36 cc04d0be
+        04ef99fb e007c2b4 445b53ff 32b310dc 0f9da44f 57a4d0da 12c277bf a4bf0327
+        e3749464 f9ca7a30 0dce25e1 8ca974d5 78a8fd59 02247986 b25cd50e 6b6eed22
+        32d7a634 2a32ac65 4bdadf9c 332aa967 f3f84afa 554a8ecf e0f727b8 ec5bb31e";
+
+    fn pair_from_private_key(group: FfdheGroup, private_key: &[u8]) -> FfdhePair {
+        let mut dh = group.new_dh().unwrap();
+        let private_key = DetachableLcPtr::<BIGNUM>::try_from(private_key).unwrap();
+        unsafe {
+            assert_eq!(1, DH_set0_key(*dh.as_mut(), null_mut(), *private_key));
+            private_key.detach();
+            assert_eq!(1, DH_generate_key(*dh.as_mut()));
+        }
+        FfdhePair::new(group, dh).unwrap()
+    }
+
+    #[test]
+    fn ffdhe2048_prime() {
+        let dh = FfdheGroup::Ffdhe2048.new_dh().unwrap();
+        let p = ConstPointer::new(unsafe { DH_get0_p(*dh.as_const()) }).unwrap();
+        assert_eq!(from_dirty_hex(FFDHE2048_P), p.to_be_bytes());
+    }
+
+    #[test]
+    fn ffdhe2048_known_answer() {
+        let expected_ya = from_dirty_hex(FFDHE2048_YA);
+        let expected_yb = from_dirty_hex(FFDHE2048_YB);
+        let expected_z = from_dirty_hex(FFDHE2048_Z);
+
+        let alice = pair_from_private_key(FfdheGroup::Ffdhe2048, &from_dirty_hex(FFDHE2048_XA));
+        let bob = pair_from_private_key(FfdheGroup::Ffdhe2048, &from_dirty_hex(FFDHE2048_XB));
+        assert_eq!(expected_ya, alice.public_key());
+        assert_eq!(expected_yb, bob.public_key());
+
+        let z = alice.agree(&expected_yb).unwrap();
+        assert_eq!(expected_z, z.derive(<[u8]>::to_vec));
+        let z = bob.agree(&expected_ya).unwrap();
+        assert_eq!(expected_z, z.derive(<[u8]>::to_vec));
+    }
+
+    #[test]
+    fn ffdhe_agreement() {
+        let rng = SystemRandom::new();
+        for group in [
+            FfdheGroup::Ffdhe2048,
+            FfdheGroup::Ffdhe3072,
+            FfdheGroup::Ffdhe4096,
+            FfdheGroup::Ffdhe8192,
+        ] {
+            let alice = FfdhePair::generate(&group, &rng).unwrap();
+            let bob = FfdhePair::generate(&group, &rng).unwrap();
+            assert_eq!(group, alice.group());
+            assert_eq!(group.prime_len(), alice.public_key().len());
+
+            let alice_public_key = alice.public_key().to_vec();
+            let bob_public_key = bob.public_key().to_vec();
+            let alice_secret = alice.agree(&bob_public_key).unwrap();
+            let bob_secret = bob.agree(&alice_public_key).unwrap();
+            assert_eq!(
+                alice_secret.derive(<[u8]>::to_vec),
+                bob_secret.derive(<[u8]>::to_vec)
+            );
+        }
+    }
+
+    #[test]
+    fn ffdhe_rejects_invalid_public_keys() {
+        let rng = SystemRandom::new();
+        let group = FfdheGroup::Ffdhe2048;
+        let p = from_dirty_hex(FFDHE2048_P);
+        let mut p_minus_1 = p.clone();
+        *p_minus_1.last_mut().unwrap() -= 1;
+        // p - 2 is in range, but is not in the prime-order subgroup.
+        let mut p_minus_2 = p.clone();
+        *p_minus_2.last_mut().unwrap() -= 2;
+
+        for invalid in [
+            &[][..],
+            &[0],
+            &[1],
+            &p_minus_2,
+            &p_minus_1,
+            &p,
+            &[0xff; 256],
+            &[1; 257],
+        ] {
+            let pair = FfdhePair::generate(&group, &rng).unwrap();
+            assert!(pair.agree(invalid).is_err());
+        }
+
+        let pair = FfdhePair::generate(&group, &rng).unwrap();
+        assert!(pair.agree(&[2]).is_ok());
+    }
+}
@@ -8,6 +8,16 @@
 //! If all the data is available in a single contiguous slice then the `digest`
 //! function should be used. Otherwise, the digest can be calculated in
 //! multiple steps using `Context`.
+//!
+//! BLAKE2b-256 ([RFC 7693]) is also provided, except in FIPS builds. The broken
+//! MD5 algorithm ([RFC 1321]) is provided for compatibility with existing systems
+//! when the "legacy" feature is enabled.
+//!
+//! The SHAKE128 and SHAKE256 extendable-output functions, which produce output
+//! of any length, are provided by `shake128`, `shake256` and `ShakeContext`.
+//!
+//! [RFC 7693]: https://www.rfc-editor.org/rfc/rfc7693
+//! [RFC 1321]: https://www.rfc-editor.org/rfc/rfc1321
 
 //! # Example
 //!
@@ -31,21 +41,35 @@
 use crate::fips::indicator_check;
 use crate::{debug, derive_debug_via_id};
 
+#[cfg(not(feature = "fips"))]
+mod blake2;
 pub(crate) mod digest_ctx;
+#[cfg(all(feature = "legacy", not(feature = "fips")))]
+mod md5;
 mod sha;
+mod shake;
+#[cfg(not(feature = "fips"))]
+use crate::aws_lc::EVP_blake2b256;
+#[cfg(all(feature = "legacy", not(feature = "fips")))]
+use crate::aws_lc::EVP_md5;
 use crate::aws_lc::{
     EVP_DigestFinal, EVP_DigestUpdate, EVP_sha1, EVP_sha224, EVP_sha256, EVP_sha384, EVP_sha3_256,
-    EVP_sha3_384, EVP_sha3_512, EVP_sha512, EVP_sha512_256, EVP_MD,
+    EVP_sha3_384, EVP_sha3_512, EVP_sha512, EVP_sha512_224, EVP_sha512_256, EVP_MD,
 };
 use crate::error::Unspecified;
 use crate::ptr::ConstPointer;
+#[cfg(not(feature = "fips"))]
+pub use blake2::{BLAKE2B_256, BLAKE2B_256_OUTPUT_LEN};
 use core::mem::MaybeUninit;
 use digest_ctx::DigestContext;
+#[cfg(all(feature = "legacy", not(feature = "fips")))]
+pub use md5::{MD5_FOR_LEGACY_USE_ONLY, MD5_OUTPUT_LEN};
 pub use sha::{
     SHA1_FOR_LEGACY_USE_ONLY, SHA1_OUTPUT_LEN, SHA224, SHA224_OUTPUT_LEN, SHA256,
-    SHA256_OUTPUT_LEN, SHA384, SHA384_OUTPUT_LEN, SHA3_256, SHA3_384, SHA3_512, SHA512, SHA512_256,
-    SHA512_256_OUTPUT_LEN, SHA512_OUTPUT_LEN,
+    SHA256_OUTPUT_LEN, SHA384, SHA384_OUTPUT_LEN, SHA3_256, SHA3_384, SHA3_512, SHA512, SHA512_224,
+    SHA512_224_OUTPUT_LEN, SHA512_256, SHA512_256_OUTPUT_LEN, SHA512_OUTPUT_LEN,
 };
+pub use shake::{shake128, shake256, ShakeContext};
 // TODO: Uncomment when MSRV >= 1.64
 //use core::ffi::c_uint;
 use std::os::raw::c_uint;
@@ -59,6 +83,7 @@ use std::os::raw::c_uint;
 // * `SHA256`
 // * `SHA384`
 // * `SHA512`
+// * `SHA512_224`
 // * `SHA512_256`
 #[derive(Clone)]
 pub struct Context {
@@ -177,6 +202,7 @@ impl Context {
 // * `SHA256`
 // * `SHA384`
 // * `SHA512`
+// * `SHA512_224`
 // * `SHA512_256`
 //
 /// # Examples:
@@ -311,10 +337,16 @@ pub(crate) enum AlgorithmID {
     SHA256,
     SHA384,
     SHA512,
+    SHA512_224,
     SHA512_256,
     SHA3_256,
     SHA3_384,
     SHA3_512,
+    #[cfg(not(feature = "fips"))]
+    #[allow(non_camel_case_types)]
+    BLAKE2B_256,
+    #[cfg(all(feature = "legacy", not(feature = "fips")))]
+    MD5,
 }
 
 impl PartialEq for Algorithm {
@@ -339,6 +371,14 @@ pub const MAX_OUTPUT_LEN: usize = 512 / 8;
 /// algorithms in this module.
 pub const MAX_CHAINING_LEN: usize = MAX_OUTPUT_LEN;
 
+/// MD5 is not FIPS-approved, so it is not available in FIPS builds:
+///
+/// ```compile_fail
+/// let _ = aws_lc_rs::digest::digest(&aws_lc_rs::digest::MD5_FOR_LEGACY_USE_ONLY, b"");
+/// ```
+#[cfg(all(doctest, feature = "fips"))]
+struct Md5UnavailableInFips;
+
 /// Match digest types for `EVP_MD` functions.
 pub(crate) fn match_digest_type(algorithm_id: &AlgorithmID) -> ConstPointer<EVP_MD> {
     unsafe {
@@ -348,10 +388,15 @@ pub(crate) fn match_digest_type(algorithm_id: &AlgorithmID) -> ConstPointer<EVP_
             AlgorithmID::SHA256 => EVP_sha256(),
             AlgorithmID::SHA384 => EVP_sha384(),
             AlgorithmID::SHA512 => EVP_sha512(),
+            AlgorithmID::SHA512_224 => EVP_sha512_224(),
             AlgorithmID::SHA512_256 => EVP_sha512_256(),
             AlgorithmID::SHA3_256 => EVP_sha3_256(),
             AlgorithmID::SHA3_384 => EVP_sha3_384(),
             AlgorithmID::SHA3_512 => EVP_sha3_512(),
+            #[cfg(not(feature = "fips"))]
+            AlgorithmID::BLAKE2B_256 => EVP_blake2b256(),
+            #[cfg(all(feature = "legacy", not(feature = "fips")))]
+            AlgorithmID::MD5 => EVP_md5(),
         })
         .unwrap_or_else(|()| panic!("Digest algorithm not found: {algorithm_id:?}"))
     }
@@ -449,6 +494,10 @@ mod tests {
             &digest::SHA512,
             &digest::SHA3_384,
             &digest::SHA3_512,
+            #[cfg(not(feature = "fips"))]
+            &digest::BLAKE2B_256,
+            #[cfg(all(feature = "legacy", not(feature = "fips")))]
+            &digest::MD5_FOR_LEGACY_USE_ONLY,
         ] {
             // Clone after updating context with message, then check if the final Digest is the same.
             let mut ctx = digest::Context::new(alg);
@@ -0,0 +1,40 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+use crate::digest::{Algorithm, AlgorithmID, Context};
+
+/// The length of a block for BLAKE2b-based algorithms, in bytes.
+const BLAKE2B_BLOCK_LEN: usize = 1024 / 8;
+
+/// The length of the internal state of BLAKE2b-based algorithms, in bytes.
+const BLAKE2B_CHAINING_LEN: usize = 512 / 8;
+
+/// The length of the output of BLAKE2b-256, in bytes.
+pub const BLAKE2B_256_OUTPUT_LEN: usize = 256 / 8;
+
+/// BLAKE2b is limited to an input size of 2^128-1 bytes. As with the SHA-2
+/// algorithms, we limit the input length to 2^64-1 bits.
+const BLAKE2B_MAX_INPUT_LEN: u64 = u64::MAX;
+
+/// BLAKE2b-256, i.e. unkeyed BLAKE2b with a 32-byte output, as specified in
+/// [RFC 7693].
+///
+/// [RFC 7693]: https://www.rfc-editor.org/rfc/rfc7693
+#[allow(deprecated)]
+pub static BLAKE2B_256: Algorithm = Algorithm {
+    output_len: BLAKE2B_256_OUTPUT_LEN,
+    chaining_len: BLAKE2B_CHAINING_LEN,
+    block_len: BLAKE2B_BLOCK_LEN,
+    max_input_len: BLAKE2B_MAX_INPUT_LEN,
+
+    one_shot_hash: blake2b_256_digest,
+
+    id: AlgorithmID::BLAKE2B_256,
+};
+
+fn blake2b_256_digest(msg: &[u8], output: &mut [u8]) {
+    let mut ctx = Context::new(&BLAKE2B_256);
+    ctx.update(msg);
+    let digest = ctx.finish();
+    output[0..BLAKE2B_256_OUTPUT_LEN].copy_from_slice(digest.as_ref());
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0 OR ISC
 
 use crate::aws_lc::{
-    EVP_DigestInit_ex, EVP_MD_CTX_cleanup, EVP_MD_CTX_copy, EVP_MD_CTX_init, EVP_MD_CTX,
+    EVP_DigestInit_ex, EVP_MD_CTX_cleanup, EVP_MD_CTX_copy, EVP_MD_CTX_init, EVP_MD, EVP_MD_CTX,
 };
 use crate::digest::{match_digest_type, Algorithm};
 use crate::error::Unspecified;
@@ -14,9 +14,13 @@ pub(crate) struct DigestContext(EVP_MD_CTX);
 impl DigestContext {
     pub fn new(algorithm: &'static Algorithm) -> Result<DigestContext, Unspecified> {
         let evp_md_type = match_digest_type(&algorithm.id);
+        Self::new_with_md(*evp_md_type)
+    }
+
+    pub fn new_with_md(evp_md: *const EVP_MD) -> Result<DigestContext, Unspecified> {
         let mut dc = Self::new_uninit();
         unsafe {
-            if 1 != EVP_DigestInit_ex(dc.as_mut_ptr(), *evp_md_type, null_mut()) {
+            if 1 != EVP_DigestInit_ex(dc.as_mut_ptr(), evp_md, null_mut()) {
                 return Err(Unspecified);
             }
             Ok(dc)
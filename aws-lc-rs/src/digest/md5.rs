@@ -0,0 +1,38 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+use crate::digest::{Algorithm, AlgorithmID, Context};
+
+/// The length of a block for MD5, in bytes.
+const MD5_BLOCK_LEN: usize = 512 / 8;
+
+/// The length of the output of MD5, in bytes.
+pub const MD5_OUTPUT_LEN: usize = 128 / 8;
+
+/// MD5 is limited to an input size of 2^64-1 bits.
+const MD5_MAX_INPUT_LEN: u64 = u64::MAX;
+
+/// MD5 as specified in [RFC 1321]. Broken; only for compatibility with existing systems.
+///
+/// MD5 is not collision resistant, and must not be used for new designs or for any purpose
+/// that relies on its security.
+///
+/// [RFC 1321]: https://www.rfc-editor.org/rfc/rfc1321
+#[allow(deprecated)]
+pub static MD5_FOR_LEGACY_USE_ONLY: Algorithm = Algorithm {
+    output_len: MD5_OUTPUT_LEN,
+    chaining_len: MD5_OUTPUT_LEN,
+    block_len: MD5_BLOCK_LEN,
+    max_input_len: MD5_MAX_INPUT_LEN,
+
+    one_shot_hash: md5_digest,
+
+    id: AlgorithmID::MD5,
+};
+
+fn md5_digest(msg: &[u8], output: &mut [u8]) {
+    let mut ctx = Context::new(&MD5_FOR_LEGACY_USE_ONLY);
+    ctx.update(msg);
+    let digest = ctx.finish();
+    output[0..MD5_OUTPUT_LEN].copy_from_slice(digest.as_ref());
+}
@@ -26,6 +26,9 @@ pub const SHA384_OUTPUT_LEN: usize = 384 / 8;
 /// The length of the output of SHA-512, in bytes.
 pub const SHA512_OUTPUT_LEN: usize = 512 / 8;
 
+/// The length of the output of SHA-512/224, in bytes.
+pub const SHA512_224_OUTPUT_LEN: usize = 224 / 8;
+
 /// The length of the output of SHA-512/256, in bytes.
 pub const SHA512_256_OUTPUT_LEN: usize = 256 / 8;
 
@@ -51,7 +54,7 @@ pub const SHA3_384_OUTPUT_LEN: usize = 384 / 8;
 pub const SHA3_512_OUTPUT_LEN: usize = 512 / 8;
 
 /// SHA-1, SHA-224, and SHA-256 are limited to an input size of 2^64-1 bits.
-/// SHA-384, SHA-512, and SHA-512/256 are limited to an input size of 2^128-1 bits according to the spec.
+/// SHA-384, SHA-512, SHA-512/224, and SHA-512/256 are limited to an input size of 2^128-1 bits according to the spec.
 /// u64 is more than sufficient enough for practical usecases, so we limit the input length to 2^64-1 bits.
 #[allow(clippy::cast_possible_truncation)]
 const DIGEST_MAX_INPUT_LEN: u64 = u64::MAX;
@@ -137,6 +140,21 @@ pub static SHA512: Algorithm = Algorithm {
     id: AlgorithmID::SHA512,
 };
 
+/// SHA-512/224 as specified in [FIPS 180-4].
+///
+/// [FIPS 180-4]: http://nvlpubs.nist.gov/nistpubs/FIPS/NIST.FIPS.180-4.pdf
+#[allow(deprecated)]
+pub static SHA512_224: Algorithm = Algorithm {
+    output_len: SHA512_224_OUTPUT_LEN,
+    chaining_len: SHA512_OUTPUT_LEN,
+    block_len: SHA512_BLOCK_LEN,
+    max_input_len: DIGEST_MAX_INPUT_LEN,
+
+    one_shot_hash: sha512_224_digest,
+
+    id: AlgorithmID::SHA512_224,
+};
+
 /// SHA-512/256 as specified in [FIPS 180-4].
 ///
 /// [FIPS 180-4]: http://nvlpubs.nist.gov/nistpubs/FIPS/NIST.FIPS.180-4.pdf
@@ -227,6 +245,12 @@ fn sha512_digest(msg: &[u8], output: &mut [u8]) {
     }
 }
 
+fn sha512_224_digest(msg: &[u8], output: &mut [u8]) {
+    unsafe {
+        aws_lc::SHA512_224(msg.as_ptr(), msg.len(), output.as_mut_ptr());
+    }
+}
+
 fn sha512_256_digest(msg: &[u8], output: &mut [u8]) {
     unsafe {
         aws_lc::SHA512_256(msg.as_ptr(), msg.len(), output.as_mut_ptr());
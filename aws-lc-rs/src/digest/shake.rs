@@ -0,0 +1,168 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+use crate::aws_lc::{EVP_DigestFinalXOF, EVP_DigestUpdate, EVP_shake128, EVP_shake256, EVP_MD};
+use crate::digest::digest_ctx::DigestContext;
+use crate::error::Unspecified;
+
+/// A context for multi-step SHAKE128 or SHAKE256 extendable-output function (XOF)
+/// calculations, as specified in [FIPS 202].
+///
+/// Unlike a [`Context`](super::Context), a `ShakeContext` produces output of any
+/// requested length.
+///
+/// [FIPS 202]: https://nvlpubs.nist.gov/nistpubs/FIPS/NIST.FIPS.202.pdf
+#[derive(Clone)]
+pub struct ShakeContext {
+    digest_ctx: DigestContext,
+}
+
+impl core::fmt::Debug for ShakeContext {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ShakeContext").finish_non_exhaustive()
+    }
+}
+
+impl ShakeContext {
+    /// Constructs a new SHAKE128 context.
+    ///
+    /// # Panics
+    /// Panics if the aws-lc digest context cannot be initialized.
+    #[must_use]
+    pub fn shake128() -> Self {
+        Self::new(unsafe { EVP_shake128() })
+    }
+
+    /// Constructs a new SHAKE256 context.
+    ///
+    /// # Panics
+    /// Panics if the aws-lc digest context cannot be initialized.
+    #[must_use]
+    pub fn shake256() -> Self {
+        Self::new(unsafe { EVP_shake256() })
+    }
+
+    fn new(evp_md: *const EVP_MD) -> Self {
+        Self {
+            digest_ctx: DigestContext::new_with_md(evp_md).expect("EVP_DigestInit_ex failed"),
+        }
+    }
+
+    /// Updates the message with all the data in `data`.
+    ///
+    /// # Panics
+    /// Panics if the data cannot be absorbed.
+    #[inline]
+    pub fn update(&mut self, data: &[u8]) {
+        self.try_update(data).expect("SHAKE update failed");
+    }
+
+    fn try_update(&mut self, data: &[u8]) -> Result<(), Unspecified> {
+        if 1 != unsafe {
+            EVP_DigestUpdate(
+                self.digest_ctx.as_mut_ptr(),
+                data.as_ptr().cast(),
+                data.len(),
+            )
+        } {
+            return Err(Unspecified);
+        }
+        Ok(())
+    }
+
+    /// Finalizes the calculation, filling `output` with the first `output.len()`
+    /// bytes of the XOF output.
+    ///
+    /// `finish_xof` consumes the context so it cannot be (mis-)used after it has
+    /// been called.
+    ///
+    /// # Panics
+    /// Panics if the output cannot be produced.
+    #[inline]
+    pub fn finish_xof(self, output: &mut [u8]) {
+        self.try_finish_xof(output)
+            .expect("EVP_DigestFinalXOF failed");
+    }
+
+    fn try_finish_xof(mut self, output: &mut [u8]) -> Result<(), Unspecified> {
+        if 1 != unsafe {
+            EVP_DigestFinalXOF(
+                self.digest_ctx.as_mut_ptr(),
+                output.as_mut_ptr(),
+                output.len(),
+            )
+        } {
+            return Err(Unspecified);
+        }
+        Ok(())
+    }
+}
+
+/// Fills `output` with the SHAKE128 output for `input`.
+///
+/// # Examples
+///
+/// ```
+/// use aws_lc_rs::digest;
+///
+/// let mut output = [0u8; 16];
+/// digest::shake128(b"", &mut output);
+/// assert_eq!(
+///     output,
+///     [
+///         0x7f, 0x9c, 0x2b, 0xa4, 0xe8, 0x8f, 0x82, 0x7d, 0x61, 0x60, 0x45, 0x50, 0x76, 0x05,
+///         0x85, 0x3e
+///     ]
+/// );
+/// ```
+#[inline]
+pub fn shake128(input: &[u8], output: &mut [u8]) {
+    let mut ctx = ShakeContext::shake128();
+    ctx.update(input);
+    ctx.finish_xof(output);
+}
+
+/// Fills `output` with the SHAKE256 output for `input`.
+#[inline]
+pub fn shake256(input: &[u8], output: &mut [u8]) {
+    let mut ctx = ShakeContext::shake256();
+    ctx.update(input);
+    ctx.finish_xof(output);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::digest::shake::{shake128, ShakeContext};
+    use crate::test;
+
+    #[test]
+    fn shake_context_traits() {
+        test::compile_time_assert_send::<ShakeContext>();
+        test::compile_time_assert_sync::<ShakeContext>();
+        assert_eq!(
+            "ShakeContext { .. }",
+            format!("{:?}", ShakeContext::shake128())
+        );
+    }
+
+    #[test]
+    fn clone_and_empty_output() {
+        let mut ctx = ShakeContext::shake128();
+        ctx.update(b"hello");
+        let ctx_clone = ctx.clone();
+        ctx.update(b", world");
+
+        let mut output = [0u8; 32];
+        ctx.finish_xof(&mut output);
+        let mut expected = [0u8; 32];
+        shake128(b"hello, world", &mut expected);
+        assert_eq!(expected, output);
+
+        let mut output = [0u8; 32];
+        ctx_clone.finish_xof(&mut output);
+        shake128(b"hello", &mut expected);
+        assert_eq!(expected, output);
+
+        ShakeContext::shake256().finish_xof(&mut []);
+    }
+}
@@ -5,7 +5,7 @@
 
 use crate::digest::{
     Context, SHA1_FOR_LEGACY_USE_ONLY, SHA224, SHA256, SHA384, SHA3_256, SHA3_384, SHA3_512,
-    SHA512, SHA512_256,
+    SHA512, SHA512_224, SHA512_256,
 };
 use crate::fips::{assert_fips_status_indicator, FipsServiceStatus};
 
@@ -37,6 +37,7 @@ digest_api!(sha224, &SHA224, FipsServiceStatus::Approved);
 digest_api!(sha256, &SHA256, FipsServiceStatus::Approved);
 digest_api!(sha384, &SHA384, FipsServiceStatus::Approved);
 digest_api!(sha512, &SHA512, FipsServiceStatus::Approved);
+digest_api!(sha512_224, &SHA512_224, FipsServiceStatus::Approved);
 digest_api!(sha512_256, &SHA512_256, FipsServiceStatus::Approved);
 digest_api!(sha3_256, &SHA3_256, FipsServiceStatus::Approved);
 digest_api!(sha3_384, &SHA3_384, FipsServiceStatus::Approved);
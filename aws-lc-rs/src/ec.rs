@@ -26,6 +26,8 @@ use crate::signature::Signature;
 
 pub(crate) mod encoding;
 pub(crate) mod key_pair;
+#[cfg(test)]
+mod rfc6979;
 pub(crate) mod signature;
 
 const ELEM_MAX_BITS: usize = 521;
@@ -13,12 +13,13 @@ use crate::ptr::LcPtr;
 // SEC 1: Elliptic Curve Cryptography, Version 2.0
 pub(crate) mod sec1 {
     use crate::aws_lc::{
-        point_conversion_form_t, BN_bn2cbb_padded, EC_GROUP_get_curve_name, EC_KEY_get0_group,
-        EC_KEY_get0_private_key, EC_KEY_get0_public_key, EC_KEY_new, EC_KEY_set_group,
-        EC_KEY_set_private_key, EC_KEY_set_public_key, EC_POINT_mul, EC_POINT_new,
-        EC_POINT_oct2point, EC_POINT_point2cbb, EVP_PKEY_assign_EC_KEY, EVP_PKEY_get0_EC_KEY,
-        EVP_PKEY_new, NID_X9_62_prime256v1, NID_secp256k1, NID_secp384r1, NID_secp521r1, BIGNUM,
-        EC_GROUP, EC_POINT, EVP_PKEY,
+        point_conversion_form_t, BN_bn2cbb_padded, EC_GROUP_get_curve_name, EC_GROUP_get_degree,
+        EC_KEY_get0_group, EC_KEY_get0_private_key, EC_KEY_get0_public_key, EC_KEY_new,
+        EC_KEY_set_group, EC_KEY_set_private_key, EC_KEY_set_public_key,
+        EC_POINT_get_affine_coordinates_GFp, EC_POINT_mul, EC_POINT_new, EC_POINT_oct2point,
+        EC_POINT_point2cbb, EVP_PKEY_assign_EC_KEY, EVP_PKEY_get0_EC_KEY, EVP_PKEY_new,
+        NID_X9_62_prime256v1, NID_secp256k1, NID_secp384r1, NID_secp521r1, BIGNUM, EC_GROUP,
+        EC_POINT, EVP_PKEY,
     };
     use crate::cbb::LcCBB;
     use crate::ec::{
@@ -144,6 +145,40 @@ pub(crate) mod sec1 {
         cbb.into_vec()
     }
 
+    /// Returns the affine coordinates `(x, y)` of the public point, each zero-padded to the size
+    /// of a field element.
+    pub(crate) fn marshal_sec1_public_coordinates(
+        evp_pkey: &LcPtr<EVP_PKEY>,
+    ) -> Result<(Vec<u8>, Vec<u8>), Unspecified> {
+        let ec_key = ConstPointer::new(unsafe { EVP_PKEY_get0_EC_KEY(*evp_pkey.as_const()) })?;
+        let ec_group = ConstPointer::new(unsafe { EC_KEY_get0_group(*ec_key) })?;
+        let ec_point = ConstPointer::new(unsafe { EC_KEY_get0_public_key(*ec_key) })?;
+        let elem_len = (unsafe { EC_GROUP_get_degree(*ec_group) } as usize + 7) / 8;
+
+        let mut x = LcPtr::<BIGNUM>::try_from(0u64)?;
+        let mut y = LcPtr::<BIGNUM>::try_from(0u64)?;
+        if 1 != unsafe {
+            EC_POINT_get_affine_coordinates_GFp(
+                *ec_group,
+                *ec_point,
+                *x.as_mut(),
+                *y.as_mut(),
+                null_mut(),
+            )
+        } {
+            return Err(Unspecified);
+        }
+
+        let to_padded_bytes = |bn: &LcPtr<BIGNUM>| -> Result<Vec<u8>, Unspecified> {
+            let mut cbb = LcCBB::new(elem_len);
+            if 1 != unsafe { BN_bn2cbb_padded(cbb.as_mut_ptr(), elem_len, *bn.as_const()) } {
+                return Err(Unspecified);
+            }
+            cbb.into_vec()
+        };
+        Ok((to_padded_bytes(&x)?, to_padded_bytes(&y)?))
+    }
+
     pub(crate) fn marshal_sec1_public_point_into_buffer(
         buffer: &mut [u8],
         evp_pkey: &LcPtr<EVP_PKEY>,
@@ -244,6 +279,203 @@ pub(crate) mod rfc5915 {
     }
 }
 
+// [RFC 5958](https://www.rfc-editor.org/rfc/rfc5958)
+//
+// Asymmetric Key Packages: PKCS#8 v2 `OneAsymmetricKey`. AWS-LC only supports the `publicKey`
+// field for Curve25519 keys, so for EC keys it is added and removed here, around the PKCS#8 v1
+// encoding.
+pub(crate) mod rfc5958 {
+    use crate::aws_lc::{
+        CBB_add_asn1, CBB_add_asn1_uint64, CBB_add_bytes, CBB_add_u8, CBB_flush, CBS_data,
+        CBS_get_asn1, CBS_get_asn1_element, CBS_get_asn1_uint64, CBS_get_optional_asn1, CBS_len,
+        CBB, CBS, CBS_ASN1_CONSTRUCTED, CBS_ASN1_CONTEXT_SPECIFIC, CBS_ASN1_OCTETSTRING,
+        CBS_ASN1_SEQUENCE, CBS_ASN1_TAG, EVP_PKEY, EVP_PKEY_EC,
+    };
+    use crate::cbb::LcCBB;
+    use crate::cbs::build_CBS;
+    use crate::ec::encoding::sec1::{marshal_sec1_public_point, parse_sec1_public_point};
+    use crate::error::{KeyRejected, Unspecified};
+    use crate::pkcs8::Version;
+    use crate::ptr::LcPtr;
+    use core::mem::MaybeUninit;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    const ATTRIBUTES_TAG: CBS_ASN1_TAG =
+        (CBS_ASN1_CONTEXT_SPECIFIC as CBS_ASN1_TAG) | (CBS_ASN1_CONSTRUCTED as CBS_ASN1_TAG);
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    const PUBLIC_KEY_TAG: CBS_ASN1_TAG = (CBS_ASN1_CONTEXT_SPECIFIC as CBS_ASN1_TAG) | 1;
+    #[allow(clippy::cast_sign_loss)]
+    const SEQUENCE_TAG: CBS_ASN1_TAG = CBS_ASN1_SEQUENCE as CBS_ASN1_TAG;
+    #[allow(clippy::cast_sign_loss)]
+    const OCTET_STRING_TAG: CBS_ASN1_TAG = CBS_ASN1_OCTETSTRING as CBS_ASN1_TAG;
+
+    const VERSION_ONE: u64 = 0;
+    const VERSION_TWO: u64 = 1;
+
+    /// The fields of a `PrivateKeyInfo` or `OneAsymmetricKey` structure. The `algorithm` and
+    /// `private_key` fields are complete DER elements.
+    struct OneAsymmetricKey<'a> {
+        algorithm: &'a [u8],
+        private_key: &'a [u8],
+        public_key: Option<&'a [u8]>,
+    }
+
+    // `cbs` must have been read from a buffer that outlives `'a`.
+    unsafe fn cbs_bytes<'a>(cbs: &CBS) -> &'a [u8] {
+        core::slice::from_raw_parts(CBS_data(cbs), CBS_len(cbs))
+    }
+
+    impl<'a> OneAsymmetricKey<'a> {
+        fn parse(bytes: &'a [u8]) -> Result<Self, ()> {
+            let mut cbs = build_CBS(bytes);
+            let mut pkcs8 = MaybeUninit::<CBS>::uninit();
+            let mut algorithm = MaybeUninit::<CBS>::uninit();
+            let mut private_key = MaybeUninit::<CBS>::uninit();
+            let mut version = 0u64;
+            if 1 != unsafe { CBS_get_asn1(&mut cbs, pkcs8.as_mut_ptr(), SEQUENCE_TAG) }
+                || 0 != unsafe { CBS_len(&cbs) }
+            {
+                return Err(());
+            }
+            let mut pkcs8 = unsafe { pkcs8.assume_init() };
+            if 1 != unsafe { CBS_get_asn1_uint64(&mut pkcs8, &mut version) }
+                || version > VERSION_TWO
+                || 1 != unsafe {
+                    CBS_get_asn1_element(&mut pkcs8, algorithm.as_mut_ptr(), SEQUENCE_TAG)
+                }
+                || 1 != unsafe {
+                    CBS_get_asn1_element(&mut pkcs8, private_key.as_mut_ptr(), OCTET_STRING_TAG)
+                }
+            {
+                return Err(());
+            }
+            // Attributes are ignored, as they are by `EVP_parse_private_key`.
+            let mut has_public_key = 0;
+            let mut public_key = MaybeUninit::<CBS>::uninit();
+            if 1 != unsafe {
+                CBS_get_optional_asn1(
+                    &mut pkcs8,
+                    core::ptr::null_mut(),
+                    core::ptr::null_mut(),
+                    ATTRIBUTES_TAG,
+                )
+            } || 1
+                != unsafe {
+                    CBS_get_optional_asn1(
+                        &mut pkcs8,
+                        public_key.as_mut_ptr(),
+                        &mut has_public_key,
+                        PUBLIC_KEY_TAG,
+                    )
+                }
+                || 0 != unsafe { CBS_len(&pkcs8) }
+            {
+                return Err(());
+            }
+            let public_key = if has_public_key == 1 {
+                if version != VERSION_TWO {
+                    return Err(());
+                }
+                Some(unsafe { cbs_bytes(&public_key.assume_init()) })
+            } else {
+                None
+            };
+            Ok(Self {
+                algorithm: unsafe { cbs_bytes(&algorithm.assume_init()) },
+                private_key: unsafe { cbs_bytes(&private_key.assume_init()) },
+                public_key,
+            })
+        }
+
+        fn marshal(&self) -> Result<Vec<u8>, Unspecified> {
+            let mut cbb = LcCBB::new(self.algorithm.len() + self.private_key.len() + 160);
+            // Child `CBB`s are referenced by their parent, and refer back to it, until `cbb` is
+            // flushed, so none of them may be moved before then.
+            let mut pkcs8 = MaybeUninit::<CBB>::uninit();
+            let mut bit_string = MaybeUninit::<CBB>::uninit();
+            if 1 != unsafe { CBB_add_asn1(cbb.as_mut_ptr(), pkcs8.as_mut_ptr(), SEQUENCE_TAG) } {
+                return Err(Unspecified);
+            }
+            let pkcs8 = pkcs8.as_mut_ptr();
+            let version = if self.public_key.is_some() {
+                VERSION_TWO
+            } else {
+                VERSION_ONE
+            };
+            if 1 != unsafe { CBB_add_asn1_uint64(pkcs8, version) }
+                || 1 != unsafe {
+                    CBB_add_bytes(pkcs8, self.algorithm.as_ptr(), self.algorithm.len())
+                }
+                || 1 != unsafe {
+                    CBB_add_bytes(pkcs8, self.private_key.as_ptr(), self.private_key.len())
+                }
+            {
+                return Err(Unspecified);
+            }
+            if let Some(public_key) = self.public_key {
+                if 1 != unsafe { CBB_add_asn1(pkcs8, bit_string.as_mut_ptr(), PUBLIC_KEY_TAG) } {
+                    return Err(Unspecified);
+                }
+                let bit_string = bit_string.as_mut_ptr();
+                // A BIT STRING whose contents are the encoded point, with no unused bits.
+                if 1 != unsafe { CBB_add_u8(bit_string, 0) }
+                    || 1 != unsafe {
+                        CBB_add_bytes(bit_string, public_key.as_ptr(), public_key.len())
+                    }
+                {
+                    return Err(Unspecified);
+                }
+            }
+            if 1 != unsafe { CBB_flush(cbb.as_mut_ptr()) } {
+                return Err(Unspecified);
+            }
+            cbb.into_vec()
+        }
+    }
+
+    pub(crate) fn marshal_rfc5958_private_key(
+        evp_pkey: &LcPtr<EVP_PKEY>,
+    ) -> Result<Vec<u8>, Unspecified> {
+        let pkcs8_v1 = evp_pkey.marshal_rfc5208_private_key(Version::V1)?;
+        let public_key = marshal_sec1_public_point(evp_pkey, false)?;
+        let key = OneAsymmetricKey::parse(&pkcs8_v1).map_err(|()| Unspecified)?;
+        OneAsymmetricKey {
+            public_key: Some(&public_key),
+            ..key
+        }
+        .marshal()
+    }
+
+    pub(crate) fn parse_rfc5958_private_key(
+        key_bytes: &[u8],
+        expected_curve_nid: i32,
+    ) -> Result<LcPtr<EVP_PKEY>, KeyRejected> {
+        let key =
+            OneAsymmetricKey::parse(key_bytes).map_err(|()| KeyRejected::invalid_encoding())?;
+        let pkcs8_v1 = OneAsymmetricKey {
+            public_key: None,
+            ..key
+        }
+        .marshal()
+        .map_err(|_| KeyRejected::unexpected_error())?;
+        // Includes a call to `EC_KEY_check_key`
+        let evp_pkey = LcPtr::<EVP_PKEY>::parse_rfc5208_private_key(&pkcs8_v1, EVP_PKEY_EC)?;
+
+        if let Some(public_key) = key.public_key {
+            let public_point = match public_key.split_first() {
+                Some((0, public_point)) => public_point,
+                _ => return Err(KeyRejected::invalid_encoding()),
+            };
+            let public_evp_pkey = parse_sec1_public_point(public_point, expected_curve_nid)?;
+            // EVP_PKEY_cmp only compares params and public key
+            if !evp_pkey.eq(&public_evp_pkey) {
+                return Err(KeyRejected::inconsistent_components());
+            }
+        }
+        Ok(evp_pkey)
+    }
+}
+
 pub(crate) fn parse_ec_public_key(
     key_bytes: &[u8],
     expected_curve_nid: i32,
@@ -0,0 +1,189 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+//! Deterministic generation of the ECDSA nonce `k`, as specified in
+//! [RFC 6979, Section 3.2](https://www.rfc-editor.org/rfc/rfc6979#section-3.2).
+
+use crate::digest::{self, AlgorithmID};
+use crate::error::Unspecified;
+use crate::hmac;
+use zeroize::Zeroize;
+
+/// Returns the nonce for signing the message digest `h1` with the private key `x` on a curve of
+/// order `q`. `q` and `x` are big-endian integers of the same length, and the nonce is returned
+/// in the same form.
+///
+/// The HMAC is instantiated with `digest_alg`, the digest algorithm of the signature.
+pub(super) fn generate_k(
+    digest_alg: &'static digest::Algorithm,
+    q: &[u8],
+    x: &[u8],
+    h1: &[u8],
+) -> Result<Vec<u8>, Unspecified> {
+    let hmac_alg = match digest_alg.id {
+        AlgorithmID::SHA1 => hmac::HMAC_SHA1_FOR_LEGACY_USE_ONLY,
+        AlgorithmID::SHA224 => hmac::HMAC_SHA224,
+        AlgorithmID::SHA256 => hmac::HMAC_SHA256,
+        AlgorithmID::SHA384 => hmac::HMAC_SHA384,
+        AlgorithmID::SHA512 => hmac::HMAC_SHA512,
+        // HMAC is not available for the other digest algorithms.
+        _ => return Err(Unspecified),
+    };
+    if q.first().map_or(true, |&byte| byte == 0) || x.len() != q.len() {
+        return Err(Unspecified);
+    }
+    let qlen = q.len() * 8 - q[0].leading_zeros() as usize;
+
+    // Step b. and c.
+    let mut v = vec![0x01u8; digest_alg.output_len];
+    let mut k = vec![0x00u8; digest_alg.output_len];
+
+    // Step d. to g.
+    let h1 = bits2octets(h1, q, qlen);
+    for separator in [0x00u8, 0x01] {
+        update_k(hmac_alg, &mut k, &[&v, &[separator], x, &h1]);
+        update_v(hmac_alg, &k, &mut v);
+    }
+
+    // Step h.
+    let mut t = Vec::with_capacity(q.len() + digest_alg.output_len);
+    loop {
+        t.clear();
+        while t.len() < q.len() {
+            update_v(hmac_alg, &k, &mut v);
+            t.extend_from_slice(&v);
+        }
+        let mut candidate = bits2int(&t, qlen, q.len());
+        if is_in_range(&candidate, q) {
+            t.zeroize();
+            v.zeroize();
+            k.zeroize();
+            return Ok(candidate);
+        }
+        candidate.zeroize();
+        update_k(hmac_alg, &mut k, &[&v, &[0x00]]);
+        update_v(hmac_alg, &k, &mut v);
+    }
+}
+
+/// Returns whether `1 <= k < q`, for big-endian integers `k` and `q` of the same length. `k` is
+/// secret, so all of its bytes are examined regardless of their values.
+fn is_in_range(k: &[u8], q: &[u8]) -> bool {
+    let mut non_zero = 0u8;
+    // The borrow out of `k - q` is 1 exactly when `k < q`.
+    let mut borrow = 0u16;
+    for (&k_byte, &q_byte) in k.iter().zip(q).rev() {
+        non_zero |= k_byte;
+        let diff = u16::from(k_byte)
+            .wrapping_sub(u16::from(q_byte))
+            .wrapping_sub(borrow);
+        borrow = (diff >> 8) & 1;
+    }
+    // 1 if `non_zero` is not zero, 0 otherwise.
+    let non_zero = (u16::from(non_zero) + 0xff) >> 8;
+    non_zero & borrow == 1
+}
+
+/// `K = HMAC_K(input)`
+fn update_k(hmac_alg: hmac::Algorithm, k: &mut [u8], input: &[&[u8]]) {
+    let key = hmac::Key::new(hmac_alg, k);
+    let mut ctx = hmac::Context::with_key(&key);
+    for part in input {
+        ctx.update(part);
+    }
+    k.copy_from_slice(ctx.sign().as_ref());
+}
+
+/// `V = HMAC_K(V)`
+fn update_v(hmac_alg: hmac::Algorithm, k: &[u8], v: &mut [u8]) {
+    let tag = hmac::sign(&hmac::Key::new(hmac_alg, k), v);
+    v.copy_from_slice(tag.as_ref());
+}
+
+/// Converts the bit string `bits` to an integer of `qlen` bits by keeping its leftmost `qlen`
+/// bits, and returns it as a big-endian integer of `len` bytes (RFC 6979, Section 2.3.2).
+fn bits2int(bits: &[u8], qlen: usize, len: usize) -> Vec<u8> {
+    let shift = (bits.len() * 8).saturating_sub(qlen);
+    let kept = &bits[..bits.len() - shift / 8];
+    let bit_shift = shift % 8;
+
+    let mut out = vec![0u8; len];
+    let offset = len - kept.len();
+    for (i, &byte) in kept.iter().enumerate() {
+        out[offset + i] = byte >> bit_shift;
+        if bit_shift != 0 && i > 0 {
+            out[offset + i] |= kept[i - 1] << (8 - bit_shift);
+        }
+    }
+    out
+}
+
+/// Converts the bit string `bits` to an integer modulo `q`, returned as a big-endian integer
+/// of the length of `q` (RFC 6979, Section 2.3.4).
+fn bits2octets(bits: &[u8], q: &[u8], qlen: usize) -> Vec<u8> {
+    let mut z = bits2int(bits, qlen, q.len());
+    // `z` has at most `qlen` bits, so it is less than `2 * q`.
+    if z.as_slice() >= q {
+        let mut borrow = 0u8;
+        for (z_byte, &q_byte) in z.iter_mut().zip(q).rev() {
+            let (diff, borrow1) = z_byte.overflowing_sub(q_byte);
+            let (diff, borrow2) = diff.overflowing_sub(borrow);
+            *z_byte = diff;
+            borrow = u8::from(borrow1 || borrow2);
+        }
+    }
+    z
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::digest;
+    use crate::ec::rfc6979::{bits2int, bits2octets, generate_k, is_in_range};
+    use crate::test::from_hex;
+
+    // RFC 6979, Appendix A.1
+    const Q: &str = "04000000000000000000020108a2e0cc0d99f8a5ef";
+    const X: &str = "009a4d6792295a7f730fc3f2b49cbc0f62e862272f";
+
+    #[test]
+    fn rfc6979_a1() {
+        let q = from_hex(Q).unwrap();
+        let x = from_hex(X).unwrap();
+        let h1 = digest::digest(&digest::SHA256, b"sample");
+
+        // Section A.1.2
+        assert_eq!(
+            from_hex("05795edf0d54db760f156f0eb4a7a0fe38d418e813").unwrap(),
+            bits2int(h1.as_ref(), 163, q.len())
+        );
+        assert_eq!(
+            from_hex("01795edf0d54db760f156d0dac04c0322b3a204224").unwrap(),
+            bits2octets(h1.as_ref(), &q, 163)
+        );
+
+        // Section A.1.3
+        assert_eq!(
+            from_hex("023af4074c90a02b3fe61d286d5c87f425e6bdd81b").unwrap(),
+            generate_k(&digest::SHA256, &q, &x, h1.as_ref()).unwrap()
+        );
+    }
+
+    #[test]
+    fn range_check() {
+        let q = [0x01, 0x00];
+        assert!(!is_in_range(&[0x00, 0x00], &q));
+        assert!(is_in_range(&[0x00, 0x01], &q));
+        assert!(is_in_range(&[0x00, 0xff], &q));
+        assert!(!is_in_range(&[0x01, 0x00], &q));
+        assert!(!is_in_range(&[0x01, 0x01], &q));
+        assert!(!is_in_range(&[0xff, 0xff], &q));
+    }
+
+    #[test]
+    fn unsupported_digest() {
+        let q = from_hex(Q).unwrap();
+        let x = from_hex(X).unwrap();
+        let h1 = digest::digest(&digest::SHA3_256, b"sample");
+        assert!(generate_k(&digest::SHA3_256, &q, &x, h1.as_ref()).is_err());
+    }
+}
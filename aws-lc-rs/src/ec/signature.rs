@@ -2,20 +2,23 @@
 // SPDX-License-Identifier: Apache-2.0 OR ISC
 
 use crate::aws_lc::{
-    ECDSA_SIG_new, ECDSA_SIG_set0, ECDSA_SIG_to_bytes, NID_X9_62_prime256v1, NID_secp256k1,
-    NID_secp384r1, NID_secp521r1, BIGNUM, ECDSA_SIG, EVP_PKEY,
+    BN_cmp, BN_copy, BN_dup, BN_is_zero, BN_new, BN_rshift1, BN_sub, ECDSA_SIG_from_bytes,
+    ECDSA_SIG_get0_r, ECDSA_SIG_get0_s, ECDSA_SIG_new, ECDSA_SIG_set0, ECDSA_SIG_to_bytes,
+    EC_GROUP_get0_order, NID_X9_62_prime256v1, NID_secp256k1, NID_secp384r1, NID_secp521r1, BIGNUM,
+    ECDSA_SIG, EVP_PKEY,
 };
 
-use crate::ec::compressed_public_key_size_bytes;
 use crate::ec::encoding::parse_ec_public_key;
 use crate::ec::encoding::sec1::marshal_sec1_public_point;
+use crate::ec::{compressed_public_key_size_bytes, ec_group_from_nid, ecdsa_asn1_to_fixed};
+use crate::encoding::jwk::{AsJwk, JwkPublicKey};
 use crate::encoding::{
     AsBigEndian, AsDer, EcPublicKeyCompressedBin, EcPublicKeyUncompressedBin, PublicKeyX509Der,
 };
 use crate::error::Unspecified;
 use crate::evp_pkey::No_EVP_PKEY_CTX_consumer;
-use crate::ptr::{DetachableLcPtr, LcPtr};
-use crate::signature::VerificationAlgorithm;
+use crate::ptr::{ConstPointer, DetachableLcPtr, LcPtr};
+use crate::signature::{Signature, VerificationAlgorithm};
 use crate::{digest, sealed};
 use core::fmt;
 use core::fmt::{Debug, Formatter};
@@ -45,6 +48,79 @@ impl Deref for EcdsaSigningAlgorithm {
     }
 }
 
+impl EcdsaVerificationAlgorithm {
+    /// Converts `signature`, in the format of this algorithm, to its "low-S" form, in which `s` is
+    /// at most half the order `n` of the curve.
+    ///
+    /// A signature `(r, s)` is also valid as `(r, n - s)`. Protocols such as Bitcoin
+    /// ([BIP 62](https://github.com/bitcoin/bips/blob/master/bip-0062.mediawiki)) and Ethereum
+    /// only accept the low-S form, so that signatures are not malleable. A signature that is
+    /// already in low-S form is returned unchanged.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if `signature` is not correctly encoded, or if `s` is not in the range
+    /// `[1, n-1]`.
+    pub fn normalize_s(&self, signature: &[u8]) -> Result<Signature, Unspecified> {
+        let sig = match self.sig_format {
+            EcdsaSignatureFormat::ASN1 => {
+                LcPtr::new(unsafe { ECDSA_SIG_from_bytes(signature.as_ptr(), signature.len()) })?
+            }
+            EcdsaSignatureFormat::Fixed => unsafe { ecdsa_sig_from_fixed(self.id, signature)? },
+        };
+        let r = ConstPointer::new(unsafe { ECDSA_SIG_get0_r(*sig.as_const()) })?;
+        let s = ConstPointer::new(unsafe { ECDSA_SIG_get0_s(*sig.as_const()) })?;
+
+        let group = ec_group_from_nid(self.id.nid())?;
+        let order = ConstPointer::new(unsafe { EC_GROUP_get0_order(*group) })?;
+        if 1 == unsafe { BN_is_zero(*s) } || unsafe { BN_cmp(*s, *order) } >= 0 {
+            return Err(Unspecified);
+        }
+        let mut half_order = LcPtr::new(unsafe { BN_new() })?;
+        if 1 != unsafe { BN_rshift1(*half_order.as_mut(), *order) } {
+            return Err(Unspecified);
+        }
+
+        let mut low_s = DetachableLcPtr::new(unsafe { BN_new() })?;
+        if unsafe { BN_cmp(*s, *half_order.as_const()) } > 0 {
+            if 1 != unsafe { BN_sub(*low_s.as_mut(), *order, *s) } {
+                return Err(Unspecified);
+            }
+        } else if unsafe { BN_copy(*low_s.as_mut(), *s) }.is_null() {
+            return Err(Unspecified);
+        }
+        let mut low_r = DetachableLcPtr::new(unsafe { BN_dup(*r) })?;
+
+        let mut low_sig = LcPtr::new(unsafe { ECDSA_SIG_new() })?;
+        if 1 != unsafe { ECDSA_SIG_set0(*low_sig.as_mut(), *low_r.as_mut(), *low_s.as_mut()) } {
+            return Err(Unspecified);
+        }
+        low_r.detach();
+        low_s.detach();
+
+        let mut out_bytes = null_mut::<u8>();
+        let mut out_bytes_len = MaybeUninit::<usize>::uninit();
+        if 1 != unsafe {
+            ECDSA_SIG_to_bytes(
+                &mut out_bytes,
+                out_bytes_len.as_mut_ptr(),
+                *low_sig.as_const(),
+            )
+        } {
+            return Err(Unspecified);
+        }
+        let out_bytes = LcPtr::new(out_bytes)?;
+        let asn1_sig = unsafe { out_bytes.as_slice(out_bytes_len.assume_init()) };
+
+        match self.sig_format {
+            EcdsaSignatureFormat::ASN1 => Ok(Signature::new(|slice| {
+                slice[..asn1_sig.len()].copy_from_slice(asn1_sig);
+                asn1_sig.len()
+            })),
+            EcdsaSignatureFormat::Fixed => ecdsa_asn1_to_fixed(self.id, asn1_sig),
+        }
+    }
+}
+
 impl sealed::Sealed for EcdsaVerificationAlgorithm {}
 impl sealed::Sealed for EcdsaSigningAlgorithm {}
 
@@ -97,12 +173,17 @@ impl AlgorithmID {
 /// Elliptic curve public key.
 #[derive(Clone)]
 pub struct PublicKey {
-    #[allow(dead_code)]
     algorithm: &'static EcdsaSigningAlgorithm,
     evp_pkey: LcPtr<EVP_PKEY>,
     octets: Box<[u8]>,
 }
 
+impl PublicKey {
+    pub(crate) fn algorithm(&self) -> &'static EcdsaSigningAlgorithm {
+        self.algorithm
+    }
+}
+
 pub(crate) fn public_key_from_evp_pkey(
     evp_pkey: &LcPtr<EVP_PKEY>,
     algorithm: &'static EcdsaSigningAlgorithm,
@@ -116,6 +197,22 @@ pub(crate) fn public_key_from_evp_pkey(
     })
 }
 
+impl AsJwk for PublicKey {
+    /// Provides the public key as an `EC` JSON Web Key.
+    fn as_jwk(&self) -> Result<JwkPublicKey, Unspecified> {
+        let crv = match self.algorithm.id {
+            AlgorithmID::ECDSA_P256 => "P-256",
+            AlgorithmID::ECDSA_P384 => "P-384",
+            AlgorithmID::ECDSA_P521 => "P-521",
+            AlgorithmID::ECDSA_P256K1 => "secp256k1",
+        };
+        // `octets` holds the uncompressed point: 0x04 || x || y
+        let coordinates = self.octets.get(1..).ok_or(Unspecified)?;
+        let (x, y) = coordinates.split_at(coordinates.len() / 2);
+        Ok(JwkPublicKey::ec(crv, x, y))
+    }
+}
+
 impl AsDer<PublicKeyX509Der<'static>> for PublicKey {
     /// Provides the public key as a DER-encoded (X.509) `SubjectPublicKeyInfo` structure.
     /// # Errors
@@ -126,6 +223,47 @@ impl AsDer<PublicKeyX509Der<'static>> for PublicKey {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for PublicKey {
+    /// Serializes the DER-encoded (X.509) `SubjectPublicKeyInfo` structure.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let der = self.as_der().map_err(serde::ser::Error::custom)?;
+        crate::encoding::serde_der::serialize(der.as_ref(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for PublicKey {
+    /// Deserializes a DER-encoded (X.509) `SubjectPublicKeyInfo` structure for any supported
+    /// curve.
+    ///
+    /// The `SubjectPublicKeyInfo` structure only identifies the curve, so the key is associated
+    /// with the ASN.1 signing algorithm for that curve.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use crate::aws_lc::EVP_PKEY_EC;
+        use crate::ec::validate_evp_key;
+        use crate::error::KeyRejected;
+        use crate::signature::{
+            ECDSA_P256K1_SHA256_ASN1_SIGNING, ECDSA_P256_SHA256_ASN1_SIGNING,
+            ECDSA_P384_SHA384_ASN1_SIGNING, ECDSA_P521_SHA512_ASN1_SIGNING,
+        };
+
+        crate::encoding::serde_der::deserialize(deserializer, |der| {
+            let evp_pkey = LcPtr::<EVP_PKEY>::parse_rfc5280_public_key(der, EVP_PKEY_EC)?;
+            let algorithm = [
+                &ECDSA_P256_SHA256_ASN1_SIGNING,
+                &ECDSA_P384_SHA384_ASN1_SIGNING,
+                &ECDSA_P521_SHA512_ASN1_SIGNING,
+                &ECDSA_P256K1_SHA256_ASN1_SIGNING,
+            ]
+            .into_iter()
+            .find(|alg| validate_evp_key(&evp_pkey.as_const(), alg.id.nid()).is_ok())
+            .ok_or_else(KeyRejected::wrong_algorithm)?;
+            Ok(public_key_from_evp_pkey(&evp_pkey, algorithm)?)
+        })
+    }
+}
+
 impl AsBigEndian<EcPublicKeyCompressedBin<'static>> for PublicKey {
     /// Provides the public key elliptic curve point to a compressed point bytes format.
     /// # Errors
@@ -202,6 +340,27 @@ impl VerificationAlgorithm for EcdsaVerificationAlgorithm {
             }
         }
     }
+
+    fn verify_digest_sig(
+        &self,
+        public_key: &[u8],
+        digest_alg: &'static digest::Algorithm,
+        digest: &[u8],
+        signature: &[u8],
+    ) -> Result<(), Unspecified> {
+        if digest_alg != self.digest {
+            return Err(Unspecified);
+        }
+        let evp_pkey = parse_ec_public_key(public_key, self.id.nid())?;
+        match self.sig_format {
+            EcdsaSignatureFormat::ASN1 => evp_pkey.verify_digest(digest_alg, digest, signature),
+            EcdsaSignatureFormat::Fixed => {
+                let (out_bytes, out_bytes_len) = fixed_to_asn1_signature(self.id, signature)?;
+                let signature = unsafe { out_bytes.as_slice(out_bytes_len) };
+                evp_pkey.verify_digest(digest_alg, digest, signature)
+            }
+        }
+    }
 }
 
 fn verify_fixed_signature(
@@ -211,6 +370,17 @@ fn verify_fixed_signature(
     msg: &[u8],
     signature: &[u8],
 ) -> Result<(), Unspecified> {
+    let (out_bytes, out_bytes_len) = fixed_to_asn1_signature(alg, signature)?;
+    let signature = unsafe { out_bytes.as_slice(out_bytes_len) };
+    verify_asn1_signature(alg, digest, public_key, msg, signature)
+}
+
+/// Converts a fixed-length signature to an ASN.1 `ECDSA-Sig-Value`, returning the
+/// AWS-LC-allocated encoding and its length.
+pub(crate) fn fixed_to_asn1_signature(
+    alg: &'static AlgorithmID,
+    signature: &[u8],
+) -> Result<(LcPtr<u8>, usize), Unspecified> {
     let mut out_bytes = null_mut::<u8>();
     let mut out_bytes_len = MaybeUninit::<usize>::uninit();
     let sig = unsafe { ecdsa_sig_from_fixed(alg, signature)? };
@@ -220,8 +390,7 @@ fn verify_fixed_signature(
         return Err(Unspecified);
     }
     let out_bytes = LcPtr::new(out_bytes)?;
-    let signature = unsafe { out_bytes.as_slice(out_bytes_len.assume_init()) };
-    verify_asn1_signature(alg, digest, public_key, msg, signature)
+    Ok((out_bytes, unsafe { out_bytes_len.assume_init() }))
 }
 
 fn verify_asn1_signature(
@@ -3,6 +3,14 @@
 
 //! Serialization formats
 
+pub mod base64url;
+pub mod der;
+pub mod jwk;
+pub mod openssh;
+pub mod pem;
+#[cfg(feature = "serde")]
+pub(crate) mod serde_der;
+
 use crate::buffer::Buffer;
 use paste::paste;
 
@@ -58,6 +66,7 @@ pub(crate) use generated_encodings;
 generated_encodings!(
     EcPrivateKeyBin,
     EcPrivateKeyRfc5915Der,
+    EcPrivateKeyPkcs8V2Der,
     EcPublicKeyUncompressedBin,
     EcPublicKeyCompressedBin,
     PublicKeyX509Der,
@@ -0,0 +1,250 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+//! Encoding and decoding of the unpadded "base64url" encoding specified in
+//! [RFC 4648, Section 5](https://www.rfc-editor.org/rfc/rfc4648#section-5), as used by
+//! JSON Web Keys ([RFC 7517](https://www.rfc-editor.org/rfc/rfc7517)).
+//!
+//! [`encode`] never emits padding. When decoding, padding characters and whitespace are rejected,
+//! as are encodings whose unused trailing bits are not zero.
+//!
+//! # `decode` vs. `decode_ct`
+//! [`decode`] branches on the value of each character and stops at the first invalid one. This is
+//! fine for public data, such as the members of a public JWK, but the timing of those branches can
+//! reveal information about the bytes being decoded.
+//!
+//! [`decode_ct`] should be used to decode secret data, such as the private members (`d`, `p`,
+//! `q`, ...) of a JWK. Its execution time only depends on the length of the input: each character
+//! is mapped to its value using arithmetic masks rather than branches or table lookups (whose
+//! memory access pattern would depend on the character), and invalid characters are accumulated
+//! into a single error reported after all input has been processed.
+//!
+//! # Example
+//!
+//! ```rust
+//! # use std::error::Error;
+//! #
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! use aws_lc_rs::encoding::base64url;
+//!
+//! assert_eq!(base64url::encode(b"foobar"), "Zm9vYmFy");
+//! assert_eq!(base64url::decode("Zm9vYmFy")?, b"foobar");
+//! assert_eq!(base64url::decode_ct("-_8")?, &[0xfb, 0xff]);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::error::Unspecified;
+
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Encodes `input` as unpadded base64url.
+///
+/// This function is not constant-time, and should only be used to encode public data.
+#[must_use]
+pub fn encode(input: &[u8]) -> String {
+    let mut output = String::with_capacity((input.len() * 4 + 2) / 3);
+    for chunk in input.chunks(3) {
+        let mut acc = 0u32;
+        for (i, &b) in chunk.iter().enumerate() {
+            acc |= u32::from(b) << (16 - 8 * i);
+        }
+        for i in 0..=chunk.len() {
+            let index = (acc >> (18 - 6 * i)) & 0x3f;
+            output.push(char::from(ALPHABET[index as usize]));
+        }
+    }
+    output
+}
+
+/// Decodes unpadded base64url-encoded `input`.
+///
+/// This function is not constant-time; use [`decode_ct`] for secret data.
+///
+/// # Errors
+/// `error::Unspecified` if `input` is not valid unpadded base64url.
+pub fn decode(input: &str) -> Result<Vec<u8>, Unspecified> {
+    let input = input.as_bytes();
+    check_len(input)?;
+    let mut output = Vec::with_capacity(decoded_len(input));
+    for chunk in input.chunks(4) {
+        let mut acc = 0u32;
+        for &c in chunk {
+            let value = match c {
+                b'A'..=b'Z' => c - b'A',
+                b'a'..=b'z' => c - b'a' + 26,
+                b'0'..=b'9' => c - b'0' + 52,
+                b'-' => 62,
+                b'_' => 63,
+                _ => return Err(Unspecified),
+            };
+            acc = (acc << 6) | u32::from(value);
+        }
+        if push_chunk(&mut output, acc, chunk.len()) != 0 {
+            return Err(Unspecified);
+        }
+    }
+    Ok(output)
+}
+
+/// Decodes unpadded base64url-encoded `input` in time that depends only on `input.len()`.
+///
+/// # Errors
+/// `error::Unspecified` if `input` is not valid unpadded base64url.
+pub fn decode_ct(input: &str) -> Result<Vec<u8>, Unspecified> {
+    let input = input.as_bytes();
+    check_len(input)?;
+    let mut output = Vec::with_capacity(decoded_len(input));
+    let mut invalid = 0u32;
+    for chunk in input.chunks(4) {
+        let mut acc = 0u32;
+        for &c in chunk {
+            let (value, valid) = decode_char_ct(c);
+            invalid |= !valid & 1;
+            acc = (acc << 6) | value;
+        }
+        invalid |= push_chunk(&mut output, acc, chunk.len());
+    }
+    if invalid != 0 {
+        return Err(Unspecified);
+    }
+    Ok(output)
+}
+
+// The encoded length is public, so it may be checked with a branch.
+fn check_len(input: &[u8]) -> Result<(), Unspecified> {
+    if input.len() % 4 == 1 {
+        return Err(Unspecified);
+    }
+    Ok(())
+}
+
+fn decoded_len(input: &[u8]) -> usize {
+    input.len() / 4 * 3 + (input.len() % 4).saturating_sub(1)
+}
+
+// Appends the bytes encoded by a chunk of `chunk_len` characters accumulated in `acc`. Returns
+// non-zero if the unused trailing bits are not zero.
+#[allow(clippy::cast_possible_truncation)]
+fn push_chunk(output: &mut Vec<u8>, acc: u32, chunk_len: usize) -> u32 {
+    match chunk_len {
+        4 => {
+            output.extend_from_slice(&[(acc >> 16) as u8, (acc >> 8) as u8, acc as u8]);
+            0
+        }
+        3 => {
+            output.extend_from_slice(&[(acc >> 10) as u8, (acc >> 2) as u8]);
+            acc & 0x3
+        }
+        _ => {
+            output.push((acc >> 4) as u8);
+            acc & 0xf
+        }
+    }
+}
+
+// Returns an all-ones mask if `lo <= c <= hi`, or zero otherwise.
+fn in_range_mask(c: u32, lo: u32, hi: u32) -> u32 {
+    // One of the subtractions underflows, setting the high bit, exactly when `c` is outside
+    // the range.
+    let outside = (c.wrapping_sub(lo) | hi.wrapping_sub(c)) >> 31;
+    outside.wrapping_sub(1)
+}
+
+// Maps a base64url character to its 6-bit value without branches or table lookups. The second
+// value is 1 if `c` is a valid base64url character, and 0 otherwise.
+fn decode_char_ct(c: u8) -> (u32, u32) {
+    let c = u32::from(c);
+    let upper = in_range_mask(c, u32::from(b'A'), u32::from(b'Z'));
+    let lower = in_range_mask(c, u32::from(b'a'), u32::from(b'z'));
+    let digit = in_range_mask(c, u32::from(b'0'), u32::from(b'9'));
+    let minus = in_range_mask(c, u32::from(b'-'), u32::from(b'-'));
+    let underscore = in_range_mask(c, u32::from(b'_'), u32::from(b'_'));
+
+    let value = (upper & c.wrapping_sub(u32::from(b'A')))
+        | (lower & c.wrapping_sub(u32::from(b'a') - 26))
+        | (digit & c.wrapping_add(52 - u32::from(b'0')))
+        | (minus & 62)
+        | (underscore & 63);
+    let valid = (upper | lower | digit | minus | underscore) & 1;
+    (value, valid)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::encoding::base64url::{decode, decode_char_ct, decode_ct, encode};
+
+    #[test]
+    fn rfc4648_test_vectors() {
+        // RFC 4648, Section 10, without padding.
+        for (encoded, decoded) in [
+            ("", &b""[..]),
+            ("Zg", b"f"),
+            ("Zm8", b"fo"),
+            ("Zm9v", b"foo"),
+            ("Zm9vYg", b"foob"),
+            ("Zm9vYmE", b"fooba"),
+            ("Zm9vYmFy", b"foobar"),
+        ] {
+            assert_eq!(encoded, encode(decoded));
+            assert_eq!(decoded, decode(encoded).unwrap().as_slice(), "{encoded}");
+            assert_eq!(decoded, decode_ct(encoded).unwrap().as_slice(), "{encoded}");
+        }
+    }
+
+    #[test]
+    fn url_safe_alphabet() {
+        // RFC 7515, Appendix C
+        let expected = [3, 236, 255, 224, 193];
+        assert_eq!("A-z_4ME", encode(&expected));
+        assert_eq!(&expected, decode("A-z_4ME").unwrap().as_slice());
+        assert_eq!(&expected, decode_ct("A-z_4ME").unwrap().as_slice());
+
+        let expected = [0xfb, 0xef, 0xbe];
+        assert_eq!(&expected, decode_ct("----").unwrap().as_slice());
+        let expected = [0xff, 0xff, 0xff];
+        assert_eq!(&expected, decode_ct("____").unwrap().as_slice());
+    }
+
+    #[test]
+    fn char_mapping_matches() {
+        for c in 0..=255u8 {
+            let (value, valid) = decode_char_ct(c);
+            let mut input = String::new();
+            input.push(char::from(c));
+            input.push('A');
+            match decode(&input) {
+                Ok(decoded) if c.is_ascii() => {
+                    assert_eq!(1, valid, "{c}");
+                    assert_eq!(u32::from(decoded[0] >> 2), value, "{c}");
+                }
+                _ => assert_eq!(0, valid, "{c}"),
+            }
+        }
+    }
+
+    #[test]
+    fn reject_invalid() {
+        for input in [
+            // Invalid length
+            "Z",
+            "Zm9vY",
+            // Padding
+            "Zg==",
+            "Zm8=",
+            // Standard base64 alphabet
+            "+/8",
+            // Whitespace
+            "Zm9v Yg",
+            "Zm9v\nYmFy",
+            // Non-zero trailing bits
+            "Zh",
+            "Zm9",
+            // Non-ASCII
+            "Zm9vé",
+        ] {
+            assert!(decode(input).is_err(), "{input}");
+            assert!(decode_ct(input).is_err(), "{input}");
+        }
+    }
+}
@@ -0,0 +1,268 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+//! A minimal reader for Distinguished Encoding Rules (DER) as specified in
+//! [ITU-T X.690](https://www.itu.int/rec/T-REC-X.690).
+//!
+//! Only the subset of DER needed to extract key components is supported:
+//! single-byte tags and definite lengths of up to four bytes. Indefinite-length
+//! encodings, high tag numbers and non-minimal length encodings are rejected.
+//!
+//! # Example
+//!
+//! ```rust
+//! # use std::error::Error;
+//! #
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! use aws_lc_rs::encoding::der::DerReader;
+//!
+//! // SEQUENCE { INTEGER 1, OCTET STRING 0xAA 0xBB }
+//! let input = [0x30, 0x07, 0x02, 0x01, 0x01, 0x04, 0x02, 0xAA, 0xBB];
+//!
+//! let mut reader = DerReader::new(&input);
+//! let mut sequence = reader.read_sequence()?;
+//! assert!(reader.is_empty());
+//!
+//! assert_eq!(sequence.read_integer()?, &[0x01]);
+//! assert_eq!(sequence.read_octet_string()?, &[0xAA, 0xBB]);
+//! assert!(sequence.is_empty());
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::error::Unspecified;
+
+/// The DER tag for an `INTEGER`.
+pub const TAG_INTEGER: u8 = 0x02;
+
+/// The DER tag for a `BIT STRING`.
+pub const TAG_BIT_STRING: u8 = 0x03;
+
+/// The DER tag for an `OCTET STRING`.
+pub const TAG_OCTET_STRING: u8 = 0x04;
+
+/// The DER tag for an `OBJECT IDENTIFIER`.
+pub const TAG_OID: u8 = 0x06;
+
+/// The DER tag for a `SEQUENCE`.
+pub const TAG_SEQUENCE: u8 = 0x30;
+
+// Lengths are limited to four bytes, which is far beyond any key encoding.
+const MAX_LENGTH_BYTES: usize = 4;
+
+/// Reads DER-encoded elements sequentially from a byte slice.
+#[derive(Clone, Copy, Debug)]
+pub struct DerReader<'a> {
+    input: &'a [u8],
+}
+
+impl<'a> DerReader<'a> {
+    /// Constructs a new reader over `input`.
+    #[must_use]
+    pub fn new(input: &'a [u8]) -> Self {
+        Self { input }
+    }
+
+    /// Returns `true` if all input has been consumed.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.input.is_empty()
+    }
+
+    /// Returns the tag of the next element without consuming it, or `None` if
+    /// all input has been consumed.
+    #[must_use]
+    pub fn peek_tag(&self) -> Option<u8> {
+        self.input.first().copied()
+    }
+
+    /// Reads the next element, returning its tag and contents.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if the element is truncated, uses the high tag
+    /// number form, or has an indefinite or non-minimally encoded length.
+    pub fn next_tag_len(&mut self) -> Result<(u8, &'a [u8]), Unspecified> {
+        let (&tag, rest) = self.input.split_first().ok_or(Unspecified)?;
+        if tag & 0x1f == 0x1f {
+            return Err(Unspecified);
+        }
+        let (&first, mut rest) = rest.split_first().ok_or(Unspecified)?;
+
+        let len = if first < 0x80 {
+            usize::from(first)
+        } else {
+            // 0x80 is the indefinite length form, which DER does not permit.
+            let num_bytes = usize::from(first & 0x7f);
+            if num_bytes == 0 || num_bytes > MAX_LENGTH_BYTES || num_bytes > rest.len() {
+                return Err(Unspecified);
+            }
+            let (len_bytes, remaining) = rest.split_at(num_bytes);
+            if len_bytes[0] == 0 {
+                return Err(Unspecified);
+            }
+            let len = len_bytes
+                .iter()
+                .fold(0usize, |acc, &b| (acc << 8) | usize::from(b));
+            if len < 0x80 {
+                return Err(Unspecified);
+            }
+            rest = remaining;
+            len
+        };
+
+        if len > rest.len() {
+            return Err(Unspecified);
+        }
+        let (contents, remaining) = rest.split_at(len);
+        self.input = remaining;
+        Ok((tag, contents))
+    }
+
+    /// Reads the next element, requiring it to have the tag `expected`.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if the element is malformed or has a different tag.
+    pub fn read_tagged(&mut self, expected: u8) -> Result<&'a [u8], Unspecified> {
+        let (tag, contents) = self.next_tag_len()?;
+        if tag != expected {
+            return Err(Unspecified);
+        }
+        Ok(contents)
+    }
+
+    /// Reads a `SEQUENCE` and returns a reader over its contents.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if the next element is not a well-formed `SEQUENCE`.
+    pub fn read_sequence(&mut self) -> Result<DerReader<'a>, Unspecified> {
+        Ok(DerReader::new(self.read_tagged(TAG_SEQUENCE)?))
+    }
+
+    /// Reads an `INTEGER`, returning its big-endian two's complement contents.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if the next element is not an `INTEGER`, or the
+    /// integer is empty or not minimally encoded.
+    pub fn read_integer(&mut self) -> Result<&'a [u8], Unspecified> {
+        let contents = self.read_tagged(TAG_INTEGER)?;
+        match contents {
+            [] => Err(Unspecified),
+            [0x00, next, ..] if next & 0x80 == 0 => Err(Unspecified),
+            [0xff, next, ..] if next & 0x80 != 0 => Err(Unspecified),
+            _ => Ok(contents),
+        }
+    }
+
+    /// Reads an `OCTET STRING`, returning its contents.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if the next element is not an `OCTET STRING`.
+    pub fn read_octet_string(&mut self) -> Result<&'a [u8], Unspecified> {
+        self.read_tagged(TAG_OCTET_STRING)
+    }
+
+    /// Reads an `OBJECT IDENTIFIER`, returning its encoded contents.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if the next element is not an `OBJECT IDENTIFIER`
+    /// or is empty.
+    pub fn read_oid(&mut self) -> Result<&'a [u8], Unspecified> {
+        let contents = self.read_tagged(TAG_OID)?;
+        if contents.is_empty() {
+            return Err(Unspecified);
+        }
+        Ok(contents)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::encoding::der::{DerReader, TAG_OCTET_STRING};
+
+    #[test]
+    fn read_nested_elements() {
+        // SEQUENCE { INTEGER 0x0080, OID 1.2.840.10045.2.1, SEQUENCE { OCTET STRING } }
+        let input = [
+            0x30, 0x13, 0x02, 0x02, 0x00, 0x80, 0x06, 0x07, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02,
+            0x01, 0x30, 0x04, 0x04, 0x02, 0x01, 0x02,
+        ];
+        let mut reader = DerReader::new(&input);
+        let mut sequence = reader.read_sequence().unwrap();
+        assert!(reader.is_empty());
+
+        assert_eq!(&[0x00, 0x80], sequence.read_integer().unwrap());
+        assert_eq!(
+            &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01],
+            sequence.read_oid().unwrap()
+        );
+        let mut inner = sequence.read_sequence().unwrap();
+        assert!(sequence.is_empty());
+        assert_eq!(Some(TAG_OCTET_STRING), inner.peek_tag());
+        assert_eq!(&[0x01, 0x02], inner.read_octet_string().unwrap());
+        assert!(inner.is_empty());
+        assert_eq!(None, inner.peek_tag());
+    }
+
+    #[test]
+    fn read_long_form_length() {
+        let mut input = vec![0x04, 0x81, 0xc8];
+        input.extend_from_slice(&[0xab; 200]);
+        let mut reader = DerReader::new(&input);
+        assert_eq!(&[0xab; 200][..], reader.read_octet_string().unwrap());
+        assert!(reader.is_empty());
+    }
+
+    #[test]
+    fn reject_malformed() {
+        for input in [
+            // Empty input
+            &[][..],
+            // Missing length
+            &[0x30],
+            // Truncated contents
+            &[0x04, 0x03, 0x01, 0x02],
+            // Truncated long-form length
+            &[0x04, 0x82, 0x01],
+            // Indefinite length
+            &[0x30, 0x80, 0x02, 0x01, 0x01, 0x00, 0x00],
+            // Non-minimal long-form length
+            &[0x04, 0x81, 0x01, 0xaa],
+            // Long-form length with leading zero
+            &[0x04, 0x82, 0x00, 0x81],
+            // High tag number form
+            &[0x1f, 0x81, 0x01, 0x00],
+        ] {
+            assert!(DerReader::new(input).next_tag_len().is_err(), "{input:?}");
+        }
+    }
+
+    #[test]
+    fn reject_overlapping_elements() {
+        // The inner OCTET STRING claims more bytes than the enclosing SEQUENCE holds,
+        // overlapping the INTEGER that follows the SEQUENCE.
+        let input = [0x30, 0x03, 0x04, 0x04, 0x01, 0x02, 0x01, 0x00];
+        let mut reader = DerReader::new(&input);
+        let mut sequence = reader.read_sequence().unwrap();
+        assert!(sequence.read_octet_string().is_err());
+    }
+
+    #[test]
+    fn reject_wrong_tag_and_bad_integers() {
+        assert!(DerReader::new(&[0x04, 0x01, 0x00]).read_integer().is_err());
+        assert!(DerReader::new(&[0x02, 0x00]).read_integer().is_err());
+        assert!(DerReader::new(&[0x02, 0x02, 0x00, 0x01])
+            .read_integer()
+            .is_err());
+        assert!(DerReader::new(&[0x02, 0x02, 0xff, 0x80])
+            .read_integer()
+            .is_err());
+        assert!(DerReader::new(&[0x06, 0x00]).read_oid().is_err());
+        assert!(DerReader::new(&[0x02, 0x01, 0x00]).read_sequence().is_err());
+        assert_eq!(
+            &[0xff, 0x7f],
+            DerReader::new(&[0x02, 0x02, 0xff, 0x7f])
+                .read_integer()
+                .unwrap()
+        );
+    }
+}
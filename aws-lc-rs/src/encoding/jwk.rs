@@ -0,0 +1,183 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+//! JSON Web Key ([RFC 7517](https://www.rfc-editor.org/rfc/rfc7517)) representations of public
+//! keys.
+//!
+//! The following key types implement [`AsJwk`]:
+//! * RSA public keys (`kty` of `RSA`), with the `n` and `e` members.
+//! * ECDSA public keys (`kty` of `EC`), with the `crv`, `x` and `y` members.
+//! * Ed25519 public keys (`kty` of `OKP`, [RFC 8037](https://www.rfc-editor.org/rfc/rfc8037)),
+//!   with the `crv` and `x` members.
+//!
+//! The `Display` implementation of [`JwkPublicKey`] emits a JSON object containing only the
+//! required members, without whitespace and in lexicographic order. This is the form hashed to
+//! compute a JWK thumbprint ([RFC 7638](https://www.rfc-editor.org/rfc/rfc7638)).
+//!
+//! # Example
+//!
+//! ```rust
+//! # use std::error::Error;
+//! #
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! use aws_lc_rs::encoding::jwk::AsJwk;
+//! use aws_lc_rs::signature::{Ed25519KeyPair, KeyPair};
+//!
+//! let key_pair = Ed25519KeyPair::from_seed_unchecked(&[0x42; 32])?;
+//! let jwk = key_pair.public_key().as_jwk()?;
+//!
+//! assert_eq!("OKP", jwk.kty());
+//! assert_eq!(Some("Ed25519"), jwk.crv());
+//! assert_eq!(
+//!     r#"{"crv":"Ed25519","kty":"OKP","x":"IVL40Zt5HSRFMkLhXy6rbLfP-ntqXtMAl5YOBpiB2xI"}"#,
+//!     jwk.to_string()
+//! );
+//! # Ok(())
+//! # }
+//! ```
+
+#![allow(clippy::module_name_repetitions)]
+
+use crate::encoding::base64url;
+use crate::error::Unspecified;
+use core::fmt::{self, Display, Formatter};
+
+/// Trait for public keys that can be represented as a JSON Web Key.
+pub trait AsJwk {
+    /// Returns the JSON Web Key representation of this public key.
+    ///
+    /// # Errors
+    /// Returns Unspecified if the key cannot be represented.
+    fn as_jwk(&self) -> Result<JwkPublicKey, Unspecified>;
+}
+
+/// A JSON Web Key representation of a public key.
+///
+/// All binary members are base64url-encoded without padding.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JwkPublicKey {
+    kty: &'static str,
+    crv: Option<&'static str>,
+    n: Option<String>,
+    e: Option<String>,
+    x: Option<String>,
+    y: Option<String>,
+}
+
+impl JwkPublicKey {
+    pub(crate) fn rsa(n: &[u8], e: &[u8]) -> Self {
+        Self {
+            kty: "RSA",
+            crv: None,
+            n: Some(base64url::encode(n)),
+            e: Some(base64url::encode(e)),
+            x: None,
+            y: None,
+        }
+    }
+
+    pub(crate) fn ec(crv: &'static str, x: &[u8], y: &[u8]) -> Self {
+        Self {
+            kty: "EC",
+            crv: Some(crv),
+            n: None,
+            e: None,
+            x: Some(base64url::encode(x)),
+            y: Some(base64url::encode(y)),
+        }
+    }
+
+    pub(crate) fn okp(crv: &'static str, x: &[u8]) -> Self {
+        Self {
+            kty: "OKP",
+            crv: Some(crv),
+            n: None,
+            e: None,
+            x: Some(base64url::encode(x)),
+            y: None,
+        }
+    }
+
+    /// The key type: `RSA`, `EC` or `OKP`.
+    #[must_use]
+    pub fn kty(&self) -> &str {
+        self.kty
+    }
+
+    /// The curve, for `EC` and `OKP` keys.
+    #[must_use]
+    pub fn crv(&self) -> Option<&str> {
+        self.crv
+    }
+
+    /// The modulus, for `RSA` keys.
+    #[must_use]
+    pub fn n(&self) -> Option<&str> {
+        self.n.as_deref()
+    }
+
+    /// The public exponent, for `RSA` keys.
+    #[must_use]
+    pub fn e(&self) -> Option<&str> {
+        self.e.as_deref()
+    }
+
+    /// The x coordinate, for `EC` keys, or the public key, for `OKP` keys.
+    #[must_use]
+    pub fn x(&self) -> Option<&str> {
+        self.x.as_deref()
+    }
+
+    /// The y coordinate, for `EC` keys.
+    #[must_use]
+    pub fn y(&self) -> Option<&str> {
+        self.y.as_deref()
+    }
+}
+
+impl Display for JwkPublicKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        // All member values are either base64url or fixed identifiers, so none need escaping.
+        let members = [
+            ("crv", self.crv),
+            ("e", self.e()),
+            ("kty", Some(self.kty)),
+            ("n", self.n()),
+            ("x", self.x()),
+            ("y", self.y()),
+        ];
+        f.write_str("{")?;
+        let mut first = true;
+        for (name, value) in members {
+            if let Some(value) = value {
+                if !first {
+                    f.write_str(",")?;
+                }
+                write!(f, "\"{name}\":\"{value}\"")?;
+                first = false;
+            }
+        }
+        f.write_str("}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::encoding::jwk::JwkPublicKey;
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            r#"{"e":"AQAB","kty":"RSA","n":"AQID"}"#,
+            JwkPublicKey::rsa(&[1, 2, 3], &[1, 0, 1]).to_string()
+        );
+        assert_eq!(
+            r#"{"crv":"P-256","kty":"EC","x":"AQ","y":"Ag"}"#,
+            JwkPublicKey::ec("P-256", &[1], &[2]).to_string()
+        );
+        assert_eq!(
+            r#"{"crv":"Ed25519","kty":"OKP","x":"_w"}"#,
+            JwkPublicKey::okp("Ed25519", &[0xff]).to_string()
+        );
+    }
+}
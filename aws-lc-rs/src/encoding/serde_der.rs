@@ -0,0 +1,64 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+//! Helpers for the `serde` implementations of public key types, which are represented by their
+//! DER encoding: a base64url string in human-readable formats, and bytes otherwise.
+
+use crate::encoding::base64url;
+use crate::error::KeyRejected;
+use core::fmt;
+use serde::de::{self, Visitor};
+use serde::{Deserializer, Serializer};
+
+pub(crate) fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() {
+        serializer.serialize_str(&base64url::encode(bytes))
+    } else {
+        serializer.serialize_bytes(bytes)
+    }
+}
+
+/// Deserializes the encoded key, and parses it with `parse`.
+pub(crate) fn deserialize<'de, D: Deserializer<'de>, T>(
+    deserializer: D,
+    parse: impl FnOnce(&[u8]) -> Result<T, KeyRejected>,
+) -> Result<T, D::Error> {
+    let bytes = if deserializer.is_human_readable() {
+        deserializer.deserialize_str(Base64UrlVisitor)?
+    } else {
+        deserializer.deserialize_byte_buf(BytesVisitor)?
+    };
+    parse(&bytes).map_err(de::Error::custom)
+}
+
+struct Base64UrlVisitor;
+
+impl Visitor<'_> for Base64UrlVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a base64url-encoded key")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        base64url::decode(v).map_err(|_| E::custom("invalid base64url encoding"))
+    }
+}
+
+struct BytesVisitor;
+
+impl Visitor<'_> for BytesVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an encoded key")
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        Ok(v.to_vec())
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+        Ok(v)
+    }
+}
@@ -115,42 +115,101 @@ impl From<core::array::TryFromSliceError> for Unspecified {
 ///
 ///  * Unexpected errors: Report this as a bug.
 #[derive(Copy, Clone, Debug, PartialEq)]
-pub struct KeyRejected(&'static str);
+pub struct KeyRejected(KeyRejectionReason);
+
+/// The reason a key was rejected; see [`KeyRejected::reason`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum KeyRejectionReason {
+    /// A component of the key has an invalid value, or the components of the key are
+    /// inconsistent with each other.
+    InconsistentComponents,
+
+    /// The encoding of the key is invalid.
+    InvalidEncoding,
+
+    /// The key is too small.
+    TooSmall,
+
+    /// The key is too large.
+    TooLarge,
+
+    /// The key does not meet the FIPS 186-5 key size requirements for its algorithm.
+    FipsKeySizeViolation,
+
+    /// The key is not valid for the algorithm with which it was used.
+    WrongAlgorithm,
+
+    /// An unexpected error occurred.
+    UnexpectedError,
+
+    /// The key was rejected for an unspecified reason.
+    Unspecified,
+}
+
+impl KeyRejectionReason {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::InconsistentComponents => "InconsistentComponents",
+            Self::InvalidEncoding => "InvalidEncoding",
+            Self::TooSmall => "TooSmall",
+            Self::TooLarge => "TooLarge",
+            Self::FipsKeySizeViolation => "FipsKeySizeViolation",
+            Self::WrongAlgorithm => "WrongAlgorithm",
+            Self::UnexpectedError => "UnexpectedError",
+            Self::Unspecified => "Unspecified",
+        }
+    }
+}
 
 impl KeyRejected {
     /// The value returned from `<Self as std::error::Error>::description()`
     #[must_use]
     pub fn description_(&self) -> &'static str {
+        self.0.as_str()
+    }
+
+    /// The reason the key was rejected.
+    #[must_use]
+    pub fn reason(&self) -> KeyRejectionReason {
         self.0
     }
 
     pub(crate) fn inconsistent_components() -> Self {
-        KeyRejected("InconsistentComponents")
+        KeyRejected(KeyRejectionReason::InconsistentComponents)
     }
 
     #[inline]
     pub(crate) fn invalid_encoding() -> Self {
-        KeyRejected("InvalidEncoding")
+        KeyRejected(KeyRejectionReason::InvalidEncoding)
     }
 
     pub(crate) fn too_small() -> Self {
-        KeyRejected("TooSmall")
+        KeyRejected(KeyRejectionReason::TooSmall)
     }
 
     pub(crate) fn too_large() -> Self {
-        KeyRejected("TooLarge")
+        KeyRejected(KeyRejectionReason::TooLarge)
+    }
+
+    /// The key does not meet the FIPS 186-5 key size requirements for its algorithm.
+    ///
+    /// This is only returned when the crate is built with the `fips` feature.
+    #[must_use]
+    pub fn fips_key_size_violation() -> Self {
+        KeyRejected(KeyRejectionReason::FipsKeySizeViolation)
     }
 
     pub(crate) fn wrong_algorithm() -> Self {
-        KeyRejected("WrongAlgorithm")
+        KeyRejected(KeyRejectionReason::WrongAlgorithm)
     }
 
     pub(crate) fn unexpected_error() -> Self {
-        KeyRejected("UnexpectedError")
+        KeyRejected(KeyRejectionReason::UnexpectedError)
     }
 
     pub(crate) fn unspecified() -> Self {
-        KeyRejected("Unspecified")
+        KeyRejected(KeyRejectionReason::Unspecified)
     }
 }
 
@@ -229,10 +288,77 @@ impl From<Unspecified> for KeyRejected {
     }
 }
 
+/// An error with details about the cause of a failure.
+///
+/// Most functions return [`Unspecified`] or [`KeyRejected`] for the reasons given in their
+/// documentation, and for compatibility with *ring*. Functions named `*_with_error`, such as
+/// `rsa::KeyPair::sign_with_error`, `signature::UnparsedPublicKey::verify_with_error` and
+/// `agreement::agree_with_error`, instead return a `CryptoError` so that callers can
+/// distinguish between failure modes. Any error can be converted back into an `Unspecified`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CryptoError {
+    /// A key was rejected.
+    KeyRejected(KeyRejectionReason),
+
+    /// A signature was not valid for the message and public key.
+    SignatureVerificationFailed,
+
+    /// The algorithms of two keys, or of a key and an operation, do not match.
+    AlgorithmMismatch,
+
+    /// An input was not validly encoded.
+    InvalidEncoding,
+
+    /// An output buffer was too small.
+    OutputBufferTooSmall {
+        /// The required length of the output buffer, in bytes.
+        required: usize,
+    },
+
+    /// An error with no further details.
+    Unspecified,
+}
+
+impl core::fmt::Display for CryptoError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::KeyRejected(reason) => write!(f, "KeyRejected({})", reason.as_str()),
+            Self::SignatureVerificationFailed => f.write_str("SignatureVerificationFailed"),
+            Self::AlgorithmMismatch => f.write_str("AlgorithmMismatch"),
+            Self::InvalidEncoding => f.write_str("InvalidEncoding"),
+            Self::OutputBufferTooSmall { required } => {
+                write!(f, "OutputBufferTooSmall(required: {required})")
+            }
+            Self::Unspecified => f.write_str("Unspecified"),
+        }
+    }
+}
+
+impl Error for CryptoError {}
+
+impl From<Unspecified> for CryptoError {
+    fn from(_: Unspecified) -> Self {
+        Self::Unspecified
+    }
+}
+
+impl From<KeyRejected> for CryptoError {
+    fn from(key_rejected: KeyRejected) -> Self {
+        Self::KeyRejected(key_rejected.reason())
+    }
+}
+
+impl From<CryptoError> for Unspecified {
+    fn from(_: CryptoError) -> Self {
+        Unspecified
+    }
+}
+
 #[allow(deprecated, unused_imports)]
 #[cfg(test)]
 mod tests {
-    use crate::error::KeyRejected;
+    use crate::error::{CryptoError, KeyRejected, KeyRejectionReason};
     use crate::test;
     use std::error::Error;
 
@@ -267,4 +393,30 @@ mod tests {
 
         test::compile_time_assert_std_error_error::<KeyRejected>();
     }
+
+    #[test]
+    fn crypto_error() {
+        let key_rejected = KeyRejected::too_small();
+        assert_eq!(KeyRejectionReason::TooSmall, key_rejected.reason());
+        assert_eq!("TooSmall", key_rejected.description());
+
+        let crypto_error = CryptoError::from(key_rejected);
+        assert_eq!(
+            CryptoError::KeyRejected(KeyRejectionReason::TooSmall),
+            crypto_error
+        );
+        assert_eq!("KeyRejected(TooSmall)", crypto_error.to_string());
+        assert_eq!(super::Unspecified, super::Unspecified::from(crypto_error));
+
+        assert_eq!(
+            CryptoError::Unspecified,
+            CryptoError::from(super::Unspecified)
+        );
+        assert_eq!(
+            "OutputBufferTooSmall(required: 256)",
+            CryptoError::OutputBufferTooSmall { required: 256 }.to_string()
+        );
+
+        test::compile_time_assert_std_error_error::<CryptoError>();
+    }
 }
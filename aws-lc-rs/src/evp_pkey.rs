@@ -3,12 +3,14 @@
 
 use crate::aws_lc::{
     EVP_DigestSign, EVP_DigestSignInit, EVP_DigestVerify, EVP_DigestVerifyInit, EVP_PKEY_CTX_new,
-    EVP_PKEY_CTX_new_id, EVP_PKEY_bits, EVP_PKEY_cmp, EVP_PKEY_get0_EC_KEY, EVP_PKEY_get0_RSA,
-    EVP_PKEY_get_raw_private_key, EVP_PKEY_get_raw_public_key, EVP_PKEY_id, EVP_PKEY_keygen,
-    EVP_PKEY_keygen_init, EVP_PKEY_new_raw_private_key, EVP_PKEY_new_raw_public_key, EVP_PKEY_size,
-    EVP_PKEY_up_ref, EVP_marshal_private_key, EVP_marshal_private_key_v2, EVP_marshal_public_key,
-    EVP_parse_private_key, EVP_parse_public_key, EC_KEY, EVP_PKEY, EVP_PKEY_CTX, EVP_PKEY_ED25519,
-    RSA,
+    EVP_PKEY_CTX_new_id, EVP_PKEY_CTX_set_signature_md, EVP_PKEY_bits, EVP_PKEY_cmp,
+    EVP_PKEY_get0_EC_KEY, EVP_PKEY_get0_RSA, EVP_PKEY_get_raw_private_key,
+    EVP_PKEY_get_raw_public_key, EVP_PKEY_id, EVP_PKEY_keygen, EVP_PKEY_keygen_init,
+    EVP_PKEY_new_raw_private_key, EVP_PKEY_new_raw_public_key, EVP_PKEY_sign, EVP_PKEY_sign_init,
+    EVP_PKEY_size, EVP_PKEY_up_ref, EVP_PKEY_verify, EVP_PKEY_verify_init, EVP_marshal_private_key,
+    EVP_marshal_private_key_v2, EVP_marshal_public_key, EVP_parse_private_key,
+    EVP_parse_public_key, PKCS8_parse_encrypted_private_key, EC_KEY, EVP_PKEY, EVP_PKEY_CTX,
+    EVP_PKEY_ED25519, RSA,
 };
 #[cfg(not(feature = "fips"))]
 use crate::aws_lc::{
@@ -177,6 +179,24 @@ impl LcPtr<EVP_PKEY> {
             .ok_or(KeyRejected::wrong_algorithm())
     }
 
+    pub(crate) fn parse_encrypted_rfc5208_private_key(
+        bytes: &[u8],
+        password: &[u8],
+        evp_pkey_type: c_int,
+    ) -> Result<Self, KeyRejected> {
+        let mut cbs = cbs::build_CBS(bytes);
+        // Also checks the validity of the key
+        let evp_pkey = LcPtr::new(unsafe {
+            PKCS8_parse_encrypted_private_key(&mut cbs, password.as_ptr().cast(), password.len())
+        })
+        .map_err(|()| KeyRejected::invalid_encoding())?;
+        evp_pkey
+            .id()
+            .eq(&evp_pkey_type)
+            .then_some(evp_pkey)
+            .ok_or(KeyRejected::wrong_algorithm())
+    }
+
     #[allow(non_snake_case)]
     pub(crate) fn create_EVP_PKEY_CTX(&self) -> Result<LcPtr<EVP_PKEY_CTX>, ()> {
         // The only modification made by EVP_PKEY_CTX_new to `priv_key` is to increment its
@@ -411,6 +431,81 @@ impl LcPtr<EVP_PKEY> {
         Ok(())
     }
 
+    /// Signs `digest`, which must already be the output of `digest_alg`.
+    pub(crate) fn sign_digest(
+        &self,
+        digest_alg: &'static digest::Algorithm,
+        digest: &[u8],
+    ) -> Result<Box<[u8]>, Unspecified> {
+        if digest.len() != digest_alg.output_len() {
+            return Err(Unspecified);
+        }
+        let mut pkey_ctx = self.create_EVP_PKEY_CTX()?;
+        if 1 != unsafe { EVP_PKEY_sign_init(*pkey_ctx.as_mut()) } {
+            return Err(Unspecified);
+        }
+        if 1 != unsafe {
+            EVP_PKEY_CTX_set_signature_md(
+                *pkey_ctx.as_mut(),
+                *digest::match_digest_type(&digest_alg.id),
+            )
+        } {
+            return Err(Unspecified);
+        }
+
+        let mut sig_len = self.signature_size_bytes();
+        let mut signature = vec![0u8; sig_len];
+        if 1 != indicator_check!(unsafe {
+            EVP_PKEY_sign(
+                *pkey_ctx.as_mut(),
+                signature.as_mut_ptr(),
+                &mut sig_len,
+                digest.as_ptr(),
+                digest.len(),
+            )
+        }) {
+            return Err(Unspecified);
+        }
+        signature.truncate(sig_len);
+        Ok(signature.into_boxed_slice())
+    }
+
+    /// Verifies `signature` of `digest`, which must already be the output of `digest_alg`.
+    pub(crate) fn verify_digest(
+        &self,
+        digest_alg: &'static digest::Algorithm,
+        digest: &[u8],
+        signature: &[u8],
+    ) -> Result<(), Unspecified> {
+        if digest.len() != digest_alg.output_len() {
+            return Err(Unspecified);
+        }
+        let mut pkey_ctx = self.create_EVP_PKEY_CTX()?;
+        if 1 != unsafe { EVP_PKEY_verify_init(*pkey_ctx.as_mut()) } {
+            return Err(Unspecified);
+        }
+        if 1 != unsafe {
+            EVP_PKEY_CTX_set_signature_md(
+                *pkey_ctx.as_mut(),
+                *digest::match_digest_type(&digest_alg.id),
+            )
+        } {
+            return Err(Unspecified);
+        }
+        if 1 != indicator_check!(unsafe {
+            EVP_PKEY_verify(
+                *pkey_ctx.as_mut(),
+                signature.as_ptr(),
+                signature.len(),
+                digest.as_ptr(),
+                digest.len(),
+            )
+        }) {
+            return Err(Unspecified);
+        }
+        Ok(())
+    }
+
     pub(crate) fn generate<F>(pkey_type: c_int, params_fn: Option<F>) -> Result<Self, Unspecified>
     where
         F: EVP_PKEY_CTX_consumer,
@@ -1,6 +1,164 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0 OR ISC
 
+//! Controls for the handling of services that are not FIPS approved.
+//!
+//! By default, using a service that is not approved by the FIPS module (for example, PBKDF2 with
+//! a salt shorter than 16 bytes) is permitted. An application that must only use approved
+//! services can instead opt to panic when a non-approved service is used:
+//!
+//! ```rust
+//! use aws_lc_rs::fips::{get_policy, set_policy, FipsPolicy};
+//!
+//! assert_eq!(FipsPolicy::AllowNonApproved, get_policy());
+//! set_policy(FipsPolicy::PanicOnNonApproved);
+//! assert_eq!(FipsPolicy::PanicOnNonApproved, get_policy());
+//! ```
+//!
+//! The policy is only enforced when the `fips` feature is enabled.
+//!
+//! With the `fips` feature enabled, the module's power-on self-tests can be run on demand with
+//! `run_self_tests`, and their outcome queried with `self_test_status`.
+
+use core::sync::atomic::{AtomicU8, Ordering};
+
+/// The action taken when a service that is not FIPS approved is used.
+#[non_exhaustive]
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FipsPolicy {
+    /// Non-approved services may be used. This is the default.
+    #[default]
+    AllowNonApproved,
+
+    /// Using a non-approved service panics.
+    PanicOnNonApproved,
+}
+
+static POLICY: AtomicU8 = AtomicU8::new(FipsPolicy::AllowNonApproved as u8);
+
+/// Sets the process-wide [`FipsPolicy`].
+///
+/// This has no effect unless the `fips` feature is enabled.
+pub fn set_policy(policy: FipsPolicy) {
+    POLICY.store(policy as u8, Ordering::SeqCst);
+}
+
+/// Returns the process-wide [`FipsPolicy`].
+#[must_use]
+pub fn get_policy() -> FipsPolicy {
+    if POLICY.load(Ordering::SeqCst) == FipsPolicy::PanicOnNonApproved as u8 {
+        FipsPolicy::PanicOnNonApproved
+    } else {
+        FipsPolicy::AllowNonApproved
+    }
+}
+
+/// The outcome of the FIPS module's power-on self-tests.
+#[cfg(feature = "fips")]
+#[non_exhaustive]
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FipsSelfTestStatus {
+    /// [`run_self_tests`] has not been called.
+    NotRun,
+    /// The self-tests passed.
+    Passed,
+    /// At least one of the self-tests failed.
+    Failed,
+}
+
+/// The FIPS module's power-on self-tests failed.
+///
+/// AWS-LC does not report which of the self-tests failed.
+#[cfg(feature = "fips")]
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FipsSelfTestError;
+
+#[cfg(feature = "fips")]
+impl core::fmt::Display for FipsSelfTestError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.write_str("FipsSelfTestError")
+    }
+}
+
+#[cfg(feature = "fips")]
+impl std::error::Error for FipsSelfTestError {}
+
+#[cfg(feature = "fips")]
+static SELF_TEST_STATUS: AtomicU8 = AtomicU8::new(FipsSelfTestStatus::NotRun as u8);
+
+/// Runs the FIPS module's power-on self-tests.
+///
+/// The self-tests are only run by the first call; the result is cached and returned by
+/// subsequent calls, and can be queried with [`self_test_status`].
+///
+/// # Errors
+/// `FipsSelfTestError` if any of the self-tests failed.
+#[cfg(feature = "fips")]
+pub fn run_self_tests() -> Result<(), FipsSelfTestError> {
+    let status = match self_test_status() {
+        FipsSelfTestStatus::NotRun => {
+            crate::init();
+            let status = if 1 == unsafe { crate::aws_lc::BORINGSSL_self_test() } {
+                FipsSelfTestStatus::Passed
+            } else {
+                FipsSelfTestStatus::Failed
+            };
+            SELF_TEST_STATUS.store(status as u8, Ordering::SeqCst);
+            status
+        }
+        status => status,
+    };
+    if status == FipsSelfTestStatus::Passed {
+        Ok(())
+    } else {
+        Err(FipsSelfTestError)
+    }
+}
+
+/// Returns the outcome of the FIPS module's power-on self-tests, as run by [`run_self_tests`].
+#[cfg(feature = "fips")]
+#[must_use]
+pub fn self_test_status() -> FipsSelfTestStatus {
+    match SELF_TEST_STATUS.load(Ordering::SeqCst) {
+        s if s == FipsSelfTestStatus::Passed as u8 => FipsSelfTestStatus::Passed,
+        s if s == FipsSelfTestStatus::Failed as u8 => FipsSelfTestStatus::Failed,
+        _ => FipsSelfTestStatus::NotRun,
+    }
+}
+
+/// ```compile_fail
+/// aws_lc_rs::fips::run_self_tests().unwrap();
+/// ```
+#[cfg(all(doctest, not(feature = "fips")))]
+struct SelfTestsUnavailableWithoutFips;
+
+#[cfg(feature = "fips")]
+#[inline]
+pub(crate) fn panic_on_non_approved() -> bool {
+    get_policy() == FipsPolicy::PanicOnNonApproved
+}
+
+// Records the outcome of a call to the FIPS module, applying the `FipsPolicy` if the service
+// was not approved.
+#[cfg(feature = "fips")]
+#[inline]
+pub(crate) fn update_service_status(approved: bool) {
+    #[cfg(debug_assertions)]
+    if approved {
+        indicator::set_approved();
+    } else {
+        indicator::set_unapproved();
+    }
+    if !approved && panic_on_non_approved() {
+        panic!(
+            "a service that is not FIPS approved was used with `FipsPolicy::PanicOnNonApproved`"
+        );
+    }
+}
+
 /// Retrieve the FIPS module service status.
 #[allow(dead_code)] // appease clippy
 #[cfg(all(feature = "fips", debug_assertions))]
@@ -18,8 +176,8 @@ pub(crate) fn get_fips_service_status() -> FipsServiceStatus<()> {
 
 #[inline]
 pub(crate) fn set_fips_service_status_unapproved() {
-    #[cfg(all(feature = "fips", debug_assertions))]
-    indicator::set_unapproved();
+    #[cfg(feature = "fips")]
+    update_service_status(false);
 }
 
 #[allow(dead_code)]
@@ -60,13 +218,13 @@ pub(crate) mod indicator {
     }
 }
 
-#[cfg(all(feature = "fips", debug_assertions))]
+#[cfg(feature = "fips")]
 #[inline]
 pub(crate) fn service_indicator_before_call() -> u64 {
     unsafe { aws_lc::FIPS_service_indicator_before_call() }
 }
 
-#[cfg(all(feature = "fips", debug_assertions))]
+#[cfg(feature = "fips")]
 #[inline]
 pub(crate) fn service_indicator_after_call() -> u64 {
     unsafe { aws_lc::FIPS_service_indicator_after_call() }
@@ -109,21 +267,26 @@ impl<R> FipsServiceStatus<R> {
 
 macro_rules! indicator_check {
     ($function:expr) => {{
-        #[cfg(all(feature = "fips", debug_assertions))]
+        #[cfg(feature = "fips")]
         {
-            use crate::fips::{service_indicator_after_call, service_indicator_before_call};
-            let before = service_indicator_before_call();
-            let result = $function;
-            let after = service_indicator_after_call();
-            if before == after {
-                crate::fips::indicator::set_unapproved();
-                result
+            use crate::fips::{
+                panic_on_non_approved, service_indicator_after_call, service_indicator_before_call,
+                update_service_status,
+            };
+            // Outside of debug builds the indicator is only consulted to enforce the policy.
+            let check = cfg!(debug_assertions) || panic_on_non_approved();
+            let before = if check {
+                service_indicator_before_call()
             } else {
-                crate::fips::indicator::set_approved();
-                result
+                0
+            };
+            let result = $function;
+            if check {
+                update_service_status(before != service_indicator_after_call());
             }
+            result
         }
-        #[cfg(any(not(feature = "fips"), not(debug_assertions)))]
+        #[cfg(not(feature = "fips"))]
         {
             $function
         }
@@ -170,6 +333,29 @@ pub(crate) use assert_fips_status_indicator;
 
 #[cfg(test)]
 mod tests {
+    // Changing the policy in a FIPS build would affect the indicator tests running in parallel.
+    #[cfg(not(feature = "fips"))]
+    #[test]
+    fn test_policy() {
+        use crate::fips::{get_policy, set_policy, FipsPolicy};
+
+        assert_eq!(FipsPolicy::AllowNonApproved, FipsPolicy::default());
+        assert_eq!(FipsPolicy::AllowNonApproved, get_policy());
+        set_policy(FipsPolicy::PanicOnNonApproved);
+        assert_eq!(FipsPolicy::PanicOnNonApproved, get_policy());
+        set_policy(FipsPolicy::AllowNonApproved);
+        assert_eq!(FipsPolicy::AllowNonApproved, get_policy());
+    }
+
+    #[cfg(feature = "fips")]
+    #[test]
+    fn test_self_tests() {
+        use crate::fips::{run_self_tests, self_test_status, FipsSelfTestStatus};
+
+        assert_eq!(Ok(()), run_self_tests());
+        assert_eq!(FipsSelfTestStatus::Passed, self_test_status());
+        assert_eq!(Ok(()), run_self_tests());
+    }
 
     #[cfg(all(feature = "fips", debug_assertions))]
     #[test]
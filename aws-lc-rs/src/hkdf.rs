@@ -38,6 +38,7 @@
 //! ```
 
 use crate::aws_lc::{HKDF_expand, HKDF};
+pub use crate::buffer::SecureBuffer;
 use crate::error::Unspecified;
 use crate::fips::indicator_check;
 use crate::{digest, hmac};
@@ -102,6 +103,7 @@ impl fmt::Debug for Salt {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.debug_struct("hkdf::Salt")
             .field("algorithm", &self.algorithm.0)
+            .field("salt_len", &self.salt_len)
             .finish()
     }
 }
@@ -390,6 +392,61 @@ impl From<Okm<'_, Algorithm>> for Prk {
     }
 }
 
+/// The [HKDF-Extract] operation, for protocols such as TLS 1.3 that extract and expand in
+/// separate steps.
+///
+/// When `salt` is `None`, a salt of `HashLen` zero bytes is used, as specified by RFC 5869.
+/// The returned [`Prk`] may be expanded any number of times with different `info` values.
+///
+/// [HKDF-Extract]: https://tools.ietf.org/html/rfc5869#section-2.2
+///
+// # FIPS
+// See [`Salt::new`] and [`Okm::fill`].
+//
+/// # Errors
+/// `error::Unspecified` if `salt` was constructed with an algorithm other than `algorithm`.
+///
+/// # Example
+/// ```
+/// use aws_lc_rs::hkdf;
+///
+/// let salt = hkdf::Salt::new(hkdf::HKDF_SHA256, b"external salt");
+/// let prk = hkdf::extract(hkdf::HKDF_SHA256, Some(&salt), b"input keying material")?;
+///
+/// let mut client_key = [0u8; 16];
+/// let mut server_key = [0u8; 16];
+/// hkdf::expand(&prk, b"client key", &mut client_key)?;
+/// hkdf::expand(&prk, b"server key", &mut server_key)?;
+/// assert_ne!(client_key, server_key);
+/// # Ok::<(), aws_lc_rs::error::Unspecified>(())
+/// ```
+pub fn extract(algorithm: Algorithm, salt: Option<&Salt>, ikm: &[u8]) -> Result<Prk, Unspecified> {
+    match salt {
+        Some(salt) if salt.algorithm != algorithm => Err(Unspecified),
+        Some(salt) => Ok(salt.extract(ikm)),
+        // HMAC pads its key with zeros, so an empty salt is equivalent to `HashLen` zeros.
+        None => Ok(Salt::new(algorithm, &[]).extract(ikm)),
+    }
+}
+
+/// The [HKDF-Expand] operation, filling `out` with output keying material derived from `prk`
+/// and `info`.
+///
+/// [HKDF-Expand]: https://tools.ietf.org/html/rfc5869#section-2.3
+///
+// # FIPS
+// See [`Okm::fill`].
+//
+/// # Errors
+/// `error::Unspecified` if `out.len()` is more than 255 times the digest algorithm's output
+/// length, or the HKDF-Expand operation fails.
+pub fn expand(prk: &Prk, info: &[u8], out: &mut [u8]) -> Result<(), Unspecified> {
+    if out.len() > 255 * prk.algorithm.0.digest_algorithm().output_len {
+        return Err(Unspecified);
+    }
+    prk.mode.fill(prk.algorithm, out, info)
+}
+
 /// An HKDF OKM (Output Keying Material)
 ///
 /// Intentionally not `Clone` or `Copy` as an OKM is generally only safe to
@@ -449,6 +506,23 @@ impl<L: KeyType> Okm<'_, L> {
 
         Ok(())
     }
+
+    /// Returns the output of the HKDF-Expand operation for the given inputs in a
+    /// [`SecureBuffer`], which is zeroed on drop and never reveals its contents through `Debug`.
+    ///
+    // # FIPS
+    // See `fill`.
+    //
+    /// # Errors
+    /// `error::Unspecified` if the HKDF-Expand operation fails.
+    pub fn into_secure_buffer(self) -> Result<SecureBuffer, Unspecified> {
+        let mut out = vec![0u8; self.len.len()];
+        if let Err(err) = self.fill(&mut out) {
+            out.zeroize();
+            return Err(err);
+        }
+        Ok(SecureBuffer::new(out))
+    }
 }
 
 #[cfg(test)]
@@ -492,9 +566,10 @@ mod tests {
         let okm = prk.expand(INFO1, alg).unwrap();
 
         assert_eq!(
-            "hkdf::Salt { algorithm: Algorithm(SHA256) }",
+            "hkdf::Salt { algorithm: Algorithm(SHA256), salt_len: 32 }",
             format!("{salt:?}")
         );
+        assert!(!format!("{salt:?}").contains("1d71"));
         assert_eq!(
             "hkdf::Prk { algorithm: Algorithm(SHA256), mode: ExtractExpand { .. } }",
             format!("{prk:?}")
@@ -104,6 +104,7 @@ use crate::fips::indicator_check;
 use crate::{constant_time, digest, hkdf};
 use core::mem::MaybeUninit;
 use core::ptr::null_mut;
+use zeroize::Zeroize;
 // TODO: Uncomment when MSRV >= 1.64
 // use core::ffi::c_uint;
 use std::os::raw::c_uint;
@@ -165,6 +166,13 @@ impl AsRef<[u8]> for Tag {
     }
 }
 
+impl Tag {
+    // Clears a tag that is used as key material, e.g. a pseudorandom key.
+    pub(crate) fn zeroize(&mut self) {
+        self.msg.zeroize();
+    }
+}
+
 struct LcHmacCtx(HMAC_CTX);
 
 impl LcHmacCtx {
@@ -213,7 +221,18 @@ impl Clone for LcHmacCtx {
 #[derive(Clone)]
 pub struct Key {
     pub(crate) algorithm: Algorithm,
+    key_len: usize,
     ctx: LcHmacCtx,
+    // Only keys created by `generate` retain their value, as the caller has no other copy.
+    #[cfg(not(feature = "fips"))]
+    key_value: Option<Box<[u8]>>,
+}
+
+#[cfg(not(feature = "fips"))]
+impl Drop for Key {
+    fn drop(&mut self) {
+        self.key_value.zeroize();
+    }
 }
 
 unsafe impl Send for Key {}
@@ -225,6 +244,7 @@ impl core::fmt::Debug for Key {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
         f.debug_struct("Key")
             .field("algorithm", &self.algorithm.digest_algorithm())
+            .field("key_len", &self.key_len)
             .finish()
     }
 }
@@ -253,7 +273,18 @@ impl Key {
         algorithm: Algorithm,
         rng: &dyn crate::rand::SecureRandom,
     ) -> Result<Self, Unspecified> {
-        Self::construct(algorithm, |buf| rng.fill(buf))
+        let mut key_bytes = [0; digest::MAX_OUTPUT_LEN];
+        let key_bytes = &mut key_bytes[..algorithm.0.output_len];
+        let result = rng
+            .fill(key_bytes)
+            .and_then(|()| Self::try_new(algorithm, key_bytes));
+        #[cfg(not(feature = "fips"))]
+        let result = result.map(|mut key| {
+            key.key_value = Some(Box::from(&*key_bytes));
+            key
+        });
+        key_bytes.zeroize();
+        result
     }
 
     fn construct<F>(algorithm: Algorithm, fill: F) -> Result<Self, Unspecified>
@@ -261,9 +292,10 @@ impl Key {
         F: FnOnce(&mut [u8]) -> Result<(), Unspecified>,
     {
         let mut key_bytes = [0; digest::MAX_OUTPUT_LEN];
-        let key_bytes = &mut key_bytes[..algorithm.0.output_len];
-        fill(key_bytes)?;
-        Ok(Self::new(algorithm, key_bytes))
+        let result = fill(&mut key_bytes[..algorithm.0.output_len])
+            .and_then(|()| Self::try_new(algorithm, &key_bytes[..algorithm.0.output_len]));
+        key_bytes.zeroize();
+        result
     }
 
     /// Construct an HMAC signing key using the given digest algorithm and key
@@ -309,7 +341,10 @@ impl Key {
             }
             let result = Self {
                 algorithm,
+                key_len: key_value.len(),
                 ctx: LcHmacCtx(ctx.assume_init()),
+                #[cfg(not(feature = "fips"))]
+                key_value: None,
             };
             Ok(result)
         }
@@ -325,6 +360,23 @@ impl Key {
     pub fn algorithm(&self) -> Algorithm {
         Algorithm(self.algorithm.digest_algorithm())
     }
+
+    /// The value of a key created by [`Key::generate`], e.g. for storing it.
+    ///
+    /// Returns `None` for keys created in any other way, whose value is not retained, and when
+    /// the "fips" feature is enabled, as key material is not exported from the FIPS module.
+    #[inline]
+    #[must_use]
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        #[cfg(not(feature = "fips"))]
+        {
+            self.key_value.as_deref()
+        }
+        #[cfg(feature = "fips")]
+        {
+            None
+        }
+    }
 }
 
 impl hkdf::KeyType for Algorithm {
@@ -107,6 +107,42 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! # Key-based Key Derivation Function (KBKDF) in Feedback Mode
+//!
+//! [`kbkdf_fb_hmac`] provides an implementation of KDF in Feedback Mode using HMAC PRF specified in
+//! [NIST SP 800-108r1-upd1](https://doi.org/10.6028/NIST.SP.800-108r1-upd1) section 4.2, where each
+//! block of output is chained into the input of the next. It is not available when the `fips`
+//! feature is enabled.
+//!
+//! ```rust
+//! use aws_lc_rs::kdf::{get_kbkdf_fb_hmac_algorithm, kbkdf_fb_hmac, KbkdfFbHmacAlgorithmId};
+//!
+//! if let Some(algorithm) = get_kbkdf_fb_hmac_algorithm(KbkdfFbHmacAlgorithmId::Sha256) {
+//!     let mut output_key = [0u8; 48];
+//!     kbkdf_fb_hmac(algorithm, &[0x42; 32], Some(&[0x24; 32]), b"info", &mut output_key)?;
+//! }
+//! # Ok::<(), aws_lc_rs::error::Unspecified>(())
+//! ```
+//!
+//! # Key-based Key Derivation Function (KBKDF) in Double-Pipeline Mode
+//!
+//! [`kbkdf_double_pipeline_hmac`] provides an implementation of KDF in Double-Pipeline Mode using
+//! HMAC PRF specified in [NIST SP 800-108r1-upd1](https://doi.org/10.6028/NIST.SP.800-108r1-upd1)
+//! section 4.3, where a first pipeline of chained PRF outputs feeds each block of the second. It
+//! is not available when the `fips` feature is enabled.
+//!
+//! ```rust
+//! use aws_lc_rs::kdf::{
+//!     get_kbkdf_dp_hmac_algorithm, kbkdf_double_pipeline_hmac, KbkdfDpHmacAlgorithmId,
+//! };
+//!
+//! if let Some(algorithm) = get_kbkdf_dp_hmac_algorithm(KbkdfDpHmacAlgorithmId::Sha256) {
+//!     let mut output_key = [0u8; 48];
+//!     kbkdf_double_pipeline_hmac(algorithm, &[0x42; 32], b"info", &mut output_key)?;
+//! }
+//! # Ok::<(), aws_lc_rs::error::Unspecified>(())
+//! ```
 //! # Single-step Key Derivation Function (SSKDF)
 //!
 //! [`sskdf_digest`] and [`sskdf_hmac`] provided implementations of a one-step key derivation function defined in
@@ -210,14 +246,56 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! # Two-step Key Derivation (Extraction-then-Expansion)
+//!
+//! The [`sp800_56c`] module provides the two-step key derivation defined in section 5 of
+//! [NIST SP 800-56Cr2](https://doi.org/10.6028/NIST.SP.800-56Cr2): HMAC-based randomness
+//! extraction followed by key expansion with [`kbkdf_ctr_hmac`].
+//!
+//! # scrypt Password-Based Key Derivation Function
+//!
+//! [`scrypt`] provides the memory-hard password-based key derivation function specified in
+//! [RFC 7914](https://www.rfc-editor.org/rfc/rfc7914). [`SCRYPT_PARAMS_INTERACTIVE`] and
+//! [`SCRYPT_PARAMS_SENSITIVE`] provide recommended cost parameters. scrypt is not FIPS approved
+//! and is not available when the `fips` feature is enabled.
+//!
+//! ```rust
+//! # #[cfg(not(feature = "fips"))]
+//! # {
+//! use aws_lc_rs::kdf::SCRYPT_PARAMS_INTERACTIVE;
+//!
+//! let mut output_key = [0u8; 32];
+//! SCRYPT_PARAMS_INTERACTIVE.derive(b"password", b"a unique salt", &mut output_key)?;
+//! # }
+//! # Ok::<(), aws_lc_rs::error::Unspecified>(())
+//! ```
 
 mod kbkdf;
+#[cfg(not(feature = "fips"))]
+mod scrypt;
+pub mod sp800_56c;
 mod sskdf;
 
 pub use kbkdf::{
-    get_kbkdf_ctr_hmac_algorithm, kbkdf_ctr_hmac, KbkdfCtrHmacAlgorithm, KbkdfCtrHmacAlgorithmId,
+    get_kbkdf_ctr_hmac_algorithm, get_kbkdf_dp_hmac_algorithm, get_kbkdf_fb_hmac_algorithm,
+    kbkdf_ctr_hmac, kbkdf_double_pipeline_hmac, kbkdf_fb_hmac, KbkdfCtrHmacAlgorithm,
+    KbkdfCtrHmacAlgorithmId, KbkdfDpHmacAlgorithm, KbkdfDpHmacAlgorithmId, KbkdfFbHmacAlgorithm,
+    KbkdfFbHmacAlgorithmId,
 };
 
+#[cfg(not(feature = "fips"))]
+pub use scrypt::{scrypt, ScryptParams, SCRYPT_PARAMS_INTERACTIVE, SCRYPT_PARAMS_SENSITIVE};
+
+/// scrypt is not FIPS-approved, so it is not available in FIPS builds:
+///
+/// ```compile_fail
+/// let mut output = [0u8; 32];
+/// aws_lc_rs::kdf::scrypt(b"password", b"salt", 16, 1, 1, &mut output).unwrap();
+/// ```
+#[cfg(all(doctest, feature = "fips"))]
+struct ScryptUnavailableInFips;
+
 pub use sskdf::{
     get_sskdf_digest_algorithm, get_sskdf_hmac_algorithm, sskdf_digest, sskdf_hmac,
     SskdfDigestAlgorithm, SskdfDigestAlgorithmId, SskdfHmacAlgorithm, SskdfHmacAlgorithmId,
@@ -365,8 +443,10 @@ mod tests {
 #[cfg(test)]
 mod more_tests {
     use crate::kdf::{
-        get_kbkdf_ctr_hmac_algorithm, get_sskdf_digest_algorithm, get_sskdf_hmac_algorithm,
-        KbkdfCtrHmacAlgorithmId, SskdfDigestAlgorithmId, SskdfHmacAlgorithmId,
+        get_kbkdf_ctr_hmac_algorithm, get_kbkdf_dp_hmac_algorithm, get_kbkdf_fb_hmac_algorithm,
+        get_sskdf_digest_algorithm, get_sskdf_hmac_algorithm, KbkdfCtrHmacAlgorithmId,
+        KbkdfDpHmacAlgorithmId, KbkdfFbHmacAlgorithmId, SskdfDigestAlgorithmId,
+        SskdfHmacAlgorithmId,
     };
 
     macro_rules! assert_get_algorithm {
@@ -420,6 +500,12 @@ mod more_tests {
         SskdfDigestAlgorithmId::Sha512
     );
 
+    #[cfg(not(feature = "fips"))]
+    assert_get_algorithm!(
+        get_kbkdf_ctr_hmac_algorithm_sha1,
+        get_kbkdf_ctr_hmac_algorithm,
+        KbkdfCtrHmacAlgorithmId::Sha1
+    );
     assert_get_algorithm!(
         get_kbkdf_ctr_hmac_algorithm_sha224,
         get_kbkdf_ctr_hmac_algorithm,
@@ -440,4 +526,60 @@ mod more_tests {
         get_kbkdf_ctr_hmac_algorithm,
         KbkdfCtrHmacAlgorithmId::Sha512
     );
+
+    #[cfg(not(feature = "fips"))]
+    assert_get_algorithm!(
+        get_kbkdf_fb_hmac_algorithm_sha256,
+        get_kbkdf_fb_hmac_algorithm,
+        KbkdfFbHmacAlgorithmId::Sha256
+    );
+    #[cfg(not(feature = "fips"))]
+    assert_get_algorithm!(
+        get_kbkdf_fb_hmac_algorithm_sha384,
+        get_kbkdf_fb_hmac_algorithm,
+        KbkdfFbHmacAlgorithmId::Sha384
+    );
+    #[cfg(not(feature = "fips"))]
+    assert_get_algorithm!(
+        get_kbkdf_fb_hmac_algorithm_sha512,
+        get_kbkdf_fb_hmac_algorithm,
+        KbkdfFbHmacAlgorithmId::Sha512
+    );
+
+    #[cfg(feature = "fips")]
+    #[test]
+    fn get_kbkdf_fb_hmac_algorithm_fips() {
+        assert!(get_kbkdf_fb_hmac_algorithm(KbkdfFbHmacAlgorithmId::Sha256).is_none());
+    }
+
+    #[cfg(not(feature = "fips"))]
+    assert_get_algorithm!(
+        get_kbkdf_dp_hmac_algorithm_sha224,
+        get_kbkdf_dp_hmac_algorithm,
+        KbkdfDpHmacAlgorithmId::Sha224
+    );
+    #[cfg(not(feature = "fips"))]
+    assert_get_algorithm!(
+        get_kbkdf_dp_hmac_algorithm_sha256,
+        get_kbkdf_dp_hmac_algorithm,
+        KbkdfDpHmacAlgorithmId::Sha256
+    );
+    #[cfg(not(feature = "fips"))]
+    assert_get_algorithm!(
+        get_kbkdf_dp_hmac_algorithm_sha384,
+        get_kbkdf_dp_hmac_algorithm,
+        KbkdfDpHmacAlgorithmId::Sha384
+    );
+    #[cfg(not(feature = "fips"))]
+    assert_get_algorithm!(
+        get_kbkdf_dp_hmac_algorithm_sha512,
+        get_kbkdf_dp_hmac_algorithm,
+        KbkdfDpHmacAlgorithmId::Sha512
+    );
+
+    #[cfg(feature = "fips")]
+    #[test]
+    fn get_kbkdf_dp_hmac_algorithm_fips() {
+        assert!(get_kbkdf_dp_hmac_algorithm(KbkdfDpHmacAlgorithmId::Sha256).is_none());
+    }
 }
@@ -5,9 +5,17 @@
 
 use crate::aws_lc::{KBKDF_ctr_hmac, EVP_MD};
 
-use crate::digest::{match_digest_type, AlgorithmID};
+use crate::digest::{self, match_digest_type, AlgorithmID};
 use crate::error::Unspecified;
+use crate::hmac;
 use crate::ptr::ConstPointer;
+use zeroize::Zeroize;
+
+/// KBKDF in Counter Mode with HMAC-SHA1
+#[cfg(not(feature = "fips"))]
+const KBKDF_CTR_HMAC_SHA1: KbkdfCtrHmacAlgorithm = KbkdfCtrHmacAlgorithm {
+    id: KbkdfCtrHmacAlgorithmId::Sha1,
+};
 
 /// KBKDF in Counter Mode with HMAC-SHA224
 #[allow(dead_code)]
@@ -40,6 +48,8 @@ pub const fn get_kbkdf_ctr_hmac_algorithm(
 ) -> Option<&'static KbkdfCtrHmacAlgorithm> {
     {
         Some(match id {
+            #[cfg(not(feature = "fips"))]
+            KbkdfCtrHmacAlgorithmId::Sha1 => &KBKDF_CTR_HMAC_SHA1,
             KbkdfCtrHmacAlgorithmId::Sha224 => &KBKDF_CTR_HMAC_SHA224,
             KbkdfCtrHmacAlgorithmId::Sha256 => &KBKDF_CTR_HMAC_SHA256,
             KbkdfCtrHmacAlgorithmId::Sha384 => &KBKDF_CTR_HMAC_SHA384,
@@ -63,6 +73,8 @@ impl KbkdfCtrHmacAlgorithm {
     #[must_use]
     fn get_evp_md(&self) -> ConstPointer<EVP_MD> {
         match_digest_type(match self.id {
+            #[cfg(not(feature = "fips"))]
+            KbkdfCtrHmacAlgorithmId::Sha1 => &AlgorithmID::SHA1,
             KbkdfCtrHmacAlgorithmId::Sha224 => &AlgorithmID::SHA224,
             KbkdfCtrHmacAlgorithmId::Sha256 => &AlgorithmID::SHA256,
             KbkdfCtrHmacAlgorithmId::Sha384 => &AlgorithmID::SHA384,
@@ -89,6 +101,13 @@ impl core::fmt::Debug for KbkdfCtrHmacAlgorithm {
 #[non_exhaustive]
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum KbkdfCtrHmacAlgorithmId {
+    /// KBKDF in Counter Mode with HMAC-SHA1
+    ///
+    /// Only provided for interoperability with legacy protocols, and not available when the
+    /// `fips` feature is enabled. New applications should use [`Self::Sha256`] instead.
+    #[cfg(not(feature = "fips"))]
+    Sha1,
+
     /// KBKDF in Counter Mode with HMAC-SHA224
     Sha224,
 
@@ -105,7 +124,7 @@ pub enum KbkdfCtrHmacAlgorithmId {
 /// # Key-based Key Derivation Function (KBKDF) in Counter Mode with HMAC PRF
 ///
 /// ## Input Validation and Defaults
-/// * `output.len() > 0 and `secret.len() > 0`
+/// * `output.len() > 0` and `secret.len() > 0`
 /// * `output.len() <= usize::MAX - DIGEST_LENGTH`
 /// * The requested `output.len()` would result in overflowing the counter.
 ///
@@ -144,3 +163,325 @@ pub fn kbkdf_ctr_hmac(
     }
     Ok(())
 }
+
+/// KBKDF in Feedback Mode with HMAC-SHA256
+#[cfg(not(feature = "fips"))]
+const KBKDF_FB_HMAC_SHA256: KbkdfFbHmacAlgorithm = KbkdfFbHmacAlgorithm {
+    id: KbkdfFbHmacAlgorithmId::Sha256,
+};
+
+/// KBKDF in Feedback Mode with HMAC-SHA384
+#[cfg(not(feature = "fips"))]
+const KBKDF_FB_HMAC_SHA384: KbkdfFbHmacAlgorithm = KbkdfFbHmacAlgorithm {
+    id: KbkdfFbHmacAlgorithmId::Sha384,
+};
+
+/// KBKDF in Feedback Mode with HMAC-SHA512
+#[cfg(not(feature = "fips"))]
+const KBKDF_FB_HMAC_SHA512: KbkdfFbHmacAlgorithm = KbkdfFbHmacAlgorithm {
+    id: KbkdfFbHmacAlgorithmId::Sha512,
+};
+
+/// Retrieve [`KbkdfFbHmacAlgorithm`] using the [`KbkdfFbHmacAlgorithmId`] specified by `id`.
+///
+/// Returns `None` when the `fips` feature is enabled, since feedback mode is not provided by the
+/// FIPS module.
+#[must_use]
+pub const fn get_kbkdf_fb_hmac_algorithm(
+    id: KbkdfFbHmacAlgorithmId,
+) -> Option<&'static KbkdfFbHmacAlgorithm> {
+    #[cfg(feature = "fips")]
+    {
+        let _ = id;
+        None
+    }
+    #[cfg(not(feature = "fips"))]
+    {
+        Some(match id {
+            KbkdfFbHmacAlgorithmId::Sha256 => &KBKDF_FB_HMAC_SHA256,
+            KbkdfFbHmacAlgorithmId::Sha384 => &KBKDF_FB_HMAC_SHA384,
+            KbkdfFbHmacAlgorithmId::Sha512 => &KBKDF_FB_HMAC_SHA512,
+        })
+    }
+}
+
+/// KBKDF in Feedback Mode with HMAC Algorithm
+pub struct KbkdfFbHmacAlgorithm {
+    id: KbkdfFbHmacAlgorithmId,
+}
+
+impl KbkdfFbHmacAlgorithm {
+    /// Returns the KBKDF Feedback HMAC Algorithm Identifier
+    #[must_use]
+    pub fn id(&self) -> KbkdfFbHmacAlgorithmId {
+        self.id
+    }
+
+    #[must_use]
+    fn hmac_algorithm(&self) -> hmac::Algorithm {
+        match self.id {
+            KbkdfFbHmacAlgorithmId::Sha256 => hmac::HMAC_SHA256,
+            KbkdfFbHmacAlgorithmId::Sha384 => hmac::HMAC_SHA384,
+            KbkdfFbHmacAlgorithmId::Sha512 => hmac::HMAC_SHA512,
+        }
+    }
+}
+
+impl PartialEq for KbkdfFbHmacAlgorithm {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for KbkdfFbHmacAlgorithm {}
+
+impl core::fmt::Debug for KbkdfFbHmacAlgorithm {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&self.id, f)
+    }
+}
+
+/// Key-based Derivation Function in Feedback Mode Algorithm Identifier
+#[non_exhaustive]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum KbkdfFbHmacAlgorithmId {
+    /// KBKDF in Feedback Mode with HMAC-SHA256
+    Sha256,
+
+    /// KBKDF in Feedback Mode with HMAC-SHA384
+    Sha384,
+
+    /// KBKDF in Feedback Mode with HMAC-SHA512
+    Sha512,
+}
+
+/// # Key-based Key Derivation Function (KBKDF) in Feedback Mode with HMAC PRF
+///
+/// ## Input Validation and Defaults
+/// * `output.len() > 0` and `secret.len() > 0`
+/// * The requested `output.len()` would result in overflowing the counter.
+///
+/// ## Implementation Notes
+///
+/// This implementation adheres to the algorithm specified in Section 4.2 of the
+/// NIST Special Publication 800-108 Revision 1 Update 1 published on August
+/// 2022. Using HMAC as the PRF function. In this implementation:
+/// * The first block is computed from `iv`, or from an empty string if `iv` is `None`
+/// * The counter is 32-bits and is represented in big-endian format
+/// * The counter is placed after the previous block and before the fixed info string
+///
+/// When `iv` is `None` or empty, the first block of output is therefore the same as that of
+/// [`kbkdf_ctr_hmac`] with the same digest algorithm. A non-empty `iv` is always hashed into the
+/// first block, so an all-zero `iv` does not produce counter-mode output.
+///
+/// Unlike counter mode, feedback mode is not provided by AWS-LC; it is built on [`hmac`] and is
+/// not available when the `fips` feature is enabled.
+///
+/// Specification available at <https://doi.org/10.6028/NIST.SP.800-108r1-upd1>
+///
+/// # Errors
+/// `Unspecified` is returned if input validation fails or an unexpected error occurs.
+pub fn kbkdf_fb_hmac(
+    algorithm: &'static KbkdfFbHmacAlgorithm,
+    secret: &[u8],
+    iv: Option<&[u8]>,
+    info: &[u8],
+    output: &mut [u8],
+) -> Result<(), Unspecified> {
+    if output.is_empty() || secret.is_empty() {
+        return Err(Unspecified);
+    }
+    let hmac_algorithm = algorithm.hmac_algorithm();
+    let block_len = hmac_algorithm.digest_algorithm().output_len;
+    let key = hmac::Key::new(hmac_algorithm, secret);
+
+    let mut previous = [0u8; digest::MAX_OUTPUT_LEN];
+    let result = output
+        .chunks_mut(block_len)
+        .enumerate()
+        .try_for_each(|(i, chunk)| {
+            let counter = u32::try_from(i + 1).map_err(|_| Unspecified)?;
+            let mut ctx = hmac::Context::with_key(&key);
+            if i == 0 {
+                ctx.update(iv.unwrap_or(&[]));
+            } else {
+                ctx.update(&previous[..block_len]);
+            }
+            ctx.update(&counter.to_be_bytes());
+            ctx.update(info);
+            let block = ctx.sign();
+            previous[..block_len].copy_from_slice(block.as_ref());
+            chunk.copy_from_slice(&previous[..chunk.len()]);
+            Ok(())
+        });
+    previous.zeroize();
+    if result.is_err() {
+        output.zeroize();
+    }
+    result
+}
+
+/// KBKDF in Double-Pipeline Mode with HMAC-SHA224
+#[cfg(not(feature = "fips"))]
+const KBKDF_DP_HMAC_SHA224: KbkdfDpHmacAlgorithm = KbkdfDpHmacAlgorithm {
+    id: KbkdfDpHmacAlgorithmId::Sha224,
+};
+
+/// KBKDF in Double-Pipeline Mode with HMAC-SHA256
+#[cfg(not(feature = "fips"))]
+const KBKDF_DP_HMAC_SHA256: KbkdfDpHmacAlgorithm = KbkdfDpHmacAlgorithm {
+    id: KbkdfDpHmacAlgorithmId::Sha256,
+};
+
+/// KBKDF in Double-Pipeline Mode with HMAC-SHA384
+#[cfg(not(feature = "fips"))]
+const KBKDF_DP_HMAC_SHA384: KbkdfDpHmacAlgorithm = KbkdfDpHmacAlgorithm {
+    id: KbkdfDpHmacAlgorithmId::Sha384,
+};
+
+/// KBKDF in Double-Pipeline Mode with HMAC-SHA512
+#[cfg(not(feature = "fips"))]
+const KBKDF_DP_HMAC_SHA512: KbkdfDpHmacAlgorithm = KbkdfDpHmacAlgorithm {
+    id: KbkdfDpHmacAlgorithmId::Sha512,
+};
+
+/// Retrieve [`KbkdfDpHmacAlgorithm`] using the [`KbkdfDpHmacAlgorithmId`] specified by `id`.
+///
+/// Returns `None` when the `fips` feature is enabled, since double-pipeline mode is not provided
+/// by the FIPS module.
+#[must_use]
+pub const fn get_kbkdf_dp_hmac_algorithm(
+    id: KbkdfDpHmacAlgorithmId,
+) -> Option<&'static KbkdfDpHmacAlgorithm> {
+    #[cfg(feature = "fips")]
+    {
+        let _ = id;
+        None
+    }
+    #[cfg(not(feature = "fips"))]
+    {
+        Some(match id {
+            KbkdfDpHmacAlgorithmId::Sha224 => &KBKDF_DP_HMAC_SHA224,
+            KbkdfDpHmacAlgorithmId::Sha256 => &KBKDF_DP_HMAC_SHA256,
+            KbkdfDpHmacAlgorithmId::Sha384 => &KBKDF_DP_HMAC_SHA384,
+            KbkdfDpHmacAlgorithmId::Sha512 => &KBKDF_DP_HMAC_SHA512,
+        })
+    }
+}
+
+/// KBKDF in Double-Pipeline Mode with HMAC Algorithm
+pub struct KbkdfDpHmacAlgorithm {
+    id: KbkdfDpHmacAlgorithmId,
+}
+
+impl KbkdfDpHmacAlgorithm {
+    /// Returns the KBKDF Double-Pipeline HMAC Algorithm Identifier
+    #[must_use]
+    pub fn id(&self) -> KbkdfDpHmacAlgorithmId {
+        self.id
+    }
+
+    #[must_use]
+    fn hmac_algorithm(&self) -> hmac::Algorithm {
+        match self.id {
+            KbkdfDpHmacAlgorithmId::Sha224 => hmac::HMAC_SHA224,
+            KbkdfDpHmacAlgorithmId::Sha256 => hmac::HMAC_SHA256,
+            KbkdfDpHmacAlgorithmId::Sha384 => hmac::HMAC_SHA384,
+            KbkdfDpHmacAlgorithmId::Sha512 => hmac::HMAC_SHA512,
+        }
+    }
+}
+
+impl PartialEq for KbkdfDpHmacAlgorithm {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for KbkdfDpHmacAlgorithm {}
+
+impl core::fmt::Debug for KbkdfDpHmacAlgorithm {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&self.id, f)
+    }
+}
+
+/// Key-based Derivation Function in Double-Pipeline Mode Algorithm Identifier
+#[non_exhaustive]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum KbkdfDpHmacAlgorithmId {
+    /// KBKDF in Double-Pipeline Mode with HMAC-SHA224
+    Sha224,
+
+    /// KBKDF in Double-Pipeline Mode with HMAC-SHA256
+    Sha256,
+
+    /// KBKDF in Double-Pipeline Mode with HMAC-SHA384
+    Sha384,
+
+    /// KBKDF in Double-Pipeline Mode with HMAC-SHA512
+    Sha512,
+}
+
+/// # Key-based Key Derivation Function (KBKDF) in Double-Pipeline Mode with HMAC PRF
+///
+/// ## Input Validation and Defaults
+/// * `output.len() > 0` and `secret.len() > 0`
+/// * The requested `output.len()` would result in overflowing the counter.
+///
+/// ## Implementation Notes
+///
+/// This implementation adheres to the algorithm specified in Section 4.3 of the
+/// NIST Special Publication 800-108 Revision 1 Update 1 published on August
+/// 2022. Using HMAC as the PRF function. In this implementation:
+/// * The first pipeline computes `A(i) = PRF(secret, A(i-1))`, with `A(0) = info`
+/// * The second pipeline computes each block as `PRF(secret, A(i) || counter || info)`
+/// * The counter is 32-bits and is represented in big-endian format
+///
+/// Like feedback mode, double-pipeline mode is not provided by AWS-LC; it is built on [`hmac`]
+/// and is not available when the `fips` feature is enabled.
+///
+/// Specification available at <https://doi.org/10.6028/NIST.SP.800-108r1-upd1>
+///
+/// # Errors
+/// `Unspecified` is returned if input validation fails or an unexpected error occurs.
+pub fn kbkdf_double_pipeline_hmac(
+    algorithm: &'static KbkdfDpHmacAlgorithm,
+    secret: &[u8],
+    info: &[u8],
+    output: &mut [u8],
+) -> Result<(), Unspecified> {
+    if output.is_empty() || secret.is_empty() {
+        return Err(Unspecified);
+    }
+    let hmac_algorithm = algorithm.hmac_algorithm();
+    let block_len = hmac_algorithm.digest_algorithm().output_len;
+    let key = hmac::Key::new(hmac_algorithm, secret);
+
+    let mut a = [0u8; digest::MAX_OUTPUT_LEN];
+    let result = output
+        .chunks_mut(block_len)
+        .enumerate()
+        .try_for_each(|(i, chunk)| {
+            let counter = u32::try_from(i + 1).map_err(|_| Unspecified)?;
+            let next_a = if i == 0 {
+                hmac::sign(&key, info)
+            } else {
+                hmac::sign(&key, &a[..block_len])
+            };
+            a[..block_len].copy_from_slice(next_a.as_ref());
+
+            let mut ctx = hmac::Context::with_key(&key);
+            ctx.update(&a[..block_len]);
+            ctx.update(&counter.to_be_bytes());
+            ctx.update(info);
+            let block = ctx.sign();
+            chunk.copy_from_slice(&block.as_ref()[..chunk.len()]);
+            Ok(())
+        });
+    a.zeroize();
+    if result.is_err() {
+        output.zeroize();
+    }
+    result
+}
@@ -0,0 +1,197 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+#![allow(clippy::module_name_repetitions)]
+
+use crate::aws_lc::EVP_PBE_scrypt;
+use crate::error::Unspecified;
+
+/// The size in bytes of a single scrypt block (`BlockMix` operates on `2 * r` of these).
+const SCRYPT_BLOCK_LEN: u64 = 64;
+
+/// The maximum output length, `(2^32 - 1) * 32`, as permitted by RFC 7914.
+const MAX_OUTPUT_LEN: u64 = ((1 << 32) - 1) * 32;
+
+/// scrypt parameters recommended for interactive logins: `n = 2^15, r = 8, p = 1`.
+///
+/// Deriving a key with these parameters requires approximately 32 MiB of memory.
+pub const SCRYPT_PARAMS_INTERACTIVE: ScryptParams = ScryptParams {
+    n: 1 << 15,
+    r: 8,
+    p: 1,
+};
+
+/// scrypt parameters recommended for sensitive data at rest: `n = 2^20, r = 8, p = 1`.
+///
+/// Deriving a key with these parameters requires approximately 1 GiB of memory.
+pub const SCRYPT_PARAMS_SENSITIVE: ScryptParams = ScryptParams {
+    n: 1 << 20,
+    r: 8,
+    p: 1,
+};
+
+/// The cost parameters for scrypt.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScryptParams {
+    n: u64,
+    r: u64,
+    p: u64,
+}
+
+impl ScryptParams {
+    /// Constructs scrypt parameters with CPU/memory cost `n`, block size `r` and
+    /// parallelization `p`.
+    ///
+    /// The parameters are validated when a key is derived.
+    #[must_use]
+    pub const fn new(n: u64, r: u64, p: u64) -> Self {
+        Self { n, r, p }
+    }
+
+    /// The CPU/memory cost parameter.
+    #[must_use]
+    pub const fn n(&self) -> u64 {
+        self.n
+    }
+
+    /// The block size parameter.
+    #[must_use]
+    pub const fn r(&self) -> u64 {
+        self.r
+    }
+
+    /// The parallelization parameter.
+    #[must_use]
+    pub const fn p(&self) -> u64 {
+        self.p
+    }
+
+    /// Derives `output.len()` bytes from `password` and `salt` using these parameters.
+    ///
+    /// # Errors
+    /// `Unspecified` under the same conditions as [`scrypt`].
+    pub fn derive(
+        &self,
+        password: &[u8],
+        salt: &[u8],
+        output: &mut [u8],
+    ) -> Result<(), Unspecified> {
+        scrypt(password, salt, self.n, self.r, self.p, output)
+    }
+}
+
+/// # scrypt Password-Based Key Derivation Function
+///
+/// Derives `output.len()` bytes from `password` and `salt`, using CPU/memory cost `n`, block size
+/// `r` and parallelization `p`.
+///
+/// ## Input Validation
+/// * `n` must be a power of two greater than 1, no larger than 2^32, and less than `2^(16 * r)`.
+/// * `r > 0`, `p > 0` and `p * r <= 2^30 - 1`.
+/// * `0 < output.len() <= (2^32 - 1) * 32`.
+///
+/// The memory required, approximately `128 * r * (n + p + 1)` bytes, is allocated for the duration
+/// of the call.
+///
+/// ## Implementation Notes
+///
+/// This implementation adheres to the algorithm specified in
+/// [RFC 7914](https://www.rfc-editor.org/rfc/rfc7914).
+///
+/// # Errors
+/// `Unspecified` is returned if input validation fails or the required memory cannot be allocated.
+pub fn scrypt(
+    password: &[u8],
+    salt: &[u8],
+    n: u64,
+    r: u64,
+    p: u64,
+    output: &mut [u8],
+) -> Result<(), Unspecified> {
+    check_output_len(output.len())?;
+    if n < 2 || !n.is_power_of_two() {
+        return Err(Unspecified);
+    }
+    let max_mem = max_mem(n, r, p)?;
+
+    if 1 != unsafe {
+        EVP_PBE_scrypt(
+            password.as_ptr().cast(),
+            password.len(),
+            salt.as_ptr(),
+            salt.len(),
+            n,
+            r,
+            p,
+            max_mem,
+            output.as_mut_ptr(),
+            output.len(),
+        )
+    } {
+        return Err(Unspecified);
+    }
+    Ok(())
+}
+
+fn check_output_len(len: usize) -> Result<(), Unspecified> {
+    if len == 0 || len as u64 > MAX_OUTPUT_LEN {
+        return Err(Unspecified);
+    }
+    Ok(())
+}
+
+// The memory limit passed to AWS-LC, sized to exactly fit the `p + 1 + n` blocks of `2 * r` scrypt
+// blocks that are allocated.
+fn max_mem(n: u64, r: u64, p: u64) -> Result<usize, Unspecified> {
+    let blocks = n
+        .checked_add(p)
+        .and_then(|b| b.checked_add(1))
+        .ok_or(Unspecified)?;
+    let bytes = r
+        .checked_mul(2 * SCRYPT_BLOCK_LEN)
+        .and_then(|b| b.checked_mul(blocks))
+        .ok_or(Unspecified)?;
+    usize::try_from(bytes).map_err(|_| Unspecified)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::kdf::scrypt::{check_output_len, max_mem, scrypt, MAX_OUTPUT_LEN};
+
+    #[test]
+    fn output_len() {
+        assert!(check_output_len(0).is_err());
+        assert!(check_output_len(1).is_ok());
+        if let Ok(max) = usize::try_from(MAX_OUTPUT_LEN) {
+            assert!(check_output_len(max).is_ok());
+            assert!(check_output_len(max + 1).is_err());
+        }
+    }
+
+    #[test]
+    fn memory_overflow() {
+        assert!(max_mem(u64::MAX, 8, 1).is_err());
+        assert!(max_mem(1 << 20, u64::MAX, 1).is_err());
+        assert_eq!(128 * 8 * ((1 << 15) + 2), max_mem(1 << 15, 8, 1).unwrap());
+    }
+
+    #[test]
+    fn invalid_params() {
+        let mut output = [0u8; 32];
+        for (n, r, p) in [
+            (0, 8, 1),
+            (1, 8, 1),
+            (3, 8, 1),
+            (1000, 8, 1),
+            (1 << 33, 8, 1),
+            (16, 0, 1),
+            (16, 8, 0),
+            (16, 1 << 30, 1 << 30),
+            // n must be less than 2^(16 * r)
+            (1 << 16, 1, 1),
+        ] {
+            assert!(scrypt(b"password", b"salt", n, r, p, &mut output).is_err());
+        }
+        assert!(scrypt(b"password", b"salt", 16, 1, 1, &mut []).is_err());
+    }
+}
@@ -0,0 +1,114 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+//! Two-step key derivation (extraction-then-expansion) as specified in Section 5 of
+//! [NIST SP 800-56Cr2](https://doi.org/10.6028/NIST.SP.800-56Cr2).
+//!
+//! A shared secret established by a key-agreement scheme is not uniformly random. [`extract`]
+//! condenses it into a pseudorandom key using HMAC as the randomness extractor, and [`expand`]
+//! then derives keying material of the requested length from that key using the KBKDF in
+//! Counter Mode of [NIST SP 800-108r1-upd1](https://doi.org/10.6028/NIST.SP.800-108r1-upd1).
+//!
+//! # Example
+//!
+//! ```rust
+//! use aws_lc_rs::hmac;
+//! use aws_lc_rs::kdf::sp800_56c;
+//! use aws_lc_rs::kdf::{get_kbkdf_ctr_hmac_algorithm, KbkdfCtrHmacAlgorithmId};
+//!
+//! let shared_secret = [0x42u8; 32];
+//! let prk = sp800_56c::extract(hmac::HMAC_SHA256, b"salt", &shared_secret);
+//!
+//! let kdf_alg = get_kbkdf_ctr_hmac_algorithm(KbkdfCtrHmacAlgorithmId::Sha256).unwrap();
+//! let key = sp800_56c::expand(&prk, kdf_alg, b"context", 32)?;
+//! assert_eq!(32, key.len());
+//! # Ok::<(), aws_lc_rs::error::Unspecified>(())
+//! ```
+
+use super::{kbkdf_ctr_hmac, KbkdfCtrHmacAlgorithm};
+use crate::digest;
+use crate::error::Unspecified;
+use crate::hmac;
+use zeroize::Zeroize;
+
+/// The pseudorandom key output by [`extract`].
+pub struct Prk {
+    algorithm: hmac::Algorithm,
+    key: [u8; digest::MAX_OUTPUT_LEN],
+    key_len: usize,
+}
+
+impl Prk {
+    /// The HMAC algorithm used to extract the key.
+    #[inline]
+    #[must_use]
+    pub fn algorithm(&self) -> hmac::Algorithm {
+        self.algorithm
+    }
+
+    /// The length of the key in bytes, which is the output length of the HMAC algorithm.
+    #[inline]
+    #[must_use]
+    pub fn key_len(&self) -> usize {
+        self.key_len
+    }
+
+    fn as_bytes(&self) -> &[u8] {
+        &self.key[..self.key_len]
+    }
+}
+
+impl Drop for Prk {
+    fn drop(&mut self) {
+        self.key.zeroize();
+    }
+}
+
+impl core::fmt::Debug for Prk {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("sp800_56c::Prk")
+            .field("algorithm", &self.algorithm.digest_algorithm())
+            .field("key_len", &self.key_len)
+            .finish()
+    }
+}
+
+/// Extracts a pseudorandom key from `shared_secret`, computing `HMAC(salt, shared_secret)` as
+/// specified in Section 5.1 of NIST SP 800-56Cr2.
+///
+/// When no salt has been agreed upon, an empty `salt` may be used. HMAC pads its key with zeros
+/// to the block length of its hash function, so this is equivalent to the default all-zero salt
+/// of the specification.
+#[must_use]
+pub fn extract(algorithm: hmac::Algorithm, salt: &[u8], shared_secret: &[u8]) -> Prk {
+    let mut tag = hmac::sign(&hmac::Key::new(algorithm, salt), shared_secret);
+    let mut key = [0u8; digest::MAX_OUTPUT_LEN];
+    let key_len = tag.as_ref().len();
+    key[..key_len].copy_from_slice(tag.as_ref());
+    tag.zeroize();
+    Prk {
+        algorithm,
+        key,
+        key_len,
+    }
+}
+
+/// Expands `prk` into `output_len` bytes of keying material, using the KBKDF in Counter Mode
+/// with `context` as the fixed info, as specified in Section 5.2 of NIST SP 800-56Cr2.
+///
+/// `context` should encode the label, the context, and the length of the derived keying
+/// material, as agreed by the parties; see the `kdf` module documentation for the construction
+/// recommended by NIST SP 800-108r1-upd1.
+///
+/// # Errors
+/// `Unspecified` if `output_len` is zero, or is too large for the counter of the KBKDF.
+pub fn expand(
+    prk: &Prk,
+    algorithm: &'static KbkdfCtrHmacAlgorithm,
+    context: &[u8],
+    output_len: usize,
+) -> Result<Vec<u8>, Unspecified> {
+    let mut output = vec![0u8; output_len];
+    kbkdf_ctr_hmac(algorithm, prk.as_bytes(), context, &mut output)?;
+    Ok(output)
+}
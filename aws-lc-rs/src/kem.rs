@@ -447,6 +447,12 @@ impl<'a> From<&'a [u8]> for Ciphertext<'a> {
 /// The cryptographic shared secret output from the KEM encapsulate / decapsulate process.
 pub struct SharedSecret(Box<[u8]>);
 
+impl Debug for SharedSecret {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SharedSecret").finish_non_exhaustive()
+    }
+}
+
 impl SharedSecret {
     fn new(value: Box<[u8]>) -> Self {
         Self(value)
@@ -269,7 +269,7 @@ impl KeyWrap for KeyEncryptionKey<AesBlockCipher> {
         ciphertext: &[u8],
         output: &'output mut [u8],
     ) -> Result<&'output mut [u8], Unspecified> {
-        if output.len() < ciphertext.len() - 8 {
+        if output.len() < ciphertext.len().checked_sub(8).ok_or(Unspecified)? {
             return Err(Unspecified);
         }
 
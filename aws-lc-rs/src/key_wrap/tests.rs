@@ -579,6 +579,64 @@ nist_aes_key_unwrap_test!(
     ]
 );
 
+// RFC 3394, Section 4.1
+nist_aes_key_wrap_test!(
+    rfc3394_4_1_aes128_128bit_len,
+    &AES_128,
+    &[
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e,
+        0x0f
+    ],
+    &[
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee,
+        0xff
+    ],
+    &[
+        0x1f, 0xa6, 0x8b, 0x0a, 0x81, 0x12, 0xb4, 0x47, 0xae, 0xf3, 0x4b, 0xd8, 0xfb, 0x5a, 0x7b,
+        0x82, 0x9d, 0x3e, 0x86, 0x23, 0x71, 0xd2, 0xcf, 0xe5
+    ]
+);
+
+// RFC 3394, Section 4.3
+nist_aes_key_wrap_test!(
+    rfc3394_4_3_aes256_128bit_len,
+    &AES_256,
+    &[
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e,
+        0x0f, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d,
+        0x1e, 0x1f
+    ],
+    &[
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee,
+        0xff
+    ],
+    &[
+        0x64, 0xe8, 0xc3, 0xf9, 0xce, 0x0f, 0x5b, 0xa2, 0x63, 0xe9, 0x77, 0x79, 0x05, 0x81, 0x8a,
+        0x2a, 0x93, 0xc8, 0x19, 0x1e, 0x7d, 0x6e, 0x8a, 0xe7
+    ]
+);
+
+// RFC 3394, Section 4.6
+nist_aes_key_wrap_test!(
+    rfc3394_4_6_aes256_256bit_len,
+    &AES_256,
+    &[
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e,
+        0x0f, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d,
+        0x1e, 0x1f
+    ],
+    &[
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee,
+        0xff, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d,
+        0x0e, 0x0f
+    ],
+    &[
+        0x28, 0xc9, 0xf4, 0x04, 0xc4, 0xb8, 0x10, 0xf4, 0xcb, 0xcc, 0xb3, 0x5c, 0xfb, 0x87, 0xf8,
+        0x26, 0x3f, 0x57, 0x86, 0xe2, 0xd8, 0x0e, 0xd3, 0x26, 0xcb, 0xc7, 0xf0, 0xe7, 0x1a, 0x99,
+        0xf4, 0x3b, 0xfb, 0x98, 0x8b, 0x9b, 0x7a, 0x02, 0xdd, 0x21
+    ]
+);
+
 macro_rules! wrap_input_output_invalid_test {
     ($name:ident, $input_len:expr, $output_len:expr) => {
         #[test]
@@ -633,6 +691,12 @@ unwrap_input_output_invalid_test!(unwrap_input_len_not_multiple_of_eight, 31, 31
 // Output length < Input length - 8
 unwrap_input_output_invalid_test!(unwrap_output_len_too_small, 24, 15);
 
+// Input length < 8
+unwrap_input_output_invalid_test!(unwrap_input_len_smaller_than_eight, 7, 7);
+
+// Input length == 0
+unwrap_input_output_invalid_test!(unwrap_input_len_zero, 0, 0);
+
 macro_rules! wrap_with_padding_input_output_invalid_test {
     ($name:ident, $input_len:expr, $output_len:expr) => {
         #[test]
@@ -682,3 +746,6 @@ unwrap_with_padding_input_output_invalid_test!(unwrap_padded_input_len_smaller_t
 
 // Output length < Input length - 8
 unwrap_with_padding_input_output_invalid_test!(unwrap_padded_output_len_too_small, 24, 15);
+
+// Input length == 0
+unwrap_with_padding_input_output_invalid_test!(unwrap_padded_input_len_zero, 0, 0);
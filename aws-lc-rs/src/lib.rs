@@ -44,6 +44,26 @@
 //! Enable feature to preserve compatibility with ring's `signature::VerificationAlgorithm::verify`
 //! function. This adds a requirement on `untrusted = "0.7.1"`.
 //!
+//! #### ring-compat
+//!
+//! Enable feature to access the `ring` module, which re-exports this crate's *ring*-compatible
+//! modules under *ring*'s module names so that code written against *ring* can switch to
+//! aws-lc-rs by changing a single `use` declaration.
+//!
+//! #### log
+//!
+//! Logs a warning through the `log` crate when a release build accepts parameters that debug builds
+//! reject as too weak, such as a `pbkdf2::Params` with fewer than 1,000 iterations.
+//!
+//! #### serde
+//!
+//! Implements `serde::Serialize` for the RSA, ECDSA and Ed25519 public key types and for
+//! `signature::UnparsedPublicKey`, and `serde::Deserialize` for the public key types. Public keys are
+//! serialized in their DER encoding (`RSAPublicKey` for RSA, `SubjectPublicKeyInfo` otherwise), and
+//! an `UnparsedPublicKey` as its bytes: a base64url string in human-readable formats such as JSON,
+//! and raw bytes in binary formats. Also implements `serde::Serialize` and `serde::Deserialize` for
+//! `aead::nonce_sequence::NonceSequenceState`, which is represented by its position.
+//!
 //! #### fips
 //!
 //! Enable this feature to have aws-lc-rs use the [*aws-lc-fips-sys*](https://crates.io/crates/aws-lc-fips-sys)
@@ -72,6 +92,12 @@
 //! for relevant security policies and information on supported operating environments.
 //! We will also update our release notes and documentation to reflect any changes in FIPS certification status.
 //!
+//! #### legacy
+//!
+//! Enables legacy algorithms that are needed for compatibility with existing systems but are
+//! unsuitable for new designs, currently `digest::MD5_FOR_LEGACY_USE_ONLY`. None of these
+//! algorithms are FIPS-approved, so this feature cannot be enabled together with "fips".
+//!
 //! #### asan
 //!
 //! Performs an "address sanitizer" build. This can be used to help detect memory leaks. See the
@@ -155,6 +181,9 @@
 #![warn(clippy::exhaustive_enums)]
 #![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
 
+#[cfg(all(feature = "legacy", feature = "fips"))]
+compile_error!("The \"legacy\" feature provides algorithms that are not FIPS-approved, and cannot be enabled together with \"fips\".");
+
 extern crate alloc;
 #[cfg(feature = "fips")]
 extern crate aws_lc_fips_sys as aws_lc;
@@ -163,7 +192,12 @@ extern crate aws_lc_sys as aws_lc;
 
 pub mod aead;
 pub mod agreement;
+#[cfg(not(feature = "fips"))]
+pub mod bip32;
+pub mod cmac;
 pub mod constant_time;
+pub mod ct;
+pub mod dh;
 pub mod digest;
 pub mod error;
 pub mod hkdf;
@@ -171,9 +205,17 @@ pub mod hmac;
 #[cfg(feature = "ring-io")]
 pub mod io;
 pub mod key_wrap;
+pub mod mac;
 pub mod pbkdf2;
+#[cfg(not(feature = "fips"))]
+pub mod pkcs12;
 pub mod pkcs8;
+pub mod pkix;
 pub mod rand;
+#[cfg(feature = "ring-compat")]
+pub mod ring;
+pub mod secret;
+pub mod secret_sharing;
 pub mod signature;
 pub mod test;
 
@@ -188,7 +230,7 @@ mod ed25519;
 pub mod encoding;
 mod endian;
 mod evp_pkey;
-mod fips;
+pub mod fips;
 mod hex;
 pub mod iv;
 pub mod kdf;
@@ -198,8 +240,10 @@ pub mod kem;
 mod pq;
 mod ptr;
 pub mod rsa;
+pub mod srp;
 pub mod tls_prf;
 pub mod unstable;
+pub mod x509;
 
 pub(crate) use debug::derive_debug_via_id;
 // TODO: Uncomment when MSRV >= 1.64
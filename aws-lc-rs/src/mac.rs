@@ -0,0 +1,38 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+//! Message authentication codes that are not covered by the `hmac` and `cmac` modules.
+//!
+//! # Poly1305
+//!
+//! Poly1305 ([RFC 8439, Section 2.5]) is a **one-time** authenticator: a `Poly1305Key` must
+//! only ever be used to authenticate a single message. An attacker who sees the tags of two
+//! different messages under the same key can forge tags for other messages. Keys should be
+//! derived freshly for each message, for example from the keystream of a stream cipher as
+//! ChaCha20-Poly1305 does. Poly1305 is not a FIPS approved algorithm.
+//!
+//! # Example
+//!
+//! ```
+//! use aws_lc_rs::mac::{Poly1305Context, Poly1305Key};
+//!
+//! // In practice, the key must be unique to this message.
+//! let key = Poly1305Key::new(&[0x42; 32]);
+//!
+//! let tag = key.sign(b"hello, world");
+//!
+//! let mut ctx = Poly1305Context::with_key(&key);
+//! ctx.update(b"hello, ");
+//! ctx.update(b"world");
+//! assert_eq!(tag, ctx.sign());
+//!
+//! key.verify(b"hello, world", &tag)?;
+//!
+//! # Ok::<(), aws_lc_rs::error::Unspecified>(())
+//! ```
+//!
+//! [RFC 8439, Section 2.5]: https://www.rfc-editor.org/rfc/rfc8439#section-2.5
+
+mod poly1305;
+
+pub use poly1305::{Poly1305Context, Poly1305Key, POLY1305_KEY_LEN, POLY1305_TAG_LEN};
@@ -0,0 +1,146 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+#![allow(clippy::module_name_repetitions)]
+
+use crate::aead::poly1305::{Context, Key, KEY_LEN};
+use crate::constant_time;
+use crate::error::Unspecified;
+use zeroize::Zeroize;
+
+/// The length of a Poly1305 key, in bytes.
+pub const POLY1305_KEY_LEN: usize = KEY_LEN;
+
+/// The length of a Poly1305 tag, in bytes.
+pub const POLY1305_TAG_LEN: usize = 16;
+
+/// A one-time Poly1305 key.
+///
+/// A key must only be used to authenticate a single message. Given the tags of two different
+/// messages under the same key, an attacker can recover the key and forge tags for any other
+/// message. Derive a fresh key for every message, as ChaCha20-Poly1305 does from the ChaCha20
+/// keystream; see the [module documentation](crate::mac).
+///
+/// The key bytes are zeroized when the key is dropped.
+pub struct Poly1305Key {
+    key: [u8; POLY1305_KEY_LEN],
+}
+
+impl core::fmt::Debug for Poly1305Key {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.debug_struct("Poly1305Key").finish_non_exhaustive()
+    }
+}
+
+impl Drop for Poly1305Key {
+    fn drop(&mut self) {
+        self.key.zeroize();
+    }
+}
+
+impl Poly1305Key {
+    /// Constructs a Poly1305 key from its 32-byte encoding, i.e. the `r` value
+    /// followed by the `s` value.
+    #[must_use]
+    pub fn new(key_value: &[u8; POLY1305_KEY_LEN]) -> Self {
+        Self { key: *key_value }
+    }
+
+    /// Calculates the Poly1305 tag of `msg` in one step.
+    ///
+    /// To verify a tag, use `verify` rather than comparing the bytes of the
+    /// returned tag.
+    #[inline]
+    #[must_use]
+    pub fn sign(&self, msg: &[u8]) -> [u8; POLY1305_TAG_LEN] {
+        let mut ctx = Poly1305Context::with_key(self);
+        ctx.update(msg);
+        ctx.sign()
+    }
+
+    /// Calculates the Poly1305 tag of `msg` and verifies whether it equals
+    /// `tag`, in one step.
+    ///
+    /// The comparison is done in constant time to prevent timing attacks.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if the inputs are not verified.
+    #[inline]
+    pub fn verify(&self, msg: &[u8], tag: &[u8]) -> Result<(), Unspecified> {
+        constant_time::verify_slices_are_equal(&self.sign(msg), tag)
+    }
+}
+
+/// A context for multi-step (Init-Update-Finish) Poly1305 signing.
+///
+/// Use `Poly1305Key::sign` for single-step signing. The one-time key requirement of
+/// [`Poly1305Key`] applies across contexts: a key must only ever be used with one context.
+///
+/// The context's internal state, which contains the key, is zeroized when it is dropped.
+pub struct Poly1305Context {
+    ctx: Context,
+}
+
+impl core::fmt::Debug for Poly1305Context {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.debug_struct("Poly1305Context").finish_non_exhaustive()
+    }
+}
+
+impl Poly1305Context {
+    /// Constructs a new Poly1305 signing context using the given key.
+    #[inline]
+    #[must_use]
+    pub fn with_key(key: &Poly1305Key) -> Self {
+        // The `Key` copy is zeroized when `from_key` drops it.
+        Self {
+            ctx: Context::from_key(Key::new(key.key)),
+        }
+    }
+
+    /// Updates the Poly1305 calculation with all the data in `data`. `update`
+    /// may be called zero or more times until `sign` is called.
+    #[inline]
+    pub fn update(&mut self, data: &[u8]) {
+        self.ctx.update(data);
+    }
+
+    /// Finalizes the Poly1305 calculation and returns the tag. `sign` consumes
+    /// the context so it cannot be (mis-)used after `sign` has been called.
+    #[inline]
+    #[must_use]
+    pub fn sign(self) -> [u8; POLY1305_TAG_LEN] {
+        let mut tag = [0u8; POLY1305_TAG_LEN];
+        tag.copy_from_slice(self.ctx.finish().as_ref());
+        tag
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mac::{Poly1305Context, Poly1305Key};
+    use crate::test;
+
+    #[test]
+    fn traits() {
+        test::compile_time_assert_send::<Poly1305Key>();
+        test::compile_time_assert_sync::<Poly1305Key>();
+        test::compile_time_assert_send::<Poly1305Context>();
+        assert_eq!(
+            "Poly1305Key { .. }",
+            format!("{:?}", Poly1305Key::new(&[0; 32]))
+        );
+    }
+
+    #[test]
+    fn context_matches_one_shot() {
+        let key = Poly1305Key::new(&[0x5a; 32]);
+        let msg: Vec<u8> = (0..=255).collect();
+        for split in [0, 1, 15, 16, 17, 100, 256] {
+            let mut ctx = Poly1305Context::with_key(&key);
+            ctx.update(&msg[..split]);
+            ctx.update(&msg[split..]);
+            assert_eq!(key.sign(&msg), ctx.sign());
+        }
+    }
+}
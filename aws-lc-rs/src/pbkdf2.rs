@@ -104,10 +104,11 @@
 //! }
 
 use crate::aws_lc::PKCS5_PBKDF2_HMAC;
+pub use crate::buffer::SecureBuffer;
 use crate::error::Unspecified;
 use crate::fips::indicator_check;
 use crate::{constant_time, digest, hmac};
-use core::num::NonZeroU32;
+use core::num::{NonZeroU32, NonZeroUsize};
 use zeroize::Zeroize;
 
 /// A PBKDF2 algorithm.
@@ -145,6 +146,78 @@ pub static PBKDF2_HMAC_SHA512: Algorithm = Algorithm {
 
 const MAX_USIZE32: u64 = u32::MAX as u64;
 
+const MIN_ITERATIONS: u32 = 1000;
+
+/// PBKDF2 parameters: an iteration count and the length of the derived key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Params {
+    iterations: NonZeroU32,
+    output_len: NonZeroUsize,
+}
+
+impl Params {
+    /// Returns parameters for deriving an `output_len`-byte key with `iterations` iterations.
+    ///
+    /// Fewer than 1,000 iterations are rejected in debug builds. Release builds accept them, and
+    /// log a warning when the "log" feature is enabled.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if `iterations` or `output_len` is zero, or in debug builds if
+    /// `iterations` is less than 1,000.
+    pub fn new(iterations: u32, output_len: usize) -> Result<Self, Unspecified> {
+        let iterations = NonZeroU32::new(iterations).ok_or(Unspecified)?;
+        let output_len = NonZeroUsize::new(output_len).ok_or(Unspecified)?;
+        if cfg!(debug_assertions) && iterations.get() < MIN_ITERATIONS {
+            return Err(Unspecified);
+        }
+        #[cfg(feature = "log")]
+        if iterations.get() < MIN_ITERATIONS {
+            log::warn!(
+                "PBKDF2 iteration count {} is below the minimum of {}",
+                iterations,
+                MIN_ITERATIONS
+            );
+        }
+        Ok(Self {
+            iterations,
+            output_len,
+        })
+    }
+
+    /// Parameters for deriving a 32-byte key from a password entered interactively, using
+    /// `PBKDF2_HMAC_SHA256`: 600,000 iterations, as recommended by OWASP in 2023.
+    #[must_use]
+    pub fn interactive() -> Self {
+        Self::recommended(600_000)
+    }
+
+    /// Parameters for deriving a 32-byte key that protects sensitive data, using
+    /// `PBKDF2_HMAC_SHA256`: 1,200,000 iterations, twice those of [`Params::interactive`].
+    #[must_use]
+    pub fn sensitive() -> Self {
+        Self::recommended(1_200_000)
+    }
+
+    fn recommended(iterations: u32) -> Self {
+        Self {
+            iterations: NonZeroU32::new(iterations).unwrap(),
+            output_len: NonZeroUsize::new(digest::SHA256_OUTPUT_LEN).unwrap(),
+        }
+    }
+
+    /// The iteration count.
+    #[must_use]
+    pub fn iterations(&self) -> NonZeroU32 {
+        self.iterations
+    }
+
+    /// The length of the derived key, in bytes.
+    #[must_use]
+    pub fn output_len(&self) -> NonZeroUsize {
+        self.output_len
+    }
+}
+
 /// Fills `out` with the key derived using PBKDF2 with the given inputs.
 ///
 /// Do not use `derive` as part of verifying a secret; use `verify` instead, to
@@ -188,6 +261,64 @@ pub fn derive(
     try_derive(algorithm, iterations, salt, secret, out).expect("pbkdf2 derive failed");
 }
 
+/// Returns a [`SecureBuffer`] containing a `len`-byte key derived using PBKDF2 with the given
+/// inputs.
+///
+/// This is equivalent to [`derive()`], except that the derived key is returned in a buffer that is
+/// zeroed on drop and is never revealed by its `Debug` implementation. Prefer this over copying
+/// the output of `derive` into a `Vec<u8>`, which would leave the key behind in freed memory.
+/// `derive` remains available for callers that cannot allocate.
+///
+/// # Errors
+/// `error::Unspecified` if `len` is larger than (2**32 - 1) * the digest algorithm's output
+/// length, or if the derivation fails.
+//
+// # FIPS
+// See `derive`.
+pub fn derive_secure(
+    algorithm: Algorithm,
+    iterations: NonZeroU32,
+    salt: &[u8],
+    secret: &[u8],
+    len: usize,
+) -> Result<SecureBuffer, Unspecified> {
+    if len as u64 > algorithm.max_output_len {
+        return Err(Unspecified);
+    }
+    let mut out = vec![0u8; len];
+    if let Err(err) = try_derive(algorithm, iterations, salt, secret, &mut out) {
+        out.zeroize();
+        return Err(err);
+    }
+    Ok(SecureBuffer::new(out))
+}
+
+/// Returns a [`SecureBuffer`] containing a key derived using PBKDF2 with the iteration count and
+/// output length of `params`.
+///
+/// This is equivalent to [`derive_secure`] with `params.iterations()` and `params.output_len()`.
+///
+/// # Errors
+/// `error::Unspecified` if `params.output_len()` is larger than (2**32 - 1) * the digest
+/// algorithm's output length, or if the derivation fails.
+//
+// # FIPS
+// See `derive`.
+pub fn derive_with_params(
+    algorithm: Algorithm,
+    params: &Params,
+    salt: &[u8],
+    secret: &[u8],
+) -> Result<SecureBuffer, Unspecified> {
+    derive_secure(
+        algorithm,
+        params.iterations,
+        salt,
+        secret,
+        params.output_len.get(),
+    )
+}
+
 #[inline]
 fn try_derive(
     algorithm: Algorithm,
@@ -0,0 +1,133 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+//! PKCS#12 is specified in [RFC 7292].
+//!
+//! PKCS#12 files, commonly named `.p12` or `.pfx`, bundle a private key with its certificate
+//! chain under a password. This module only extracts their contents; it does not create them.
+//!
+//! # Example
+//!
+//! ```rust
+//! use aws_lc_rs::pkcs12;
+//! use aws_lc_rs::signature::EcdsaKeyPair;
+//! use aws_lc_rs::signature::ECDSA_P256_SHA256_ASN1_SIGNING;
+//!
+//! let p12 = include_bytes!("../tests/data/pkcs12_ecdsa_p256_legacy.p12");
+//! let bundle = pkcs12::parse(p12, b"password")?;
+//!
+//! let private_key = bundle.private_key.as_ref().unwrap();
+//! let key_pair = EcdsaKeyPair::from_pkcs8(&ECDSA_P256_SHA256_ASN1_SIGNING, private_key).unwrap();
+//! assert_eq!(2, bundle.certificates.len());
+//! # Ok::<(), aws_lc_rs::error::Unspecified>(())
+//! ```
+//!
+//! [RFC 7292]: https://www.rfc-editor.org/rfc/rfc7292
+
+use crate::aws_lc::{
+    i2d_X509, OPENSSL_sk_free, OPENSSL_sk_new_null, OPENSSL_sk_shift, PKCS12_get_key_and_certs,
+    X509,
+};
+use crate::cbs::build_CBS;
+use crate::error::Unspecified;
+use crate::pkcs8::Version;
+use crate::ptr::LcPtr;
+use core::fmt::{self, Debug, Formatter};
+use core::ptr::null_mut;
+use std::ffi::CString;
+use zeroize::Zeroize;
+
+/// The contents of a PKCS#12 file.
+///
+/// The private key is zeroized when the bundle is dropped.
+pub struct Pkcs12Bundle {
+    /// The private key as a DER-encoded PKCS#8 v1 `PrivateKeyInfo`, if the file contains one.
+    ///
+    /// This can be passed to `from_pkcs8` of the key pair type matching the key's algorithm,
+    /// e.g. [`rsa::KeyPair::from_pkcs8`](crate::rsa::KeyPair::from_pkcs8) or
+    /// [`EcdsaKeyPair::from_pkcs8`](crate::signature::EcdsaKeyPair::from_pkcs8).
+    pub private_key: Option<Vec<u8>>,
+
+    /// The DER-encoded X.509 certificates in the file, in the order in which they appear.
+    pub certificates: Vec<Vec<u8>>,
+}
+
+impl Debug for Pkcs12Bundle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Pkcs12Bundle")
+            .field("has_private_key", &self.private_key.is_some())
+            .field("certificates", &self.certificates.len())
+            .finish()
+    }
+}
+
+impl Drop for Pkcs12Bundle {
+    fn drop(&mut self) {
+        if let Some(private_key) = self.private_key.as_mut() {
+            private_key.zeroize();
+        }
+    }
+}
+
+/// Parses a DER- or BER-encoded PKCS#12 file protected by `password`.
+///
+/// The file's MAC is checked with `password`. PKCS#12 files written without a password, such as
+/// some `.pfx` files exported on Windows, are read by passing an empty `password`; both the
+/// empty and the absent password are tried in that case.
+///
+/// # Errors
+/// `error::Unspecified` if `data` is not a valid PKCS#12 file, if `password` is incorrect or
+/// contains a NUL byte, or if the file uses an unsupported encryption algorithm.
+pub fn parse(data: &[u8], password: &[u8]) -> Result<Pkcs12Bundle, Unspecified> {
+    let mut password = CString::new(password)
+        .map_err(|_| Unspecified)?
+        .into_bytes_with_nul();
+
+    let certs = unsafe { OPENSSL_sk_new_null() };
+    if certs.is_null() {
+        password.zeroize();
+        return Err(Unspecified);
+    }
+    let mut private_key = null_mut();
+    let mut cbs = build_CBS(data);
+    let result = unsafe {
+        PKCS12_get_key_and_certs(
+            &mut private_key,
+            certs.cast(),
+            &mut cbs,
+            password.as_ptr().cast(),
+        )
+    };
+    password.zeroize();
+
+    let mut x509s = Vec::new();
+    while let Ok(cert) = LcPtr::new(unsafe { OPENSSL_sk_shift(certs) }.cast::<X509>()) {
+        x509s.push(cert);
+    }
+    unsafe { OPENSSL_sk_free(certs) };
+    let private_key = LcPtr::new(private_key).ok();
+    if 1 != result {
+        return Err(Unspecified);
+    }
+
+    let private_key = match private_key {
+        Some(private_key) => Some(private_key.marshal_rfc5208_private_key(Version::V1)?),
+        None => None,
+    };
+    let certificates = x509s
+        .iter_mut()
+        .map(encode_certificate)
+        .collect::<Result<_, _>>()?;
+    Ok(Pkcs12Bundle {
+        private_key,
+        certificates,
+    })
+}
+
+fn encode_certificate(cert: &mut LcPtr<X509>) -> Result<Vec<u8>, Unspecified> {
+    let mut der = null_mut::<u8>();
+    let der_len = unsafe { i2d_X509(*cert.as_mut(), &mut der) };
+    let der = LcPtr::new(der)?;
+    let der_len = usize::try_from(der_len).map_err(|_| Unspecified)?;
+    Ok(unsafe { der.as_slice(der_len) }.to_vec())
+}
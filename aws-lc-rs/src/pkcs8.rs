@@ -14,6 +14,12 @@ pub struct Document {
     bytes: Vec<u8>,
 }
 
+impl core::fmt::Debug for Document {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("pkcs8::Document").finish_non_exhaustive()
+    }
+}
+
 impl Document {
     pub(crate) fn new(bytes: Vec<u8>) -> Self {
         Self { bytes }
@@ -0,0 +1,520 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+//! Public-key infrastructure (PKIX) structures.
+//!
+//! [`CsrBuilder`] produces PKCS #10 certificate signing requests, as specified in
+//! [RFC 2986](https://www.rfc-editor.org/rfc/rfc2986), signed with an RSA or an ECDSA key pair.
+//!
+//! # Example
+//!
+//! ```rust
+//! # use std::error::Error;
+//! #
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! use aws_lc_rs::pkix::{CsrBuilder, DistinguishedName, SubjectAltName};
+//! use aws_lc_rs::signature::{EcdsaKeyPair, ECDSA_P256_SHA256_ASN1_SIGNING};
+//!
+//! let key_pair = EcdsaKeyPair::generate(&ECDSA_P256_SHA256_ASN1_SIGNING)?;
+//! let subject = DistinguishedName::new()
+//!     .country("US")
+//!     .organization("Example Corp")
+//!     .common_name("example.com");
+//!
+//! let csr_der = CsrBuilder::new()
+//!     .subject(&subject)
+//!     .subject_alt_names(&[SubjectAltName::DnsName("www.example.com".to_string())])
+//!     .sign_with_ecdsa(&key_pair)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::aws_lc::{
+    CBB_add_asn1, CBB_add_asn1_uint64, CBB_add_bytes, CBB_add_u8, CBB_flush, CBB,
+    CBS_ASN1_BITSTRING, CBS_ASN1_CONSTRUCTED, CBS_ASN1_CONTEXT_SPECIFIC, CBS_ASN1_NULL,
+    CBS_ASN1_OBJECT, CBS_ASN1_OCTETSTRING, CBS_ASN1_PRINTABLESTRING, CBS_ASN1_SEQUENCE,
+    CBS_ASN1_SET, CBS_ASN1_TAG, CBS_ASN1_UTF8STRING,
+};
+use crate::cbb::LcCBB;
+use crate::digest::{self, AlgorithmID};
+use crate::ec::signature::{fixed_to_asn1_signature, EcdsaSignatureFormat};
+use crate::encoding::{AsDer, PublicKeyX509Der};
+use crate::error::Unspecified;
+use crate::rand::SystemRandom;
+use crate::signature::{self, EcdsaKeyPair, KeyPair, RsaEncoding, RsaKeyPair};
+use core::mem::MaybeUninit;
+use std::net::IpAddr;
+
+#[allow(clippy::cast_sign_loss)]
+const BIT_STRING_TAG: CBS_ASN1_TAG = CBS_ASN1_BITSTRING as CBS_ASN1_TAG;
+#[allow(clippy::cast_sign_loss)]
+const OCTET_STRING_TAG: CBS_ASN1_TAG = CBS_ASN1_OCTETSTRING as CBS_ASN1_TAG;
+#[allow(clippy::cast_sign_loss)]
+const NULL_TAG: CBS_ASN1_TAG = CBS_ASN1_NULL as CBS_ASN1_TAG;
+#[allow(clippy::cast_sign_loss)]
+const OBJECT_TAG: CBS_ASN1_TAG = CBS_ASN1_OBJECT as CBS_ASN1_TAG;
+#[allow(clippy::cast_sign_loss)]
+const UTF8_STRING_TAG: CBS_ASN1_TAG = CBS_ASN1_UTF8STRING as CBS_ASN1_TAG;
+#[allow(clippy::cast_sign_loss)]
+const PRINTABLE_STRING_TAG: CBS_ASN1_TAG = CBS_ASN1_PRINTABLESTRING as CBS_ASN1_TAG;
+#[allow(clippy::cast_sign_loss)]
+const SEQUENCE_TAG: CBS_ASN1_TAG = CBS_ASN1_SEQUENCE as CBS_ASN1_TAG;
+#[allow(clippy::cast_sign_loss)]
+const SET_TAG: CBS_ASN1_TAG = CBS_ASN1_SET as CBS_ASN1_TAG;
+// attributes [0] IMPLICIT SET OF Attribute (RFC 2986, Section 4.1)
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+const ATTRIBUTES_TAG: CBS_ASN1_TAG =
+    (CBS_ASN1_CONTEXT_SPECIFIC as CBS_ASN1_TAG) | (CBS_ASN1_CONSTRUCTED as CBS_ASN1_TAG);
+// rfc822Name [1], dNSName [2] and iPAddress [7] IMPLICIT GeneralName (RFC 5280, Section 4.2.1.6)
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+const RFC822_NAME_TAG: CBS_ASN1_TAG = (CBS_ASN1_CONTEXT_SPECIFIC as CBS_ASN1_TAG) | 1;
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+const DNS_NAME_TAG: CBS_ASN1_TAG = (CBS_ASN1_CONTEXT_SPECIFIC as CBS_ASN1_TAG) | 2;
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+const IP_ADDRESS_TAG: CBS_ASN1_TAG = (CBS_ASN1_CONTEXT_SPECIFIC as CBS_ASN1_TAG) | 7;
+
+// 2.5.4.3, 2.5.4.6, 2.5.4.8 and 2.5.4.10 (RFC 5280, Appendix A.1)
+const OID_COMMON_NAME: [u8; 3] = [0x55, 0x04, 0x03];
+const OID_COUNTRY_NAME: [u8; 3] = [0x55, 0x04, 0x06];
+const OID_STATE_OR_PROVINCE_NAME: [u8; 3] = [0x55, 0x04, 0x08];
+const OID_ORGANIZATION_NAME: [u8; 3] = [0x55, 0x04, 0x0a];
+
+// id-ce-subjectAltName: 2.5.29.17 (RFC 5280)
+const OID_SUBJECT_ALT_NAME: [u8; 3] = [0x55, 0x1d, 0x11];
+
+// pkcs-9-at-extensionRequest: 1.2.840.113549.1.9.14 (RFC 2985)
+const OID_EXTENSION_REQUEST: [u8; 9] = [0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x09, 0x0e];
+
+// sha{256,384,512}WithRSAEncryption: 1.2.840.113549.1.1.{11,12,13} (RFC 4055)
+const OID_SHA256_WITH_RSA: [u8; 9] = [0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0b];
+const OID_SHA384_WITH_RSA: [u8; 9] = [0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0c];
+const OID_SHA512_WITH_RSA: [u8; 9] = [0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0d];
+
+// ecdsa-with-SHA{256,384,512}: 1.2.840.10045.4.3.{2,3,4} (RFC 5758)
+const OID_ECDSA_WITH_SHA256: [u8; 8] = [0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x03, 0x02];
+const OID_ECDSA_WITH_SHA384: [u8; 8] = [0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x03, 0x03];
+const OID_ECDSA_WITH_SHA512: [u8; 8] = [0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x03, 0x04];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum AttributeType {
+    CommonName,
+    Country,
+    StateOrProvince,
+    Organization,
+}
+
+/// An X.501 distinguished name, used as the subject of a certificate signing request.
+///
+/// Each attribute is encoded as its own relative distinguished name, in the order it was added.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DistinguishedName {
+    attributes: Vec<(AttributeType, String)>,
+}
+
+impl DistinguishedName {
+    /// Constructs an empty distinguished name.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a common name (CN) attribute.
+    #[must_use]
+    pub fn common_name(self, value: &str) -> Self {
+        self.with(AttributeType::CommonName, value)
+    }
+
+    /// Adds a country (C) attribute. `value` must be a two-letter ISO 3166 country code.
+    #[must_use]
+    pub fn country(self, value: &str) -> Self {
+        self.with(AttributeType::Country, value)
+    }
+
+    /// Adds a state or province (ST) attribute.
+    #[must_use]
+    pub fn state_or_province(self, value: &str) -> Self {
+        self.with(AttributeType::StateOrProvince, value)
+    }
+
+    /// Adds an organization (O) attribute.
+    #[must_use]
+    pub fn organization(self, value: &str) -> Self {
+        self.with(AttributeType::Organization, value)
+    }
+
+    fn with(mut self, attribute_type: AttributeType, value: &str) -> Self {
+        self.attributes.push((attribute_type, value.to_string()));
+        self
+    }
+
+    // Adds the `Name` to `cbb`.
+    fn add_to(&self, cbb: *mut CBB) -> Result<(), Unspecified> {
+        add_asn1(cbb, SEQUENCE_TAG, |rdns| {
+            for (attribute_type, value) in &self.attributes {
+                let (oid, value_tag): (&[u8], _) = match attribute_type {
+                    AttributeType::Country => {
+                        if value.len() != 2 || !value.bytes().all(|b| b.is_ascii_alphabetic()) {
+                            return Err(Unspecified);
+                        }
+                        (&OID_COUNTRY_NAME, PRINTABLE_STRING_TAG)
+                    }
+                    AttributeType::CommonName => (&OID_COMMON_NAME, UTF8_STRING_TAG),
+                    AttributeType::StateOrProvince => {
+                        (&OID_STATE_OR_PROVINCE_NAME, UTF8_STRING_TAG)
+                    }
+                    AttributeType::Organization => (&OID_ORGANIZATION_NAME, UTF8_STRING_TAG),
+                };
+                add_asn1(rdns, SET_TAG, |rdn| {
+                    add_asn1(rdn, SEQUENCE_TAG, |attribute| {
+                        add_asn1_bytes(attribute, OBJECT_TAG, oid)?;
+                        add_asn1_bytes(attribute, value_tag, value.as_bytes())
+                    })
+                })?;
+            }
+            Ok(())
+        })
+    }
+}
+
+/// A subject alternative name (RFC 5280, Section 4.2.1.6).
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SubjectAltName {
+    /// A DNS name. Must be ASCII; internationalized names must be A-labels.
+    DnsName(String),
+    /// An IPv4 or IPv6 address.
+    IpAddress(IpAddr),
+    /// An email address. Must be ASCII.
+    Email(String),
+}
+
+impl SubjectAltName {
+    // Adds the `GeneralName` to `cbb`.
+    fn add_to(&self, cbb: *mut CBB) -> Result<(), Unspecified> {
+        match self {
+            SubjectAltName::DnsName(name) => add_asn1_bytes(cbb, DNS_NAME_TAG, ia5_string(name)?),
+            SubjectAltName::Email(email) => {
+                add_asn1_bytes(cbb, RFC822_NAME_TAG, ia5_string(email)?)
+            }
+            SubjectAltName::IpAddress(IpAddr::V4(addr)) => {
+                add_asn1_bytes(cbb, IP_ADDRESS_TAG, &addr.octets())
+            }
+            SubjectAltName::IpAddress(IpAddr::V6(addr)) => {
+                add_asn1_bytes(cbb, IP_ADDRESS_TAG, &addr.octets())
+            }
+        }
+    }
+}
+
+fn ia5_string(value: &str) -> Result<&[u8], Unspecified> {
+    if value.is_empty() || !value.is_ascii() {
+        return Err(Unspecified);
+    }
+    Ok(value.as_bytes())
+}
+
+/// A builder for PKCS #10 certificate signing requests (RFC 2986).
+#[derive(Clone, Debug, Default)]
+pub struct CsrBuilder {
+    subject: DistinguishedName,
+    subject_alt_names: Vec<SubjectAltName>,
+}
+
+impl CsrBuilder {
+    /// Constructs a builder with an empty subject and no subject alternative names.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the subject of the request.
+    #[must_use]
+    pub fn subject(mut self, name: &DistinguishedName) -> Self {
+        self.subject = name.clone();
+        self
+    }
+
+    /// Sets the subject alternative names requested in the `extensionRequest` attribute.
+    #[must_use]
+    pub fn subject_alt_names(mut self, sans: &[SubjectAltName]) -> Self {
+        self.subject_alt_names = sans.to_vec();
+        self
+    }
+
+    /// Produces a DER-encoded `CertificationRequest` signed by `key` using RSASSA-PKCS1-v1_5
+    /// with `digest`.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if `digest` is not SHA-256, SHA-384 or SHA-512, if the subject or a
+    /// subject alternative name cannot be encoded, or if signing fails.
+    pub fn sign_with_rsa(
+        &self,
+        key: &RsaKeyPair,
+        digest: &'static digest::Algorithm,
+    ) -> Result<Vec<u8>, Unspecified> {
+        let (padding_alg, oid): (&'static dyn RsaEncoding, &[u8]) = match digest.id {
+            AlgorithmID::SHA256 => (&signature::RSA_PKCS1_SHA256, &OID_SHA256_WITH_RSA),
+            AlgorithmID::SHA384 => (&signature::RSA_PKCS1_SHA384, &OID_SHA384_WITH_RSA),
+            AlgorithmID::SHA512 => (&signature::RSA_PKCS1_SHA512, &OID_SHA512_WITH_RSA),
+            _ => return Err(Unspecified),
+        };
+        let info = self.certification_request_info(&key.public_key_x509_der()?)?;
+        let mut signature = vec![0u8; key.public_modulus_len()];
+        key.sign(padding_alg, &SystemRandom::new(), &info, &mut signature)?;
+
+        // The parameters of the RSA signature algorithms are NULL (RFC 4055, Section 5).
+        certification_request(&info, oid, true, &signature)
+    }
+
+    /// Produces a DER-encoded `CertificationRequest` signed by `key`, using the digest
+    /// algorithm of the key's signing algorithm.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if the key's digest algorithm is not SHA-256, SHA-384 or SHA-512, if
+    /// the subject or a subject alternative name cannot be encoded, or if signing fails.
+    pub fn sign_with_ecdsa(&self, key: &EcdsaKeyPair) -> Result<Vec<u8>, Unspecified> {
+        let signing_alg = key.algorithm();
+        let oid: &[u8] = match signing_alg.digest.id {
+            AlgorithmID::SHA256 => &OID_ECDSA_WITH_SHA256,
+            AlgorithmID::SHA384 => &OID_ECDSA_WITH_SHA384,
+            AlgorithmID::SHA512 => &OID_ECDSA_WITH_SHA512,
+            _ => return Err(Unspecified),
+        };
+        let public_key: PublicKeyX509Der = key.public_key().as_der()?;
+        let info = self.certification_request_info(public_key.as_ref())?;
+        let signature = key.sign(&SystemRandom::new(), &info)?;
+
+        // The parameters of the ECDSA signature algorithms are absent (RFC 5758, Section 3.2).
+        match signing_alg.sig_format {
+            EcdsaSignatureFormat::ASN1 => {
+                certification_request(&info, oid, false, signature.as_ref())
+            }
+            EcdsaSignatureFormat::Fixed => {
+                // X.509 requires the `Ecdsa-Sig-Value` structure (RFC 3279, Section 2.2.3).
+                let (asn1, asn1_len) = fixed_to_asn1_signature(signing_alg.id, signature.as_ref())?;
+                certification_request(&info, oid, false, unsafe { asn1.as_slice(asn1_len) })
+            }
+        }
+    }
+
+    fn certification_request_info(&self, public_key: &[u8]) -> Result<Vec<u8>, Unspecified> {
+        let mut cbb = LcCBB::new(public_key.len() + 256);
+        add_asn1(cbb.as_mut_ptr(), SEQUENCE_TAG, |info| {
+            // version: v1(0)
+            if 1 != unsafe { CBB_add_asn1_uint64(info, 0) } {
+                return Err(Unspecified);
+            }
+            self.subject.add_to(info)?;
+            add_bytes(info, public_key)?;
+            add_asn1(info, ATTRIBUTES_TAG, |attributes| {
+                if self.subject_alt_names.is_empty() {
+                    return Ok(());
+                }
+                add_asn1(attributes, SEQUENCE_TAG, |attribute| {
+                    add_asn1_bytes(attribute, OBJECT_TAG, &OID_EXTENSION_REQUEST)?;
+                    add_asn1(attribute, SET_TAG, |values| {
+                        add_asn1(values, SEQUENCE_TAG, |extensions| {
+                            add_asn1(extensions, SEQUENCE_TAG, |extension| {
+                                add_asn1_bytes(extension, OBJECT_TAG, &OID_SUBJECT_ALT_NAME)?;
+                                add_asn1(extension, OCTET_STRING_TAG, |value| {
+                                    add_asn1(value, SEQUENCE_TAG, |general_names| {
+                                        for san in &self.subject_alt_names {
+                                            san.add_to(general_names)?;
+                                        }
+                                        Ok(())
+                                    })
+                                })
+                            })
+                        })
+                    })
+                })
+            })
+        })?;
+        cbb.into_vec()
+    }
+}
+
+fn certification_request(
+    info: &[u8],
+    algorithm_oid: &[u8],
+    null_parameters: bool,
+    signature: &[u8],
+) -> Result<Vec<u8>, Unspecified> {
+    let mut cbb = LcCBB::new(info.len() + signature.len() + 32);
+    add_asn1(cbb.as_mut_ptr(), SEQUENCE_TAG, |request| {
+        add_bytes(request, info)?;
+        add_asn1(request, SEQUENCE_TAG, |algorithm| {
+            add_asn1_bytes(algorithm, OBJECT_TAG, algorithm_oid)?;
+            if null_parameters {
+                add_asn1_bytes(algorithm, NULL_TAG, &[])?;
+            }
+            Ok(())
+        })?;
+        add_asn1(request, BIT_STRING_TAG, |bit_string| {
+            // The signature is a BIT STRING without unused bits.
+            if 1 != unsafe { CBB_add_u8(bit_string, 0) } {
+                return Err(Unspecified);
+            }
+            add_bytes(bit_string, signature)
+        })
+    })?;
+    cbb.into_vec()
+}
+
+// Adds an element with `tag` to `parent`, with the contents written by `contents`.
+//
+// A child `CBB` is referenced by its parent until the parent is flushed, so `parent` is flushed
+// before the child goes out of scope.
+fn add_asn1(
+    parent: *mut CBB,
+    tag: CBS_ASN1_TAG,
+    contents: impl FnOnce(*mut CBB) -> Result<(), Unspecified>,
+) -> Result<(), Unspecified> {
+    let mut child = MaybeUninit::<CBB>::uninit();
+    if 1 != unsafe { CBB_add_asn1(parent, child.as_mut_ptr(), tag) } {
+        return Err(Unspecified);
+    }
+    contents(child.as_mut_ptr())?;
+    if 1 != unsafe { CBB_flush(parent) } {
+        return Err(Unspecified);
+    }
+    Ok(())
+}
+
+fn add_asn1_bytes(parent: *mut CBB, tag: CBS_ASN1_TAG, bytes: &[u8]) -> Result<(), Unspecified> {
+    add_asn1(parent, tag, |child| add_bytes(child, bytes))
+}
+
+fn add_bytes(cbb: *mut CBB, bytes: &[u8]) -> Result<(), Unspecified> {
+    if 1 != unsafe { CBB_add_bytes(cbb, bytes.as_ptr(), bytes.len()) } {
+        return Err(Unspecified);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aws_lc::{
+        d2i_X509_REQ, ASN1_STRING_get0_data, ASN1_STRING_length, EVP_PKEY_free, GENERAL_NAMES_free,
+        NID_commonName, NID_countryName, NID_organizationName, NID_stateOrProvinceName,
+        NID_subject_alt_name, OPENSSL_sk_free, OPENSSL_sk_num, OPENSSL_sk_pop, OPENSSL_sk_value,
+        X509V3_get_d2i, X509_EXTENSION_free, X509_NAME_get_text_by_NID, X509_REQ_free,
+        X509_REQ_get_extensions, X509_REQ_get_pubkey, X509_REQ_get_subject_name,
+        X509_REQ_get_version, X509_REQ_verify, ASN1_STRING, GENERAL_NAME, GEN_DNS, GEN_EMAIL,
+        GEN_IPADD,
+    };
+    use crate::digest;
+    use crate::pkix::{CsrBuilder, DistinguishedName, SubjectAltName};
+    use crate::signature::{
+        EcdsaKeyPair, RsaKeyPair, ECDSA_P256_SHA256_ASN1_SIGNING, ECDSA_P384_SHA384_FIXED_SIGNING,
+    };
+    use core::ptr::null_mut;
+    use std::net::{IpAddr, Ipv4Addr};
+
+    fn subject() -> DistinguishedName {
+        DistinguishedName::new()
+            .country("US")
+            .state_or_province("Washington")
+            .organization("Example Corp")
+            .common_name("example.com")
+    }
+
+    fn subject_alt_names() -> Vec<SubjectAltName> {
+        vec![
+            SubjectAltName::DnsName("www.example.com".to_string()),
+            SubjectAltName::IpAddress(IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1))),
+            SubjectAltName::Email("admin@example.com".to_string()),
+        ]
+    }
+
+    unsafe fn asn1_string_bytes<'a>(string: *const ASN1_STRING) -> &'a [u8] {
+        let len = usize::try_from(ASN1_STRING_length(string)).unwrap();
+        core::slice::from_raw_parts(ASN1_STRING_get0_data(string), len)
+    }
+
+    // Parses `csr` with AWS-LC's X.509 code, independently of the encoder, and checks its
+    // signature, subject and requested subject alternative names.
+    fn check_csr(csr: &[u8], sans: bool) {
+        unsafe {
+            let mut der = csr.as_ptr();
+            let req = d2i_X509_REQ(null_mut(), &mut der, csr.len().try_into().unwrap());
+            assert!(!req.is_null());
+            assert_eq!(csr.as_ptr_range().end, der);
+            assert_eq!(0, X509_REQ_get_version(req));
+
+            let public_key = X509_REQ_get_pubkey(req);
+            assert!(!public_key.is_null());
+            assert_eq!(1, X509_REQ_verify(req, public_key));
+            EVP_PKEY_free(public_key);
+
+            let name = X509_REQ_get_subject_name(req);
+            for (nid, expected) in [
+                (NID_countryName, "US"),
+                (NID_stateOrProvinceName, "Washington"),
+                (NID_organizationName, "Example Corp"),
+                (NID_commonName, "example.com"),
+            ] {
+                let mut buf = [0u8; 64];
+                let len =
+                    X509_NAME_get_text_by_NID(name, nid, buf.as_mut_ptr().cast(), buf.len() as _);
+                assert_eq!(expected.as_bytes(), &buf[..usize::try_from(len).unwrap()]);
+            }
+
+            let extensions = X509_REQ_get_extensions(req);
+            if sans {
+                let names =
+                    X509V3_get_d2i(extensions, NID_subject_alt_name, null_mut(), null_mut());
+                assert!(!names.is_null());
+                let names = names.cast();
+                assert_eq!(3, OPENSSL_sk_num(names));
+                let name = |i| &*OPENSSL_sk_value(names, i).cast::<GENERAL_NAME>();
+                assert_eq!(GEN_DNS, name(0).type_);
+                assert_eq!(b"www.example.com", asn1_string_bytes(name(0).d.dNSName));
+                assert_eq!(GEN_IPADD, name(1).type_);
+                assert_eq!(&[192, 0, 2, 1], asn1_string_bytes(name(1).d.iPAddress));
+                assert_eq!(GEN_EMAIL, name(2).type_);
+                assert_eq!(
+                    b"admin@example.com",
+                    asn1_string_bytes(name(2).d.rfc822Name)
+                );
+                GENERAL_NAMES_free(names.cast());
+
+                let extensions = extensions.cast();
+                while OPENSSL_sk_num(extensions) > 0 {
+                    X509_EXTENSION_free(OPENSSL_sk_pop(extensions).cast());
+                }
+                OPENSSL_sk_free(extensions);
+            } else {
+                assert!(extensions.is_null());
+            }
+            X509_REQ_free(req);
+        }
+    }
+
+    #[test]
+    fn csr_parsed_by_aws_lc() {
+        for alg in [
+            &ECDSA_P256_SHA256_ASN1_SIGNING,
+            &ECDSA_P384_SHA384_FIXED_SIGNING,
+        ] {
+            let key_pair = EcdsaKeyPair::generate(alg).unwrap();
+            for sans in [false, true] {
+                let mut builder = CsrBuilder::new().subject(&subject());
+                if sans {
+                    builder = builder.subject_alt_names(&subject_alt_names());
+                }
+                check_csr(&builder.sign_with_ecdsa(&key_pair).unwrap(), sans);
+            }
+        }
+
+        let key_pair =
+            RsaKeyPair::from_pkcs8(include_bytes!("../tests/data/rsa_test_private_key_2048.p8"))
+                .unwrap();
+        let csr = CsrBuilder::new()
+            .subject(&subject())
+            .subject_alt_names(&subject_alt_names())
+            .sign_with_rsa(&key_pair, &digest::SHA384)
+            .unwrap();
+        check_csr(&csr, true);
+    }
+}
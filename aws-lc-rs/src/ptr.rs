@@ -4,14 +4,17 @@
 use core::ops::Deref;
 
 use crate::aws_lc::{
-    BN_free, ECDSA_SIG_free, EC_GROUP_free, EC_KEY_free, EC_POINT_free, EVP_AEAD_CTX_free,
-    EVP_CIPHER_CTX_free, EVP_PKEY_CTX_free, EVP_PKEY_free, OPENSSL_free, RSA_free, BIGNUM,
-    ECDSA_SIG, EC_GROUP, EC_KEY, EC_POINT, EVP_AEAD_CTX, EVP_CIPHER_CTX, EVP_PKEY, EVP_PKEY_CTX,
-    RSA,
+    BN_CTX_free, BN_clear_free, BN_free, CMAC_CTX_free, DH_free, ECDSA_SIG_free, EC_GROUP_free,
+    EC_KEY_free, EC_POINT_free, EVP_AEAD_CTX_free, EVP_CIPHER_CTX_free, EVP_HPKE_CTX_free,
+    EVP_HPKE_KEY_free, EVP_PKEY_CTX_free, EVP_PKEY_free, OPENSSL_free, RSA_free, X509_free, BIGNUM,
+    BN_CTX, CMAC_CTX, DH, ECDSA_SIG, EC_GROUP, EC_KEY, EC_POINT, EVP_AEAD_CTX, EVP_CIPHER_CTX,
+    EVP_HPKE_CTX, EVP_HPKE_KEY, EVP_PKEY, EVP_PKEY_CTX, RSA, X509,
 };
 
 pub(crate) type LcPtr<T> = ManagedPointer<*mut T>;
 pub(crate) type DetachableLcPtr<T> = DetachablePointer<*mut T>;
+pub(crate) type SecretBnPtr = ManagedPointer<SecretBignum>;
+pub(crate) type DetachableSecretBnPtr = DetachablePointer<SecretBignum>;
 
 #[derive(Debug)]
 pub(crate) struct ManagedPointer<P: Pointer> {
@@ -33,6 +36,9 @@ impl<P: Pointer> ManagedPointer<P> {
     }
 }
 
+// Dropping a `ManagedPointer` calls the AWS-LC `XXX_free` function for its type. For key types
+// such as `EVP_PKEY`, this releases the underlying `RSA`/`EC_KEY` and their `BIGNUM`s, which
+// AWS-LC zeroizes before returning the memory to the allocator.
 impl<P: Pointer> Drop for ManagedPointer<P> {
     #[inline]
     fn drop(&mut self) {
@@ -225,16 +231,62 @@ create_pointer!(EC_POINT, EC_POINT_free);
 create_pointer!(EC_KEY, EC_KEY_free);
 create_pointer!(ECDSA_SIG, ECDSA_SIG_free);
 create_pointer!(BIGNUM, BN_free);
+create_pointer!(BN_CTX, BN_CTX_free);
+create_pointer!(DH, DH_free);
 create_pointer!(EVP_PKEY, EVP_PKEY_free);
 create_pointer!(EVP_PKEY_CTX, EVP_PKEY_CTX_free);
 create_pointer!(RSA, RSA_free);
+create_pointer!(X509, X509_free);
 create_pointer!(EVP_AEAD_CTX, EVP_AEAD_CTX_free);
 create_pointer!(EVP_CIPHER_CTX, EVP_CIPHER_CTX_free);
+create_pointer!(CMAC_CTX, CMAC_CTX_free);
+create_pointer!(EVP_HPKE_CTX, EVP_HPKE_CTX_free);
+create_pointer!(EVP_HPKE_KEY, EVP_HPKE_KEY_free);
+
+/// A `BIGNUM` holding secret material, such as a private exponent, which is freed with
+/// `BN_clear_free`.
+#[derive(Debug)]
+pub(crate) struct SecretBignum(*mut BIGNUM);
+
+impl Pointer for SecretBignum {
+    type T = BIGNUM;
+
+    #[inline]
+    fn free(&mut self) {
+        unsafe { BN_clear_free(self.0) }
+    }
+
+    #[inline]
+    fn as_const_ptr(&self) -> *const BIGNUM {
+        self.0
+    }
+
+    #[inline]
+    fn as_mut_ptr(&mut self) -> *mut BIGNUM {
+        self.0
+    }
+}
+
+impl IntoPointer<SecretBignum> for *mut BIGNUM {
+    #[inline]
+    fn into_pointer(self) -> Option<SecretBignum> {
+        if self.is_null() {
+            None
+        } else {
+            Some(SecretBignum(self))
+        }
+    }
+}
 
 #[cfg(test)]
 mod tests {
-    use crate::aws_lc::BIGNUM;
-    use crate::ptr::{DetachablePointer, ManagedPointer};
+    use crate::aws_lc::{
+        RSA_get_ex_new_index, RSA_set_ex_data, BIGNUM, CRYPTO_EX_DATA, EVP_PKEY, EVP_PKEY_RSA, RSA,
+    };
+    use crate::ptr::{DetachablePointer, LcPtr, ManagedPointer};
+    use core::ptr::null_mut;
+    use core::sync::atomic::{AtomicBool, Ordering};
+    use std::os::raw::{c_int, c_long, c_void};
 
     #[test]
     fn test_debug() {
@@ -244,8 +296,55 @@ mod tests {
         let debug = format!("{detachable_ptr:?}");
         assert!(debug.contains("DetachablePointer { pointer: Some("));
 
-        let lc_ptr = ManagedPointer::new(detachable_ptr.detach()).unwrap();
+        let lc_ptr: LcPtr<BIGNUM> = ManagedPointer::new(detachable_ptr.detach()).unwrap();
         let debug = format!("{lc_ptr:?}");
         assert!(debug.contains("ManagedPointer { pointer:"));
     }
+
+    static RSA_FREED: AtomicBool = AtomicBool::new(false);
+
+    fn rsa_freed_marker() -> *mut c_void {
+        core::ptr::addr_of!(RSA_FREED) as *mut c_void
+    }
+
+    unsafe extern "C" fn rsa_ex_data_free(
+        _parent: *mut c_void,
+        ptr: *mut c_void,
+        _ad: *mut CRYPTO_EX_DATA,
+        _index: c_int,
+        _argl: c_long,
+        _argp: *mut c_void,
+    ) {
+        // This callback runs for every `RSA` freed in the process, so only
+        // record the key tagged by the test below.
+        if ptr == rsa_freed_marker() {
+            RSA_FREED.store(true, Ordering::SeqCst);
+        }
+    }
+
+    // Key material is zeroized by AWS-LC when it is freed, so the property to verify on the Rust
+    // side is that dropping an `LcPtr<EVP_PKEY>` actually frees the underlying key.
+    #[test]
+    fn key_zeroization_on_drop() {
+        const PRIVATE_KEY: &[u8] = include_bytes!("../tests/data/rsa_test_private_key_2048.p8");
+
+        let index = unsafe {
+            RSA_get_ex_new_index(0, null_mut(), null_mut(), None, Some(rsa_ex_data_free))
+        };
+        assert!(index >= 0);
+
+        let evp_pkey =
+            LcPtr::<EVP_PKEY>::parse_rfc5208_private_key(PRIVATE_KEY, EVP_PKEY_RSA).unwrap();
+        let rsa = evp_pkey.get_rsa().unwrap();
+        assert_eq!(1, unsafe {
+            RSA_set_ex_data(*rsa as *mut RSA, index, rsa_freed_marker())
+        });
+
+        let clone = evp_pkey.clone();
+        drop(evp_pkey);
+        assert!(!RSA_FREED.load(Ordering::SeqCst));
+
+        drop(clone);
+        assert!(RSA_FREED.load(Ordering::SeqCst));
+    }
 }
@@ -31,6 +31,11 @@
 //! // Using `rand::generate`
 //! let random_array = rand::generate(&rng).unwrap();
 //! let more_rand_bytes: [u8; 64] = random_array.expose();
+//!
+//! // Using `rand::generate_bytes` and `rand::generate_u64`
+//! let mut key_material = vec![0u8; 4096];
+//! rand::generate_bytes(&rng, &mut key_material).unwrap();
+//! let random_u64 = rand::generate_u64(&rng).unwrap();
 //! ```
 use crate::aws_lc::RAND_bytes;
 use crate::error::Unspecified;
@@ -83,6 +88,37 @@ pub fn generate<T: RandomlyConstructable>(
     Ok(Random(r))
 }
 
+/// Fills `dest` with random bytes from `rng`.
+///
+/// Unlike `generate`, this can fill a buffer of any length, including one allocated on the heap.
+///
+/// # Errors
+/// `error::Unspecified` if unable to fill `dest`.
+#[inline]
+pub fn generate_bytes(rng: &dyn SecureRandom, dest: &mut [u8]) -> Result<(), Unspecified> {
+    rng.fill(dest)
+}
+
+/// Generates a random `u64` using `rng`.
+///
+/// # Errors
+/// `error::Unspecified` if unable to fill buffer.
+#[inline]
+pub fn generate_u64(rng: &dyn SecureRandom) -> Result<u64, Unspecified> {
+    let bytes: [u8; 8] = generate(rng)?.expose();
+    Ok(u64::from_le_bytes(bytes))
+}
+
+/// Generates a random `u128` using `rng`.
+///
+/// # Errors
+/// `error::Unspecified` if unable to fill buffer.
+#[inline]
+pub fn generate_u128(rng: &dyn SecureRandom) -> Result<u128, Unspecified> {
+    let bytes: [u8; 16] = generate(rng)?.expose();
+    Ok(u128::from_le_bytes(bytes))
+}
+
 pub(crate) mod sealed {
     use crate::error;
 
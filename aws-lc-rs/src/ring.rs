@@ -0,0 +1,26 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+//! *ring*-compatible module paths.
+//!
+//! This module re-exports the modules of this crate whose API matches *ring*'s, under the names
+//! *ring* uses. Code written against *ring* can be compiled against aws-lc-rs by replacing its
+//! `ring` crate import with `use aws_lc_rs::ring;`, leaving every `ring::...` path unchanged.
+//! Items that aws-lc-rs provides in addition to *ring*'s API remain available through the same
+//! paths.
+//!
+//! # Example
+//!
+//! ```rust
+//! use aws_lc_rs::ring;
+//!
+//! let digest = ring::digest::digest(&ring::digest::SHA256, b"hello, world");
+//! assert_eq!(ring::digest::SHA256_OUTPUT_LEN, digest.as_ref().len());
+//! ```
+
+pub use crate::{
+    aead, agreement, constant_time, digest, error, hkdf, hmac, pbkdf2, pkcs8, rand, signature, test,
+};
+
+#[cfg(feature = "ring-io")]
+pub use crate::io;
@@ -67,16 +67,21 @@
 
 mod encoding;
 mod encryption;
+pub mod kem;
 pub(crate) mod key;
+mod pss;
 pub(crate) mod signature;
 
 pub use self::encryption::oaep::{
     OaepAlgorithm, OaepPrivateDecryptingKey, OaepPublicEncryptingKey, OAEP_SHA1_MGF1SHA1,
-    OAEP_SHA256_MGF1SHA256, OAEP_SHA384_MGF1SHA384, OAEP_SHA512_MGF1SHA512,
+    OAEP_SHA256_MGF1SHA256, OAEP_SHA256_MGF1SHA384, OAEP_SHA256_MGF1SHA512, OAEP_SHA384_MGF1SHA256,
+    OAEP_SHA384_MGF1SHA384, OAEP_SHA384_MGF1SHA512, OAEP_SHA512_MGF1SHA256, OAEP_SHA512_MGF1SHA384,
+    OAEP_SHA512_MGF1SHA512,
 };
 pub use self::encryption::pkcs1::{Pkcs1PrivateDecryptingKey, Pkcs1PublicEncryptingKey};
 pub use self::encryption::{EncryptionAlgorithmId, PrivateDecryptingKey, PublicEncryptingKey};
-pub use self::key::{KeyPair, KeySize, PublicKey, PublicKeyComponents};
+pub use self::key::{KeyPair, KeySize, PrivateKeyComponents, PublicKey, PublicKeyComponents};
+pub use self::pss::{RsaPssKeyPair, RsaPssPublicKey, RsaPssSaltLength};
 #[allow(clippy::module_name_repetitions)]
 pub use self::signature::RsaParameters;
 
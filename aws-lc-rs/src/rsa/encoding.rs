@@ -95,3 +95,133 @@ pub(in crate::rsa) mod rfc5280 {
         LcPtr::<EVP_PKEY>::parse_rfc5280_public_key(value, EVP_PKEY_RSA)
     }
 }
+
+/// [RFC 4055](https://www.rfc-editor.org/rfc/rfc4055.html)
+///
+/// Encodings of `id-RSASSA-PSS` keys, whose `AlgorithmIdentifier` carries RSASSA-PSS parameters.
+/// AWS-LC parses, but does not encode, such keys.
+pub(in crate::rsa) mod rfc4055 {
+    use crate::aws_lc::{
+        CBB_add_asn1, CBB_add_asn1_uint64, CBB_add_bytes, CBB_flush, RSA_marshal_private_key, CBB,
+        CBS_ASN1_CONSTRUCTED, CBS_ASN1_CONTEXT_SPECIFIC, CBS_ASN1_OBJECT, CBS_ASN1_OCTETSTRING,
+        CBS_ASN1_SEQUENCE, CBS_ASN1_TAG, EVP_PKEY,
+    };
+    use crate::cbb::LcCBB;
+    use crate::digest::{self, AlgorithmID};
+    use crate::error::Unspecified;
+    use crate::ptr::LcPtr;
+    use core::mem::MaybeUninit;
+
+    #[allow(clippy::cast_sign_loss)]
+    const SEQUENCE_TAG: CBS_ASN1_TAG = CBS_ASN1_SEQUENCE as CBS_ASN1_TAG;
+    #[allow(clippy::cast_sign_loss)]
+    const OBJECT_TAG: CBS_ASN1_TAG = CBS_ASN1_OBJECT as CBS_ASN1_TAG;
+    #[allow(clippy::cast_sign_loss)]
+    const OCTET_STRING_TAG: CBS_ASN1_TAG = CBS_ASN1_OCTETSTRING as CBS_ASN1_TAG;
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    const PARAMETER_TAG: CBS_ASN1_TAG =
+        (CBS_ASN1_CONTEXT_SPECIFIC as CBS_ASN1_TAG) | (CBS_ASN1_CONSTRUCTED as CBS_ASN1_TAG);
+
+    // 1.2.840.113549.1.1.10
+    const OID_RSASSA_PSS: [u8; 9] = [0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0a];
+    // 1.2.840.113549.1.1.8
+    const OID_MGF1: [u8; 9] = [0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x08];
+
+    // The `AlgorithmIdentifier`s of the SHA-2 digest algorithms, with `NULL` parameters.
+    const SHA256_IDENTIFIER: [u8; 15] = [
+        0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x01, 0x05, 0x00,
+    ];
+    const SHA384_IDENTIFIER: [u8; 15] = [
+        0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x02, 0x05, 0x00,
+    ];
+    const SHA512_IDENTIFIER: [u8; 15] = [
+        0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x03, 0x05, 0x00,
+    ];
+
+    // The `saltLength` field is omitted when it has its default value.
+    const DEFAULT_SALT_LEN: u64 = 20;
+
+    /// Encodes the RSA private key of `key` as a PKCS#8 v1 `id-RSASSA-PSS` private key, whose
+    /// parameters use `digest_alg` for both the message digest and MGF1, and a salt of
+    /// `salt_len` bytes.
+    pub(in crate::rsa) fn encode_private_key_der(
+        key: &LcPtr<EVP_PKEY>,
+        digest_alg: &'static digest::Algorithm,
+        salt_len: u64,
+    ) -> Result<Vec<u8>, Unspecified> {
+        let digest_identifier: &[u8] = match digest_alg.id {
+            AlgorithmID::SHA256 => &SHA256_IDENTIFIER,
+            AlgorithmID::SHA384 => &SHA384_IDENTIFIER,
+            AlgorithmID::SHA512 => &SHA512_IDENTIFIER,
+            _ => return Err(Unspecified),
+        };
+        let rsa = key.get_rsa()?;
+
+        let mut cbb = LcCBB::new(key.signature_size_bytes() * 5);
+        // Child `CBB`s are referenced by their parent, and refer back to it, until `cbb` is
+        // flushed, so none of them may be moved before then.
+        let mut pkcs8 = MaybeUninit::<CBB>::uninit();
+        let mut algorithm = MaybeUninit::<CBB>::uninit();
+        let mut oid = MaybeUninit::<CBB>::uninit();
+        let mut params = MaybeUninit::<CBB>::uninit();
+        let mut hash = MaybeUninit::<CBB>::uninit();
+        let mut mask_gen = MaybeUninit::<CBB>::uninit();
+        let mut mask_gen_algorithm = MaybeUninit::<CBB>::uninit();
+        let mut mask_gen_oid = MaybeUninit::<CBB>::uninit();
+        let mut salt = MaybeUninit::<CBB>::uninit();
+        let mut private_key = MaybeUninit::<CBB>::uninit();
+        unsafe {
+            if 1 != CBB_add_asn1(cbb.as_mut_ptr(), pkcs8.as_mut_ptr(), SEQUENCE_TAG)
+                || 1 != CBB_add_asn1_uint64(pkcs8.as_mut_ptr(), 0)
+                || 1 != CBB_add_asn1(pkcs8.as_mut_ptr(), algorithm.as_mut_ptr(), SEQUENCE_TAG)
+                || 1 != CBB_add_asn1(algorithm.as_mut_ptr(), oid.as_mut_ptr(), OBJECT_TAG)
+                || 1 != CBB_add_bytes(oid.as_mut_ptr(), OID_RSASSA_PSS.as_ptr(), OID_RSASSA_PSS.len())
+                || 1 != CBB_add_asn1(algorithm.as_mut_ptr(), params.as_mut_ptr(), SEQUENCE_TAG)
+                // hashAlgorithm [0]
+                || 1 != CBB_add_asn1(params.as_mut_ptr(), hash.as_mut_ptr(), PARAMETER_TAG)
+                || 1 != CBB_add_bytes(
+                    hash.as_mut_ptr(),
+                    digest_identifier.as_ptr(),
+                    digest_identifier.len(),
+                )
+                // maskGenAlgorithm [1]
+                || 1 != CBB_add_asn1(params.as_mut_ptr(), mask_gen.as_mut_ptr(), PARAMETER_TAG | 1)
+                || 1 != CBB_add_asn1(
+                    mask_gen.as_mut_ptr(),
+                    mask_gen_algorithm.as_mut_ptr(),
+                    SEQUENCE_TAG,
+                )
+                || 1 != CBB_add_asn1(
+                    mask_gen_algorithm.as_mut_ptr(),
+                    mask_gen_oid.as_mut_ptr(),
+                    OBJECT_TAG,
+                )
+                || 1 != CBB_add_bytes(mask_gen_oid.as_mut_ptr(), OID_MGF1.as_ptr(), OID_MGF1.len())
+                || 1 != CBB_add_bytes(
+                    mask_gen_algorithm.as_mut_ptr(),
+                    digest_identifier.as_ptr(),
+                    digest_identifier.len(),
+                )
+            {
+                return Err(Unspecified);
+            }
+            // saltLength [2]
+            if salt_len != DEFAULT_SALT_LEN
+                && (1 != CBB_add_asn1(params.as_mut_ptr(), salt.as_mut_ptr(), PARAMETER_TAG | 2)
+                    || 1 != CBB_add_asn1_uint64(salt.as_mut_ptr(), salt_len))
+            {
+                return Err(Unspecified);
+            }
+            if 1 != CBB_add_asn1(
+                pkcs8.as_mut_ptr(),
+                private_key.as_mut_ptr(),
+                OCTET_STRING_TAG,
+            ) || 1 != RSA_marshal_private_key(private_key.as_mut_ptr(), *rsa)
+                || 1 != CBB_flush(cbb.as_mut_ptr())
+            {
+                return Err(Unspecified);
+            }
+        }
+        cbb.into_vec()
+    }
+}
@@ -4,9 +4,12 @@
 pub(super) mod oaep;
 pub(super) mod pkcs1;
 
-use super::key::{generate_rsa_key, is_rsa_key};
-use super::{encoding, KeySize};
+use super::key::{generate_rsa_key, is_rsa_key, jwk_from_evp_pkey};
+use super::{
+    encoding, KeySize, OaepPrivateDecryptingKey, OaepPublicEncryptingKey, OAEP_SHA256_MGF1SHA256,
+};
 use crate::aws_lc::{EVP_PKEY, EVP_PKEY_RSA};
+use crate::encoding::jwk::{AsJwk, JwkPublicKey};
 use crate::encoding::{AsDer, Pkcs8V1Der, PublicKeyX509Der};
 use crate::error::{KeyRejected, Unspecified};
 use crate::pkcs8::Version;
@@ -24,14 +27,35 @@ pub enum EncryptionAlgorithmId {
     /// RSA-OAEP with SHA256 Hash and SHA256 MGF1
     OaepSha256Mgf1sha256,
 
+    /// RSA-OAEP with SHA256 Hash and SHA384 MGF1
+    OaepSha256Mgf1sha384,
+
+    /// RSA-OAEP with SHA256 Hash and SHA512 MGF1
+    OaepSha256Mgf1sha512,
+
+    /// RSA-OAEP with SHA384 Hash and SHA256 MGF1
+    OaepSha384Mgf1sha256,
+
     /// RSA-OAEP with SHA384 Hash and SHA384 MGF1
     OaepSha384Mgf1sha384,
 
+    /// RSA-OAEP with SHA384 Hash and SHA512 MGF1
+    OaepSha384Mgf1sha512,
+
+    /// RSA-OAEP with SHA512 Hash and SHA256 MGF1
+    OaepSha512Mgf1sha256,
+
+    /// RSA-OAEP with SHA512 Hash and SHA384 MGF1
+    OaepSha512Mgf1sha384,
+
     /// RSA-OAEP with SHA512 Hash and SHA512 MGF1
     OaepSha512Mgf1sha512,
 }
 
 /// An RSA private key used for decrypting ciphertext encrypted by a [`PublicEncryptingKey`].
+///
+/// This type can only be used for decryption; it does not implement any of the signing traits,
+/// so a key loaded for decryption cannot be misused to produce signatures.
 pub struct PrivateDecryptingKey(LcPtr<EVP_PKEY>);
 
 impl PrivateDecryptingKey {
@@ -95,6 +119,17 @@ impl PrivateDecryptingKey {
         Ok(Self::new(key)?)
     }
 
+    /// Construct a `PrivateDecryptingKey` from a DER-encoded `RSAPrivateKey` structure (RFC 8017).
+    ///
+    /// Supports RSA key sizes between 2048 and 8192 (inclusive).
+    ///
+    /// # Errors
+    /// * `KeyRejected` for any error that occurs during deserialization of this key.
+    pub fn from_der(input: &[u8]) -> Result<Self, KeyRejected> {
+        let key = encoding::rfc8017::decode_private_key_der(input)?;
+        Ok(Self::new(key)?)
+    }
+
     /// Returns a boolean indicator if this RSA key is an approved FIPS 140-3 key.
     #[cfg(feature = "fips")]
     #[must_use]
@@ -114,6 +149,28 @@ impl PrivateDecryptingKey {
         self.0.key_size_bits()
     }
 
+    /// Decrypts `ciphertext` using RSA-OAEP with SHA-256 as both the hash and MGF1
+    /// function ([`OAEP_SHA256_MGF1SHA256`]), and the provided `label`.
+    ///
+    /// This is the counterpart of [`PublicEncryptingKey::oaep_sha256_encrypt`].
+    ///
+    /// # Errors
+    /// * `Unspecified` for any error that occurs while decrypting `ciphertext`.
+    pub fn decrypt_oaep(&self, ciphertext: &[u8], label: &[u8]) -> Result<Vec<u8>, Unspecified> {
+        let oaep_key = OaepPrivateDecryptingKey::new(self.clone())?;
+        let mut plaintext = vec![0u8; oaep_key.min_output_size()];
+        let len = oaep_key
+            .decrypt(
+                &OAEP_SHA256_MGF1SHA256,
+                ciphertext,
+                &mut plaintext,
+                Some(label),
+            )?
+            .len();
+        plaintext.truncate(len);
+        Ok(plaintext)
+    }
+
     /// Retrieves the `PublicEncryptingKey` corresponding with this `PrivateDecryptingKey`.
     #[must_use]
     #[allow(clippy::missing_panics_doc)]
@@ -182,6 +239,31 @@ impl PublicEncryptingKey {
     pub fn key_size_bits(&self) -> usize {
         self.0.key_size_bits()
     }
+
+    /// Encrypts `plaintext` using RSA-OAEP with SHA-256 as both the hash and MGF1
+    /// function ([`OAEP_SHA256_MGF1SHA256`]), and the provided `label`.
+    ///
+    /// # Errors
+    /// * `Unspecified` if `plaintext` is too long for this key, or for any other error that
+    ///   occurs while encrypting.
+    pub fn oaep_sha256_encrypt(
+        &self,
+        plaintext: &[u8],
+        label: &[u8],
+    ) -> Result<Vec<u8>, Unspecified> {
+        let oaep_key = OaepPublicEncryptingKey::new(self.clone())?;
+        let mut ciphertext = vec![0u8; oaep_key.ciphertext_size()];
+        let len = oaep_key
+            .encrypt(
+                &OAEP_SHA256_MGF1SHA256,
+                plaintext,
+                &mut ciphertext,
+                Some(label),
+            )?
+            .len();
+        ciphertext.truncate(len);
+        Ok(ciphertext)
+    }
 }
 
 impl Debug for PublicEncryptingKey {
@@ -196,6 +278,13 @@ impl Clone for PublicEncryptingKey {
     }
 }
 
+impl AsJwk for PublicEncryptingKey {
+    /// Provides the public key as an `RSA` JSON Web Key.
+    fn as_jwk(&self) -> Result<JwkPublicKey, Unspecified> {
+        jwk_from_evp_pkey(&self.0)
+    }
+}
+
 impl AsDer<PublicKeyX509Der<'static>> for PublicEncryptingKey {
     /// Serialize this `PublicEncryptingKey` to a X.509 `SubjectPublicKeyInfo` structure as DER encoded bytes.
     ///
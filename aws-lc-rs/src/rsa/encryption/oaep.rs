@@ -45,6 +45,48 @@ pub const OAEP_SHA512_MGF1SHA512: OaepAlgorithm = OaepAlgorithm {
     mgf1_hash_fn: EVP_sha512,
 };
 
+/// RSA-OAEP with SHA256 Hash and SHA384 MGF1
+pub const OAEP_SHA256_MGF1SHA384: OaepAlgorithm = OaepAlgorithm {
+    id: EncryptionAlgorithmId::OaepSha256Mgf1sha384,
+    oaep_hash_fn: EVP_sha256,
+    mgf1_hash_fn: EVP_sha384,
+};
+
+/// RSA-OAEP with SHA256 Hash and SHA512 MGF1
+pub const OAEP_SHA256_MGF1SHA512: OaepAlgorithm = OaepAlgorithm {
+    id: EncryptionAlgorithmId::OaepSha256Mgf1sha512,
+    oaep_hash_fn: EVP_sha256,
+    mgf1_hash_fn: EVP_sha512,
+};
+
+/// RSA-OAEP with SHA384 Hash and SHA256 MGF1
+pub const OAEP_SHA384_MGF1SHA256: OaepAlgorithm = OaepAlgorithm {
+    id: EncryptionAlgorithmId::OaepSha384Mgf1sha256,
+    oaep_hash_fn: EVP_sha384,
+    mgf1_hash_fn: EVP_sha256,
+};
+
+/// RSA-OAEP with SHA384 Hash and SHA512 MGF1
+pub const OAEP_SHA384_MGF1SHA512: OaepAlgorithm = OaepAlgorithm {
+    id: EncryptionAlgorithmId::OaepSha384Mgf1sha512,
+    oaep_hash_fn: EVP_sha384,
+    mgf1_hash_fn: EVP_sha512,
+};
+
+/// RSA-OAEP with SHA512 Hash and SHA256 MGF1
+pub const OAEP_SHA512_MGF1SHA256: OaepAlgorithm = OaepAlgorithm {
+    id: EncryptionAlgorithmId::OaepSha512Mgf1sha256,
+    oaep_hash_fn: EVP_sha512,
+    mgf1_hash_fn: EVP_sha256,
+};
+
+/// RSA-OAEP with SHA512 Hash and SHA384 MGF1
+pub const OAEP_SHA512_MGF1SHA384: OaepAlgorithm = OaepAlgorithm {
+    id: EncryptionAlgorithmId::OaepSha512Mgf1sha384,
+    oaep_hash_fn: EVP_sha512,
+    mgf1_hash_fn: EVP_sha384,
+};
+
 type OaepHashFn = unsafe extern "C" fn() -> *const EVP_MD;
 type Mgf1HashFn = unsafe extern "C" fn() -> *const EVP_MD;
 
@@ -99,8 +141,7 @@ impl OaepPublicEncryptingKey {
     /// The provided length of `plaintext` must be at most [`Self::max_plaintext_size`].
     ///
     /// # Sizing `output`
-    /// For `OAEP_SHA1_MGF1SHA1`, `OAEP_SHA256_MGF1SHA256`, `OAEP_SHA384_MGF1SHA384`, `OAEP_SHA512_MGF1SHA512` The
-    /// length of `output` must be greater then or equal to [`Self::ciphertext_size`].
+    /// For all of the `OaepAlgorithm`s, the length of `output` must be greater then or equal to [`Self::ciphertext_size`].
     ///
     /// # Errors
     /// * `Unspecified` for any error that occurs while encrypting `plaintext`.
@@ -159,13 +200,20 @@ impl OaepPublicEncryptingKey {
         #[allow(unreachable_patterns)]
         let hash_len: usize = match algorithm.id() {
             EncryptionAlgorithmId::OaepSha1Mgf1sha1 => 20,
-            EncryptionAlgorithmId::OaepSha256Mgf1sha256 => 32,
-            EncryptionAlgorithmId::OaepSha384Mgf1sha384 => 48,
-            EncryptionAlgorithmId::OaepSha512Mgf1sha512 => 64,
+            EncryptionAlgorithmId::OaepSha256Mgf1sha256
+            | EncryptionAlgorithmId::OaepSha256Mgf1sha384
+            | EncryptionAlgorithmId::OaepSha256Mgf1sha512 => 32,
+            EncryptionAlgorithmId::OaepSha384Mgf1sha256
+            | EncryptionAlgorithmId::OaepSha384Mgf1sha384
+            | EncryptionAlgorithmId::OaepSha384Mgf1sha512 => 48,
+            EncryptionAlgorithmId::OaepSha512Mgf1sha256
+            | EncryptionAlgorithmId::OaepSha512Mgf1sha384
+            | EncryptionAlgorithmId::OaepSha512Mgf1sha512 => 64,
             _ => unreachable!(),
         };
 
-        // The RSA-OAEP algorithms we support use the hashing algorithm for the hash and mgf1 functions.
+        // The maximum plaintext size only depends on the output length of the OAEP hash function,
+        // not on the MGF1 hash function.
         self.key_size_bytes() - 2 * hash_len - 2
     }
 
@@ -203,8 +251,7 @@ impl OaepPrivateDecryptingKey {
     /// The provided length of `ciphertext` must be [`Self::key_size_bytes`].
     ///
     /// # Sizing `output`
-    /// For `OAEP_SHA1_MGF1SHA1`, `OAEP_SHA256_MGF1SHA256`, `OAEP_SHA384_MGF1SHA384`, `OAEP_SHA512_MGF1SHA512`. The
-    /// length of `output` must be greater then or equal to [`Self::min_output_size`].
+    /// For all of the `OaepAlgorithm`s, the length of `output` must be greater then or equal to [`Self::min_output_size`].
     ///
     /// # Errors
     /// * `Unspecified` for any error that occurs while decrypting `ciphertext`.
@@ -122,6 +122,14 @@ impl Pkcs1PrivateDecryptingKey {
     /// # Sizing `output`
     /// The length of `output` must be greater than or equal to [`Self::min_output_size`].
     ///
+    /// # Security Considerations
+    /// PKCS#1 v1.5 decryption is vulnerable to padding oracle attacks (Bleichenbacher's attack)
+    /// if an attacker can learn whether decryption of a chosen ciphertext succeeded. AWS-LC
+    /// removes the padding in constant time, but callers must not reveal the outcome either: the
+    /// success or failure of this function, and the length of the plaintext, must not influence
+    /// any observable behavior (errors, timing, or messages sent) before the plaintext has been
+    /// authenticated by other means. New protocols should use OAEP instead.
+    ///
     /// # Errors
     /// * `Unspecified` for any error that occurs while decrypting `ciphertext`.
     pub fn decrypt<'plaintext>(
@@ -0,0 +1,119 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+//! RSA key encapsulation using RSA-OAEP, i.e. the KTS-OAEP key-transport scheme of
+//! [NIST SP 800-56Br2](https://doi.org/10.6028/NIST.SP.800-56Br2).
+//!
+//! The sender generates a random secret and encrypts it to the recipient's RSA public key using
+//! RSA-OAEP with SHA-256 for both the hash and MGF1 functions. The recipient decrypts the
+//! ciphertext with their private key to recover the secret.
+//!
+//! # Example
+//!
+//! ```rust
+//! use aws_lc_rs::rand::SystemRandom;
+//! use aws_lc_rs::rsa::kem;
+//! use aws_lc_rs::rsa::{KeySize, PrivateDecryptingKey};
+//!
+//! let private_key = PrivateDecryptingKey::generate(KeySize::Rsa2048)?;
+//! let public_key = private_key.public_key();
+//!
+//! let (sender_secret, ciphertext) = kem::encapsulate(&public_key, &SystemRandom::new())?;
+//! let recipient_secret = kem::decapsulate(&private_key, &ciphertext)?;
+//!
+//! let sender_key = sender_secret.derive(|secret| secret.to_vec());
+//! let recipient_key = recipient_secret.derive(|secret| secret.to_vec());
+//! assert_eq!(sender_key, recipient_key);
+//! # Ok::<(), aws_lc_rs::error::Unspecified>(())
+//! ```
+
+use super::{
+    OaepPrivateDecryptingKey, OaepPublicEncryptingKey, PrivateDecryptingKey, PublicEncryptingKey,
+    OAEP_SHA256_MGF1SHA256,
+};
+use crate::error::Unspecified;
+use crate::rand::SecureRandom;
+use crate::secret::SecretBox;
+use core::fmt::{self, Debug, Formatter};
+use zeroize::Zeroize;
+
+/// The length in bytes of the secret established by [`encapsulate`].
+pub const SHARED_SECRET_LEN: usize = 32;
+
+/// A secret established by [`encapsulate`] or [`decapsulate`].
+///
+/// The secret is only exposed to the key derivation function passed to [`SharedSecret::derive`].
+pub struct SharedSecret(SecretBox<[u8; SHARED_SECRET_LEN]>);
+
+impl SharedSecret {
+    /// Consumes the secret, calling `kdf` with its bytes and returning what `kdf` returns.
+    ///
+    /// As with any key agreement, the secret should be passed through a key derivation function
+    /// rather than used directly as a key.
+    #[inline]
+    pub fn derive<F, R>(self, kdf: F) -> R
+    where
+        F: FnOnce(&[u8]) -> R,
+    {
+        kdf(self.0.expose())
+    }
+}
+
+impl Debug for SharedSecret {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SharedSecret").finish_non_exhaustive()
+    }
+}
+
+/// Generates a random secret and encrypts it to `public_key`, returning the secret and the
+/// ciphertext to be sent to the holder of the private key.
+///
+/// # Errors
+/// `error::Unspecified` if `rng` fails, or on an internal failure.
+pub fn encapsulate(
+    public_key: &PublicEncryptingKey,
+    rng: &dyn SecureRandom,
+) -> Result<(SharedSecret, Vec<u8>), Unspecified> {
+    let mut secret = SharedSecret(SecretBox::new([0u8; SHARED_SECRET_LEN]));
+    rng.fill(secret.0.expose_mut())?;
+
+    let public_key = OaepPublicEncryptingKey::new(public_key.clone())?;
+    let mut ciphertext = vec![0u8; public_key.ciphertext_size()];
+    let ciphertext_len = public_key
+        .encrypt(
+            &OAEP_SHA256_MGF1SHA256,
+            secret.0.expose(),
+            &mut ciphertext,
+            None,
+        )?
+        .len();
+    ciphertext.truncate(ciphertext_len);
+
+    Ok((secret, ciphertext))
+}
+
+/// Decrypts `ciphertext`, as produced by [`encapsulate`], with `private_key`, returning the
+/// secret.
+///
+/// # Errors
+/// `error::Unspecified` if `ciphertext` is not a valid encapsulation of a secret for
+/// `private_key`.
+pub fn decapsulate(
+    private_key: &PrivateDecryptingKey,
+    ciphertext: &[u8],
+) -> Result<SharedSecret, Unspecified> {
+    let private_key = OaepPrivateDecryptingKey::new(private_key.clone())?;
+    let mut plaintext = vec![0u8; private_key.min_output_size()];
+    let result = private_key
+        .decrypt(&OAEP_SHA256_MGF1SHA256, ciphertext, &mut plaintext, None)
+        .and_then(|secret| {
+            let mut shared_secret = SharedSecret(SecretBox::new([0u8; SHARED_SECRET_LEN]));
+            if secret.len() != SHARED_SECRET_LEN {
+                return Err(Unspecified);
+            }
+            shared_secret.0.expose_mut().copy_from_slice(secret);
+            Ok(shared_secret)
+        });
+    plaintext.zeroize();
+    result
+}
@@ -4,21 +4,24 @@
 // SPDX-License-Identifier: Apache-2.0 OR ISC
 use super::signature::{RsaEncoding, RsaPadding};
 use super::{encoding, RsaParameters};
+#[cfg(not(feature = "fips"))]
+use crate::aws_lc::RSA_check_key;
 #[cfg(feature = "fips")]
 use crate::aws_lc::RSA;
 use crate::aws_lc::{
     EVP_PKEY_CTX_set_rsa_keygen_bits, EVP_PKEY_assign_RSA, EVP_PKEY_new, RSA_new, RSA_set0_key,
-    RSA_size, EVP_PKEY, EVP_PKEY_RSA, EVP_PKEY_RSA_PSS,
+    RSA_size, BIGNUM, EVP_PKEY, EVP_PKEY_RSA, EVP_PKEY_RSA_PSS,
 };
-#[cfg(feature = "ring-io")]
-use crate::aws_lc::{RSA_get0_e, RSA_get0_n};
-use crate::encoding::{AsDer, Pkcs8V1Der};
-use crate::error::{KeyRejected, Unspecified};
+use crate::aws_lc::{
+    RSA_get0_d, RSA_get0_dmp1, RSA_get0_dmq1, RSA_get0_e, RSA_get0_iqmp, RSA_get0_n, RSA_get0_p,
+    RSA_get0_q, RSA_set0_crt_params, RSA_set0_factors,
+};
+use crate::encoding::jwk::{AsJwk, JwkPublicKey};
+use crate::encoding::{pem, AsDer, Pkcs8V1Der, PublicKeyX509Der};
+use crate::error::{CryptoError, KeyRejected, Unspecified};
 #[cfg(feature = "ring-io")]
 use crate::io;
-#[cfg(feature = "ring-io")]
-use crate::ptr::ConstPointer;
-use crate::ptr::{DetachableLcPtr, LcPtr};
+use crate::ptr::{ConstPointer, DetachableLcPtr, DetachableSecretBnPtr, LcPtr};
 use crate::rsa::PublicEncryptingKey;
 use crate::sealed::Sealed;
 use crate::{hex, rand};
@@ -79,6 +82,16 @@ impl KeySize {
             Self::Rsa8192 => 8192,
         }
     }
+
+    fn from_bits(bits: usize) -> Option<Self> {
+        match bits {
+            2048 => Some(Self::Rsa2048),
+            3072 => Some(Self::Rsa3072),
+            4096 => Some(Self::Rsa4096),
+            8192 => Some(Self::Rsa8192),
+            _ => None,
+        }
+    }
 }
 
 /// An RSA key pair, used for signing.
@@ -156,6 +169,25 @@ impl KeyPair {
         Self::new(key)
     }
 
+    /// Parses a DER-encoded PKCS#8 `EncryptedPrivateKeyInfo` containing an RSA private key,
+    /// encrypted under `password`.
+    ///
+    /// Both PBES2 ([RFC 8018]) with AES-CBC, as written by `openssl pkcs8 -topk8 -v2 aes-128-cbc`,
+    /// and the older PKCS#12 password-based encryption schemes are supported. An
+    /// `ENCRYPTED PRIVATE KEY` PEM document can be decoded first with
+    /// [`pem_decode`](crate::encoding::pem::pem_decode).
+    ///
+    /// [RFC 8018]: https://www.rfc-editor.org/rfc/rfc8018
+    ///
+    /// # Errors
+    /// `error::KeyRejected` if `pkcs8` cannot be decrypted with `password`, if it does not encode
+    /// an RSA private key, or if the key is otherwise not acceptable.
+    pub fn from_encrypted_pkcs8(pkcs8: &[u8], password: &[u8]) -> Result<Self, KeyRejected> {
+        let key =
+            LcPtr::<EVP_PKEY>::parse_encrypted_rfc5208_private_key(pkcs8, password, EVP_PKEY_RSA)?;
+        Self::new(key)
+    }
+
     /// Parses a DER-encoded `RSAPrivateKey` structure (RFC 8017).
     ///
     /// # Errors
@@ -165,6 +197,47 @@ impl KeyPair {
         Self::new(key)
     }
 
+    /// Parses a PEM-encoded RSA private key: either a PKCS#8 document (`PRIVATE KEY`) or an
+    /// `RSAPrivateKey` structure (`RSA PRIVATE KEY`).
+    ///
+    /// If `pem` contains multiple PEM documents, only the first is parsed.
+    ///
+    /// # Errors
+    /// `error::KeyRejected` if `pem` is not a valid PEM document with one of the above labels, or
+    /// if the key is otherwise not acceptable.
+    pub fn from_pem(pem: &str) -> Result<Self, KeyRejected> {
+        pem::decode_key(pem, |label, der| match label {
+            "PRIVATE KEY" => Self::from_pkcs8(der),
+            "RSA PRIVATE KEY" => Self::from_der(der),
+            _ => Err(KeyRejected::invalid_encoding()),
+        })
+    }
+
+    /// Returns the private key components of this key pair, for export to systems that do not
+    /// accept PKCS#8 or `RSAPrivateKey` documents.
+    ///
+    /// The components are secret key material; the caller is responsible for protecting them,
+    /// and for zeroizing them once they are no longer needed.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if the key pair does not contain the CRT components.
+    pub fn private_key_components(&self) -> Result<PrivateKeyComponents<Vec<u8>>, Unspecified> {
+        let rsa = self.evp_pkey.get_rsa()?;
+        let component = |bn: *const BIGNUM| -> Result<Vec<u8>, Unspecified> {
+            Ok(ConstPointer::new(bn)?.to_be_bytes())
+        };
+        Ok(PrivateKeyComponents {
+            n: component(unsafe { RSA_get0_n(*rsa) })?,
+            e: component(unsafe { RSA_get0_e(*rsa) })?,
+            d: component(unsafe { RSA_get0_d(*rsa) })?,
+            p: component(unsafe { RSA_get0_p(*rsa) })?,
+            q: component(unsafe { RSA_get0_q(*rsa) })?,
+            dp: component(unsafe { RSA_get0_dmp1(*rsa) })?,
+            dq: component(unsafe { RSA_get0_dmq1(*rsa) })?,
+            qi: component(unsafe { RSA_get0_iqmp(*rsa) })?,
+        })
+    }
+
     /// Returns a boolean indicator if this RSA key is an approved FIPS 140-3 key.
     #[cfg(feature = "fips")]
     #[must_use]
@@ -178,6 +251,9 @@ impl KeyPair {
         }
         match key.key_size_bits() {
             2048..=8192 => Ok(()),
+            // FIPS 186-5 requires RSA moduli of at least 2048 bits.
+            #[cfg(feature = "fips")]
+            0..=2047 => Err(KeyRejected::fips_key_size_violation()),
             _ => Err(KeyRejected::unspecified()),
         }
     }
@@ -206,10 +282,35 @@ impl KeyPair {
     pub fn sign(
         &self,
         padding_alg: &'static dyn RsaEncoding,
-        _rng: &dyn rand::SecureRandom,
+        rng: &dyn rand::SecureRandom,
         msg: &[u8],
         signature: &mut [u8],
     ) -> Result<(), Unspecified> {
+        Ok(self.sign_with_error(padding_alg, rng, msg, signature)?)
+    }
+
+    /// Like [`Self::sign`], but returns a [`CryptoError`] describing the failure.
+    ///
+    /// # Errors
+    /// * `CryptoError::OutputBufferTooSmall` if `signature` is shorter than
+    ///   `public_modulus_len()`.
+    /// * `CryptoError::Unspecified` if `signature` is longer than `public_modulus_len()`, or on
+    ///   any other error.
+    pub fn sign_with_error(
+        &self,
+        padding_alg: &'static dyn RsaEncoding,
+        _rng: &dyn rand::SecureRandom,
+        msg: &[u8],
+        signature: &mut [u8],
+    ) -> Result<(), CryptoError> {
+        let required = self.public_modulus_len();
+        if signature.len() < required {
+            return Err(CryptoError::OutputBufferTooSmall { required });
+        }
+        if signature.len() != required {
+            return Err(CryptoError::Unspecified);
+        }
+
         let encoding = padding_alg.encoding();
         let padding_fn = if let RsaPadding::RSA_PKCS1_PSS_PADDING = encoding.padding() {
             Some(configure_rsa_pkcs1_pss_padding)
@@ -225,6 +326,19 @@ impl KeyPair {
         Ok(())
     }
 
+    /// Returns the size of the key pair's public modulus in bits.
+    #[must_use]
+    pub fn key_size_bits(&self) -> usize {
+        self.evp_pkey.key_size_bits()
+    }
+
+    /// Returns the `KeySize` of the key pair, or `None` if its modulus is not one of the sizes
+    /// of `KeySize`; see [`Self::key_size_bits`].
+    #[must_use]
+    pub fn key_size(&self) -> Option<KeySize> {
+        KeySize::from_bits(self.key_size_bits())
+    }
+
     /// Returns the length in bytes of the key pair's public modulus.
     ///
     /// A signature has the same length as the public modulus.
@@ -239,6 +353,11 @@ impl KeyPair {
             Err(_) => unreachable!(),
         }
     }
+
+    /// Returns the DER-encoded X.509 `SubjectPublicKeyInfo` of the key pair's public key.
+    pub(crate) fn public_key_x509_der(&self) -> Result<Vec<u8>, Unspecified> {
+        self.evp_pkey.marshal_rfc5280_public_key()
+    }
 }
 
 impl Debug for KeyPair {
@@ -271,6 +390,7 @@ impl AsDer<Pkcs8V1Der<'static>> for KeyPair {
 #[allow(clippy::module_name_repetitions)]
 pub struct PublicKey {
     key: Box<[u8]>,
+    key_size_bits: usize,
     #[cfg(feature = "ring-io")]
     modulus: Box<[u8]>,
     #[cfg(feature = "ring-io")]
@@ -290,6 +410,7 @@ impl Drop for PublicKey {
 impl PublicKey {
     pub(super) fn new(evp_pkey: &LcPtr<EVP_PKEY>) -> Result<Self, Unspecified> {
         let key = encoding::rfc8017::encode_public_key_der(evp_pkey)?;
+        let key_size_bits = evp_pkey.key_size_bits();
         #[cfg(feature = "ring-io")]
         {
             let pubkey = evp_pkey.get_rsa()?;
@@ -299,13 +420,59 @@ impl PublicKey {
             let exponent = exponent.to_be_bytes().into_boxed_slice();
             Ok(PublicKey {
                 key,
+                key_size_bits,
                 modulus,
                 exponent,
             })
         }
 
         #[cfg(not(feature = "ring-io"))]
-        Ok(PublicKey { key })
+        Ok(PublicKey { key, key_size_bits })
+    }
+
+    /// Returns the size of the public modulus in bits.
+    #[must_use]
+    pub fn key_size_bits(&self) -> usize {
+        self.key_size_bits
+    }
+}
+
+impl PublicKey {
+    /// Parses a PEM-encoded RSA public key: either an X.509 `SubjectPublicKeyInfo` structure
+    /// (`PUBLIC KEY`) or an `RSAPublicKey` structure (`RSA PUBLIC KEY`).
+    ///
+    /// If `pem` contains multiple PEM documents, only the first is parsed.
+    ///
+    /// # Errors
+    /// `error::KeyRejected` if `pem` is not a valid PEM document with one of the above labels, or
+    /// if it does not contain an RSA public key.
+    pub fn from_pem(pem: &str) -> Result<Self, KeyRejected> {
+        let evp_pkey = pem::decode_key(pem, |label, der| match label {
+            "PUBLIC KEY" => encoding::rfc5280::decode_public_key_der(der),
+            "RSA PUBLIC KEY" => encoding::rfc8017::decode_public_key_der(der),
+            _ => Err(KeyRejected::invalid_encoding()),
+        })?;
+        Ok(Self::new(&evp_pkey)?)
+    }
+
+    /// Parses a DER-encoded X.509 `SubjectPublicKeyInfo` structure containing an RSA public key.
+    ///
+    /// # Errors
+    /// `error::KeyRejected` if `spki_der` is not a valid `SubjectPublicKeyInfo` for an RSA key.
+    pub fn from_der(spki_der: &[u8]) -> Result<Self, KeyRejected> {
+        let evp_pkey = encoding::rfc5280::decode_public_key_der(spki_der)?;
+        Ok(Self::new(&evp_pkey)?)
+    }
+}
+
+impl AsDer<PublicKeyX509Der<'static>> for PublicKey {
+    /// Serialize this `PublicKey` to a X.509 `SubjectPublicKeyInfo` structure as DER encoded bytes.
+    ///
+    /// # Errors
+    /// * `Unspecified` for any error that occurs serializing to bytes.
+    fn as_der(&self) -> Result<PublicKeyX509Der<'static>, Unspecified> {
+        let evp_pkey = encoding::rfc8017::decode_public_key_der(&self.key)?;
+        encoding::rfc5280::encode_public_key_der(&evp_pkey)
     }
 }
 
@@ -318,6 +485,43 @@ impl Debug for PublicKey {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for PublicKey {
+    /// Serializes the DER-encoded (RFC 8017) `RSAPublicKey` structure.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::encoding::serde_der::serialize(&self.key, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for PublicKey {
+    /// Deserializes a DER-encoded (RFC 8017) `RSAPublicKey` structure.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::encoding::serde_der::deserialize(deserializer, |der| {
+            let evp_pkey = encoding::rfc8017::decode_public_key_der(der)?;
+            Ok(Self::new(&evp_pkey)?)
+        })
+    }
+}
+
+impl AsJwk for PublicKey {
+    /// Provides the public key as an `RSA` JSON Web Key.
+    fn as_jwk(&self) -> Result<JwkPublicKey, Unspecified> {
+        let evp_pkey = encoding::rfc8017::decode_public_key_der(&self.key)?;
+        jwk_from_evp_pkey(&evp_pkey)
+    }
+}
+
+pub(super) fn jwk_from_evp_pkey(evp_pkey: &LcPtr<EVP_PKEY>) -> Result<JwkPublicKey, Unspecified> {
+    let rsa = evp_pkey.get_rsa()?;
+    let modulus = ConstPointer::new(unsafe { RSA_get0_n(*rsa) })?;
+    let exponent = ConstPointer::new(unsafe { RSA_get0_e(*rsa) })?;
+    Ok(JwkPublicKey::rsa(
+        &modulus.to_be_bytes(),
+        &exponent.to_be_bytes(),
+    ))
+}
+
 impl AsRef<[u8]> for PublicKey {
     /// DER encode a RSA public key to (RFC 8017) `RSAPublicKey` structure.
     fn as_ref(&self) -> &[u8] {
@@ -375,6 +579,16 @@ impl<B> PublicKeyComponents<B>
 where
     B: AsRef<[u8]> + Debug,
 {
+    /// Returns the size of the public modulus `n` in bits, ignoring any leading zeros.
+    #[must_use]
+    pub fn key_size_bits(&self) -> usize {
+        let n = self.n.as_ref();
+        match n.iter().position(|&byte| byte != 0) {
+            Some(i) => (n.len() - i) * 8 - n[i].leading_zeros() as usize,
+            None => 0,
+        }
+    }
+
     #[inline]
     fn build_rsa(&self) -> Result<LcPtr<EVP_PKEY>, ()> {
         let n_bytes = self.n.as_ref();
@@ -446,6 +660,207 @@ where
     }
 }
 
+impl<B> TryFrom<PublicKeyComponents<B>> for PublicKey
+where
+    B: AsRef<[u8]> + Debug,
+{
+    type Error = Unspecified;
+
+    /// Constructs a `PublicKey` from its components.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if a component is empty or has a leading zero, or if the components
+    /// do not form a valid RSA public key.
+    fn try_from(components: PublicKeyComponents<B>) -> Result<Self, Self::Error> {
+        let evp_pkey = components.build_rsa()?;
+        #[cfg(not(feature = "fips"))]
+        if 1 != unsafe { RSA_check_key(*evp_pkey.get_rsa()?) } {
+            return Err(Unspecified);
+        }
+        #[cfg(feature = "fips")]
+        if !is_valid_fips_key(&evp_pkey) {
+            return Err(Unspecified);
+        }
+        Self::new(&evp_pkey)
+    }
+}
+
+impl TryFrom<&PublicKey> for PublicKeyComponents<Vec<u8>> {
+    type Error = Unspecified;
+
+    /// Extracts the modulus and public exponent of `public_key`.
+    ///
+    /// # Errors
+    /// `error::Unspecified` on an internal failure.
+    fn try_from(public_key: &PublicKey) -> Result<Self, Self::Error> {
+        let evp_pkey = encoding::rfc8017::decode_public_key_der(public_key.as_ref())?;
+        Ok(Self::from_evp_pkey(&evp_pkey)?)
+    }
+}
+
+impl PublicKeyComponents<Vec<u8>> {
+    /// Parses the modulus and public exponent from a DER-encoded PKCS#1 `RSAPublicKey`
+    /// structure, as specified in [RFC 8017, Appendix A.1.1].
+    ///
+    /// [RFC 8017, Appendix A.1.1]: https://www.rfc-editor.org/rfc/rfc8017#appendix-A.1.1
+    ///
+    /// # Errors
+    /// `error::KeyRejected` if `der` is not a valid `RSAPublicKey` or has an empty modulus.
+    pub fn from_pkcs1_der(der: &[u8]) -> Result<Self, KeyRejected> {
+        let evp_pkey = encoding::rfc8017::decode_public_key_der(der)?;
+        Self::from_evp_pkey(&evp_pkey)
+    }
+
+    /// Parses the modulus and public exponent from a DER-encoded X.509 `SubjectPublicKeyInfo`
+    /// structure, as specified in [RFC 5280, Section 4.1].
+    ///
+    /// [RFC 5280, Section 4.1]: https://www.rfc-editor.org/rfc/rfc5280#section-4.1
+    ///
+    /// # Errors
+    /// `error::KeyRejected` if `der` is not a valid `SubjectPublicKeyInfo` for an RSA key or the
+    /// key has an empty modulus.
+    pub fn from_spki_der(der: &[u8]) -> Result<Self, KeyRejected> {
+        let evp_pkey = encoding::rfc5280::decode_public_key_der(der)?;
+        Self::from_evp_pkey(&evp_pkey)
+    }
+
+    fn from_evp_pkey(evp_pkey: &LcPtr<EVP_PKEY>) -> Result<Self, KeyRejected> {
+        let rsa = evp_pkey.get_rsa()?;
+        let n = ConstPointer::new(unsafe { RSA_get0_n(*rsa) })?.to_be_bytes();
+        if n.is_empty() {
+            return Err(KeyRejected::invalid_encoding());
+        }
+        let e = ConstPointer::new(unsafe { RSA_get0_e(*rsa) })?.to_be_bytes();
+        Ok(PublicKeyComponents { n, e })
+    }
+}
+
+/// RSA private key components, in the Chinese Remainder Theorem (CRT) form of
+/// [RFC 8017, Section 3.2].
+///
+/// Some HSM and KMS systems export RSA private keys as these raw integers rather than as a DER
+/// document. A `KeyPair` is constructed from them with `KeyPair::try_from`, and
+/// `KeyPair::private_key_components` exports them.
+///
+/// Each component is a non-zero integer encoded in big-endian bytes; leading zeros are
+/// permitted. The `Debug` implementation only shows the public components.
+///
+/// [RFC 8017, Section 3.2]: https://www.rfc-editor.org/rfc/rfc8017#section-3.2
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone)]
+pub struct PrivateKeyComponents<B>
+where
+    B: AsRef<[u8]> + Debug,
+{
+    /// The public modulus.
+    pub n: B,
+    /// The public exponent.
+    pub e: B,
+    /// The private exponent.
+    pub d: B,
+    /// The first prime factor of `n`.
+    pub p: B,
+    /// The second prime factor of `n`.
+    pub q: B,
+    /// The first factor's CRT exponent, `d mod (p - 1)`.
+    pub dp: B,
+    /// The second factor's CRT exponent, `d mod (q - 1)`.
+    pub dq: B,
+    /// The CRT coefficient, `q^(-1) mod p`.
+    pub qi: B,
+}
+
+impl<B: AsRef<[u8]> + Debug> Debug for PrivateKeyComponents<B> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RsaPrivateKeyComponents")
+            .field("n", &self.n)
+            .field("e", &self.e)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<B> PrivateKeyComponents<B>
+where
+    B: AsRef<[u8]> + Debug,
+{
+    fn build_rsa(&self) -> Result<LcPtr<EVP_PKEY>, KeyRejected> {
+        let n: DetachableLcPtr<BIGNUM> = non_zero_bignum(self.n.as_ref())?;
+        let e: DetachableLcPtr<BIGNUM> = non_zero_bignum(self.e.as_ref())?;
+        // The private components are cleared when freed if construction fails before `rsa` takes
+        // ownership of them.
+        let mut d: DetachableSecretBnPtr = non_zero_bignum(self.d.as_ref())?;
+        let mut p: DetachableSecretBnPtr = non_zero_bignum(self.p.as_ref())?;
+        let mut q: DetachableSecretBnPtr = non_zero_bignum(self.q.as_ref())?;
+        let mut dp: DetachableSecretBnPtr = non_zero_bignum(self.dp.as_ref())?;
+        let mut dq: DetachableSecretBnPtr = non_zero_bignum(self.dq.as_ref())?;
+        let mut qi: DetachableSecretBnPtr = non_zero_bignum(self.qi.as_ref())?;
+
+        let rsa = DetachableLcPtr::new(unsafe { RSA_new() })?;
+        if 1 != unsafe { RSA_set0_key(*rsa, *n, *e, *d.as_mut()) } {
+            return Err(KeyRejected::unexpected_error());
+        }
+        n.detach();
+        e.detach();
+        d.detach();
+        if 1 != unsafe { RSA_set0_factors(*rsa, *p.as_mut(), *q.as_mut()) } {
+            return Err(KeyRejected::unexpected_error());
+        }
+        p.detach();
+        q.detach();
+        if 1 != unsafe { RSA_set0_crt_params(*rsa, *dp.as_mut(), *dq.as_mut(), *qi.as_mut()) } {
+            return Err(KeyRejected::unexpected_error());
+        }
+        dp.detach();
+        dq.detach();
+        qi.detach();
+
+        // Among other checks, this verifies that `n = p * q`, and that `d` and the CRT parameters
+        // are consistent with `e`, `p` and `q`.
+        #[cfg(not(feature = "fips"))]
+        if 1 != unsafe { RSA_check_key(*rsa) } {
+            return Err(KeyRejected::inconsistent_components());
+        }
+        #[cfg(feature = "fips")]
+        if 1 != unsafe { RSA_check_fips(*rsa) } {
+            return Err(KeyRejected::inconsistent_components());
+        }
+
+        let mut pkey = LcPtr::new(unsafe { EVP_PKEY_new() })?;
+        if 1 != unsafe { EVP_PKEY_assign_RSA(*pkey.as_mut(), *rsa) } {
+            return Err(KeyRejected::unexpected_error());
+        }
+        rsa.detach();
+
+        Ok(pkey)
+    }
+}
+
+fn non_zero_bignum<T>(bytes: &[u8]) -> Result<T, KeyRejected>
+where
+    T: for<'a> TryFrom<&'a [u8], Error = ()>,
+{
+    if bytes.iter().all(|&byte| byte == 0) {
+        return Err(KeyRejected::invalid_encoding());
+    }
+    Ok(T::try_from(bytes)?)
+}
+
+impl<B> TryFrom<PrivateKeyComponents<B>> for KeyPair
+where
+    B: AsRef<[u8]> + Debug,
+{
+    type Error = KeyRejected;
+
+    /// Constructs a `KeyPair` from its private key components.
+    ///
+    /// # Errors
+    /// `error::KeyRejected` if a component is zero, if the components are inconsistent, or if
+    /// the key is otherwise not acceptable.
+    fn try_from(components: PrivateKeyComponents<B>) -> Result<Self, Self::Error> {
+        Self::new(components.build_rsa()?)
+    }
+}
+
 pub(super) fn generate_rsa_key(size: c_int) -> Result<LcPtr<EVP_PKEY>, Unspecified> {
     let params_fn = |ctx| {
         if 1 == unsafe { EVP_PKEY_CTX_set_rsa_keygen_bits(ctx, size) } {
@@ -0,0 +1,286 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+use super::encoding;
+use super::key::{generate_rsa_key, KeySize};
+use crate::aws_lc::{
+    EVP_PKEY_CTX_get_rsa_mgf1_md, EVP_PKEY_CTX_get_rsa_pss_saltlen, EVP_PKEY_CTX_get_signature_md,
+    EVP_PKEY_CTX_set_rsa_padding, EVP_PKEY_CTX_set_rsa_pss_saltlen, EVP_PKEY_sign_init, EVP_MD,
+    EVP_PKEY, EVP_PKEY_CTX, EVP_PKEY_RSA_PSS, RSA_PKCS1_PSS_PADDING,
+};
+use crate::digest;
+use crate::error::{KeyRejected, Unspecified};
+use crate::evp_pkey::No_EVP_PKEY_CTX_consumer;
+use crate::hex;
+use crate::ptr::LcPtr;
+use crate::rand;
+use core::fmt::{self, Debug, Formatter};
+use core::ptr::null;
+use zeroize::Zeroize;
+
+// TODO: Uncomment when MSRV >= 1.64
+// use core::ffi::c_int;
+use std::os::raw::c_int;
+
+/// The digest algorithms permitted for `RsaPssKeyPair`.
+const PSS_DIGEST_ALGORITHMS: [&digest::Algorithm; 3] =
+    [&digest::SHA256, &digest::SHA384, &digest::SHA512];
+
+/// The salt length of an RSASSA-PSS key.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RsaPssSaltLength {
+    /// A salt with the same length as the output of the key's digest algorithm.
+    DigestLength,
+
+    /// A salt of the given length in bytes. FIPS 186-5 limits this to the length of the output
+    /// of the key's digest algorithm.
+    Bytes(usize),
+}
+
+impl RsaPssSaltLength {
+    fn len(self, digest_alg: &'static digest::Algorithm) -> Result<usize, Unspecified> {
+        match self {
+            Self::DigestLength => Ok(digest_alg.output_len()),
+            Self::Bytes(len) if len <= digest_alg.output_len() => Ok(len),
+            Self::Bytes(_) => Err(Unspecified),
+        }
+    }
+}
+
+/// An RSA key pair restricted to RSASSA-PSS signatures ([RFC 8017, Section 8.1]).
+///
+/// Unlike [`KeyPair`](super::KeyPair), whose padding is chosen for each signature, the digest
+/// algorithm, MGF1 digest algorithm and salt length are fixed when the key is generated and are
+/// carried in its PKCS#8 `id-RSASSA-PSS` parameters, as described in FIPS 186-5. The MGF1 digest
+/// algorithm is always the same as the message digest algorithm, which must be SHA-256, SHA-384
+/// or SHA-512.
+///
+/// [RFC 8017, Section 8.1]: https://www.rfc-editor.org/rfc/rfc8017#section-8.1
+#[allow(clippy::module_name_repetitions)]
+pub struct RsaPssKeyPair {
+    evp_pkey: LcPtr<EVP_PKEY>,
+    public_key: RsaPssPublicKey,
+}
+
+unsafe impl Send for RsaPssKeyPair {}
+unsafe impl Sync for RsaPssKeyPair {}
+
+impl RsaPssKeyPair {
+    fn new(evp_pkey: LcPtr<EVP_PKEY>) -> Result<Self, KeyRejected> {
+        match evp_pkey.key_size_bits() {
+            2048..=8192 => {}
+            // FIPS 186-5 requires RSA moduli of at least 2048 bits.
+            #[cfg(feature = "fips")]
+            0..=2047 => return Err(KeyRejected::fips_key_size_violation()),
+            _ => return Err(KeyRejected::unspecified()),
+        }
+        let (digest_alg, salt_len) = pss_parameters(&evp_pkey)?;
+        let key = encoding::rfc8017::encode_public_key_der(&evp_pkey)?;
+        Ok(Self {
+            evp_pkey,
+            public_key: RsaPssPublicKey {
+                key,
+                digest_alg,
+                salt_len,
+            },
+        })
+    }
+
+    /// Generates an RSASSA-PSS key pair of the specified key-strength, whose signatures use
+    /// `digest_alg` for both the message digest and MGF1, and a salt of `salt_len`.
+    ///
+    /// # Errors
+    /// * `Unspecified`: If `digest_alg` is not SHA-256, SHA-384 or SHA-512, if the salt is longer
+    ///   than the digest, or on any key generation failure.
+    pub fn generate(
+        size: KeySize,
+        digest_alg: &'static digest::Algorithm,
+        salt_len: RsaPssSaltLength,
+    ) -> Result<Self, Unspecified> {
+        if !PSS_DIGEST_ALGORITHMS.contains(&digest_alg) {
+            return Err(Unspecified);
+        }
+        let salt_len = salt_len.len(digest_alg)?.try_into()?;
+        // AWS-LC's `EVP_PKEY_CTX_set_rsa_pss_keygen_*` functions always fail, so a key generated
+        // from an `EVP_PKEY_RSA_PSS` context would carry no parameters. Instead an RSA key is
+        // generated and re-parsed as an `id-RSASSA-PSS` key with the parameters encoded.
+        let rsa_key = generate_rsa_key(size.bits())?;
+        let mut pkcs8 = encoding::rfc4055::encode_private_key_der(&rsa_key, digest_alg, salt_len)?;
+        let result = LcPtr::<EVP_PKEY>::parse_rfc5208_private_key(&pkcs8, EVP_PKEY_RSA_PSS);
+        pkcs8.zeroize();
+        Ok(Self::new(result?)?)
+    }
+
+    /// Parses an unencrypted PKCS#8 DER encoded `id-RSASSA-PSS` private key.
+    ///
+    /// The key must carry RSASSA-PSS parameters using the same digest algorithm, one of SHA-256,
+    /// SHA-384 or SHA-512, for both the message digest and MGF1.
+    ///
+    /// # Errors
+    /// `error::KeyRejected` if bytes do not encode an `id-RSASSA-PSS` private key, if the key has
+    /// no or unsupported parameters, or if the key is otherwise not acceptable.
+    pub fn from_pkcs8(pkcs8: &[u8]) -> Result<Self, KeyRejected> {
+        let evp_pkey = LcPtr::<EVP_PKEY>::parse_rfc5208_private_key(pkcs8, EVP_PKEY_RSA_PSS)?;
+        Self::new(evp_pkey)
+    }
+
+    /// Signs `msg` using the key's RSASSA-PSS parameters. The signature is written into
+    /// `signature`, whose length must be exactly `public_modulus_len()`.
+    ///
+    /// # *ring* Compatibility
+    /// Our implementation ignores the `SecureRandom` parameter.
+    // # FIPS
+    // The following conditions must be met:
+    // * RSA Key Sizes: 2048, 3072, 4096
+    // * Digest Algorithms: SHA256, SHA384, SHA512
+    //
+    /// # Errors
+    /// `error::Unspecified` if `signature` has the wrong length, or on error.
+    pub fn sign(
+        &self,
+        _rng: &dyn rand::SecureRandom,
+        msg: &[u8],
+        signature: &mut [u8],
+    ) -> Result<(), Unspecified> {
+        if signature.len() != self.public_modulus_len() {
+            return Err(Unspecified);
+        }
+        // The padding and salt length are taken from the key's parameters.
+        let sig_bytes = self.evp_pkey.sign(
+            msg,
+            Some(self.public_key.digest_alg),
+            No_EVP_PKEY_CTX_consumer,
+        )?;
+        signature.copy_from_slice(&sig_bytes);
+        Ok(())
+    }
+
+    /// The public key for the key pair.
+    #[must_use]
+    pub fn public_key(&self) -> &RsaPssPublicKey {
+        &self.public_key
+    }
+
+    /// Returns the length in bytes of the key pair's public modulus.
+    ///
+    /// A signature has the same length as the public modulus.
+    #[must_use]
+    pub fn public_modulus_len(&self) -> usize {
+        self.evp_pkey.signature_size_bytes()
+    }
+
+    /// Returns a boolean indicator if this RSA key is an approved FIPS 140-3 key.
+    #[cfg(feature = "fips")]
+    #[must_use]
+    pub fn is_valid_fips_key(&self) -> bool {
+        super::key::is_valid_fips_key(&self.evp_pkey)
+    }
+}
+
+impl Debug for RsaPssKeyPair {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        f.write_str(&format!(
+            "RsaPssKeyPair {{ public_key: {:?} }}",
+            self.public_key
+        ))
+    }
+}
+
+/// The public key of an [`RsaPssKeyPair`], together with its RSASSA-PSS parameters.
+#[derive(Clone)]
+#[allow(clippy::module_name_repetitions)]
+pub struct RsaPssPublicKey {
+    key: Box<[u8]>,
+    digest_alg: &'static digest::Algorithm,
+    salt_len: usize,
+}
+
+impl RsaPssPublicKey {
+    /// The digest algorithm used for both the message digest and MGF1.
+    #[must_use]
+    pub fn digest_algorithm(&self) -> &'static digest::Algorithm {
+        self.digest_alg
+    }
+
+    /// The length of the salt in bytes.
+    #[must_use]
+    pub fn salt_len(&self) -> usize {
+        self.salt_len
+    }
+
+    /// Verifies that `signature` is a valid RSASSA-PSS signature of `msg`, using the key's
+    /// parameters.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if `msg` was not verified.
+    pub fn verify(&self, msg: &[u8], signature: &[u8]) -> Result<(), Unspecified> {
+        let evp_pkey = encoding::rfc8017::decode_public_key_der(&self.key)?;
+        let salt_len: c_int = self.salt_len.try_into()?;
+        let padding_fn = |pctx: *mut EVP_PKEY_CTX| {
+            if 1 != unsafe { EVP_PKEY_CTX_set_rsa_padding(pctx, RSA_PKCS1_PSS_PADDING) } {
+                return Err(());
+            }
+            // The MGF1 digest algorithm defaults to the message digest algorithm.
+            if 1 != unsafe { EVP_PKEY_CTX_set_rsa_pss_saltlen(pctx, salt_len) } {
+                return Err(());
+            }
+            Ok(())
+        };
+        evp_pkey.verify(msg, Some(self.digest_alg), Some(padding_fn), signature)
+    }
+}
+
+impl AsRef<[u8]> for RsaPssPublicKey {
+    /// DER encode a RSA public key to (RFC 8017) `RSAPublicKey` structure.
+    fn as_ref(&self) -> &[u8] {
+        self.key.as_ref()
+    }
+}
+
+impl Debug for RsaPssPublicKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        f.debug_struct("RsaPssPublicKey")
+            .field("key", &hex::encode(self.key.as_ref()))
+            .field("digest_algorithm", &self.digest_alg.id)
+            .field("salt_len", &self.salt_len)
+            .finish()
+    }
+}
+
+/// Returns the digest algorithm and salt length of the RSASSA-PSS parameters of `evp_pkey`.
+fn pss_parameters(
+    evp_pkey: &LcPtr<EVP_PKEY>,
+) -> Result<(&'static digest::Algorithm, usize), KeyRejected> {
+    let mut pkey_ctx = evp_pkey.create_EVP_PKEY_CTX()?;
+    // Initializing the context for signing loads the key's parameters into it.
+    if 1 != unsafe { EVP_PKEY_sign_init(*pkey_ctx.as_mut()) } {
+        return Err(KeyRejected::invalid_encoding());
+    }
+
+    let mut md = null::<EVP_MD>();
+    let mut mgf1_md = null::<EVP_MD>();
+    let mut salt_len: c_int = 0;
+    if 1 != unsafe { EVP_PKEY_CTX_get_signature_md(*pkey_ctx.as_mut(), &mut md) }
+        || 1 != unsafe { EVP_PKEY_CTX_get_rsa_mgf1_md(*pkey_ctx.as_mut(), &mut mgf1_md) }
+        || 1 != unsafe { EVP_PKEY_CTX_get_rsa_pss_saltlen(*pkey_ctx.as_mut(), &mut salt_len) }
+    {
+        return Err(KeyRejected::invalid_encoding());
+    }
+
+    // A key without parameters may be used with any digest algorithm and salt length.
+    if md.is_null() || md != mgf1_md {
+        return Err(KeyRejected::invalid_encoding());
+    }
+    let digest_alg = PSS_DIGEST_ALGORITHMS
+        .into_iter()
+        .find(|alg| *digest::match_digest_type(&alg.id) == md)
+        .ok_or_else(KeyRejected::invalid_encoding)?;
+    let salt_len: usize = salt_len
+        .try_into()
+        .map_err(|_| KeyRejected::invalid_encoding())?;
+    if salt_len > digest_alg.output_len() {
+        return Err(KeyRejected::invalid_encoding());
+    }
+    Ok((digest_alg, salt_len))
+}
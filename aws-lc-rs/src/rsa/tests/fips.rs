@@ -3,8 +3,10 @@
 
 #![cfg(debug_assertions)]
 
+use crate::digest;
 use crate::fips::{assert_fips_status_indicator, FipsServiceStatus};
-use crate::rsa::{KeyPair, KeySize, PrivateDecryptingKey};
+use crate::rand::SystemRandom;
+use crate::rsa::{KeyPair, KeySize, PrivateDecryptingKey, RsaPssKeyPair, RsaPssSaltLength};
 
 macro_rules! generate_key {
     ($name:ident, KeyPair, $size:expr) => {
@@ -119,3 +121,23 @@ generate_key!(
     PrivateDecryptingKey,
     KeySize::Rsa8192
 );
+
+#[test]
+fn rsa2048_pss_generate_key_and_sign() {
+    let key_pair = assert_fips_status_indicator!(
+        RsaPssKeyPair::generate(
+            KeySize::Rsa2048,
+            &digest::SHA256,
+            RsaPssSaltLength::DigestLength
+        ),
+        FipsServiceStatus::Approved
+    )
+    .expect("key generated");
+
+    let mut signature = vec![0u8; key_pair.public_modulus_len()];
+    assert_fips_status_indicator!(
+        key_pair.sign(&SystemRandom::new(), b"message", &mut signature),
+        FipsServiceStatus::Approved
+    )
+    .expect("message signed");
+}
@@ -0,0 +1,131 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+//! A container for sensitive values.
+//!
+//! A [`SecretBox`] holds a value such as derived key material or a plaintext buffer on the heap
+//! and zeroizes it when dropped. It cannot be cloned, and its `Debug` output does not reveal the
+//! value.
+//!
+//! # Choosing a secret type
+//!
+//! Functions in this crate that produce secrets return a type specific to how the secret is
+//! meant to be used, each of which zeroizes its contents when dropped:
+//!
+//! * Key derivation functions such as [`hkdf::Okm::into_secure_buffer`] and
+//!   [`pbkdf2::derive_secure`] return a [`SecureBuffer`](crate::hkdf::SecureBuffer), a read-only
+//!   byte buffer for key material the caller uses directly.
+//! * Key agreement and key encapsulation, such as [`dh::FfdhePair::agree`] and
+//!   [`rsa::kem::decapsulate`], return a `SharedSecret` that is only exposed to a key derivation
+//!   function, because the raw secret should not be used as a key. These types store their secret
+//!   in a [`SecretBox`].
+//!
+//! [`SecretBox`] is for secrets the caller owns and manages itself, such as a plaintext buffer
+//! that is filled or modified in place. Prefer the type returned by an API over copying its
+//! contents into a [`SecretBox`].
+//!
+//! [`hkdf::Okm::into_secure_buffer`]: crate::hkdf::Okm::into_secure_buffer
+//! [`pbkdf2::derive_secure`]: crate::pbkdf2::derive_secure
+//! [`dh::FfdhePair::agree`]: crate::dh::FfdhePair::agree
+//! [`rsa::kem::decapsulate`]: crate::rsa::kem::decapsulate
+//!
+//! # Example
+//!
+//! ```rust
+//! use aws_lc_rs::secret::SecretBox;
+//!
+//! let mut secret = SecretBox::from(vec![0u8; 32]);
+//! secret.expose_mut().copy_from_slice(&[0x42; 32]);
+//! assert_eq!(&[0x42; 32][..], secret.expose().as_slice());
+//! assert_eq!("<secret>", format!("{secret:?}"));
+//! ```
+
+use core::fmt::{self, Debug, Formatter};
+use zeroize::Zeroize;
+
+/// A heap-allocated value that is zeroized when dropped.
+pub struct SecretBox<T: Zeroize> {
+    value: Box<T>,
+}
+
+impl<T: Zeroize> SecretBox<T> {
+    /// Moves `value` to the heap.
+    ///
+    /// `value` is moved rather than copied where possible, but a value held on the stack before
+    /// this call may leave copies behind that are not zeroized.
+    #[must_use]
+    pub fn new(value: T) -> Self {
+        Self {
+            value: Box::new(value),
+        }
+    }
+
+    /// Returns a reference to the value.
+    #[must_use]
+    pub fn expose(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns a mutable reference to the value.
+    #[must_use]
+    pub fn expose_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+impl From<Vec<u8>> for SecretBox<Vec<u8>> {
+    fn from(value: Vec<u8>) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T: Zeroize> Drop for SecretBox<T> {
+    fn drop(&mut self) {
+        self.value.zeroize();
+    }
+}
+
+impl<T: Zeroize> Debug for SecretBox<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("<secret>")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::secret::SecretBox;
+    use core::mem::ManuallyDrop;
+    use zeroize::Zeroize;
+
+    // Keeps the buffer of a `Vec` allocated after it is dropped so that its contents can be
+    // inspected.
+    struct Leaked(ManuallyDrop<Vec<u8>>);
+
+    impl Zeroize for Leaked {
+        fn zeroize(&mut self) {
+            self.0.zeroize();
+        }
+    }
+
+    #[test]
+    fn zeroized_on_drop() {
+        let mut value = ManuallyDrop::new(vec![0x42u8; 64]);
+        let ptr = value.as_mut_ptr();
+        let capacity = value.capacity();
+
+        let secret = SecretBox::new(Leaked(value));
+        assert_eq!(&[0x42; 64][..], secret.expose().0.as_slice());
+        drop(secret);
+
+        let buffer = unsafe { core::slice::from_raw_parts(ptr, capacity) };
+        assert!(buffer.iter().all(|b| *b == 0));
+        drop(unsafe { Vec::from_raw_parts(ptr, 0, capacity) });
+    }
+
+    #[test]
+    fn debug_hides_value() {
+        let secret = SecretBox::from(b"password".to_vec());
+        assert_eq!("<secret>", format!("{secret:?}"));
+        assert_eq!(b"password", secret.expose().as_slice());
+    }
+}
@@ -0,0 +1,232 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+//! Shamir's Secret Sharing over GF(2^8).
+//!
+//! A secret is split into `n` shares such that any `k` of them are sufficient to
+//! reconstruct it, while fewer than `k` shares reveal nothing about the secret.
+//! Each byte of the secret is shared independently using a random polynomial of
+//! degree `k - 1` over GF(2^8) (using the AES reduction polynomial
+//! `x^8 + x^4 + x^3 + x + 1`).
+//!
+//! Every share is `secret.len() + 1` bytes long. The first byte of a share is its
+//! x-coordinate, which is always non-zero, and the remaining bytes are the
+//! evaluations of each polynomial at that x-coordinate.
+//!
+//! # Example
+//!
+//! ```rust
+//! # use std::error::Error;
+//! #
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! use aws_lc_rs::rand::SystemRandom;
+//! use aws_lc_rs::secret_sharing;
+//!
+//! let rng = SystemRandom::new();
+//! let secret = b"master key material";
+//!
+//! let shares = secret_sharing::split(secret, 5, 3, &rng)?;
+//! assert_eq!(shares.len(), 5);
+//!
+//! let recovered = secret_sharing::combine(&shares[1..4])?;
+//! assert_eq!(recovered.as_slice(), secret);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::error::Unspecified;
+use crate::rand::SecureRandom;
+use zeroize::Zeroize;
+
+/// Splits `secret` into `n` shares, any `k` of which can be used to reconstruct it.
+///
+/// # Errors
+/// `error::Unspecified` if `secret` is empty, if `k` is zero, if `k > n`, or if
+/// `rng` fails to generate random coefficients.
+pub fn split(
+    secret: &[u8],
+    n: u8,
+    k: u8,
+    rng: &dyn SecureRandom,
+) -> Result<Vec<Vec<u8>>, Unspecified> {
+    if secret.is_empty() || k == 0 || k > n {
+        return Err(Unspecified);
+    }
+
+    let mut shares: Vec<Vec<u8>> = (1..=n)
+        .map(|x| {
+            let mut share = Vec::with_capacity(secret.len() + 1);
+            share.push(x);
+            share
+        })
+        .collect();
+
+    // coefficients[0] is the secret byte, the remaining `k - 1` are random.
+    let mut coefficients = vec![0u8; usize::from(k)];
+    for &secret_byte in secret {
+        coefficients[0] = secret_byte;
+        rng.fill(&mut coefficients[1..])?;
+        for share in &mut shares {
+            let y = evaluate(&coefficients, share[0]);
+            share.push(y);
+        }
+    }
+    coefficients.zeroize();
+
+    Ok(shares)
+}
+
+/// Reconstructs a secret from `shares` produced by [`split`].
+///
+/// At least `k` distinct shares must be provided to recover the original secret.
+/// Providing fewer than `k` shares produces an unrelated value; this cannot be
+/// detected.
+///
+/// # Errors
+/// `error::Unspecified` if `shares` is empty, if the shares differ in length, if a
+/// share has no data, or if a share x-coordinate is zero or repeated.
+pub fn combine(shares: &[Vec<u8>]) -> Result<Vec<u8>, Unspecified> {
+    let first = shares.first().ok_or(Unspecified)?;
+    let share_len = first.len();
+    if share_len < 2 {
+        return Err(Unspecified);
+    }
+    for (i, share) in shares.iter().enumerate() {
+        if share.len() != share_len || share[0] == 0 {
+            return Err(Unspecified);
+        }
+        if shares[..i].iter().any(|other| other[0] == share[0]) {
+            return Err(Unspecified);
+        }
+    }
+
+    // The Lagrange basis polynomials evaluated at zero only depend on the public
+    // x-coordinates: l_i(0) = prod_{j != i} x_j / (x_j - x_i).
+    let basis: Vec<u8> = shares
+        .iter()
+        .map(|share_i| {
+            let mut numerator = 1u8;
+            let mut denominator = 1u8;
+            for share_j in shares {
+                if share_j[0] != share_i[0] {
+                    numerator = gf256_mul(numerator, share_j[0]);
+                    denominator = gf256_mul(denominator, share_j[0] ^ share_i[0]);
+                }
+            }
+            gf256_mul(numerator, gf256_inv(denominator))
+        })
+        .collect();
+
+    let mut secret = vec![0u8; share_len - 1];
+    for (share, l) in shares.iter().zip(basis) {
+        for (out, &y) in secret.iter_mut().zip(&share[1..]) {
+            *out ^= gf256_mul(y, l);
+        }
+    }
+
+    Ok(secret)
+}
+
+// Evaluates the polynomial with the given coefficients at `x` using Horner's method.
+fn evaluate(coefficients: &[u8], x: u8) -> u8 {
+    coefficients
+        .iter()
+        .rev()
+        .fold(0u8, |acc, &c| gf256_mul(acc, x) ^ c)
+}
+
+// Multiplication in GF(2^8) without secret-dependent branches or table lookups.
+fn gf256_mul(mut a: u8, mut b: u8) -> u8 {
+    let mut product = 0u8;
+    for _ in 0..8 {
+        product ^= 0u8.wrapping_sub(b & 1) & a;
+        let carry = 0u8.wrapping_sub(a >> 7);
+        a = (a << 1) ^ (carry & 0x1b);
+        b >>= 1;
+    }
+    product
+}
+
+// Multiplicative inverse in GF(2^8), computed as a^254. The inverse of zero is zero.
+fn gf256_inv(a: u8) -> u8 {
+    let a2 = gf256_mul(a, a);
+    let a4 = gf256_mul(a2, a2);
+    let a8 = gf256_mul(a4, a4);
+    let a16 = gf256_mul(a8, a8);
+    let a32 = gf256_mul(a16, a16);
+    let a64 = gf256_mul(a32, a32);
+    let a128 = gf256_mul(a64, a64);
+    // 254 = 128 + 64 + 32 + 16 + 8 + 4 + 2
+    [a64, a32, a16, a8, a4, a2]
+        .iter()
+        .fold(a128, |acc, &v| gf256_mul(acc, v))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rand::SystemRandom;
+    use crate::secret_sharing::{combine, gf256_inv, gf256_mul, split};
+
+    #[test]
+    fn gf256_arithmetic() {
+        // FIPS 197, Section 4.2
+        assert_eq!(0xc1, gf256_mul(0x57, 0x83));
+        assert_eq!(0xfe, gf256_mul(0x57, 0x13));
+        assert_eq!(0, gf256_inv(0));
+        for a in 1..=255u8 {
+            assert_eq!(1, gf256_mul(a, gf256_inv(a)));
+        }
+    }
+
+    #[test]
+    fn split_and_combine_3_of_5() {
+        let rng = SystemRandom::new();
+        let secret = b"a 256-bit master key for backup!";
+        let shares = split(secret, 5, 3, &rng).unwrap();
+        assert_eq!(5, shares.len());
+        for share in &shares {
+            assert_eq!(secret.len() + 1, share.len());
+        }
+
+        for i in 0..5 {
+            for j in (i + 1)..5 {
+                let pair = vec![shares[i].clone(), shares[j].clone()];
+                assert_ne!(secret.as_slice(), combine(&pair).unwrap().as_slice());
+
+                for l in (j + 1)..5 {
+                    let subset = vec![shares[i].clone(), shares[j].clone(), shares[l].clone()];
+                    assert_eq!(secret.as_slice(), combine(&subset).unwrap().as_slice());
+                }
+            }
+        }
+
+        assert_eq!(secret.as_slice(), combine(&shares).unwrap().as_slice());
+    }
+
+    #[test]
+    fn split_invalid_parameters() {
+        let rng = SystemRandom::new();
+        assert!(split(b"secret", 5, 0, &rng).is_err());
+        assert!(split(b"secret", 2, 3, &rng).is_err());
+        assert!(split(b"", 5, 3, &rng).is_err());
+        assert_eq!(255, split(b"secret", 255, 255, &rng).unwrap().len());
+
+        let shares = split(b"secret", 1, 1, &rng).unwrap();
+        assert_eq!(b"secret".as_slice(), combine(&shares).unwrap().as_slice());
+    }
+
+    #[test]
+    fn combine_invalid_shares() {
+        let rng = SystemRandom::new();
+        let shares = split(b"secret", 3, 2, &rng).unwrap();
+
+        assert!(combine(&[]).is_err());
+        assert!(combine(&[shares[0].clone(), shares[0].clone()]).is_err());
+        assert!(combine(&[shares[0].clone(), shares[1][..3].to_vec()]).is_err());
+        assert!(combine(&[vec![1u8]]).is_err());
+
+        let mut zero_x = shares[1].clone();
+        zero_x[0] = 0;
+        assert!(combine(&[shares[0].clone(), zero_x]).is_err());
+    }
+}
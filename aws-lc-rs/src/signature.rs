@@ -17,11 +17,12 @@
 //! reduce the risks of algorithm agility and to provide consistency with ECDSA
 //! and `EdDSA`.
 //!
-//! Currently this module does not support digesting the message to be signed
-//! separately from the public key operation, as it is currently being
+//! For the most part, this module does not support digesting the message to be
+//! signed separately from the public key operation, as it is currently being
 //! optimized for Ed25519 and for the implementation of protocols that do not
-//! requiring signing large messages. An interface for efficiently supporting
-//! larger messages may be added later.
+//! requiring signing large messages. The exception is ECDSA, where a message
+//! that was already hashed can be signed with `EcdsaKeyPair::sign_digest` and
+//! verified with `UnparsedPublicKey::verify_digest`.
 //!
 //!
 //! # Algorithm Details
@@ -240,8 +241,8 @@ use untrusted::Input;
 
 pub use crate::rsa::signature::RsaEncoding;
 pub use crate::rsa::{
-    KeyPair as RsaKeyPair, PublicKey as RsaSubjectPublicKey,
-    PublicKeyComponents as RsaPublicKeyComponents, RsaParameters,
+    KeyPair as RsaKeyPair, PrivateKeyComponents as RsaPrivateKeyComponents,
+    PublicKey as RsaSubjectPublicKey, PublicKeyComponents as RsaPublicKeyComponents, RsaParameters,
 };
 
 use crate::rsa::signature::{RsaSignatureEncoding, RsaSigningAlgorithmId};
@@ -253,8 +254,8 @@ pub use crate::ec::signature::{
     EcdsaSigningAlgorithm, EcdsaVerificationAlgorithm, PublicKey as EcdsaPublicKey,
 };
 pub use crate::ed25519::{
-    Ed25519KeyPair, EdDSAParameters, PublicKey as Ed25519PublicKey, Seed as Ed25519Seed,
-    ED25519_PUBLIC_KEY_LEN,
+    batch_verify as ed25519_batch_verify, Ed25519KeyPair, EdDSAParameters,
+    PublicKey as Ed25519PublicKey, Seed as Ed25519Seed, ED25519_PUBLIC_KEY_LEN,
 };
 use crate::{digest, ec, error, hex, rsa, sealed};
 
@@ -342,6 +343,26 @@ pub trait VerificationAlgorithm: Debug + Sync + sealed::Sealed {
         msg: &[u8],
         signature: &[u8],
     ) -> Result<(), error::Unspecified>;
+
+    /// Verify the signature `signature` of a message whose digest, computed with `digest_alg`,
+    /// is `digest`, with the public key `public_key`.
+    ///
+    /// Only ECDSA supports verifying pre-hashed messages; other algorithms always return an
+    /// error.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if inputs not verified, or if the algorithm does not support
+    /// pre-hashed messages.
+    fn verify_digest_sig(
+        &self,
+        public_key: &[u8],
+        digest_alg: &'static digest::Algorithm,
+        digest: &[u8],
+        signature: &[u8],
+    ) -> Result<(), error::Unspecified> {
+        let _ = (public_key, digest_alg, digest, signature);
+        Err(error::Unspecified)
+    }
 }
 
 /// An unparsed, possibly malformed, public key for signature verification.
@@ -363,6 +384,17 @@ impl<B: AsRef<[u8]>> Debug for UnparsedPublicKey<B> {
     }
 }
 
+#[cfg(feature = "serde")]
+impl<B: AsRef<[u8]>> serde::Serialize for UnparsedPublicKey<B> {
+    /// Serializes the unparsed public key bytes.
+    ///
+    /// The verification algorithm is not serialized, so there is no corresponding `Deserialize`
+    /// implementation.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::encoding::serde_der::serialize(self.bytes.as_ref(), serializer)
+    }
+}
+
 impl<B: AsRef<[u8]>> UnparsedPublicKey<B> {
     /// Construct a new `UnparsedPublicKey`.
     ///
@@ -390,6 +422,118 @@ impl<B: AsRef<[u8]>> UnparsedPublicKey<B> {
         self.algorithm
             .verify_sig(self.bytes.as_ref(), message, signature)
     }
+
+    /// Parses the public key and verifies `signature` is a valid signature of the message whose
+    /// digest, computed with `digest_alg`, is `digest`.
+    ///
+    /// `digest_alg` must be the digest algorithm of the key's verification algorithm, and
+    /// `digest` must be `digest_alg.output_len()` bytes long. Only ECDSA verification algorithms
+    /// are supported.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if inputs not verified.
+    #[inline]
+    pub fn verify_digest(
+        &self,
+        digest_alg: &'static digest::Algorithm,
+        digest: &[u8],
+        signature: &[u8],
+    ) -> Result<(), error::Unspecified> {
+        self.algorithm
+            .verify_digest_sig(self.bytes.as_ref(), digest_alg, digest, signature)
+    }
+
+    /// Like [`Self::verify`], but returns a [`CryptoError`](error::CryptoError) describing the
+    /// failure.
+    ///
+    /// As with `verify`, a malformed public key cannot be distinguished from an invalid
+    /// signature.
+    ///
+    /// # Errors
+    /// `error::CryptoError::SignatureVerificationFailed` if inputs not verified.
+    #[inline]
+    pub fn verify_with_error(
+        &self,
+        message: &[u8],
+        signature: &[u8],
+    ) -> Result<(), error::CryptoError> {
+        self.verify(message, signature)
+            .map_err(|_| error::CryptoError::SignatureVerificationFailed)
+    }
+}
+
+/// A signature verification algorithm selected at runtime.
+///
+/// This is useful for protocols that negotiate the signature algorithm, or that identify it
+/// by an `AlgorithmIdentifier`, such as X.509 certificates.
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub enum AnyVerificationAlgorithm {
+    /// An RSA verification algorithm.
+    Rsa(&'static RsaParameters),
+
+    /// An ECDSA verification algorithm.
+    Ecdsa(&'static EcdsaVerificationAlgorithm),
+
+    /// The Ed25519 verification algorithm.
+    Ed25519,
+}
+
+// sha256WithRSAEncryption: 1.2.840.113549.1.1.11
+const OID_RSA_PKCS1_SHA256: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0b];
+// sha384WithRSAEncryption: 1.2.840.113549.1.1.12
+const OID_RSA_PKCS1_SHA384: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0c];
+// sha512WithRSAEncryption: 1.2.840.113549.1.1.13
+const OID_RSA_PKCS1_SHA512: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0d];
+// ecdsa-with-SHA256: 1.2.840.10045.4.3.2
+const OID_ECDSA_SHA256: &[u8] = &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x03, 0x02];
+// ecdsa-with-SHA384: 1.2.840.10045.4.3.3
+const OID_ECDSA_SHA384: &[u8] = &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x03, 0x03];
+// ecdsa-with-SHA512: 1.2.840.10045.4.3.4
+const OID_ECDSA_SHA512: &[u8] = &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x03, 0x04];
+// id-Ed25519: 1.3.101.112
+const OID_ED25519: &[u8] = &[0x2b, 0x65, 0x70];
+
+impl AnyVerificationAlgorithm {
+    /// Returns the verification algorithm identified by the DER-encoded contents of a signature
+    /// algorithm `OBJECT IDENTIFIER`, or `None` if the algorithm is not supported.
+    ///
+    /// The ECDSA identifiers do not specify a curve, so they are mapped to the curve
+    /// conventionally paired with the digest: P-256 for SHA-256, P-384 for SHA-384, and P-521 for
+    /// SHA-512. RSASSA-PSS is not supported since its parameters are not part of the identifier.
+    #[must_use]
+    pub fn from_oid(oid: &[u8]) -> Option<Self> {
+        Some(match oid {
+            OID_RSA_PKCS1_SHA256 => Self::Rsa(&RSA_PKCS1_2048_8192_SHA256),
+            OID_RSA_PKCS1_SHA384 => Self::Rsa(&RSA_PKCS1_2048_8192_SHA384),
+            OID_RSA_PKCS1_SHA512 => Self::Rsa(&RSA_PKCS1_2048_8192_SHA512),
+            OID_ECDSA_SHA256 => Self::Ecdsa(&ECDSA_P256_SHA256_ASN1),
+            OID_ECDSA_SHA384 => Self::Ecdsa(&ECDSA_P384_SHA384_ASN1),
+            OID_ECDSA_SHA512 => Self::Ecdsa(&ECDSA_P521_SHA512_ASN1),
+            OID_ED25519 => Self::Ed25519,
+            _ => return None,
+        })
+    }
+
+    /// Parses `public_key` and verifies `signature` is a valid signature of `message` using it.
+    ///
+    /// This is equivalent to `UnparsedPublicKey::new(algorithm, public_key).verify(message, signature)`.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if inputs not verified.
+    pub fn verify(
+        self,
+        public_key: &[u8],
+        message: &[u8],
+        signature: &[u8],
+    ) -> Result<(), error::Unspecified> {
+        let algorithm: &'static dyn VerificationAlgorithm = match self {
+            Self::Rsa(parameters) => parameters,
+            Self::Ecdsa(algorithm) => algorithm,
+            Self::Ed25519 => &ED25519,
+        };
+        UnparsedPublicKey::new(algorithm, public_key).verify(message, signature)
+    }
 }
 
 /// Verification of signatures using RSA keys of 1024-8192 bits, PKCS#1.5 padding, and SHA-1.
@@ -796,7 +940,11 @@ mod tests {
     use regex::Regex;
 
     use crate::rand::{generate, SystemRandom};
-    use crate::signature::{UnparsedPublicKey, ED25519};
+    use crate::signature::{
+        AnyVerificationAlgorithm, EcdsaKeyPair, Ed25519KeyPair, KeyPair, UnparsedPublicKey,
+        ECDSA_P256_SHA256_ASN1, ECDSA_P256_SHA256_ASN1_SIGNING, ECDSA_P384_SHA384_ASN1, ED25519,
+        RSA_PKCS1_2048_8192_SHA256,
+    };
 
     #[cfg(feature = "fips")]
     mod fips;
@@ -817,4 +965,70 @@ mod tests {
 
         assert!(pubkey_re.is_match(&unparsed_pubkey_debug));
     }
+
+    #[test]
+    fn test_any_verification_algorithm_from_oid() {
+        // ecdsa-with-SHA256: 1.2.840.10045.4.3.2
+        let oid = [0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x03, 0x02];
+        assert!(matches!(
+            AnyVerificationAlgorithm::from_oid(&oid),
+            Some(AnyVerificationAlgorithm::Ecdsa(alg)) if core::ptr::eq(alg, &ECDSA_P256_SHA256_ASN1)
+        ));
+
+        // ecdsa-with-SHA384: 1.2.840.10045.4.3.3
+        let oid = [0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x03, 0x03];
+        assert!(matches!(
+            AnyVerificationAlgorithm::from_oid(&oid),
+            Some(AnyVerificationAlgorithm::Ecdsa(alg)) if core::ptr::eq(alg, &ECDSA_P384_SHA384_ASN1)
+        ));
+
+        // sha256WithRSAEncryption: 1.2.840.113549.1.1.11
+        let oid = [0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0b];
+        assert!(matches!(
+            AnyVerificationAlgorithm::from_oid(&oid),
+            Some(AnyVerificationAlgorithm::Rsa(alg)) if core::ptr::eq(alg, &RSA_PKCS1_2048_8192_SHA256)
+        ));
+
+        // id-Ed25519: 1.3.101.112
+        assert!(matches!(
+            AnyVerificationAlgorithm::from_oid(&[0x2b, 0x65, 0x70]),
+            Some(AnyVerificationAlgorithm::Ed25519)
+        ));
+
+        // RSASSA-PSS: 1.2.840.113549.1.1.10
+        let oid = [0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0a];
+        assert!(AnyVerificationAlgorithm::from_oid(&oid).is_none());
+        assert!(AnyVerificationAlgorithm::from_oid(&[]).is_none());
+    }
+
+    #[test]
+    fn test_any_verification_algorithm_verify() {
+        const MESSAGE: &[u8] = b"hello, world";
+        let rng = SystemRandom::new();
+
+        let pkcs8 = Ed25519KeyPair::generate_pkcs8(&rng).unwrap();
+        let key_pair = Ed25519KeyPair::from_pkcs8(pkcs8.as_ref()).unwrap();
+        let signature = key_pair.sign(MESSAGE);
+        let public_key = key_pair.public_key().as_ref();
+        let algorithm = AnyVerificationAlgorithm::Ed25519;
+        algorithm
+            .verify(public_key, MESSAGE, signature.as_ref())
+            .unwrap();
+        assert!(algorithm
+            .verify(public_key, b"goodbye", signature.as_ref())
+            .is_err());
+
+        let pkcs8 = EcdsaKeyPair::generate_pkcs8(&ECDSA_P256_SHA256_ASN1_SIGNING, &rng).unwrap();
+        let key_pair =
+            EcdsaKeyPair::from_pkcs8(&ECDSA_P256_SHA256_ASN1_SIGNING, pkcs8.as_ref()).unwrap();
+        let signature = key_pair.sign(&rng, MESSAGE).unwrap();
+        let public_key = key_pair.public_key().as_ref();
+        let algorithm = AnyVerificationAlgorithm::Ecdsa(&ECDSA_P256_SHA256_ASN1);
+        algorithm
+            .verify(public_key, MESSAGE, signature.as_ref())
+            .unwrap();
+        assert!(AnyVerificationAlgorithm::Ed25519
+            .verify(public_key, MESSAGE, signature.as_ref())
+            .is_err());
+    }
 }
@@ -3,23 +3,24 @@
 
 #![cfg(debug_assertions)]
 
+use crate::digest;
 use crate::fips::{assert_fips_status_indicator, FipsServiceStatus};
 use crate::rand::SystemRandom;
 use crate::signature::{
-    EcdsaKeyPair, Ed25519KeyPair, EdDSAParameters, KeyPair, RsaKeyPair, VerificationAlgorithm,
-    ECDSA_P256_SHA256_ASN1, ECDSA_P256_SHA256_ASN1_SIGNING, ECDSA_P256_SHA256_FIXED,
-    ECDSA_P256_SHA256_FIXED_SIGNING, ECDSA_P256_SHA384_ASN1, ECDSA_P384_SHA256_ASN1,
-    ECDSA_P384_SHA384_ASN1, ECDSA_P384_SHA384_ASN1_SIGNING, ECDSA_P384_SHA384_FIXED,
-    ECDSA_P384_SHA384_FIXED_SIGNING, ECDSA_P384_SHA3_384_ASN1, ECDSA_P384_SHA3_384_ASN1_SIGNING,
-    ECDSA_P384_SHA3_384_FIXED, ECDSA_P384_SHA3_384_FIXED_SIGNING, ECDSA_P521_SHA3_512_ASN1,
-    ECDSA_P521_SHA3_512_ASN1_SIGNING, ECDSA_P521_SHA3_512_FIXED, ECDSA_P521_SHA3_512_FIXED_SIGNING,
-    ECDSA_P521_SHA512_ASN1, ECDSA_P521_SHA512_ASN1_SIGNING, ECDSA_P521_SHA512_FIXED,
-    ECDSA_P521_SHA512_FIXED_SIGNING, RSA_PKCS1_1024_8192_SHA1_FOR_LEGACY_USE_ONLY,
-    RSA_PKCS1_1024_8192_SHA256_FOR_LEGACY_USE_ONLY, RSA_PKCS1_1024_8192_SHA512_FOR_LEGACY_USE_ONLY,
-    RSA_PKCS1_2048_8192_SHA256, RSA_PKCS1_2048_8192_SHA384, RSA_PKCS1_2048_8192_SHA512,
-    RSA_PKCS1_SHA256, RSA_PKCS1_SHA384, RSA_PKCS1_SHA512, RSA_PSS_2048_8192_SHA256,
-    RSA_PSS_2048_8192_SHA384, RSA_PSS_2048_8192_SHA512, RSA_PSS_SHA256, RSA_PSS_SHA384,
-    RSA_PSS_SHA512,
+    EcdsaKeyPair, Ed25519KeyPair, EdDSAParameters, KeyPair, RsaKeyPair, UnparsedPublicKey,
+    VerificationAlgorithm, ECDSA_P256_SHA256_ASN1, ECDSA_P256_SHA256_ASN1_SIGNING,
+    ECDSA_P256_SHA256_FIXED, ECDSA_P256_SHA256_FIXED_SIGNING, ECDSA_P256_SHA384_ASN1,
+    ECDSA_P384_SHA256_ASN1, ECDSA_P384_SHA384_ASN1, ECDSA_P384_SHA384_ASN1_SIGNING,
+    ECDSA_P384_SHA384_FIXED, ECDSA_P384_SHA384_FIXED_SIGNING, ECDSA_P384_SHA3_384_ASN1,
+    ECDSA_P384_SHA3_384_ASN1_SIGNING, ECDSA_P384_SHA3_384_FIXED, ECDSA_P384_SHA3_384_FIXED_SIGNING,
+    ECDSA_P521_SHA3_512_ASN1, ECDSA_P521_SHA3_512_ASN1_SIGNING, ECDSA_P521_SHA3_512_FIXED,
+    ECDSA_P521_SHA3_512_FIXED_SIGNING, ECDSA_P521_SHA512_ASN1, ECDSA_P521_SHA512_ASN1_SIGNING,
+    ECDSA_P521_SHA512_FIXED, ECDSA_P521_SHA512_FIXED_SIGNING,
+    RSA_PKCS1_1024_8192_SHA1_FOR_LEGACY_USE_ONLY, RSA_PKCS1_1024_8192_SHA256_FOR_LEGACY_USE_ONLY,
+    RSA_PKCS1_1024_8192_SHA512_FOR_LEGACY_USE_ONLY, RSA_PKCS1_2048_8192_SHA256,
+    RSA_PKCS1_2048_8192_SHA384, RSA_PKCS1_2048_8192_SHA512, RSA_PKCS1_SHA256, RSA_PKCS1_SHA384,
+    RSA_PKCS1_SHA512, RSA_PSS_2048_8192_SHA256, RSA_PSS_2048_8192_SHA384, RSA_PSS_2048_8192_SHA512,
+    RSA_PSS_SHA256, RSA_PSS_SHA384, RSA_PSS_SHA512,
 };
 
 mod keys;
@@ -138,6 +139,61 @@ ecdsa_generate_sign_verify!(
     FipsServiceStatus::Approved
 );
 
+macro_rules! ecdsa_sign_verify_digest {
+    ($name:ident, $sign_alg:expr, $verify_alg:expr, $digest_alg:expr, $expect:path) => {
+        #[test]
+        fn $name() {
+            let rng = SystemRandom::new();
+            let key_document = EcdsaKeyPair::generate_pkcs8($sign_alg, &rng).unwrap();
+            let keypair = EcdsaKeyPair::from_pkcs8($sign_alg, key_document.as_ref()).unwrap();
+            let digest = digest::digest($digest_alg, TEST_MESSAGE.as_bytes());
+
+            let signature = assert_fips_status_indicator!(
+                keypair.sign_digest($digest_alg, digest.as_ref()),
+                $expect
+            )
+            .unwrap();
+
+            let public_key = UnparsedPublicKey::new(&$verify_alg, keypair.public_key());
+
+            assert_fips_status_indicator!(
+                public_key.verify_digest($digest_alg, digest.as_ref(), signature.as_ref()),
+                $expect
+            )
+            .unwrap();
+        }
+    };
+}
+
+ecdsa_sign_verify_digest!(
+    ecdsa_p256_sha256_asn1_digest,
+    &ECDSA_P256_SHA256_ASN1_SIGNING,
+    ECDSA_P256_SHA256_ASN1,
+    &digest::SHA256,
+    FipsServiceStatus::Approved
+);
+ecdsa_sign_verify_digest!(
+    ecdsa_p384_sha384_fixed_digest,
+    &ECDSA_P384_SHA384_FIXED_SIGNING,
+    ECDSA_P384_SHA384_FIXED,
+    &digest::SHA384,
+    FipsServiceStatus::Approved
+);
+ecdsa_sign_verify_digest!(
+    ecdsa_p384_sha3_384_asn1_digest,
+    &ECDSA_P384_SHA3_384_ASN1_SIGNING,
+    ECDSA_P384_SHA3_384_ASN1,
+    &digest::SHA3_384,
+    FipsServiceStatus::Approved
+);
+ecdsa_sign_verify_digest!(
+    ecdsa_p521_sha512_asn1_digest,
+    &ECDSA_P521_SHA512_ASN1_SIGNING,
+    ECDSA_P521_SHA512_ASN1,
+    &digest::SHA512,
+    FipsServiceStatus::Approved
+);
+
 #[test]
 fn ed25519() {
     let rng = SystemRandom::new();
@@ -347,6 +403,30 @@ rsa_sign_verify!(
     FipsServiceStatus::Approved,
     FipsServiceStatus::Approved
 );
+rsa_sign_verify!(
+    rsa_pss_3072_sha256,
+    &TEST_RSA_3072_PRIVATE_PKCS8_DER[..],
+    &RSA_PSS_SHA256,
+    RSA_PSS_2048_8192_SHA256,
+    FipsServiceStatus::Approved,
+    FipsServiceStatus::Approved
+);
+rsa_sign_verify!(
+    rsa_pss_3072_sha384,
+    &TEST_RSA_3072_PRIVATE_PKCS8_DER[..],
+    &RSA_PSS_SHA384,
+    RSA_PSS_2048_8192_SHA384,
+    FipsServiceStatus::Approved,
+    FipsServiceStatus::Approved
+);
+rsa_sign_verify!(
+    rsa_pss_3072_sha512,
+    &TEST_RSA_3072_PRIVATE_PKCS8_DER[..],
+    &RSA_PSS_SHA512,
+    RSA_PSS_2048_8192_SHA512,
+    FipsServiceStatus::Approved,
+    FipsServiceStatus::Approved
+);
 rsa_sign_verify!(
     rsa_pss_4096_sha256,
     &TEST_RSA_4096_PRIVATE_PKCS8_DER[..],
@@ -0,0 +1,651 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+//! SRP-6a password-authenticated key exchange as described in
+//! [RFC 5054](https://www.rfc-editor.org/rfc/rfc5054) and
+//! [RFC 2945](https://www.rfc-editor.org/rfc/rfc2945).
+//!
+//! SHA-256 is used as the hash function `H` for all computations, and the
+//! session key is `K = H(PAD(S))`.
+//!
+//! SRP is provided for interoperability with legacy authentication protocols.
+//! It is not a FIPS-approved algorithm.
+//!
+//! # Example
+//!
+//! ```rust
+//! # use std::error::Error;
+//! #
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! use aws_lc_rs::srp::{SrpClient, SrpGroup, SrpServer};
+//!
+//! let group = SrpGroup::rfc5054_2048();
+//! let salt = [0x5a; 16];
+//!
+//! // Computed once at registration time and stored by the server.
+//! let verifier = group.compute_verifier("alice", "password123", &salt)?;
+//!
+//! let client = SrpClient::new(&group, "alice", "password123")?;
+//! let server = SrpServer::new(&group, &verifier)?;
+//!
+//! let client_key = client.compute_key(&server.get_public_b(), &salt)?;
+//! let server_key = server.compute_key(&client.get_public_a())?;
+//!
+//! assert_eq!(client_key, server_key);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::aws_lc::{
+    BN_CTX_new, BN_add, BN_bn2bin_padded, BN_is_zero, BN_mod_add, BN_mod_exp_mont_consttime,
+    BN_mod_mul, BN_mod_sub, BN_mul, BN_new, BN_nnmod, BN_CTX,
+};
+use crate::digest::{self, Context, SHA256, SHA256_OUTPUT_LEN};
+use crate::error::Unspecified;
+use crate::ptr::{LcPtr, SecretBnPtr};
+use crate::rand;
+use core::fmt::{self, Debug, Formatter};
+use core::ptr::null_mut;
+use zeroize::Zeroize;
+
+/// Length in bytes of the session key derived by SRP-6a.
+pub const SESSION_KEY_LEN: usize = SHA256_OUTPUT_LEN;
+
+// Length of the randomly generated private exponents `a` and `b`.
+const PRIVATE_EXPONENT_LEN: usize = 32;
+
+// RFC 5054, Appendix A: 2048-bit Group.
+const RFC5054_2048_N: [u8; 256] = [
+    0xAC, 0x6B, 0xDB, 0x41, 0x32, 0x4A, 0x9A, 0x9B, 0xF1, 0x66, 0xDE, 0x5E, 0x13, 0x89, 0x58, 0x2F,
+    0xAF, 0x72, 0xB6, 0x65, 0x19, 0x87, 0xEE, 0x07, 0xFC, 0x31, 0x92, 0x94, 0x3D, 0xB5, 0x60, 0x50,
+    0xA3, 0x73, 0x29, 0xCB, 0xB4, 0xA0, 0x99, 0xED, 0x81, 0x93, 0xE0, 0x75, 0x77, 0x67, 0xA1, 0x3D,
+    0xD5, 0x23, 0x12, 0xAB, 0x4B, 0x03, 0x31, 0x0D, 0xCD, 0x7F, 0x48, 0xA9, 0xDA, 0x04, 0xFD, 0x50,
+    0xE8, 0x08, 0x39, 0x69, 0xED, 0xB7, 0x67, 0xB0, 0xCF, 0x60, 0x95, 0x17, 0x9A, 0x16, 0x3A, 0xB3,
+    0x66, 0x1A, 0x05, 0xFB, 0xD5, 0xFA, 0xAA, 0xE8, 0x29, 0x18, 0xA9, 0x96, 0x2F, 0x0B, 0x93, 0xB8,
+    0x55, 0xF9, 0x79, 0x93, 0xEC, 0x97, 0x5E, 0xEA, 0xA8, 0x0D, 0x74, 0x0A, 0xDB, 0xF4, 0xFF, 0x74,
+    0x73, 0x59, 0xD0, 0x41, 0xD5, 0xC3, 0x3E, 0xA7, 0x1D, 0x28, 0x1E, 0x44, 0x6B, 0x14, 0x77, 0x3B,
+    0xCA, 0x97, 0xB4, 0x3A, 0x23, 0xFB, 0x80, 0x16, 0x76, 0xBD, 0x20, 0x7A, 0x43, 0x6C, 0x64, 0x81,
+    0xF1, 0xD2, 0xB9, 0x07, 0x87, 0x17, 0x46, 0x1A, 0x5B, 0x9D, 0x32, 0xE6, 0x88, 0xF8, 0x77, 0x48,
+    0x54, 0x45, 0x23, 0xB5, 0x24, 0xB0, 0xD5, 0x7D, 0x5E, 0xA7, 0x7A, 0x27, 0x75, 0xD2, 0xEC, 0xFA,
+    0x03, 0x2C, 0xFB, 0xDB, 0xF5, 0x2F, 0xB3, 0x78, 0x61, 0x60, 0x27, 0x90, 0x04, 0xE5, 0x7A, 0xE6,
+    0xAF, 0x87, 0x4E, 0x73, 0x03, 0xCE, 0x53, 0x29, 0x9C, 0xCC, 0x04, 0x1C, 0x7B, 0xC3, 0x08, 0xD8,
+    0x2A, 0x56, 0x98, 0xF3, 0xA8, 0xD0, 0xC3, 0x82, 0x71, 0xAE, 0x35, 0xF8, 0xE9, 0xDB, 0xFB, 0xB6,
+    0x94, 0xB5, 0xC8, 0x03, 0xD8, 0x9F, 0x7A, 0xE4, 0x35, 0xDE, 0x23, 0x6D, 0x52, 0x5F, 0x54, 0x75,
+    0x9B, 0x65, 0xE3, 0x72, 0xFC, 0xD6, 0x8E, 0xF2, 0x0F, 0xA7, 0x11, 0x1F, 0x9E, 0x4A, 0xFF, 0x73,
+];
+
+const RFC5054_2048_G: [u8; 1] = [0x02];
+
+/// An SRP group: a safe prime `N` and a generator `g` modulo `N`.
+#[derive(Clone, Copy)]
+pub struct SrpGroup {
+    n: &'static [u8],
+    g: &'static [u8],
+    // The hash function `H`. Groups other than the SHA-256 ones are only constructed by the
+    // tests, which never derive a session key from them.
+    digest: &'static digest::Algorithm,
+}
+
+impl Debug for SrpGroup {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SrpGroup")
+            .field("bits", &(self.n.len() * 8))
+            .finish_non_exhaustive()
+    }
+}
+
+impl SrpGroup {
+    /// The 2048-bit group from [RFC 5054, Appendix A](https://www.rfc-editor.org/rfc/rfc5054#appendix-A).
+    #[must_use]
+    pub fn rfc5054_2048() -> Self {
+        Self {
+            n: &RFC5054_2048_N,
+            g: &RFC5054_2048_G,
+            digest: &SHA256,
+        }
+    }
+
+    /// Computes the password verifier `v = g^x % N` where
+    /// `x = H(salt | H(username | ":" | password))`.
+    ///
+    /// The verifier, together with `salt`, is what a server stores for `username`.
+    /// The returned value is left-padded with zeros to the length of `N`.
+    ///
+    /// # Errors
+    /// `error::Unspecified` when operation fails due to internal error.
+    pub fn compute_verifier(
+        &self,
+        username: &str,
+        password: &str,
+        salt: &[u8],
+    ) -> Result<Vec<u8>, Unspecified> {
+        let mut ctx = new_bn_ctx()?;
+        let n = self.modulus()?;
+        let g = self.generator()?;
+        let x = self.compute_x(username.as_bytes(), password.as_bytes(), salt)?;
+        let v = mod_exp_consttime(&g, &x, &n, &mut ctx)?;
+        self.pad(&v)
+    }
+
+    fn modulus(&self) -> Result<SecretBnPtr, Unspecified> {
+        Ok(SecretBnPtr::try_from(self.n)?)
+    }
+
+    fn generator(&self) -> Result<SecretBnPtr, Unspecified> {
+        Ok(SecretBnPtr::try_from(self.g)?)
+    }
+
+    fn hash(&self, parts: &[&[u8]]) -> digest::Digest {
+        let mut ctx = Context::new(self.digest);
+        for part in parts {
+            ctx.update(part);
+        }
+        ctx.finish()
+    }
+
+    // x = H(salt | H(username | ":" | password))
+    fn compute_x(
+        &self,
+        username: &[u8],
+        password: &[u8],
+        salt: &[u8],
+    ) -> Result<SecretBnPtr, Unspecified> {
+        let inner = self.hash(&[username, b":", password]);
+        Ok(SecretBnPtr::try_from(
+            self.hash(&[salt, inner.as_ref()]).as_ref(),
+        )?)
+    }
+
+    // k = H(N | PAD(g))
+    fn multiplier(&self) -> Result<SecretBnPtr, Unspecified> {
+        let g = self.pad(&self.generator()?)?;
+        let k = self.hash(&[self.n, &g]);
+        Ok(SecretBnPtr::try_from(k.as_ref())?)
+    }
+
+    // u = H(PAD(A) | PAD(B))
+    fn scrambler(&self, public_a: &[u8], public_b: &[u8]) -> Result<SecretBnPtr, Unspecified> {
+        let a = self.pad(&SecretBnPtr::try_from(public_a)?)?;
+        let b = self.pad(&SecretBnPtr::try_from(public_b)?)?;
+        let u = SecretBnPtr::try_from(self.hash(&[&a, &b]).as_ref())?;
+        if 1 == unsafe { BN_is_zero(*u.as_const()) } {
+            return Err(Unspecified);
+        }
+        Ok(u)
+    }
+
+    // Converts a value to a big-endian byte string with the length of `N`.
+    fn pad(&self, value: &SecretBnPtr) -> Result<Vec<u8>, Unspecified> {
+        let mut out = vec![0u8; self.n.len()];
+        if 1 != unsafe { BN_bn2bin_padded(out.as_mut_ptr(), out.len(), *value.as_const()) } {
+            return Err(Unspecified);
+        }
+        Ok(out)
+    }
+
+    // Parses a peer's public value, rejecting it if it is zero modulo `N`.
+    fn parse_public_value(
+        &self,
+        public: &[u8],
+        n: &SecretBnPtr,
+        ctx: &mut LcPtr<BN_CTX>,
+    ) -> Result<SecretBnPtr, Unspecified> {
+        if public.is_empty() || public.len() > self.n.len() {
+            return Err(Unspecified);
+        }
+        let value = SecretBnPtr::try_from(public)?;
+        let mut reduced = new_bn()?;
+        if 1 != unsafe {
+            BN_nnmod(
+                *reduced.as_mut(),
+                *value.as_const(),
+                *n.as_const(),
+                *ctx.as_mut(),
+            )
+        } {
+            return Err(Unspecified);
+        }
+        if 1 == unsafe { BN_is_zero(*reduced.as_const()) } {
+            return Err(Unspecified);
+        }
+        Ok(reduced)
+    }
+}
+
+/// The server side of an SRP-6a exchange.
+pub struct SrpServer {
+    group: SrpGroup,
+    verifier: Box<[u8]>,
+    private_b: Box<[u8]>,
+    public_b: Vec<u8>,
+}
+
+impl SrpServer {
+    /// Generates a new ephemeral server key for the user identified by `verifier`.
+    ///
+    /// The server public value is computed as `B = k*v + g^b % N`.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if `verifier` is not a valid value modulo `N`, or when
+    /// the operation fails due to internal error.
+    pub fn new(group: &SrpGroup, verifier: &[u8]) -> Result<Self, Unspecified> {
+        let mut private_b = [0u8; PRIVATE_EXPONENT_LEN];
+        let server = rand::fill(&mut private_b)
+            .and_then(|()| Self::with_private_b(group, verifier, &private_b));
+        private_b.zeroize();
+        server
+    }
+
+    fn with_private_b(
+        group: &SrpGroup,
+        verifier: &[u8],
+        private_b: &[u8],
+    ) -> Result<Self, Unspecified> {
+        let mut ctx = new_bn_ctx()?;
+        let n = group.modulus()?;
+        let v = group.parse_public_value(verifier, &n, &mut ctx)?;
+        let k = group.multiplier()?;
+        let b = SecretBnPtr::try_from(private_b)?;
+
+        let g_b = mod_exp_consttime(&group.generator()?, &b, &n, &mut ctx)?;
+        let k_v = mod_mul(&k, &v, &n, &mut ctx)?;
+        let mut public_b = new_bn()?;
+        if 1 != unsafe {
+            BN_mod_add(
+                *public_b.as_mut(),
+                *k_v.as_const(),
+                *g_b.as_const(),
+                *n.as_const(),
+                *ctx.as_mut(),
+            )
+        } {
+            return Err(Unspecified);
+        }
+
+        Ok(Self {
+            group: *group,
+            verifier: group.pad(&v)?.into_boxed_slice(),
+            private_b: private_b.into(),
+            public_b: group.pad(&public_b)?,
+        })
+    }
+
+    /// The server public value `B`, left-padded to the length of `N`.
+    #[must_use]
+    pub fn get_public_b(&self) -> Vec<u8> {
+        self.public_b.clone()
+    }
+
+    /// Computes the session key `K = H(PAD(S))` where `S = (A * v^u) ^ b % N`.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if `pub_a` is zero modulo `N`, if the scrambling
+    /// parameter `u` is zero, or when the operation fails due to internal error.
+    pub fn compute_key(&self, pub_a: &[u8]) -> Result<[u8; SESSION_KEY_LEN], Unspecified> {
+        session_key(&self.group, self.premaster_secret(pub_a)?)
+    }
+
+    // Computes PAD(S).
+    fn premaster_secret(&self, pub_a: &[u8]) -> Result<Vec<u8>, Unspecified> {
+        let mut ctx = new_bn_ctx()?;
+        let n = self.group.modulus()?;
+        let a = self.group.parse_public_value(pub_a, &n, &mut ctx)?;
+        let u = self.group.scrambler(pub_a, &self.public_b)?;
+        let v = SecretBnPtr::try_from(self.verifier.as_ref())?;
+        let b = SecretBnPtr::try_from(self.private_b.as_ref())?;
+
+        let v_u = mod_exp_consttime(&v, &u, &n, &mut ctx)?;
+        let base = mod_mul(&a, &v_u, &n, &mut ctx)?;
+        let s = mod_exp_consttime(&base, &b, &n, &mut ctx)?;
+
+        self.group.pad(&s)
+    }
+}
+
+impl Drop for SrpServer {
+    fn drop(&mut self) {
+        self.private_b.zeroize();
+        self.verifier.zeroize();
+    }
+}
+
+impl Debug for SrpServer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SrpServer")
+            .field("group", &self.group)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The client side of an SRP-6a exchange.
+pub struct SrpClient {
+    group: SrpGroup,
+    username: Box<[u8]>,
+    password: Box<[u8]>,
+    private_a: Box<[u8]>,
+    public_a: Vec<u8>,
+}
+
+impl SrpClient {
+    /// Generates a new ephemeral client key `A = g^a % N` for `username`.
+    ///
+    /// # Errors
+    /// `error::Unspecified` when operation fails due to internal error.
+    pub fn new(group: &SrpGroup, username: &str, password: &str) -> Result<Self, Unspecified> {
+        let mut private_a = [0u8; PRIVATE_EXPONENT_LEN];
+        let client = rand::fill(&mut private_a)
+            .and_then(|()| Self::with_private_a(group, username, password, &private_a));
+        private_a.zeroize();
+        client
+    }
+
+    fn with_private_a(
+        group: &SrpGroup,
+        username: &str,
+        password: &str,
+        private_a: &[u8],
+    ) -> Result<Self, Unspecified> {
+        let mut ctx = new_bn_ctx()?;
+        let n = group.modulus()?;
+        let a = SecretBnPtr::try_from(private_a)?;
+        let public_a = mod_exp_consttime(&group.generator()?, &a, &n, &mut ctx)?;
+
+        Ok(Self {
+            group: *group,
+            username: username.as_bytes().into(),
+            password: password.as_bytes().into(),
+            private_a: private_a.into(),
+            public_a: group.pad(&public_a)?,
+        })
+    }
+
+    /// The client public value `A`, left-padded to the length of `N`.
+    #[must_use]
+    pub fn get_public_a(&self) -> Vec<u8> {
+        self.public_a.clone()
+    }
+
+    /// Computes the session key `K = H(PAD(S))` where
+    /// `S = (B - (k * g^x)) ^ (a + (u * x)) % N`.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if `pub_b` is zero modulo `N`, if the scrambling
+    /// parameter `u` is zero, or when the operation fails due to internal error.
+    pub fn compute_key(
+        &self,
+        pub_b: &[u8],
+        salt: &[u8],
+    ) -> Result<[u8; SESSION_KEY_LEN], Unspecified> {
+        session_key(&self.group, self.premaster_secret(pub_b, salt)?)
+    }
+
+    // Computes PAD(S).
+    fn premaster_secret(&self, pub_b: &[u8], salt: &[u8]) -> Result<Vec<u8>, Unspecified> {
+        let mut ctx = new_bn_ctx()?;
+        let n = self.group.modulus()?;
+        let g = self.group.generator()?;
+        let b = self.group.parse_public_value(pub_b, &n, &mut ctx)?;
+        let u = self.group.scrambler(&self.public_a, pub_b)?;
+        let k = self.group.multiplier()?;
+        let a = SecretBnPtr::try_from(self.private_a.as_ref())?;
+        let x = self.group.compute_x(&self.username, &self.password, salt)?;
+
+        // base = B - k * g^x
+        let g_x = mod_exp_consttime(&g, &x, &n, &mut ctx)?;
+        let k_g_x = mod_mul(&k, &g_x, &n, &mut ctx)?;
+        let mut base = new_bn()?;
+        if 1 != unsafe {
+            BN_mod_sub(
+                *base.as_mut(),
+                *b.as_const(),
+                *k_g_x.as_const(),
+                *n.as_const(),
+                *ctx.as_mut(),
+            )
+        } {
+            return Err(Unspecified);
+        }
+
+        // exponent = a + u * x
+        let mut u_x = new_bn()?;
+        if 1 != unsafe { BN_mul(*u_x.as_mut(), *u.as_const(), *x.as_const(), *ctx.as_mut()) } {
+            return Err(Unspecified);
+        }
+        let mut exponent = new_bn()?;
+        if 1 != unsafe { BN_add(*exponent.as_mut(), *a.as_const(), *u_x.as_const()) } {
+            return Err(Unspecified);
+        }
+
+        let s = mod_exp_consttime(&base, &exponent, &n, &mut ctx)?;
+
+        self.group.pad(&s)
+    }
+}
+
+impl Drop for SrpClient {
+    fn drop(&mut self) {
+        self.private_a.zeroize();
+        self.password.zeroize();
+    }
+}
+
+impl Debug for SrpClient {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SrpClient")
+            .field("group", &self.group)
+            .finish_non_exhaustive()
+    }
+}
+
+// K = H(PAD(S))
+fn session_key(
+    group: &SrpGroup,
+    mut premaster_secret: Vec<u8>,
+) -> Result<[u8; SESSION_KEY_LEN], Unspecified> {
+    let mut key = [0u8; SESSION_KEY_LEN];
+    key.copy_from_slice(group.hash(&[&premaster_secret]).as_ref());
+    premaster_secret.zeroize();
+    Ok(key)
+}
+
+// Every `BIGNUM` in an exchange is derived from, or combined with, secret values, so all of
+// them are released with `BN_clear_free`.
+fn new_bn() -> Result<SecretBnPtr, Unspecified> {
+    Ok(SecretBnPtr::new(unsafe { BN_new() })?)
+}
+
+fn new_bn_ctx() -> Result<LcPtr<BN_CTX>, Unspecified> {
+    Ok(LcPtr::new(unsafe { BN_CTX_new() })?)
+}
+
+fn mod_mul(
+    a: &SecretBnPtr,
+    b: &SecretBnPtr,
+    n: &SecretBnPtr,
+    ctx: &mut LcPtr<BN_CTX>,
+) -> Result<SecretBnPtr, Unspecified> {
+    let mut r = new_bn()?;
+    if 1 != unsafe {
+        BN_mod_mul(
+            *r.as_mut(),
+            *a.as_const(),
+            *b.as_const(),
+            *n.as_const(),
+            *ctx.as_mut(),
+        )
+    } {
+        return Err(Unspecified);
+    }
+    Ok(r)
+}
+
+// Computes `base^exponent % n`. `base` must already be reduced modulo `n`.
+fn mod_exp_consttime(
+    base: &SecretBnPtr,
+    exponent: &SecretBnPtr,
+    n: &SecretBnPtr,
+    ctx: &mut LcPtr<BN_CTX>,
+) -> Result<SecretBnPtr, Unspecified> {
+    let mut r = new_bn()?;
+    if 1 != unsafe {
+        BN_mod_exp_mont_consttime(
+            *r.as_mut(),
+            *base.as_const(),
+            *exponent.as_const(),
+            *n.as_const(),
+            *ctx.as_mut(),
+            null_mut(),
+        )
+    } {
+        return Err(Unspecified);
+    }
+    Ok(r)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::digest::SHA1_FOR_LEGACY_USE_ONLY;
+    use crate::srp::{SrpClient, SrpGroup, SrpServer};
+    use crate::test::from_dirty_hex;
+
+    // Inputs from RFC 5054, Appendix B.
+    const USERNAME: &str = "alice";
+    const PASSWORD: &str = "password123";
+    const SALT: &str = "BEB25379 D1A8581E B5A72767 3A2441EE";
+
+    // RFC 5054, Appendix A: 1024-bit Group. Appendix B uses it with `H = SHA-1`.
+    static RFC5054_1024_N: [u8; 128] = [
+        0xEE, 0xAF, 0x0A, 0xB9, 0xAD, 0xB3, 0x8D, 0xD6, 0x9C, 0x33, 0xF8, 0x0A, 0xFA, 0x8F, 0xC5,
+        0xE8, 0x60, 0x72, 0x61, 0x87, 0x75, 0xFF, 0x3C, 0x0B, 0x9E, 0xA2, 0x31, 0x4C, 0x9C, 0x25,
+        0x65, 0x76, 0xD6, 0x74, 0xDF, 0x74, 0x96, 0xEA, 0x81, 0xD3, 0x38, 0x3B, 0x48, 0x13, 0xD6,
+        0x92, 0xC6, 0xE0, 0xE0, 0xD5, 0xD8, 0xE2, 0x50, 0xB9, 0x8B, 0xE4, 0x8E, 0x49, 0x5C, 0x1D,
+        0x60, 0x89, 0xDA, 0xD1, 0x5D, 0xC7, 0xD7, 0xB4, 0x61, 0x54, 0xD6, 0xB6, 0xCE, 0x8E, 0xF4,
+        0xAD, 0x69, 0xB1, 0x5D, 0x49, 0x82, 0x55, 0x9B, 0x29, 0x7B, 0xCF, 0x18, 0x85, 0xC5, 0x29,
+        0xF5, 0x66, 0x66, 0x0E, 0x57, 0xEC, 0x68, 0xED, 0xBC, 0x3C, 0x05, 0x72, 0x6C, 0xC0, 0x2F,
+        0xD4, 0xCB, 0xF4, 0x97, 0x6E, 0xAA, 0x9A, 0xFD, 0x51, 0x38, 0xFE, 0x83, 0x76, 0x43, 0x5B,
+        0x9F, 0xC6, 0x1D, 0x2F, 0xC0, 0xEB, 0x06, 0xE3,
+    ];
+
+    // Expected values from RFC 5054, Appendix B.
+    const PRIVATE_A: &str =
+        "60975527 035CF2AD 1989806F 0407210B C81EDC04 E2762A56 AFD529DD DA2D4393";
+    const PRIVATE_B: &str =
+        "E487CB59 D31AC550 471E81F0 0F6928E0 1DDA08E9 74A004F4 9E61F5D1 05284D20";
+    const K: &str = "7556AA04 5AEF2CDD 07ABAF0F 665C3E81 8913186F";
+    const X: &str = "94B7555A ABE9127C C58CCF49 93DB6CF8 4D16C124";
+    const V: &str = "7E273DE8 696FFC4F 4E337D05 B4B375BE B0DDE156 9E8FA00A 9886D812 9BADA1F1\
+         822223CA 1A605B53 0E379BA4 729FDC59 F105B478 7E5186F5 C671085A 1447B52A\
+         48CF1970 B4FB6F84 00BBF4CE BFBB1681 52E08AB5 EA53D15C 1AFF87B2 B9DA6E04\
+         E058AD51 CC72BFC9 033B564E 26480D78 E955A5E2 9E7AB245 DB2BE315 E2099AFB";
+    const PUBLIC_A: &str =
+        "61D5E490 F6F1B795 47B0704C 436F523D D0E560F0 C64115BB 72557EC4 4352E890\
+         3211C046 92272D8B 2D1A5358 A2CF1B6E 0BFCF99F 921530EC 8E393561 79EAE45E\
+         42BA92AE ACED8251 71E1E8B9 AF6D9C03 E1327F44 BE087EF0 6530E69F 66615261\
+         EEF54073 CA11CF58 58F0EDFD FE15EFEA B349EF5D 76988A36 72FAC47B 0769447B";
+    const PUBLIC_B: &str =
+        "BD0C6151 2C692C0C B6D041FA 01BB152D 4916A1E7 7AF46AE1 05393011 BAF38964\
+         DC46A067 0DD125B9 5A981652 236F99D9 B681CBF8 7837EC99 6C6DA044 53728610\
+         D0C6DDB5 8B318885 D7D82C7F 8DEB75CE 7BD4FBAA 37089E6F 9C6059F3 88838E7A\
+         00030B33 1EB76840 910440B1 B27AAEAE EB4012B7 D7665238 A8E3FB00 4B117B58";
+    const U: &str = "CE38B959 3487DA98 554ED47D 70A7AE5F 462EF019";
+    const PREMASTER_SECRET: &str =
+        "B0DC82BA BCF30674 AE450C02 87745E79 90A3381F 63B387AA F271A10D 233861E3\
+         59B48220 F7C4693C 9AE12B0A 6F67809F 0876E2D0 13800D6C 41BB59B6 D5979B5C\
+         00A172B4 A2A5903A 0BDCAF8A 709585EB 2AFAFA8F 3499B200 210DCC1F 10EB3394\
+         3CD67FC8 8A2F39A4 BE5BEC4E C0A3212D C346D7E4 74B29EDE 8A469FFE CA686E5A";
+
+    fn rfc5054_1024_sha1() -> SrpGroup {
+        SrpGroup {
+            n: &RFC5054_1024_N,
+            g: &[0x02],
+            digest: &SHA1_FOR_LEGACY_USE_ONLY,
+        }
+    }
+
+    #[test]
+    fn srp_rfc5054_appendix_b() {
+        let group = rfc5054_1024_sha1();
+        let salt = from_dirty_hex(SALT);
+
+        let k = group.multiplier().unwrap();
+        assert_eq!(from_dirty_hex(K), k.as_const().to_be_bytes());
+        let x = group
+            .compute_x(USERNAME.as_bytes(), PASSWORD.as_bytes(), &salt)
+            .unwrap();
+        assert_eq!(from_dirty_hex(X), x.as_const().to_be_bytes());
+
+        let verifier = group.compute_verifier(USERNAME, PASSWORD, &salt).unwrap();
+        assert_eq!(from_dirty_hex(V), verifier);
+
+        let client =
+            SrpClient::with_private_a(&group, USERNAME, PASSWORD, &from_dirty_hex(PRIVATE_A))
+                .unwrap();
+        assert_eq!(from_dirty_hex(PUBLIC_A), client.get_public_a());
+
+        let server =
+            SrpServer::with_private_b(&group, &verifier, &from_dirty_hex(PRIVATE_B)).unwrap();
+        assert_eq!(from_dirty_hex(PUBLIC_B), server.get_public_b());
+
+        let u = group
+            .scrambler(&client.get_public_a(), &server.get_public_b())
+            .unwrap();
+        assert_eq!(from_dirty_hex(U), u.as_const().to_be_bytes());
+
+        let expected = from_dirty_hex(PREMASTER_SECRET);
+        assert_eq!(
+            expected,
+            client
+                .premaster_secret(&server.get_public_b(), &salt)
+                .unwrap()
+        );
+        assert_eq!(
+            expected,
+            server.premaster_secret(&client.get_public_a()).unwrap()
+        );
+    }
+
+    #[test]
+    fn srp_key_agreement() {
+        let group = SrpGroup::rfc5054_2048();
+        let salt = from_dirty_hex(SALT);
+        let verifier = group.compute_verifier(USERNAME, PASSWORD, &salt).unwrap();
+        assert_eq!(256, verifier.len());
+
+        let client = SrpClient::new(&group, USERNAME, PASSWORD).unwrap();
+        let server = SrpServer::new(&group, &verifier).unwrap();
+
+        let client_key = client.compute_key(&server.get_public_b(), &salt).unwrap();
+        let server_key = server.compute_key(&client.get_public_a()).unwrap();
+        assert_eq!(client_key, server_key);
+    }
+
+    #[test]
+    fn srp_wrong_password() {
+        let group = SrpGroup::rfc5054_2048();
+        let salt = from_dirty_hex(SALT);
+        let verifier = group.compute_verifier(USERNAME, PASSWORD, &salt).unwrap();
+
+        let client = SrpClient::new(&group, USERNAME, "password124").unwrap();
+        let server = SrpServer::new(&group, &verifier).unwrap();
+
+        let client_key = client.compute_key(&server.get_public_b(), &salt).unwrap();
+        let server_key = server.compute_key(&client.get_public_a()).unwrap();
+        assert_ne!(client_key, server_key);
+    }
+
+    #[test]
+    fn srp_rejects_zero_public_values() {
+        let group = SrpGroup::rfc5054_2048();
+        let salt = from_dirty_hex(SALT);
+        let verifier = group.compute_verifier(USERNAME, PASSWORD, &salt).unwrap();
+
+        let client = SrpClient::new(&group, USERNAME, PASSWORD).unwrap();
+        let server = SrpServer::new(&group, &verifier).unwrap();
+
+        assert!(client.compute_key(&[0u8; 256], &salt).is_err());
+        assert!(client.compute_key(group.n, &salt).is_err());
+        assert!(server.compute_key(&[0u8; 256]).is_err());
+        assert!(server.compute_key(group.n).is_err());
+        assert!(server.compute_key(&[]).is_err());
+    }
+}
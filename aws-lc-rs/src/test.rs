@@ -177,10 +177,15 @@ impl TestCase {
             "SHA256" => Some(&digest::SHA256),
             "SHA384" => Some(&digest::SHA384),
             "SHA512" => Some(&digest::SHA512),
+            "SHA512_224" => Some(&digest::SHA512_224),
             "SHA512_256" => Some(&digest::SHA512_256),
             "SHA3_256" => Some(&digest::SHA3_256),
             "SHA3_384" => Some(&digest::SHA3_384),
             "SHA3_512" => Some(&digest::SHA3_512),
+            #[cfg(not(feature = "fips"))]
+            "BLAKE2B_256" => Some(&digest::BLAKE2B_256),
+            #[cfg(all(feature = "legacy", not(feature = "fips")))]
+            "MD5" => Some(&digest::MD5_FOR_LEGACY_USE_ONLY),
             _ => unreachable!("Unsupported digest algorithm: {}", name),
         }
     }
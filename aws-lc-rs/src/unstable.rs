@@ -9,8 +9,13 @@
 //! # ⚠️ Warning
 //! Features contained within this module, or child modules are subject to changes, relocation,
 //! or removal across minor releases, and thus are not subject to semantic versioning policies.
+pub mod hpke;
+
 #[deprecated(note = "use  `aws_lc_rs::kdf` instead")]
 pub mod kdf;
 
 #[deprecated(note = "use `aws_lc_rs::kem` instead")]
 pub mod kem;
+
+#[cfg(not(feature = "fips"))]
+pub mod signature;
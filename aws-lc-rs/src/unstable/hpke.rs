@@ -0,0 +1,499 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+//! Hybrid Public Key Encryption (HPKE), as specified in
+//! [RFC 9180](https://www.rfc-editor.org/rfc/rfc9180).
+//!
+//! A sender sets up a [`HpkeSenderContext`] with the recipient's public key, and transmits the
+//! resulting [`EncapsulatedKey`] to the recipient, who sets up the matching
+//! [`HpkeRecipientContext`] with its [`PrivateKey`]. Messages sealed by the sender can then be
+//! opened by the recipient, in the order they were sealed.
+//!
+//! # Supported algorithms
+//!
+//! * KEM: [`DHKEM_X25519_HKDF_SHA256`]
+//! * KDF: [`HKDF_SHA256`]
+//! * AEAD: [`AES_128_GCM`], [`AES_256_GCM`], [`CHACHA20_POLY1305`]
+//!
+//! These are the only algorithms provided by AWS-LC's HPKE implementation (`openssl/hpke.h`), so
+//! DHKEM(P-256, HKDF-SHA256), HKDF-SHA384 and HKDF-SHA512 are not available. Of the four modes,
+//! `Base` and `Auth` are supported; AWS-LC does not implement the `PSK` and `AuthPSK` modes.
+//!
+//! # Example
+//!
+//! ```
+//! # use aws_lc_rs::error::Unspecified;
+//! use aws_lc_rs::unstable::hpke::{
+//!     HpkeRecipientContext, HpkeSenderContext, PrivateKey, RecipientMode, SenderMode,
+//!     AES_128_GCM, DHKEM_X25519_HKDF_SHA256, HKDF_SHA256,
+//! };
+//!
+//! let recipient_key = PrivateKey::generate(&DHKEM_X25519_HKDF_SHA256)?;
+//! let info = b"example application";
+//!
+//! let (mut sender, encapsulated_key) = HpkeSenderContext::new(
+//!     SenderMode::Base,
+//!     &DHKEM_X25519_HKDF_SHA256,
+//!     &HKDF_SHA256,
+//!     &AES_128_GCM,
+//!     recipient_key.public_key(),
+//!     info,
+//! )?;
+//! let ciphertext = sender.seal(b"hello", b"aad")?;
+//!
+//! let mut recipient = HpkeRecipientContext::new(
+//!     RecipientMode::Base,
+//!     &DHKEM_X25519_HKDF_SHA256,
+//!     &HKDF_SHA256,
+//!     &AES_128_GCM,
+//!     &recipient_key,
+//!     encapsulated_key.as_ref(),
+//!     info,
+//! )?;
+//! assert_eq!(b"hello", recipient.open(&ciphertext, b"aad")?.as_slice());
+//! # Ok::<(), Unspecified>(())
+//! ```
+
+use crate::aws_lc::{
+    EVP_HPKE_CTX_export, EVP_HPKE_CTX_max_overhead, EVP_HPKE_CTX_new, EVP_HPKE_CTX_open,
+    EVP_HPKE_CTX_seal, EVP_HPKE_CTX_setup_auth_recipient, EVP_HPKE_CTX_setup_auth_sender,
+    EVP_HPKE_CTX_setup_recipient, EVP_HPKE_CTX_setup_sender, EVP_HPKE_KEM_enc_len,
+    EVP_HPKE_KEM_public_key_len, EVP_HPKE_KEY_generate, EVP_HPKE_KEY_init, EVP_HPKE_KEY_new,
+    EVP_HPKE_KEY_public_key, EVP_hpke_aes_128_gcm, EVP_hpke_aes_256_gcm,
+    EVP_hpke_chacha20_poly1305, EVP_hpke_hkdf_sha256, EVP_hpke_x25519_hkdf_sha256, EVP_HPKE_AEAD,
+    EVP_HPKE_CTX, EVP_HPKE_KDF, EVP_HPKE_KEM, EVP_HPKE_KEY,
+};
+use crate::error::{KeyRejected, Unspecified};
+use crate::ptr::LcPtr;
+use core::fmt::{Debug, Formatter};
+
+/// An HPKE key encapsulation mechanism (KEM).
+pub struct Kem {
+    id: u16,
+    kem: unsafe extern "C" fn() -> *const EVP_HPKE_KEM,
+}
+
+/// DHKEM(X25519, HKDF-SHA256)
+pub static DHKEM_X25519_HKDF_SHA256: Kem = Kem {
+    id: 0x0020,
+    kem: EVP_hpke_x25519_hkdf_sha256,
+};
+
+/// An HPKE key derivation function (KDF).
+pub struct Kdf {
+    id: u16,
+    kdf: unsafe extern "C" fn() -> *const EVP_HPKE_KDF,
+}
+
+/// HKDF-SHA256
+pub static HKDF_SHA256: Kdf = Kdf {
+    id: 0x0001,
+    kdf: EVP_hpke_hkdf_sha256,
+};
+
+/// An HPKE authenticated encryption with associated data (AEAD) algorithm.
+pub struct Aead {
+    id: u16,
+    aead: unsafe extern "C" fn() -> *const EVP_HPKE_AEAD,
+}
+
+/// AES-128-GCM
+pub static AES_128_GCM: Aead = Aead {
+    id: 0x0001,
+    aead: EVP_hpke_aes_128_gcm,
+};
+
+/// AES-256-GCM
+pub static AES_256_GCM: Aead = Aead {
+    id: 0x0002,
+    aead: EVP_hpke_aes_256_gcm,
+};
+
+/// ChaCha20Poly1305
+pub static CHACHA20_POLY1305: Aead = Aead {
+    id: 0x0003,
+    aead: EVP_hpke_chacha20_poly1305,
+};
+
+macro_rules! hpke_algorithm {
+    ($ty:ident, $field:ident, $evp_ty:ty) => {
+        impl $ty {
+            /// The algorithm's identifier, as registered in the IANA "HPKE" registry.
+            #[must_use]
+            pub fn id(&self) -> u16 {
+                self.id
+            }
+
+            fn $field(&self) -> *const $evp_ty {
+                unsafe { (self.$field)() }
+            }
+        }
+
+        impl PartialEq for $ty {
+            fn eq(&self, other: &Self) -> bool {
+                self.id == other.id
+            }
+        }
+
+        impl Eq for $ty {}
+
+        impl Debug for $ty {
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                f.debug_struct(stringify!($ty))
+                    .field("id", &self.id)
+                    .finish()
+            }
+        }
+    };
+}
+
+hpke_algorithm!(Kem, kem, EVP_HPKE_KEM);
+hpke_algorithm!(Kdf, kdf, EVP_HPKE_KDF);
+hpke_algorithm!(Aead, aead, EVP_HPKE_AEAD);
+
+/// An HPKE private key, for use by a recipient, or by a sender in `Auth` mode.
+pub struct PrivateKey {
+    kem: &'static Kem,
+    key: LcPtr<EVP_HPKE_KEY>,
+    public_key: Box<[u8]>,
+}
+
+impl PrivateKey {
+    /// Generates a new private key for `kem`.
+    ///
+    /// # Errors
+    /// `Unspecified` if the key could not be generated.
+    pub fn generate(kem: &'static Kem) -> Result<Self, Unspecified> {
+        let mut key = LcPtr::new(unsafe { EVP_HPKE_KEY_new() })?;
+        if 1 != unsafe { EVP_HPKE_KEY_generate(*key.as_mut(), kem.kem()) } {
+            return Err(Unspecified);
+        }
+        Self::new(kem, key)
+    }
+
+    /// Constructs a private key for `kem` from its serialized form, as defined by
+    /// `SerializePrivateKey` in RFC 9180.
+    ///
+    /// # Errors
+    /// `KeyRejected` if `private_key` is not a valid private key for `kem`.
+    pub fn from_private_key(kem: &'static Kem, private_key: &[u8]) -> Result<Self, KeyRejected> {
+        let mut key = LcPtr::new(unsafe { EVP_HPKE_KEY_new() })?;
+        if 1 != unsafe {
+            EVP_HPKE_KEY_init(
+                *key.as_mut(),
+                kem.kem(),
+                private_key.as_ptr(),
+                private_key.len(),
+            )
+        } {
+            return Err(KeyRejected::invalid_encoding());
+        }
+        Ok(Self::new(kem, key)?)
+    }
+
+    fn new(kem: &'static Kem, key: LcPtr<EVP_HPKE_KEY>) -> Result<Self, Unspecified> {
+        let mut public_key = vec![0u8; unsafe { EVP_HPKE_KEM_public_key_len(kem.kem()) }];
+        let mut public_key_len = 0;
+        if 1 != unsafe {
+            EVP_HPKE_KEY_public_key(
+                *key.as_const(),
+                public_key.as_mut_ptr(),
+                &mut public_key_len,
+                public_key.len(),
+            )
+        } {
+            return Err(Unspecified);
+        }
+        public_key.truncate(public_key_len);
+        Ok(Self {
+            kem,
+            key,
+            public_key: public_key.into_boxed_slice(),
+        })
+    }
+
+    /// The KEM this key is used with.
+    #[must_use]
+    pub fn kem(&self) -> &'static Kem {
+        self.kem
+    }
+
+    /// The serialized public key, as defined by `SerializePublicKey` in RFC 9180.
+    #[must_use]
+    pub fn public_key(&self) -> &[u8] {
+        &self.public_key
+    }
+}
+
+unsafe impl Send for PrivateKey {}
+unsafe impl Sync for PrivateKey {}
+
+impl Debug for PrivateKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PrivateKey")
+            .field("kem", self.kem)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The HPKE mode of a sender.
+#[non_exhaustive]
+#[derive(Debug)]
+pub enum SenderMode<'a> {
+    /// `mode_base`: the sender is not authenticated.
+    Base,
+    /// `mode_auth`: the sender is authenticated by its private key.
+    Auth(&'a PrivateKey),
+}
+
+/// The HPKE mode of a recipient.
+#[non_exhaustive]
+#[derive(Debug)]
+pub enum RecipientMode<'a> {
+    /// `mode_base`: the sender is not authenticated.
+    Base,
+    /// `mode_auth`: the sender is authenticated by its serialized public key.
+    Auth(&'a [u8]),
+}
+
+/// The encapsulated key produced by the sender, to be transmitted to the recipient.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EncapsulatedKey(Box<[u8]>);
+
+impl AsRef<[u8]> for EncapsulatedKey {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// The sender's HPKE context, used to seal messages for the recipient.
+pub struct HpkeSenderContext {
+    ctx: LcPtr<EVP_HPKE_CTX>,
+}
+
+impl HpkeSenderContext {
+    /// Sets up a sender context for the recipient's serialized `recipient_public_key`.
+    ///
+    /// Returns the context, and the encapsulated key which must be transmitted to the recipient.
+    ///
+    /// # Errors
+    /// `Unspecified` if `recipient_public_key` is invalid, if the sender's private key in `mode`
+    /// is not a key for `kem`, or if the setup fails.
+    pub fn new(
+        mode: SenderMode<'_>,
+        kem: &'static Kem,
+        kdf: &'static Kdf,
+        aead: &'static Aead,
+        recipient_public_key: &[u8],
+        info: &[u8],
+    ) -> Result<(Self, EncapsulatedKey), Unspecified> {
+        let mut ctx = LcPtr::new(unsafe { EVP_HPKE_CTX_new() })?;
+        let mut enc = vec![0u8; unsafe { EVP_HPKE_KEM_enc_len(kem.kem()) }];
+        let mut enc_len = 0;
+        let result = match mode {
+            SenderMode::Base => unsafe {
+                EVP_HPKE_CTX_setup_sender(
+                    *ctx.as_mut(),
+                    enc.as_mut_ptr(),
+                    &mut enc_len,
+                    enc.len(),
+                    kem.kem(),
+                    kdf.kdf(),
+                    aead.aead(),
+                    recipient_public_key.as_ptr(),
+                    recipient_public_key.len(),
+                    info.as_ptr(),
+                    info.len(),
+                )
+            },
+            SenderMode::Auth(sender_key) => {
+                if sender_key.kem != kem {
+                    return Err(Unspecified);
+                }
+                unsafe {
+                    EVP_HPKE_CTX_setup_auth_sender(
+                        *ctx.as_mut(),
+                        enc.as_mut_ptr(),
+                        &mut enc_len,
+                        enc.len(),
+                        *sender_key.key.as_const(),
+                        kdf.kdf(),
+                        aead.aead(),
+                        recipient_public_key.as_ptr(),
+                        recipient_public_key.len(),
+                        info.as_ptr(),
+                        info.len(),
+                    )
+                }
+            }
+        };
+        if 1 != result {
+            return Err(Unspecified);
+        }
+        enc.truncate(enc_len);
+        Ok((Self { ctx }, EncapsulatedKey(enc.into_boxed_slice())))
+    }
+
+    /// Encrypts and authenticates `plaintext` and `aad`, returning the ciphertext.
+    ///
+    /// # Errors
+    /// `Unspecified` if the message limit of the context has been reached, or if sealing fails.
+    pub fn seal(&mut self, plaintext: &[u8], aad: &[u8]) -> Result<Vec<u8>, Unspecified> {
+        let max_overhead = unsafe { EVP_HPKE_CTX_max_overhead(*self.ctx.as_const()) };
+        let mut ciphertext = vec![0u8; plaintext.len() + max_overhead];
+        let mut ciphertext_len = 0;
+        if 1 != unsafe {
+            EVP_HPKE_CTX_seal(
+                *self.ctx.as_mut(),
+                ciphertext.as_mut_ptr(),
+                &mut ciphertext_len,
+                ciphertext.len(),
+                plaintext.as_ptr(),
+                plaintext.len(),
+                aad.as_ptr(),
+                aad.len(),
+            )
+        } {
+            return Err(Unspecified);
+        }
+        ciphertext.truncate(ciphertext_len);
+        Ok(ciphertext)
+    }
+
+    /// Fills `out` with a secret derived from the context and `exporter_context`.
+    ///
+    /// # Errors
+    /// `Unspecified` if `out` is too long for the KDF.
+    pub fn export(&self, exporter_context: &[u8], out: &mut [u8]) -> Result<(), Unspecified> {
+        export(&self.ctx, exporter_context, out)
+    }
+}
+
+unsafe impl Send for HpkeSenderContext {}
+
+impl Debug for HpkeSenderContext {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("HpkeSenderContext").finish_non_exhaustive()
+    }
+}
+
+/// The recipient's HPKE context, used to open messages from the sender.
+pub struct HpkeRecipientContext {
+    ctx: LcPtr<EVP_HPKE_CTX>,
+}
+
+impl HpkeRecipientContext {
+    /// Sets up a recipient context from the `encapsulated_key` received from the sender.
+    ///
+    /// # Errors
+    /// `Unspecified` if `recipient_private_key` is not a key for `kem`, if `encapsulated_key` or
+    /// the sender's public key in `mode` is invalid, or if the setup fails.
+    pub fn new(
+        mode: RecipientMode<'_>,
+        kem: &'static Kem,
+        kdf: &'static Kdf,
+        aead: &'static Aead,
+        recipient_private_key: &PrivateKey,
+        encapsulated_key: &[u8],
+        info: &[u8],
+    ) -> Result<Self, Unspecified> {
+        if recipient_private_key.kem != kem {
+            return Err(Unspecified);
+        }
+        let mut ctx = LcPtr::new(unsafe { EVP_HPKE_CTX_new() })?;
+        let result = match mode {
+            RecipientMode::Base => unsafe {
+                EVP_HPKE_CTX_setup_recipient(
+                    *ctx.as_mut(),
+                    *recipient_private_key.key.as_const(),
+                    kdf.kdf(),
+                    aead.aead(),
+                    encapsulated_key.as_ptr(),
+                    encapsulated_key.len(),
+                    info.as_ptr(),
+                    info.len(),
+                )
+            },
+            RecipientMode::Auth(sender_public_key) => unsafe {
+                EVP_HPKE_CTX_setup_auth_recipient(
+                    *ctx.as_mut(),
+                    *recipient_private_key.key.as_const(),
+                    kdf.kdf(),
+                    aead.aead(),
+                    encapsulated_key.as_ptr(),
+                    encapsulated_key.len(),
+                    info.as_ptr(),
+                    info.len(),
+                    sender_public_key.as_ptr(),
+                    sender_public_key.len(),
+                )
+            },
+        };
+        if 1 != result {
+            return Err(Unspecified);
+        }
+        Ok(Self { ctx })
+    }
+
+    /// Authenticates and decrypts `ciphertext` and `aad`, returning the plaintext.
+    ///
+    /// # Errors
+    /// `Unspecified` if the ciphertext is not authentic, or if the message limit of the context
+    /// has been reached.
+    pub fn open(&mut self, ciphertext: &[u8], aad: &[u8]) -> Result<Vec<u8>, Unspecified> {
+        let mut plaintext = vec![0u8; ciphertext.len()];
+        let mut plaintext_len = 0;
+        if 1 != unsafe {
+            EVP_HPKE_CTX_open(
+                *self.ctx.as_mut(),
+                plaintext.as_mut_ptr(),
+                &mut plaintext_len,
+                plaintext.len(),
+                ciphertext.as_ptr(),
+                ciphertext.len(),
+                aad.as_ptr(),
+                aad.len(),
+            )
+        } {
+            return Err(Unspecified);
+        }
+        plaintext.truncate(plaintext_len);
+        Ok(plaintext)
+    }
+
+    /// Fills `out` with a secret derived from the context and `exporter_context`.
+    ///
+    /// # Errors
+    /// `Unspecified` if `out` is too long for the KDF.
+    pub fn export(&self, exporter_context: &[u8], out: &mut [u8]) -> Result<(), Unspecified> {
+        export(&self.ctx, exporter_context, out)
+    }
+}
+
+unsafe impl Send for HpkeRecipientContext {}
+
+impl Debug for HpkeRecipientContext {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("HpkeRecipientContext")
+            .finish_non_exhaustive()
+    }
+}
+
+fn export(
+    ctx: &LcPtr<EVP_HPKE_CTX>,
+    exporter_context: &[u8],
+    out: &mut [u8],
+) -> Result<(), Unspecified> {
+    if 1 != unsafe {
+        EVP_HPKE_CTX_export(
+            *ctx.as_const(),
+            out.as_mut_ptr(),
+            out.len(),
+            exporter_context.as_ptr(),
+            exporter_context.len(),
+        )
+    } {
+        return Err(Unspecified);
+    }
+    Ok(())
+}
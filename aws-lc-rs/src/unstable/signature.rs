@@ -0,0 +1,353 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+//! ML-DSA post-quantum digital signatures, as specified in
+//! [FIPS 204](https://doi.org/10.6028/NIST.FIPS.204).
+//!
+//! ML-DSA signatures are far larger than the fixed-capacity [`crate::signature::Signature`], so
+//! [`MlDsaKeyPair::sign`] writes the signature to a caller-provided buffer, as
+//! [`crate::signature::RsaKeyPair::sign`] does. Verification uses the usual
+//! [`UnparsedPublicKey`](crate::signature::UnparsedPublicKey) with one of the
+//! [`MlDsaVerificationAlgorithm`]s.
+//!
+//! ML-DSA is not yet available from the FIPS module, so this module is unavailable when the
+//! "fips" feature is enabled.
+//!
+//! # Example
+//!
+//! ```
+//! # use aws_lc_rs::error::Unspecified;
+//! use aws_lc_rs::signature::{KeyPair, UnparsedPublicKey};
+//! use aws_lc_rs::unstable::signature::{MlDsaKeyPair, ML_DSA_65, ML_DSA_65_SIGNING};
+//!
+//! let key_pair = MlDsaKeyPair::generate(&ML_DSA_65_SIGNING)?;
+//!
+//! let mut signature = vec![0u8; key_pair.algorithm().signature_len()];
+//! let signature_len = key_pair.sign(b"hello, world", &mut signature)?;
+//!
+//! let public_key = UnparsedPublicKey::new(&ML_DSA_65, key_pair.public_key().as_ref());
+//! public_key.verify(b"hello, world", &signature[..signature_len])?;
+//! # Ok::<(), Unspecified>(())
+//! ```
+
+use crate::aws_lc::{
+    EVP_PKEY_pqdsa_new_raw_private_key, EVP_PKEY_pqdsa_new_raw_public_key, EVP_PKEY,
+    EVP_PKEY_PQDSA, NID_MLDSA44, NID_MLDSA65, NID_MLDSA87,
+};
+use crate::buffer::Buffer;
+use crate::encoding::{AsDer, Pkcs8V1Der, PublicKeyX509Der};
+use crate::error::{KeyRejected, Unspecified};
+use crate::evp_pkey::No_EVP_PKEY_CTX_consumer;
+use crate::pkcs8::Version;
+use crate::pq::evp_key_pqdsa_generate;
+use crate::ptr::LcPtr;
+use crate::signature::{KeyPair, VerificationAlgorithm};
+use crate::{hex, sealed};
+use core::fmt;
+use core::fmt::{Debug, Formatter};
+use core::ops::Deref;
+#[cfg(feature = "ring-sig-verify")]
+use untrusted::Input;
+
+/// An ML-DSA parameter set.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MlDsaAlgorithmId {
+    /// ML-DSA-44
+    MlDsa44,
+    /// ML-DSA-65
+    MlDsa65,
+    /// ML-DSA-87
+    MlDsa87,
+}
+
+/// An ML-DSA verification algorithm.
+#[derive(PartialEq, Eq)]
+pub struct MlDsaVerificationAlgorithm {
+    id: MlDsaAlgorithmId,
+    nid: i32,
+    public_key_len: usize,
+    signature_len: usize,
+}
+
+impl MlDsaVerificationAlgorithm {
+    /// The parameter set of the algorithm.
+    #[must_use]
+    pub fn id(&self) -> MlDsaAlgorithmId {
+        self.id
+    }
+
+    /// The length in bytes of an encoded public key.
+    #[must_use]
+    pub fn public_key_len(&self) -> usize {
+        self.public_key_len
+    }
+
+    /// The length in bytes of a signature.
+    #[must_use]
+    pub fn signature_len(&self) -> usize {
+        self.signature_len
+    }
+}
+
+impl Debug for MlDsaVerificationAlgorithm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.id, f)
+    }
+}
+
+impl sealed::Sealed for MlDsaVerificationAlgorithm {}
+
+impl VerificationAlgorithm for MlDsaVerificationAlgorithm {
+    #[inline]
+    #[cfg(feature = "ring-sig-verify")]
+    fn verify(
+        &self,
+        public_key: Input<'_>,
+        msg: Input<'_>,
+        signature: Input<'_>,
+    ) -> Result<(), Unspecified> {
+        self.verify_sig(
+            public_key.as_slice_less_safe(),
+            msg.as_slice_less_safe(),
+            signature.as_slice_less_safe(),
+        )
+    }
+
+    /// Verifies `signature` of `msg` with `public_key`, which is either the raw encoded public key
+    /// or a DER-encoded X.509 `SubjectPublicKeyInfo` structure.
+    fn verify_sig(
+        &self,
+        public_key: &[u8],
+        msg: &[u8],
+        signature: &[u8],
+    ) -> Result<(), Unspecified> {
+        let evp_pkey = self.parse_public_key(public_key)?;
+        evp_pkey.verify(msg, None, No_EVP_PKEY_CTX_consumer, signature)
+    }
+}
+
+impl MlDsaVerificationAlgorithm {
+    fn parse_public_key(&self, public_key: &[u8]) -> Result<LcPtr<EVP_PKEY>, KeyRejected> {
+        if public_key.len() == self.public_key_len {
+            return LcPtr::new(unsafe {
+                EVP_PKEY_pqdsa_new_raw_public_key(self.nid, public_key.as_ptr(), public_key.len())
+            })
+            .map_err(|()| KeyRejected::invalid_encoding());
+        }
+        let evp_pkey = LcPtr::<EVP_PKEY>::parse_rfc5280_public_key(public_key, EVP_PKEY_PQDSA)?;
+        if evp_pkey.marshal_raw_public_key()?.len() != self.public_key_len {
+            return Err(KeyRejected::wrong_algorithm());
+        }
+        Ok(evp_pkey)
+    }
+}
+
+/// An ML-DSA signing algorithm.
+#[derive(Debug, PartialEq, Eq)]
+pub struct MlDsaSigningAlgorithm(&'static MlDsaVerificationAlgorithm);
+
+impl Deref for MlDsaSigningAlgorithm {
+    type Target = MlDsaVerificationAlgorithm;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.0
+    }
+}
+
+/// Verification of ML-DSA-44 signatures.
+pub static ML_DSA_44: MlDsaVerificationAlgorithm = MlDsaVerificationAlgorithm {
+    id: MlDsaAlgorithmId::MlDsa44,
+    nid: NID_MLDSA44,
+    public_key_len: 1312,
+    signature_len: 2420,
+};
+
+/// Verification of ML-DSA-65 signatures.
+pub static ML_DSA_65: MlDsaVerificationAlgorithm = MlDsaVerificationAlgorithm {
+    id: MlDsaAlgorithmId::MlDsa65,
+    nid: NID_MLDSA65,
+    public_key_len: 1952,
+    signature_len: 3309,
+};
+
+/// Verification of ML-DSA-87 signatures.
+pub static ML_DSA_87: MlDsaVerificationAlgorithm = MlDsaVerificationAlgorithm {
+    id: MlDsaAlgorithmId::MlDsa87,
+    nid: NID_MLDSA87,
+    public_key_len: 2592,
+    signature_len: 4627,
+};
+
+/// Signing with ML-DSA-44.
+pub static ML_DSA_44_SIGNING: MlDsaSigningAlgorithm = MlDsaSigningAlgorithm(&ML_DSA_44);
+
+/// Signing with ML-DSA-65.
+pub static ML_DSA_65_SIGNING: MlDsaSigningAlgorithm = MlDsaSigningAlgorithm(&ML_DSA_65);
+
+/// Signing with ML-DSA-87.
+pub static ML_DSA_87_SIGNING: MlDsaSigningAlgorithm = MlDsaSigningAlgorithm(&ML_DSA_87);
+
+/// An ML-DSA public key.
+#[derive(Clone)]
+pub struct MlDsaPublicKey {
+    evp_pkey: LcPtr<EVP_PKEY>,
+    public_key_bytes: Box<[u8]>,
+}
+
+impl AsRef<[u8]> for MlDsaPublicKey {
+    /// Returns the encoded public key, as specified in Algorithm 22 of FIPS 204.
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        &self.public_key_bytes
+    }
+}
+
+impl Debug for MlDsaPublicKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&format!(
+            "MlDsaPublicKey(\"{}\")",
+            hex::encode(&self.public_key_bytes)
+        ))
+    }
+}
+
+impl AsDer<PublicKeyX509Der<'static>> for MlDsaPublicKey {
+    /// Provides the public key as a DER-encoded (X.509) `SubjectPublicKeyInfo` structure.
+    ///
+    /// # Errors
+    /// Returns an error if the public key fails to marshal to X.509.
+    fn as_der(&self) -> Result<PublicKeyX509Der<'static>, Unspecified> {
+        let der = self.evp_pkey.marshal_rfc5280_public_key()?;
+        Ok(PublicKeyX509Der::from(Buffer::new(der)))
+    }
+}
+
+unsafe impl Send for MlDsaPublicKey {}
+unsafe impl Sync for MlDsaPublicKey {}
+
+/// An ML-DSA key pair, for signing.
+pub struct MlDsaKeyPair {
+    algorithm: &'static MlDsaSigningAlgorithm,
+    evp_pkey: LcPtr<EVP_PKEY>,
+    public_key: MlDsaPublicKey,
+}
+
+impl Debug for MlDsaKeyPair {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&format!(
+            "MlDsaKeyPair {{ algorithm: {:?}, public_key: {:?} }}",
+            self.algorithm.id, self.public_key
+        ))
+    }
+}
+
+impl KeyPair for MlDsaKeyPair {
+    type PublicKey = MlDsaPublicKey;
+
+    #[inline]
+    fn public_key(&self) -> &Self::PublicKey {
+        &self.public_key
+    }
+}
+
+unsafe impl Send for MlDsaKeyPair {}
+unsafe impl Sync for MlDsaKeyPair {}
+
+impl MlDsaKeyPair {
+    /// Generates a new key pair.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if key generation fails.
+    pub fn generate(algorithm: &'static MlDsaSigningAlgorithm) -> Result<Self, Unspecified> {
+        let evp_pkey = evp_key_pqdsa_generate(algorithm.nid)?;
+        Ok(Self::new(algorithm, evp_pkey)?)
+    }
+
+    /// Deterministically derives a key pair from the 32-byte seed `ξ` of FIPS 204,
+    /// `ML-DSA.KeyGen_internal`.
+    ///
+    /// The same `seed` always produces the same key pair. `seed` must be generated by a
+    /// cryptographically secure random number generator and kept secret, as it is equivalent to
+    /// the private key.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if key generation fails.
+    pub fn from_seed(
+        algorithm: &'static MlDsaSigningAlgorithm,
+        seed: &[u8; 32],
+    ) -> Result<Self, Unspecified> {
+        let evp_pkey = LcPtr::new(unsafe {
+            EVP_PKEY_pqdsa_new_raw_private_key(algorithm.nid, seed.as_ptr(), seed.len())
+        })?;
+        Ok(Self::new(algorithm, evp_pkey)?)
+    }
+
+    /// Constructs a key pair by parsing an unencrypted PKCS#8 v1 `PrivateKeyInfo` structure, as
+    /// produced by [`AsDer::<Pkcs8V1Der>::as_der`].
+    ///
+    /// # Errors
+    /// `error::KeyRejected` if `pkcs8` is not a valid private key for `algorithm`.
+    pub fn from_pkcs8(
+        algorithm: &'static MlDsaSigningAlgorithm,
+        pkcs8: &[u8],
+    ) -> Result<Self, KeyRejected> {
+        let evp_pkey = LcPtr::<EVP_PKEY>::parse_rfc5208_private_key(pkcs8, EVP_PKEY_PQDSA)?;
+        Self::new(algorithm, evp_pkey)
+    }
+
+    fn new(
+        algorithm: &'static MlDsaSigningAlgorithm,
+        evp_pkey: LcPtr<EVP_PKEY>,
+    ) -> Result<Self, KeyRejected> {
+        let public_key_bytes = evp_pkey.marshal_raw_public_key()?;
+        if public_key_bytes.len() != algorithm.public_key_len {
+            return Err(KeyRejected::wrong_algorithm());
+        }
+        Ok(Self {
+            algorithm,
+            public_key: MlDsaPublicKey {
+                evp_pkey: evp_pkey.clone(),
+                public_key_bytes: public_key_bytes.into_boxed_slice(),
+            },
+            evp_pkey,
+        })
+    }
+
+    /// The signing algorithm of the key pair.
+    #[must_use]
+    pub fn algorithm(&self) -> &'static MlDsaSigningAlgorithm {
+        self.algorithm
+    }
+
+    /// Signs `msg`, writing the signature to `signature` and returning its length.
+    ///
+    /// The signature is randomized ("hedged"), as recommended by FIPS 204, with an empty context
+    /// string.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if `signature` is shorter than
+    /// [`MlDsaVerificationAlgorithm::signature_len`], or on internal failure.
+    pub fn sign(&self, msg: &[u8], signature: &mut [u8]) -> Result<usize, Unspecified> {
+        let signature_len = self.algorithm.signature_len;
+        let signature = signature.get_mut(..signature_len).ok_or(Unspecified)?;
+        let sig_bytes = self.evp_pkey.sign(msg, None, No_EVP_PKEY_CTX_consumer)?;
+        if sig_bytes.len() != signature_len {
+            return Err(Unspecified);
+        }
+        signature.copy_from_slice(&sig_bytes);
+        Ok(signature_len)
+    }
+}
+
+impl AsDer<Pkcs8V1Der<'static>> for MlDsaKeyPair {
+    /// Serializes the key pair as a PKCS#8 v1 `PrivateKeyInfo` structure.
+    ///
+    /// # Errors
+    /// `error::Unspecified` on internal error.
+    fn as_der(&self) -> Result<Pkcs8V1Der<'static>, Unspecified> {
+        Ok(Pkcs8V1Der::new(
+            self.evp_pkey.marshal_rfc5208_private_key(Version::V1)?,
+        ))
+    }
+}
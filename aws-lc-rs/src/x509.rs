@@ -0,0 +1,225 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+//! X.509 certificates as specified in [RFC 5280](https://www.rfc-editor.org/rfc/rfc5280).
+//!
+//! This module does not perform path building or signature validation. It
+//! only exposes properties of a certificate that a TLS stack may need to act on.
+//!
+//! # Example
+//!
+//! ```rust
+//! # use std::error::Error;
+//! #
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! use aws_lc_rs::x509::Certificate;
+//!
+//! let der = include_bytes!("../tests/data/x509_must_staple.der");
+//! let certificate = Certificate::from_der(der)?;
+//!
+//! assert!(certificate.requires_ocsp_stapling());
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::encoding::der::{DerReader, TAG_BIT_STRING, TAG_INTEGER};
+use crate::error::Unspecified;
+use core::fmt::{self, Debug, Formatter};
+
+// id-pe-tlsfeature: 1.3.6.1.5.5.7.1.24 (RFC 7633)
+const OID_PE_TLSFEATURE: [u8; 8] = [0x2b, 0x06, 0x01, 0x05, 0x05, 0x07, 0x01, 0x18];
+
+// TLS ExtensionType value for `status_request` (RFC 6066).
+const TLS_FEATURE_STATUS_REQUEST: u8 = 5;
+
+const TAG_BOOLEAN: u8 = 0x01;
+const TAG_VERSION: u8 = 0xa0;
+const TAG_ISSUER_UNIQUE_ID: u8 = 0x81;
+const TAG_SUBJECT_UNIQUE_ID: u8 = 0x82;
+const TAG_EXTENSIONS: u8 = 0xa3;
+
+/// A DER-encoded X.509 certificate.
+#[derive(Clone)]
+pub struct Certificate {
+    der: Box<[u8]>,
+    requires_ocsp_stapling: bool,
+}
+
+impl Certificate {
+    /// Parses a DER-encoded X.509 `Certificate` structure.
+    ///
+    /// Only the structure of the certificate is checked; its signature is not verified.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if `der` is not a well-formed X.509 certificate, or if its TLS Feature
+    /// extension is malformed.
+    pub fn from_der(der: &[u8]) -> Result<Self, Unspecified> {
+        let mut reader = DerReader::new(der);
+        let mut certificate = reader.read_sequence()?;
+        if !reader.is_empty() {
+            return Err(Unspecified);
+        }
+
+        let mut tbs_certificate = certificate.read_sequence()?;
+        let _signature_algorithm = certificate.read_sequence()?;
+        let _signature_value = certificate.read_tagged(TAG_BIT_STRING)?;
+        if !certificate.is_empty() {
+            return Err(Unspecified);
+        }
+
+        let requires_ocsp_stapling = parse_tbs_certificate(&mut tbs_certificate)?;
+
+        Ok(Self {
+            der: der.into(),
+            requires_ocsp_stapling,
+        })
+    }
+
+    /// Returns `true` if the certificate has the TLS Feature extension
+    /// ([RFC 7633](https://www.rfc-editor.org/rfc/rfc7633)) listing `status_request`,
+    /// commonly known as "OCSP Must-Staple".
+    ///
+    /// A TLS client that sees this must require a stapled OCSP response from the server.
+    #[must_use]
+    pub fn requires_ocsp_stapling(&self) -> bool {
+        self.requires_ocsp_stapling
+    }
+}
+
+impl AsRef<[u8]> for Certificate {
+    fn as_ref(&self) -> &[u8] {
+        &self.der
+    }
+}
+
+impl Debug for Certificate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Certificate").finish_non_exhaustive()
+    }
+}
+
+// Returns whether the certificate has a TLS Feature extension listing `status_request`.
+fn parse_tbs_certificate(tbs: &mut DerReader<'_>) -> Result<bool, Unspecified> {
+    if tbs.peek_tag() == Some(TAG_VERSION) {
+        let mut version = DerReader::new(tbs.read_tagged(TAG_VERSION)?);
+        version.read_integer()?;
+        if !version.is_empty() {
+            return Err(Unspecified);
+        }
+    }
+    tbs.read_tagged(TAG_INTEGER)?; // serialNumber
+    tbs.read_sequence()?; // signature
+    tbs.read_sequence()?; // issuer
+    tbs.read_sequence()?; // validity
+    tbs.read_sequence()?; // subject
+    tbs.read_sequence()?; // subjectPublicKeyInfo
+    if tbs.peek_tag() == Some(TAG_ISSUER_UNIQUE_ID) {
+        tbs.read_tagged(TAG_ISSUER_UNIQUE_ID)?;
+    }
+    if tbs.peek_tag() == Some(TAG_SUBJECT_UNIQUE_ID) {
+        tbs.read_tagged(TAG_SUBJECT_UNIQUE_ID)?;
+    }
+
+    let mut requires_ocsp_stapling = false;
+    if tbs.peek_tag() == Some(TAG_EXTENSIONS) {
+        let mut explicit = DerReader::new(tbs.read_tagged(TAG_EXTENSIONS)?);
+        let mut list = explicit.read_sequence()?;
+        if !explicit.is_empty() {
+            return Err(Unspecified);
+        }
+        while !list.is_empty() {
+            let mut extension = list.read_sequence()?;
+            let oid = extension.read_oid()?;
+            if extension.peek_tag() == Some(TAG_BOOLEAN) {
+                extension.read_tagged(TAG_BOOLEAN)?;
+            }
+            let value = extension.read_octet_string()?;
+            if !extension.is_empty() {
+                return Err(Unspecified);
+            }
+            if oid == OID_PE_TLSFEATURE && tls_features_include_status_request(value)? {
+                requires_ocsp_stapling = true;
+            }
+        }
+    }
+    if !tbs.is_empty() {
+        return Err(Unspecified);
+    }
+
+    Ok(requires_ocsp_stapling)
+}
+
+// Features ::= SEQUENCE OF INTEGER
+fn tls_features_include_status_request(value: &[u8]) -> Result<bool, Unspecified> {
+    let mut reader = DerReader::new(value);
+    let mut features = reader.read_sequence()?;
+    if !reader.is_empty() {
+        return Err(Unspecified);
+    }
+    let mut found = false;
+    while !features.is_empty() {
+        if features.read_integer()? == [TLS_FEATURE_STATUS_REQUEST] {
+            found = true;
+        }
+    }
+    Ok(found)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::x509::{tls_features_include_status_request, Certificate};
+
+    const MUST_STAPLE: &[u8] = include_bytes!("../tests/data/x509_must_staple.der");
+    const NO_MUST_STAPLE: &[u8] = include_bytes!("../tests/data/x509_no_must_staple.der");
+
+    #[test]
+    fn ocsp_must_staple() {
+        let certificate = Certificate::from_der(MUST_STAPLE).unwrap();
+        assert!(certificate.requires_ocsp_stapling());
+        assert_eq!(MUST_STAPLE, certificate.as_ref());
+
+        let certificate = Certificate::from_der(NO_MUST_STAPLE).unwrap();
+        assert!(!certificate.requires_ocsp_stapling());
+    }
+
+    #[test]
+    fn tls_feature_list() {
+        // SEQUENCE { INTEGER 5 }
+        assert!(tls_features_include_status_request(&[0x30, 0x03, 0x02, 0x01, 0x05]).unwrap());
+        // SEQUENCE { INTEGER 17, INTEGER 5 }
+        assert!(tls_features_include_status_request(&[
+            0x30, 0x06, 0x02, 0x01, 0x11, 0x02, 0x01, 0x05
+        ])
+        .unwrap());
+        // SEQUENCE { INTEGER 17 }
+        assert!(!tls_features_include_status_request(&[0x30, 0x03, 0x02, 0x01, 0x11]).unwrap());
+        // SEQUENCE {}
+        assert!(!tls_features_include_status_request(&[0x30, 0x00]).unwrap());
+        // Truncated
+        assert!(tls_features_include_status_request(&[0x30, 0x03, 0x02, 0x01]).is_err());
+    }
+
+    #[test]
+    fn malformed_certificate() {
+        assert!(Certificate::from_der(&[]).is_err());
+        assert!(Certificate::from_der(&MUST_STAPLE[..MUST_STAPLE.len() - 1]).is_err());
+
+        let mut trailing = MUST_STAPLE.to_vec();
+        trailing.push(0);
+        assert!(Certificate::from_der(&trailing).is_err());
+    }
+
+    #[test]
+    fn malformed_tls_feature() {
+        // The TLS Feature value is SEQUENCE { INTEGER 5 }. Retagging the INTEGER as an OCTET
+        // STRING keeps the certificate well-formed but the feature list is not.
+        const TLS_FEATURE: [u8; 7] = [0x04, 0x05, 0x30, 0x03, 0x02, 0x01, 0x05];
+        let offset = MUST_STAPLE
+            .windows(TLS_FEATURE.len())
+            .position(|window| window == TLS_FEATURE)
+            .unwrap();
+        let mut corrupted = MUST_STAPLE.to_vec();
+        corrupted[offset + 4] = 0x04;
+        assert!(Certificate::from_der(&corrupted).is_err());
+    }
+}
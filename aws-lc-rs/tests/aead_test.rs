@@ -3,11 +3,15 @@
 // Modifications copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0 OR ISC
 
-use aws_lc_rs::aead::nonce_sequence::Counter32Builder;
+use aws_lc_rs::aead::nonce_sequence::{
+    Counter32Builder, Counter64Builder, UnpredictableNonceSequence,
+};
 use aws_lc_rs::{aead, error, test, test_file};
 
 use aws_lc_rs::aead::{
-    Aad, BoundKey, Nonce, OpeningKey, SealingKey, UnboundKey, AES_128_GCM, NONCE_LEN,
+    Aad, BoundKey, CommittingOpeningKey, CommittingSealingKey, LessSafeKey, Nonce, OpeningKey,
+    OpeningStream, SealingKey, SealingStream, UnboundKey, AES_128_GCM, AES_128_GCM_SIV,
+    AES_192_GCM, AES_256_GCM, AES_256_GCM_SIV, CHACHA20_POLY1305, COMMITMENT_LEN, NONCE_LEN,
 };
 use core::ops::RangeFrom;
 
@@ -569,6 +573,21 @@ fn test_aead_traits() {
     test::compile_time_assert_sync::<aead::LessSafeKey>();
 }
 
+#[test]
+fn test_aead_zeroize_on_drop() {
+    fn assert_zeroize_on_drop<T: zeroize::ZeroizeOnDrop>() {}
+
+    assert_zeroize_on_drop::<aead::UnboundKey>();
+    assert_zeroize_on_drop::<aead::LessSafeKey>();
+    assert_zeroize_on_drop::<aead::SealingKey<aead::nonce_sequence::Counter64>>();
+    assert_zeroize_on_drop::<aead::OpeningKey<aead::nonce_sequence::Counter64>>();
+    assert_zeroize_on_drop::<aead::RandomizedNonceKey>();
+    assert_zeroize_on_drop::<aead::CommittingSealingKey>();
+    assert_zeroize_on_drop::<aead::CommittingOpeningKey>();
+    assert_zeroize_on_drop::<aead::TlsRecordSealingKey>();
+    assert_zeroize_on_drop::<aead::TlsRecordOpeningKey>();
+}
+
 #[test]
 fn test_aead_thread_safeness() {
     lazy_static::lazy_static! {
@@ -606,13 +625,83 @@ fn test_aead_thread_safeness() {
     }
 }
 
+#[test]
+fn test_aead_unbound_key_try_clone() {
+    let nonce = [7u8; NONCE_LEN];
+    for (algorithm, key_bytes) in [
+        (&AES_128_GCM, &[1u8; 16][..]),
+        (&AES_256_GCM, &[2u8; 32][..]),
+        (&AES_256_GCM_SIV, &[3u8; 32][..]),
+        (&CHACHA20_POLY1305, &[4u8; 32][..]),
+    ] {
+        let key = UnboundKey::new(algorithm, key_bytes).unwrap();
+        let clone = key.try_clone().unwrap();
+        assert_eq!(key.algorithm(), clone.algorithm());
+
+        let key = LessSafeKey::new(key);
+        let mut expected = b"hello, world".to_vec();
+        key.seal_in_place_append_tag(
+            Nonce::try_assume_unique_for_key(&nonce).unwrap(),
+            Aad::from(b"aad"),
+            &mut expected,
+        )
+        .unwrap();
+        // The clone is independent of the original.
+        drop(key);
+
+        let clone = LessSafeKey::new(clone);
+        let mut in_out = b"hello, world".to_vec();
+        clone
+            .seal_in_place_append_tag(
+                Nonce::try_assume_unique_for_key(&nonce).unwrap(),
+                Aad::from(b"aad"),
+                &mut in_out,
+            )
+            .unwrap();
+        assert_eq!(expected, in_out);
+
+        let plaintext = clone
+            .open_in_place(
+                Nonce::try_assume_unique_for_key(&nonce).unwrap(),
+                Aad::from(b"aad"),
+                &mut in_out,
+            )
+            .unwrap();
+        assert_eq!(b"hello, world", plaintext);
+    }
+}
+
 #[test]
 fn test_aead_key_debug() {
     let key_bytes = [0; 32];
     let nonce = [0; NONCE_LEN];
 
     let key = aead::UnboundKey::new(&aead::AES_256_GCM, &key_bytes).unwrap();
-    assert_eq!("UnboundKey { algorithm: AES_256_GCM }", format!("{key:?}"));
+    assert_eq!(
+        "UnboundKey { algorithm: AES_256_GCM, key_len: 32 }",
+        format!("{key:?}")
+    );
+
+    // The key material is never included.
+    let mut secret_key_bytes = [0x0b; 32];
+    secret_key_bytes[0] = 0xff;
+    for key_debug in [
+        format!(
+            "{:?}",
+            aead::UnboundKey::new(&aead::AES_256_GCM, &secret_key_bytes).unwrap()
+        ),
+        format!(
+            "{:?}",
+            make_less_safe_key(&aead::AES_256_GCM, &secret_key_bytes)
+        ),
+        format!(
+            "{:?}",
+            aead::chacha20_poly1305_openssh::SealingKey::new(&[0xff; 64])
+        ),
+    ] {
+        assert!(!key_debug.contains("ff0b"));
+        assert!(!key_debug.contains("255"));
+    }
 
     let sealing_key: aead::SealingKey<OneNonceSequence> = make_key(
         &aead::AES_256_GCM,
@@ -754,3 +843,355 @@ fn prepare_nonce() {
     ok.open_in_place(Aad::empty(), &mut message)
         .expect_err("sequence limit reached");
 }
+
+#[test]
+fn reset_nonce_sequence() {
+    const KEY: &[u8] = &[
+        0x52, 0x05, 0x19, 0x7a, 0xcc, 0x88, 0xdb, 0x78, 0x39, 0x59, 0xbc, 0x03, 0xb8, 0x1d, 0x4a,
+        0x6c,
+    ];
+    const MESSAGE: &[u8] = b"Raccoon Mischief";
+    const IDENTIFIER: [u8; 4] = [0xA1, 0xB2, 0xC3, 0xD4];
+
+    let mut sk = SealingKey::new(
+        UnboundKey::new(&AES_128_GCM, KEY).unwrap(),
+        Counter32Builder::new().limit(1).build(),
+    );
+    let mut ok = OpeningKey::new(
+        UnboundKey::new(&AES_128_GCM, KEY).unwrap(),
+        Counter32Builder::new().limit(1).build(),
+    );
+
+    let mut message = MESSAGE.to_vec();
+    sk.seal_in_place_append_tag(Aad::empty(), &mut message)
+        .unwrap();
+    ok.open_in_place(Aad::empty(), &mut message).unwrap();
+    sk.prepare_nonce().expect_err("sequence limit reached");
+    ok.prepare_nonce().expect_err("sequence limit reached");
+
+    let mut sk = sk.reset_nonce_sequence(
+        Counter64Builder::new()
+            .identifier(IDENTIFIER)
+            .counter(7)
+            .build(),
+    );
+    let mut ok = ok.reset_nonce_sequence(
+        Counter64Builder::new()
+            .identifier(IDENTIFIER)
+            .counter(7)
+            .build(),
+    );
+
+    let expected_nonce = [0xA1, 0xB2, 0xC3, 0xD4, 0, 0, 0, 0, 0, 0, 0, 7];
+    let skpn = sk.prepare_nonce().unwrap();
+    let okpn = ok.prepare_nonce().unwrap();
+    assert_eq!(&expected_nonce, skpn.nonce().as_ref());
+    assert_eq!(&expected_nonce, okpn.nonce().as_ref());
+
+    let mut message = MESSAGE.to_vec();
+    skpn.seal_in_place_append_tag(Aad::empty(), &mut message)
+        .unwrap();
+    let message = okpn.open_in_place(Aad::empty(), &mut message).unwrap();
+    assert_eq!(MESSAGE, message);
+}
+
+#[test]
+fn chacha20_poly1305_nonce_sequences() {
+    // RFC 8439, Section 2.8.2
+    const KEY: &str = "808182838485868788898a8b8c8d8e8f909192939495969798999a9b9c9d9e9f";
+    const AAD: &str = "50515253c0c1c2c3c4c5c6c7";
+    const MESSAGE: &[u8] = b"Ladies and Gentlemen of the class of '99: If I could offer you only one tip for the future, sunscreen would be it.";
+    const CIPHERTEXT_AND_TAG: &str = "d31a8d34648e60db7b86afbc53ef7ec2a4aded51296e08fea9e2b5a736ee62d63dbea45e8ca9671282fafb69da92728b1a71de0a9e060b2905d6a5b67ecd3b3692ddbd7f2d778b8c9803aee328091b58fab324e4fad675945585808b4831d7bc3ff4def08e4b7a9de576d26586cec64b61161ae10b594f09e26a7e902ecbd0600691";
+
+    let key = test::from_hex(KEY).unwrap();
+    let aad = test::from_hex(AAD).unwrap();
+
+    // The nonce 070000004041424344454647 is the identifier followed by the big-endian counter.
+    let nonce_sequence = || {
+        Counter64Builder::new()
+            .identifier([0x07, 0, 0, 0])
+            .counter(0x4041_4243_4445_4647)
+            .build()
+    };
+    let mut sk = SealingKey::new(
+        UnboundKey::new(&CHACHA20_POLY1305, &key).unwrap(),
+        nonce_sequence(),
+    );
+    let mut ok = OpeningKey::new(
+        UnboundKey::new(&CHACHA20_POLY1305, &key).unwrap(),
+        nonce_sequence(),
+    );
+    let mut in_out = MESSAGE.to_vec();
+    sk.seal_in_place_append_tag(Aad::from(&aad), &mut in_out)
+        .unwrap();
+    assert_eq!(test::from_hex(CIPHERTEXT_AND_TAG).unwrap(), in_out);
+    let plaintext = ok.open_in_place(Aad::from(&aad), &mut in_out).unwrap();
+    assert_eq!(MESSAGE, plaintext);
+
+    // Unpredictable nonces are accepted in the same way, and both sides stay in step.
+    let ns_key = [0x42u8; 32];
+    let mut sk = SealingKey::new(
+        UnboundKey::new(&CHACHA20_POLY1305, &key).unwrap(),
+        UnpredictableNonceSequence::with_chacha20(&ns_key, 0),
+    );
+    let mut ok = OpeningKey::new(
+        UnboundKey::new(&CHACHA20_POLY1305, &key).unwrap(),
+        UnpredictableNonceSequence::with_chacha20(&ns_key, 0),
+    );
+    let mut previous = Vec::new();
+    for _ in 0..3 {
+        let mut in_out = MESSAGE.to_vec();
+        sk.seal_in_place_append_tag(Aad::from(&aad), &mut in_out)
+            .unwrap();
+        assert!(!previous.contains(&in_out));
+        previous.push(in_out.clone());
+        let plaintext = ok.open_in_place(Aad::from(&aad), &mut in_out).unwrap();
+        assert_eq!(MESSAGE, plaintext);
+    }
+}
+
+#[test]
+fn aes_gcm_siv_deterministic() {
+    const MESSAGE: &[u8] = b"Raccoon Mischief";
+
+    for (alg, key_len) in [(&AES_128_GCM_SIV, 16), (&AES_256_GCM_SIV, 32)] {
+        let key = LessSafeKey::new(UnboundKey::new(alg, &vec![0x42u8; key_len]).unwrap());
+        let seal = |message: &[u8]| {
+            let nonce = Nonce::assume_unique_for_key([0x24u8; NONCE_LEN]);
+            let mut in_out = message.to_vec();
+            key.seal_in_place_append_tag(nonce, Aad::from(b"aad"), &mut in_out)
+                .unwrap();
+            in_out
+        };
+
+        assert_eq!(seal(MESSAGE), seal(MESSAGE));
+    }
+}
+
+#[test]
+fn aes_gcm_siv_nonce_reuse() {
+    const FIRST: &[u8] = b"attack at dawn!!";
+    const SECOND: &[u8] = b"retreat at dusk!";
+
+    let xor = |a: &[u8], b: &[u8]| -> Vec<u8> { a.iter().zip(b).map(|(a, b)| a ^ b).collect() };
+
+    for (alg, key_len) in [
+        (&AES_128_GCM, 16),
+        (&AES_256_GCM, 32),
+        (&AES_128_GCM_SIV, 16),
+        (&AES_256_GCM_SIV, 32),
+    ] {
+        let key = LessSafeKey::new(UnboundKey::new(alg, &vec![0x42u8; key_len]).unwrap());
+        let nonce = || Nonce::assume_unique_for_key([0x24u8; NONCE_LEN]);
+        let seal = |message: &[u8]| {
+            let mut in_out = message.to_vec();
+            key.seal_in_place_append_tag(nonce(), Aad::from(b"aad"), &mut in_out)
+                .unwrap();
+            in_out
+        };
+
+        let first = seal(FIRST);
+        let second = seal(SECOND);
+
+        // With AES-GCM, two messages sealed under one nonce share a keystream, so the XOR of the
+        // ciphertexts is the XOR of the plaintexts. AES-GCM-SIV derives the keystream from the
+        // tag, which differs for each message.
+        let leaks_xor = xor(&first[..FIRST.len()], &second[..SECOND.len()]) == xor(FIRST, SECOND);
+        let is_siv = alg == &AES_128_GCM_SIV || alg == &AES_256_GCM_SIV;
+        assert_eq!(!is_siv, leaks_xor);
+        if is_siv {
+            assert_ne!(first[FIRST.len()..], second[SECOND.len()..]);
+        }
+
+        // Both messages still open, and a ciphertext spliced from the two does not.
+        for (sealed, message) in [(&first, FIRST), (&second, SECOND)] {
+            let mut in_out = sealed.clone();
+            let plaintext = key
+                .open_in_place(nonce(), Aad::from(b"aad"), &mut in_out)
+                .unwrap();
+            assert_eq!(message, plaintext);
+        }
+        let mut spliced = second[..SECOND.len()].to_vec();
+        spliced.extend_from_slice(&first[FIRST.len()..]);
+        assert!(key
+            .open_in_place(nonce(), Aad::from(b"aad"), &mut spliced)
+            .is_err());
+    }
+}
+
+fn make_streams(
+    algorithm: &'static aead::Algorithm,
+) -> (
+    SealingStream<aead::nonce_sequence::Counter64>,
+    OpeningStream<aead::nonce_sequence::Counter64>,
+) {
+    let key = vec![0x42u8; algorithm.key_len()];
+    (
+        SealingStream::new(SealingKey::new(
+            UnboundKey::new(algorithm, &key).unwrap(),
+            Counter64Builder::new().build(),
+        )),
+        OpeningStream::new(OpeningKey::new(
+            UnboundKey::new(algorithm, &key).unwrap(),
+            Counter64Builder::new().build(),
+        )),
+    )
+}
+
+#[test]
+fn aead_stream_round_trip() {
+    const STREAM_LEN: usize = 10 * 1024 * 1024;
+    const CHUNK_LEN: usize = 64 * 1024;
+
+    let stream: Vec<u8> = (0..STREAM_LEN).map(|i| (i % 251) as u8).collect();
+
+    for algorithm in [&AES_128_GCM, &AES_256_GCM, &CHACHA20_POLY1305] {
+        let (mut sealing, mut opening) = make_streams(algorithm);
+        let mut opened = Vec::with_capacity(STREAM_LEN);
+        for (i, chunk) in stream.chunks(CHUNK_LEN).enumerate() {
+            let aad = (i as u64).to_be_bytes();
+            let mut sealed = sealing.seal_chunk(&aad, chunk).unwrap();
+            assert_eq!(chunk.len() + algorithm.tag_len(), sealed.len());
+            assert_ne!(chunk, &sealed[..chunk.len()]);
+
+            let plaintext = opening.open_chunk(&aad, &mut sealed).unwrap();
+            assert_eq!(chunk, plaintext);
+            opened.extend_from_slice(plaintext);
+        }
+        assert_eq!(stream, opened);
+    }
+}
+
+#[test]
+fn aead_stream_ordering() {
+    let (mut sealing, mut opening) = make_streams(&AES_128_GCM);
+    let chunks: Vec<Vec<u8>> = (0..4u8)
+        .map(|i| sealing.seal_chunk(b"aad", &[i; 32]).unwrap())
+        .collect();
+
+    // Chunks are sealed with distinct nonces.
+    assert_ne!(chunks[0], chunks[1]);
+
+    let mut chunk = chunks[0].clone();
+    assert_eq!(&[0u8; 32], opening.open_chunk(b"aad", &mut chunk).unwrap());
+    assert_eq!([0u8; 32].as_slice(), chunk.as_slice());
+
+    // Skipping the second chunk fails, as does every chunk thereafter.
+    let mut chunk = chunks[2].clone();
+    assert!(opening.open_chunk(b"aad", &mut chunk).is_err());
+    let mut chunk = chunks[1].clone();
+    assert!(opening.open_chunk(b"aad", &mut chunk).is_err());
+
+    // Replaying a chunk, or changing its aad, fails.
+    let (mut sealing, mut opening) = make_streams(&CHACHA20_POLY1305);
+    let chunk = sealing.seal_chunk(b"aad", b"chunk").unwrap();
+    assert!(opening.open_chunk(b"aad", &mut chunk.clone()).is_ok());
+    assert!(opening.open_chunk(b"aad", &mut chunk.clone()).is_err());
+    let (mut sealing, mut opening) = make_streams(&CHACHA20_POLY1305);
+    let mut chunk = sealing.seal_chunk(b"aad", b"chunk").unwrap();
+    assert!(opening.open_chunk(b"other", &mut chunk).is_err());
+
+    assert_eq!(
+        "SealingStream { algorithm: CHACHA20_POLY1305 }",
+        format!("{sealing:?}")
+    );
+    assert_eq!(&CHACHA20_POLY1305, opening.into_inner().algorithm());
+}
+
+#[test]
+fn aead_committing_round_trip() {
+    const MESSAGE: &[u8] = b"committing aead message";
+    const NONCE: [u8; NONCE_LEN] = [0x42; NONCE_LEN];
+
+    for algorithm in [
+        &AES_128_GCM,
+        &AES_192_GCM,
+        &AES_256_GCM,
+        &AES_128_GCM_SIV,
+        &AES_256_GCM_SIV,
+        &CHACHA20_POLY1305,
+    ] {
+        let key_bytes = vec![0x11u8; algorithm.key_len()];
+        let other_key_bytes = vec![0x22u8; algorithm.key_len()];
+        let sealing_key =
+            CommittingSealingKey::new(UnboundKey::new(algorithm, &key_bytes).unwrap());
+        let opening_key =
+            CommittingOpeningKey::new(UnboundKey::new(algorithm, &key_bytes).unwrap());
+        let other_opening_key =
+            CommittingOpeningKey::new(UnboundKey::new(algorithm, &other_key_bytes).unwrap());
+        assert_eq!(algorithm, sealing_key.algorithm());
+        assert_eq!(algorithm, opening_key.algorithm());
+
+        let mut sealed = MESSAGE.to_vec();
+        sealing_key
+            .seal_in_place_append_tag(
+                Nonce::assume_unique_for_key(NONCE),
+                Aad::from(b"aad"),
+                &mut sealed,
+            )
+            .unwrap();
+        assert_eq!(
+            MESSAGE.len() + COMMITMENT_LEN + algorithm.tag_len(),
+            sealed.len()
+        );
+
+        // The underlying AEAD is keyed with a derived subkey, not the key itself.
+        let less_safe_key = LessSafeKey::new(UnboundKey::new(algorithm, &key_bytes).unwrap());
+        assert!(less_safe_key
+            .open_in_place(
+                Nonce::assume_unique_for_key(NONCE),
+                Aad::from(b"aad"),
+                &mut sealed[COMMITMENT_LEN..].to_vec(),
+            )
+            .is_err());
+
+        let mut in_out = sealed.clone();
+        let plaintext = opening_key
+            .open_in_place(
+                Nonce::assume_unique_for_key(NONCE),
+                Aad::from(b"aad"),
+                &mut in_out,
+            )
+            .unwrap();
+        assert_eq!(MESSAGE, plaintext);
+
+        // A different key, nonce or aad fails, as does a modified commitment.
+        assert!(other_opening_key
+            .open_in_place(
+                Nonce::assume_unique_for_key(NONCE),
+                Aad::from(b"aad"),
+                &mut sealed.clone(),
+            )
+            .is_err());
+        assert!(opening_key
+            .open_in_place(
+                Nonce::assume_unique_for_key([0x24; NONCE_LEN]),
+                Aad::from(b"aad"),
+                &mut sealed.clone(),
+            )
+            .is_err());
+        assert!(opening_key
+            .open_in_place(
+                Nonce::assume_unique_for_key(NONCE),
+                Aad::from(b"other"),
+                &mut sealed.clone(),
+            )
+            .is_err());
+        let mut tampered = sealed.clone();
+        tampered[0] ^= 1;
+        assert!(opening_key
+            .open_in_place(
+                Nonce::assume_unique_for_key(NONCE),
+                Aad::from(b"aad"),
+                &mut tampered,
+            )
+            .is_err());
+        assert!(opening_key
+            .open_in_place(
+                Nonce::assume_unique_for_key(NONCE),
+                Aad::from(b"aad"),
+                &mut sealed[..COMMITMENT_LEN - 1],
+            )
+            .is_err());
+    }
+}
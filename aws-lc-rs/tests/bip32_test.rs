@@ -0,0 +1,121 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+#![cfg(not(feature = "fips"))]
+
+use aws_lc_rs::bip32::{ExtendedPrivateKey, HARDENED_INDEX};
+use aws_lc_rs::encoding::AsBigEndian;
+use aws_lc_rs::rand::SystemRandom;
+use aws_lc_rs::signature::{self, KeyPair, UnparsedPublicKey};
+use aws_lc_rs::{test, test_file};
+
+#[test]
+fn bip32_traits() {
+    test::compile_time_assert_send::<ExtendedPrivateKey>();
+    test::compile_time_assert_sync::<ExtendedPrivateKey>();
+}
+
+#[test]
+fn bip32_tests() {
+    test::run(test_file!("data/bip32_tests.txt"), |section, test_case| {
+        assert_eq!(section, "");
+        let seed = test_case.consume_bytes("Seed");
+        let path = test_case.consume_string("Path");
+        let parent_fingerprint = test_case.consume_bytes("ParentFingerprint");
+        let chain_code = test_case.consume_bytes("ChainCode");
+        let private_key = test_case.consume_bytes("PrivateKey");
+        let public_key = test_case.consume_bytes("PublicKey");
+
+        let mut key = ExtendedPrivateKey::from_seed(&seed)?;
+        let mut child_number = 0;
+        for component in path.split('/').skip(1) {
+            child_number = match component.strip_suffix('H') {
+                Some(index) => HARDENED_INDEX + index.parse::<u32>().unwrap(),
+                None => component.parse::<u32>().unwrap(),
+            };
+            key = key.derive_child(child_number)?;
+        }
+        assert_eq!(path.split('/').count() - 1, usize::from(key.depth()));
+        assert_eq!(child_number, key.child_number());
+        assert_eq!(parent_fingerprint, key.parent_fingerprint());
+        assert_eq!(chain_code, key.chain_code());
+
+        let extended_public_key = key.public_key();
+        assert_eq!(public_key, extended_public_key.public_key());
+        assert_eq!(chain_code, extended_public_key.chain_code());
+        assert_eq!(key.depth(), extended_public_key.depth());
+        assert_eq!(child_number, extended_public_key.child_number());
+        assert_eq!(parent_fingerprint, extended_public_key.parent_fingerprint());
+
+        let key_pair = key.signing_key(&signature::ECDSA_P256K1_SHA256_ASN1_SIGNING)?;
+        assert_eq!(private_key, key_pair.private_key().as_be_bytes()?.as_ref());
+
+        Ok(())
+    });
+}
+
+#[test]
+fn bip32_fingerprint() {
+    let seed = test::from_hex("000102030405060708090a0b0c0d0e0f").unwrap();
+    let master = ExtendedPrivateKey::from_seed(&seed).unwrap();
+    let child = master.derive_child(HARDENED_INDEX).unwrap();
+    assert_eq!(
+        master.public_key().fingerprint(),
+        child.parent_fingerprint()
+    );
+    assert_eq!([0x34, 0x42, 0x19, 0x3e], master.public_key().fingerprint());
+}
+
+#[test]
+fn bip32_signing_key() {
+    let master = ExtendedPrivateKey::from_seed(&[0x42; 32]).unwrap();
+    let key = master.derive_child(HARDENED_INDEX + 44).unwrap();
+
+    let message = b"hello, world";
+    for (signing_alg, verification_alg) in [
+        (
+            &signature::ECDSA_P256K1_SHA256_ASN1_SIGNING,
+            &signature::ECDSA_P256K1_SHA256_ASN1,
+        ),
+        (
+            &signature::ECDSA_P256K1_SHA256_FIXED_SIGNING,
+            &signature::ECDSA_P256K1_SHA256_FIXED,
+        ),
+    ] {
+        let key_pair = key.signing_key(signing_alg).unwrap();
+        let sig = key_pair.sign(&SystemRandom::new(), message).unwrap();
+        UnparsedPublicKey::new(verification_alg, key_pair.public_key())
+            .verify(message, sig.as_ref())
+            .unwrap();
+        UnparsedPublicKey::new(verification_alg, key.public_key().public_key())
+            .verify(message, sig.as_ref())
+            .unwrap();
+    }
+
+    assert!(key
+        .signing_key(&signature::ECDSA_P256_SHA256_ASN1_SIGNING)
+        .is_err());
+}
+
+#[test]
+fn bip32_invalid() {
+    assert!(ExtendedPrivateKey::from_seed(&[0; 15]).is_err());
+    assert!(ExtendedPrivateKey::from_seed(&[0; 65]).is_err());
+    assert!(ExtendedPrivateKey::from_seed(&[0; 16]).is_ok());
+    assert!(ExtendedPrivateKey::from_seed(&[0; 64]).is_ok());
+
+    let mut key = ExtendedPrivateKey::from_seed(&[0; 16]).unwrap();
+    for _ in 0..255 {
+        key = key.derive_child(0).unwrap();
+    }
+    assert_eq!(255, key.depth());
+    assert!(key.derive_child(0).is_err());
+}
+
+#[test]
+fn bip32_debug() {
+    let key = ExtendedPrivateKey::from_seed(&[0; 16]).unwrap();
+    let debug = format!("{key:?}");
+    assert!(debug.starts_with("ExtendedPrivateKey { public: ExtendedPublicKey { public_key: \""));
+    assert!(debug.ends_with("depth: 0, child_number: 0, .. }, .. }"));
+}
@@ -1,13 +1,16 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0 OR ISC
 
+use aws_lc_rs::cipher::block::AesBlockCipher;
 use aws_lc_rs::cipher::{
-    DecryptingKey, DecryptionContext, EncryptingKey, EncryptionContext, OperatingMode,
-    PaddedBlockDecryptingKey, PaddedBlockEncryptingKey, StreamingDecryptingKey,
-    StreamingEncryptingKey, UnboundCipherKey, AES_128, AES_192, AES_256,
+    DecryptingKey, DecryptionContext, EncryptingContext, EncryptingKey, EncryptionContext,
+    OperatingMode, PaddedBlockDecryptingKey, PaddedBlockEncryptingContext,
+    PaddedBlockEncryptingKey, StreamingDecryptingKey, StreamingEncryptingKey, UnboundCipherKey,
+    XtsDecryptingKey, XtsEncryptingKey, AES_128, AES_192, AES_256, AES_256_KEY_LEN, AES_256_XTS,
 };
 use aws_lc_rs::iv::{FixedLength, IV_LEN_128_BIT};
 use aws_lc_rs::test::from_hex;
+use aws_lc_rs::{test, test_file};
 use paste::paste;
 
 fn step_encrypt(
@@ -51,7 +54,7 @@ fn step_encrypt(
             assert!(ciphertext.len() > plaintext.len());
             assert!(ciphertext.len() <= plaintext.len() + alg.block_len());
         }
-        OperatingMode::CTR | OperatingMode::CFB128 => {
+        OperatingMode::CTR | OperatingMode::CFB128 | OperatingMode::CFB8 | OperatingMode::OFB => {
             assert_eq!(ciphertext.len(), plaintext.len());
         }
         _ => panic!("Unknown cipher mode"),
@@ -101,7 +104,7 @@ fn step_decrypt(
             assert!(ciphertext.len() > plaintext.len());
             assert!(ciphertext.len() <= plaintext.len() + alg.block_len());
         }
-        OperatingMode::CTR | OperatingMode::CFB128 => {
+        OperatingMode::CTR | OperatingMode::CFB128 | OperatingMode::CFB8 | OperatingMode::OFB => {
             assert_eq!(ciphertext.len(), plaintext.len());
         }
         _ => panic!("Unknown cipher mode"),
@@ -527,6 +530,32 @@ padded_cipher_kat!(
     "2ddfb635a651a43f582997966840ca0c"
 );
 
+// Ciphertext produced by `openssl enc -aes-256-cbc -K <key> -iv <iv>`.
+padded_cipher_kat!(
+    test_kat_aes_256_cbc_47_bytes_openssl,
+    &AES_256,
+    OperatingMode::CBC,
+    cbc_pkcs7,
+    "603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4",
+    "000102030405060708090a0b0c0d0e0f",
+    "3082014a020100300506032b657004200e8f3c6d1e9b2a4f5c7d8e9f0a1b2c3d4e5f60718293a4b5c6d7e8f90a1b2c",
+    "add11a9a88fb0f159787979f04322d8bc8d76393d4876b252fbef201607bd1d9e4869017d54390c19537ad2245ec0cf7"
+);
+
+#[test]
+fn test_aes_256_key_length() {
+    for len in [0, 16, 24, 31, 33, 64] {
+        let key = vec![42u8; len];
+        assert!(UnboundCipherKey::new(&AES_256, &key).is_err(), "{len}");
+    }
+    let key = UnboundCipherKey::new(&AES_256, &[42u8; AES_256_KEY_LEN]).unwrap();
+    assert_eq!(&AES_256, key.algorithm());
+
+    // 24-byte keys are only accepted for AES-192.
+    assert!(UnboundCipherKey::new(&AES_192, &[42u8; 24]).is_ok());
+    assert!(UnboundCipherKey::new(&AES_128, &[42u8; 24]).is_err());
+}
+
 cipher_kat!(
     test_kat_aes_128_ctr_16_bytes,
     &AES_128,
@@ -1029,3 +1058,559 @@ cipher_kat!(
     "9c1675a95f573b4504e6bc5275d0df",
     "b8e816bd9e74adebdacf9036cbda41"
 );
+
+// NIST SP 800-38A, F.3.13
+cipher_kat!(
+    test_kat_aes_128_cfb128_sp800_38a,
+    &AES_128,
+    OperatingMode::CFB128,
+    cfb128,
+    "2b7e151628aed2a6abf7158809cf4f3c",
+    "000102030405060708090a0b0c0d0e0f",
+    "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e5130c81c46a35ce411e5fbc1191a0a52eff69f2445df4f9b17ad2b417be66c3710",
+    "3b3fd92eb72dad20333449f8e83cfb4ac8a64537a0b3a93fcde3cdad9f1ce58b26751f67a3cbb140b1808cf187a4f4dfc04b05357c5d1c0eeac4c66f9ff7f2e6"
+);
+
+// NIST SP 800-38A, F.3.15
+cipher_kat!(
+    test_kat_aes_192_cfb128_sp800_38a,
+    &AES_192,
+    OperatingMode::CFB128,
+    cfb128,
+    "8e73b0f7da0e6452c810f32b809079e562f8ead2522c6b7b",
+    "000102030405060708090a0b0c0d0e0f",
+    "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e5130c81c46a35ce411e5fbc1191a0a52eff69f2445df4f9b17ad2b417be66c3710",
+    "cdc80d6fddf18cab34c25909c99a417467ce7f7f81173621961a2b70171d3d7a2e1e8a1dd59b88b1c8e60fed1efac4c9c05f9f9ca9834fa042ae8fba584b09ff"
+);
+
+// NIST SP 800-38A, F.3.17
+cipher_kat!(
+    test_kat_aes_256_cfb128_sp800_38a,
+    &AES_256,
+    OperatingMode::CFB128,
+    cfb128,
+    "603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4",
+    "000102030405060708090a0b0c0d0e0f",
+    "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e5130c81c46a35ce411e5fbc1191a0a52eff69f2445df4f9b17ad2b417be66c3710",
+    "dc7e84bfda79164b7ecd8486985d386039ffed143b28b1c832113c6331e5407bdf10132415e54b92a13ed0a8267ae2f975a385741ab9cef82031623d55b1e471"
+);
+
+// NIST SP 800-38A, F.3.7
+cipher_kat!(
+    test_kat_aes_128_cfb8_sp800_38a,
+    &AES_128,
+    OperatingMode::CFB8,
+    cfb8,
+    "2b7e151628aed2a6abf7158809cf4f3c",
+    "000102030405060708090a0b0c0d0e0f",
+    "6bc1bee22e409f96e93d7e117393172aae2d",
+    "3b79424c9c0dd436bace9e0ed4586a4f32b9"
+);
+
+// NIST SP 800-38A, F.3.9
+cipher_kat!(
+    test_kat_aes_192_cfb8_sp800_38a,
+    &AES_192,
+    OperatingMode::CFB8,
+    cfb8,
+    "8e73b0f7da0e6452c810f32b809079e562f8ead2522c6b7b",
+    "000102030405060708090a0b0c0d0e0f",
+    "6bc1bee22e409f96e93d7e117393172aae2d",
+    "cda2521ef0a905ca44cd057cbf0d47a0678a"
+);
+
+// NIST SP 800-38A, F.3.11
+cipher_kat!(
+    test_kat_aes_256_cfb8_sp800_38a,
+    &AES_256,
+    OperatingMode::CFB8,
+    cfb8,
+    "603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4",
+    "000102030405060708090a0b0c0d0e0f",
+    "6bc1bee22e409f96e93d7e117393172aae2d",
+    "dc1f1a8520a64db55fcc8ac554844e889700"
+);
+
+#[test]
+fn test_kat_aes_256_xts() {
+    test::run(
+        test_file!("data/aes_256_xts_tests.txt"),
+        |section, test_case| {
+            assert_eq!(section, "");
+            let key = test_case.consume_bytes("Key");
+            let sector = test_case.consume_bytes("Sector");
+            let sector = u64::from_be_bytes(sector.as_slice().try_into().unwrap());
+            let plaintext = test_case.consume_bytes("Plaintext");
+            let ciphertext = test_case.consume_bytes("Ciphertext");
+
+            let mut encrypting_key =
+                XtsEncryptingKey::new(UnboundCipherKey::new(&AES_256_XTS, &key)?)?;
+            let mut in_out = plaintext.clone();
+            encrypting_key.encrypt_sector(sector, &mut in_out)?;
+            assert_eq!(ciphertext, in_out);
+
+            let mut decrypting_key =
+                XtsDecryptingKey::new(UnboundCipherKey::new(&AES_256_XTS, &key)?)?;
+            decrypting_key.decrypt_sector(sector, &mut in_out)?;
+            assert_eq!(plaintext, in_out);
+
+            // A sector shorter than one block is rejected.
+            assert!(encrypting_key
+                .encrypt_sector(sector, &mut in_out[..15])
+                .is_err());
+            assert!(decrypting_key
+                .decrypt_sector(sector, &mut in_out[..15])
+                .is_err());
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn test_aes_256_xts_key_not_usable_in_other_modes() {
+    let key_bytes: Vec<u8> = (0..64).collect();
+    let key = UnboundCipherKey::new(&AES_256_XTS, &key_bytes).unwrap();
+    assert!(EncryptingKey::ctr(key).is_err());
+    let key = UnboundCipherKey::new(&AES_256_XTS, &key_bytes).unwrap();
+    assert!(StreamingEncryptingKey::cbc_pkcs7(key).is_err());
+    assert!(UnboundCipherKey::new(&AES_256_XTS, &key_bytes[..32]).is_err());
+}
+
+// NIST SP 800-38A, F.4.1
+cipher_kat!(
+    test_kat_aes_128_ofb_sp800_38a,
+    &AES_128,
+    OperatingMode::OFB,
+    ofb,
+    "2b7e151628aed2a6abf7158809cf4f3c",
+    "000102030405060708090a0b0c0d0e0f",
+    "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e5130c81c46a35ce411e5fbc1191a0a52eff69f2445df4f9b17ad2b417be66c3710",
+    "3b3fd92eb72dad20333449f8e83cfb4a7789508d16918f03f53c52dac54ed8259740051e9c5fecf64344f7a82260edcc304c6528f659c77866a510d9c1d6ae5e"
+);
+
+// NIST SP 800-38A, F.4.3
+cipher_kat!(
+    test_kat_aes_192_ofb_sp800_38a,
+    &AES_192,
+    OperatingMode::OFB,
+    ofb,
+    "8e73b0f7da0e6452c810f32b809079e562f8ead2522c6b7b",
+    "000102030405060708090a0b0c0d0e0f",
+    "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e5130c81c46a35ce411e5fbc1191a0a52eff69f2445df4f9b17ad2b417be66c3710",
+    "cdc80d6fddf18cab34c25909c99a4174fcc28b8d4c63837c09e81700c11004018d9a9aeac0f6596f559c6d4daf59a5f26d9f200857ca6c3e9cac524bd9acc92a"
+);
+
+// NIST SP 800-38A, F.4.5
+cipher_kat!(
+    test_kat_aes_256_ofb_sp800_38a,
+    &AES_256,
+    OperatingMode::OFB,
+    ofb,
+    "603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4",
+    "000102030405060708090a0b0c0d0e0f",
+    "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e5130c81c46a35ce411e5fbc1191a0a52eff69f2445df4f9b17ad2b417be66c3710",
+    "dc7e84bfda79164b7ecd8486985d38604febdc6740d20b3ac88f6ad82a4fb08d71ab47a086e86eedf39d1c5bba97c4080126141d67f37be8538f5a8be740e484"
+);
+
+#[test]
+fn test_aes_ofb_encrypt_twice_is_identity() {
+    let key_bytes = from_hex("2b7e151628aed2a6abf7158809cf4f3c").unwrap();
+    let iv = from_hex("000102030405060708090a0b0c0d0e0f").unwrap();
+    let plaintext = b"OFB is a stream cipher over AES blocks".to_vec();
+
+    let encrypting_key =
+        EncryptingKey::ofb(UnboundCipherKey::new(&AES_128, &key_bytes).unwrap()).unwrap();
+    let mut in_out = plaintext.clone();
+    let context = EncryptionContext::Iv128(FixedLength::try_from(iv.as_slice()).unwrap());
+    encrypting_key
+        .less_safe_encrypt(&mut in_out, context)
+        .unwrap();
+    assert_ne!(plaintext, in_out);
+
+    // Encrypting again with the same key and IV recovers the plaintext.
+    let context = EncryptionContext::Iv128(FixedLength::try_from(iv.as_slice()).unwrap());
+    encrypting_key
+        .less_safe_encrypt(&mut in_out, context)
+        .unwrap();
+    assert_eq!(plaintext, in_out);
+
+    // The IV must be exactly 16 bytes.
+    assert!(FixedLength::<IV_LEN_128_BIT>::try_from(&iv[..15]).is_err());
+    assert!(encrypting_key
+        .less_safe_encrypt(&mut in_out, EncryptionContext::None)
+        .is_err());
+}
+
+#[test]
+fn aes_block_cipher_kat() {
+    // NIST SP 800-38A, F.1.1 ECB-AES128.Encrypt and F.1.2 ECB-AES128.Decrypt
+    let key_128: [u8; 16] = from_hex("2b7e151628aed2a6abf7158809cf4f3c")
+        .unwrap()
+        .try_into()
+        .unwrap();
+    let cipher_128 = AesBlockCipher::new_128(&key_128).unwrap();
+    // NIST SP 800-38A, F.1.5 ECB-AES256.Encrypt and F.1.6 ECB-AES256.Decrypt
+    let key_256: [u8; 32] =
+        from_hex("603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4")
+            .unwrap()
+            .try_into()
+            .unwrap();
+    let cipher_256 = AesBlockCipher::new_256(&key_256).unwrap();
+
+    for (cipher, plaintext, ciphertext) in [
+        (
+            &cipher_128,
+            "6bc1bee22e409f96e93d7e117393172a",
+            "3ad77bb40d7a3660a89ecaf32466ef97",
+        ),
+        (
+            &cipher_128,
+            "ae2d8a571e03ac9c9eb76fac45af8e51",
+            "f5d3d58503b9699de785895a96fdbaaf",
+        ),
+        (
+            &cipher_128,
+            "30c81c46a35ce411e5fbc1191a0a52ef",
+            "43b1cd7f598ece23881b00e3ed030688",
+        ),
+        (
+            &cipher_128,
+            "f69f2445df4f9b17ad2b417be66c3710",
+            "7b0c785e27e8ad3f8223207104725dd4",
+        ),
+        (
+            &cipher_256,
+            "6bc1bee22e409f96e93d7e117393172a",
+            "f3eed1bdb5d2a03c064b5a7e3db181f8",
+        ),
+        (
+            &cipher_256,
+            "f69f2445df4f9b17ad2b417be66c3710",
+            "23304b7a39f9f3ff067d8d8f9e24ecc7",
+        ),
+    ] {
+        let plaintext: [u8; 16] = from_hex(plaintext).unwrap().try_into().unwrap();
+        let ciphertext: [u8; 16] = from_hex(ciphertext).unwrap().try_into().unwrap();
+        assert_eq!(ciphertext, cipher.encrypt_block(&plaintext));
+        assert_eq!(plaintext, cipher.decrypt_block(&ciphertext));
+    }
+
+    // FIPS 197, Appendix C.1
+    let key: [u8; 16] = from_hex("000102030405060708090a0b0c0d0e0f")
+        .unwrap()
+        .try_into()
+        .unwrap();
+    let cipher = AesBlockCipher::new_128(&key).unwrap();
+    let plaintext: [u8; 16] = from_hex("00112233445566778899aabbccddeeff")
+        .unwrap()
+        .try_into()
+        .unwrap();
+    let ciphertext = cipher.encrypt_block(&plaintext);
+    assert_eq!(
+        from_hex("69c4e0d86a7b0430d8cdb78070b4c55a").unwrap(),
+        ciphertext
+    );
+    assert_eq!(plaintext, cipher.decrypt_block(&ciphertext));
+    assert_eq!("AesBlockCipher { .. }", format!("{cipher:?}"));
+}
+
+#[test]
+fn test_aes_cbc_iso7816_x923_padding() {
+    let key_bytes = from_hex("000102030405060708090a0b0c0d0e0f").unwrap();
+    let iv = from_hex("00000000000000000000000000000000").unwrap();
+    let plaintext = from_hex("00112233445566778899aabbccddeeff").unwrap();
+
+    // ISO/IEC 7816-4 padding is deterministic: a full block of `80 00 .. 00` is appended.
+    let encrypting_key =
+        PaddedBlockEncryptingKey::cbc_iso7816(UnboundCipherKey::new(&AES_128, &key_bytes).unwrap())
+            .unwrap();
+    let mut in_out = plaintext.clone();
+    let context = EncryptionContext::Iv128(FixedLength::try_from(iv.as_slice()).unwrap());
+    encrypting_key
+        .less_safe_encrypt(&mut in_out, context)
+        .unwrap();
+    assert_eq!(
+        from_hex("69c4e0d86a7b0430d8cdb78070b4c55a8e17c55e98d0f4cb1a31eb14b39c547a").unwrap(),
+        in_out
+    );
+
+    for len in [0, 1, 15, 16, 17, 31, 32, 33] {
+        let plaintext = vec![0x80u8; len];
+        let keys = [
+            (
+                PaddedBlockEncryptingKey::cbc_iso7816(
+                    UnboundCipherKey::new(&AES_128, &key_bytes).unwrap(),
+                )
+                .unwrap(),
+                PaddedBlockDecryptingKey::cbc_iso7816(
+                    UnboundCipherKey::new(&AES_128, &key_bytes).unwrap(),
+                )
+                .unwrap(),
+            ),
+            (
+                PaddedBlockEncryptingKey::cbc_x923(
+                    UnboundCipherKey::new(&AES_128, &key_bytes).unwrap(),
+                )
+                .unwrap(),
+                PaddedBlockDecryptingKey::cbc_x923(
+                    UnboundCipherKey::new(&AES_128, &key_bytes).unwrap(),
+                )
+                .unwrap(),
+            ),
+        ];
+        for (encrypting_key, decrypting_key) in keys {
+            let mut in_out = plaintext.clone();
+            let context = encrypting_key.encrypt(&mut in_out).unwrap();
+            assert_eq!((len / 16 + 1) * 16, in_out.len());
+            let result = decrypting_key.decrypt(&mut in_out, context).unwrap();
+            assert_eq!(plaintext.as_slice(), result);
+        }
+    }
+
+    // PKCS#7 ciphertext whose last byte decrypts to `0x10` is not valid ISO/IEC 7816-4 padding.
+    let decrypting_key =
+        PaddedBlockDecryptingKey::cbc_iso7816(UnboundCipherKey::new(&AES_128, &key_bytes).unwrap())
+            .unwrap();
+    let mut in_out =
+        from_hex("69c4e0d86a7b0430d8cdb78070b4c55a9e978e6d16b086570ef794ef97984232").unwrap();
+    let context = DecryptionContext::Iv128(FixedLength::try_from(iv.as_slice()).unwrap());
+    assert!(decrypting_key.decrypt(&mut in_out, context).is_err());
+}
+
+#[test]
+fn test_unbound_cipher_key_clone() {
+    let key = UnboundCipherKey::new(&AES_256, &[5u8; AES_256_KEY_LEN]).unwrap();
+    let clone = key.clone();
+    assert_eq!(key.algorithm(), clone.algorithm());
+
+    let encrypting_key = PaddedBlockEncryptingKey::cbc_pkcs7(key).unwrap();
+    let mut in_out = b"hello, world".to_vec();
+    let context = encrypting_key.encrypt(&mut in_out).unwrap();
+    drop(encrypting_key);
+
+    // The clone is independent of the original, which has been dropped.
+    let decrypting_key = PaddedBlockDecryptingKey::cbc_pkcs7(clone).unwrap();
+    let plaintext = decrypting_key.decrypt(&mut in_out, context).unwrap();
+    assert_eq!(b"hello, world", plaintext);
+}
+
+#[test]
+fn test_cipher_key_debug() {
+    let mut key_bytes = [0x0b; AES_256_KEY_LEN];
+    key_bytes[0] = 0xff;
+    let key = UnboundCipherKey::new(&AES_256, &key_bytes).unwrap();
+    assert_eq!(
+        "UnboundCipherKey { algorithm: Algorithm { id: Aes256, key_len: 32, block_len: 16 } }",
+        format!("{key:?}")
+    );
+
+    // The key material is never included.
+    for key_debug in [
+        format!("{key:?}"),
+        format!(
+            "{:?}",
+            EncryptingKey::ctr(UnboundCipherKey::new(&AES_256, &key_bytes).unwrap()).unwrap()
+        ),
+        format!(
+            "{:?}",
+            StreamingEncryptingKey::ctr(UnboundCipherKey::new(&AES_256, &key_bytes).unwrap())
+                .unwrap()
+        ),
+        format!(
+            "{:?}",
+            StreamingDecryptingKey::ctr(
+                UnboundCipherKey::new(&AES_256, &key_bytes).unwrap(),
+                DecryptionContext::Iv128(FixedLength::from([0u8; IV_LEN_128_BIT])),
+            )
+            .unwrap()
+        ),
+    ] {
+        assert!(!key_debug.contains("ff0b"));
+        assert!(!key_debug.contains("255"));
+    }
+}
+
+#[test]
+fn unbound_cipher_key_zeroize_on_drop() {
+    fn assert_zeroize_on_drop<T: zeroize::ZeroizeOnDrop>() {}
+
+    assert_zeroize_on_drop::<UnboundCipherKey>();
+}
+
+fn padded_context_encrypt(
+    mut context: PaddedBlockEncryptingContext,
+    plaintext: &[u8],
+    step: usize,
+) -> (Vec<u8>, DecryptionContext) {
+    let mut ciphertext = Vec::new();
+    for chunk in plaintext.chunks(step) {
+        context.update(chunk, &mut ciphertext).unwrap();
+    }
+    let decryption_context = context.finalize(&mut ciphertext).unwrap();
+    (ciphertext, decryption_context)
+}
+
+fn context_encrypt(
+    mut context: EncryptingContext,
+    plaintext: &[u8],
+    step: usize,
+) -> (Vec<u8>, DecryptionContext) {
+    let mut ciphertext = Vec::new();
+    for chunk in plaintext.chunks(step) {
+        context.update(chunk, &mut ciphertext).unwrap();
+    }
+    let decryption_context = context.finalize(&mut ciphertext).unwrap();
+    (ciphertext, decryption_context)
+}
+
+#[test]
+fn test_padded_block_encrypting_context() {
+    let key_bytes = from_hex("000102030405060708090a0b0c0d0e0f").unwrap();
+    let iv = [0x42u8; IV_LEN_128_BIT];
+    let plaintext: Vec<u8> = (0..100u8).collect();
+
+    for (key_creator, uses_iv) in [
+        (PaddedBlockEncryptingKey::cbc_pkcs7 as fn(_) -> _, true),
+        (PaddedBlockEncryptingKey::cbc_iso7816, true),
+        (PaddedBlockEncryptingKey::ecb_pkcs7, false),
+    ] {
+        let key = key_creator(UnboundCipherKey::new(&AES_128, &key_bytes).unwrap()).unwrap();
+        let encryption_context = || {
+            if uses_iv {
+                EncryptionContext::Iv128(FixedLength::from(iv))
+            } else {
+                EncryptionContext::None
+            }
+        };
+        for len in [0, 1, 15, 16, 17, 32, 100] {
+            let mut expected = plaintext[..len].to_vec();
+            key.less_safe_encrypt(&mut expected, encryption_context())
+                .unwrap();
+
+            for step in [1, 7, 16, 33, 100] {
+                let context = key
+                    .less_safe_new_encrypt_context(encryption_context())
+                    .unwrap();
+                let (ciphertext, _) = padded_context_encrypt(context, &plaintext[..len], step);
+                assert_eq!(
+                    expected,
+                    ciphertext,
+                    "mode: {:?}, len: {len}, step: {step}",
+                    key.mode()
+                );
+            }
+        }
+    }
+
+    // The randomly generated IV is returned by `finalize`, and decrypts the ciphertext.
+    let key =
+        PaddedBlockEncryptingKey::cbc_x923(UnboundCipherKey::new(&AES_128, &key_bytes).unwrap())
+            .unwrap();
+    let context = key.new_encrypt_context().unwrap();
+    assert_eq!(
+        "PaddedBlockEncryptingContext { algorithm: Algorithm { id: Aes128, key_len: 16, block_len: 16 }, mode: CBC, padding: X923, .. }",
+        format!("{context:?}")
+    );
+    let (mut ciphertext, decryption_context) = padded_context_encrypt(context, &plaintext, 1);
+    assert_eq!(112, ciphertext.len());
+    let key =
+        PaddedBlockDecryptingKey::cbc_x923(UnboundCipherKey::new(&AES_128, &key_bytes).unwrap())
+            .unwrap();
+    assert_eq!(
+        plaintext,
+        key.decrypt(&mut ciphertext, decryption_context).unwrap()
+    );
+
+    // An IV is required in CBC mode.
+    let key =
+        PaddedBlockEncryptingKey::cbc_pkcs7(UnboundCipherKey::new(&AES_128, &key_bytes).unwrap())
+            .unwrap();
+    assert!(key
+        .less_safe_new_encrypt_context(EncryptionContext::None)
+        .is_err());
+}
+
+#[test]
+fn test_encrypting_context() {
+    let key_bytes =
+        from_hex("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f").unwrap();
+    let plaintext: Vec<u8> = (0..100u8).collect();
+
+    for key_creator in [
+        EncryptingKey::ctr as fn(_) -> _,
+        EncryptingKey::cfb128,
+        EncryptingKey::cfb8,
+        EncryptingKey::ofb,
+    ] {
+        let key = key_creator(UnboundCipherKey::new(&AES_256, &key_bytes).unwrap()).unwrap();
+        // The all-ones IV checks that the CTR mode counter carries across the full block.
+        for iv in [[0x42u8; IV_LEN_128_BIT], [0xff; IV_LEN_128_BIT]] {
+            for len in [0, 1, 15, 16, 17, 32, 100] {
+                let mut expected = plaintext[..len].to_vec();
+                key.less_safe_encrypt(
+                    &mut expected,
+                    EncryptionContext::Iv128(FixedLength::from(iv)),
+                )
+                .unwrap();
+
+                for step in [1, 7, 16, 33, 100] {
+                    let context = key
+                        .less_safe_new_encrypt_context(EncryptionContext::Iv128(FixedLength::from(
+                            iv,
+                        )))
+                        .unwrap();
+                    let (ciphertext, _) = context_encrypt(context, &plaintext[..len], step);
+                    assert_eq!(
+                        expected,
+                        ciphertext,
+                        "mode: {:?}, len: {len}, step: {step}",
+                        key.mode()
+                    );
+                }
+            }
+        }
+    }
+
+    let key = EncryptingKey::ecb(UnboundCipherKey::new(&AES_256, &key_bytes).unwrap()).unwrap();
+    let mut expected = plaintext[..96].to_vec();
+    key.less_safe_encrypt(&mut expected, EncryptionContext::None)
+        .unwrap();
+    let context = key.new_encrypt_context().unwrap();
+    assert_eq!(
+        "EncryptingContext { algorithm: Algorithm { id: Aes256, key_len: 32, block_len: 16 }, mode: ECB, .. }",
+        format!("{context:?}")
+    );
+    let (ciphertext, _) = context_encrypt(context, &plaintext[..96], 1);
+    assert_eq!(expected, ciphertext);
+    // In ECB mode, the input must be a multiple of the block length.
+    let mut context = key.new_encrypt_context().unwrap();
+    context.update(&plaintext[..17], &mut Vec::new()).unwrap();
+    assert!(context.finalize(&mut Vec::new()).is_err());
+
+    // A context does not borrow the key it was created from.
+    let key = EncryptingKey::ctr(UnboundCipherKey::new(&AES_256, &key_bytes).unwrap()).unwrap();
+    let iv = [0x42u8; IV_LEN_128_BIT];
+    let context = key
+        .less_safe_new_encrypt_context(EncryptionContext::Iv128(FixedLength::from(iv)))
+        .unwrap();
+    let mut expected = plaintext.clone();
+    key.less_safe_encrypt(
+        &mut expected,
+        EncryptionContext::Iv128(FixedLength::from(iv)),
+    )
+    .unwrap();
+    drop(key);
+    let (ciphertext, _) = context_encrypt(context, &plaintext, 5);
+    assert_eq!(expected, ciphertext);
+
+    // The randomly generated IV is returned by `finalize`, and decrypts the ciphertext.
+    let key = EncryptingKey::ctr(UnboundCipherKey::new(&AES_256, &key_bytes).unwrap()).unwrap();
+    let (mut ciphertext, decryption_context) =
+        context_encrypt(key.new_encrypt_context().unwrap(), &plaintext, 3);
+    let key = DecryptingKey::ctr(UnboundCipherKey::new(&AES_256, &key_bytes).unwrap()).unwrap();
+    assert_eq!(
+        plaintext,
+        key.decrypt(&mut ciphertext, decryption_context).unwrap()
+    );
+}
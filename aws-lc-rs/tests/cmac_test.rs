@@ -0,0 +1,56 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+use aws_lc_rs::cmac::{self, CmacContext, CmacKey};
+use aws_lc_rs::{test, test_file};
+
+#[test]
+fn cmac_tests() {
+    test::run(test_file!("data/cmac_tests.txt"), |section, test_case| {
+        assert_eq!(section, "");
+        let key_value = test_case.consume_bytes("Key");
+        let mut input = test_case.consume_bytes("Input");
+        let output = test_case.consume_bytes("Output");
+
+        let key = match key_value.len() {
+            16 => CmacKey::new_aes128(key_value.as_slice().try_into().unwrap()),
+            32 => CmacKey::new_aes256(key_value.as_slice().try_into().unwrap()),
+            _ => unreachable!(),
+        };
+
+        cmac_test_case_inner(&key, &input, &output, true);
+
+        // Tamper with the input and check that verification fails.
+        if input.is_empty() {
+            input.push(0);
+        } else {
+            input[0] ^= 1;
+        }
+        cmac_test_case_inner(&key, &input, &output, false);
+
+        Ok(())
+    });
+}
+
+fn cmac_test_case_inner(key: &CmacKey, input: &[u8], output: &[u8], is_ok: bool) {
+    // One-shot API.
+    let tag = cmac::cmac(key, input);
+    assert_eq!(is_ok, tag.as_ref() == output);
+    assert_eq!(is_ok, cmac::verify(key, input, output).is_ok());
+
+    // Multi-part API, byte by byte.
+    let mut ctx = CmacContext::with_key(key);
+    for b in input {
+        ctx.update(&[*b]);
+    }
+    assert_eq!(tag, ctx.sign());
+}
+
+#[test]
+fn cmac_verify_wrong_length() {
+    let key = CmacKey::new_aes128(&[0u8; 16]);
+    let tag = cmac::cmac(&key, b"message");
+    assert!(cmac::verify(&key, b"message", tag.as_ref()).is_ok());
+    assert!(cmac::verify(&key, b"message", &tag.as_ref()[..15]).is_err());
+    assert!(cmac::verify(&key, b"message", &[]).is_err());
+}
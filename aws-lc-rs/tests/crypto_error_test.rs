@@ -0,0 +1,136 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+use aws_lc_rs::agreement::{
+    agree, agree_with_error, PrivateKey, UnparsedPublicKey as AgreementPublicKey, ECDH_P256, X25519,
+};
+use aws_lc_rs::error::{CryptoError, KeyRejectionReason, Unspecified};
+use aws_lc_rs::rand::SystemRandom;
+use aws_lc_rs::rsa::KeyPair as RsaKeyPair;
+use aws_lc_rs::signature::{
+    self, EcdsaKeyPair, Ed25519KeyPair, KeyPair, UnparsedPublicKey, RSA_PKCS1_SHA256,
+};
+
+#[test]
+fn rsa_sign_with_error() {
+    let key_pair =
+        RsaKeyPair::from_pkcs8(include_bytes!("data/rsa_test_private_key_2048.p8")).unwrap();
+    let rng = SystemRandom::new();
+    let required = key_pair.public_modulus_len();
+
+    let mut signature = vec![0u8; required];
+    key_pair
+        .sign_with_error(&RSA_PKCS1_SHA256, &rng, b"message", &mut signature)
+        .unwrap();
+    UnparsedPublicKey::new(
+        &signature::RSA_PKCS1_2048_8192_SHA256,
+        key_pair.public_key().as_ref(),
+    )
+    .verify_with_error(b"message", &signature)
+    .unwrap();
+
+    let mut short = vec![0u8; required - 1];
+    assert_eq!(
+        Err(CryptoError::OutputBufferTooSmall { required }),
+        key_pair.sign_with_error(&RSA_PKCS1_SHA256, &rng, b"message", &mut short)
+    );
+    // The ring-compatible method reports the same failure as `Unspecified`.
+    assert_eq!(
+        Err(Unspecified),
+        key_pair.sign(&RSA_PKCS1_SHA256, &rng, b"message", &mut short)
+    );
+
+    let mut long = vec![0u8; required + 1];
+    assert_eq!(
+        Err(CryptoError::Unspecified),
+        key_pair.sign_with_error(&RSA_PKCS1_SHA256, &rng, b"message", &mut long)
+    );
+}
+
+#[test]
+fn key_rejected() {
+    let err = RsaKeyPair::from_pkcs8(b"not a key").unwrap_err();
+    assert_eq!(KeyRejectionReason::InvalidEncoding, err.reason());
+    assert_eq!(
+        CryptoError::KeyRejected(KeyRejectionReason::InvalidEncoding),
+        CryptoError::from(err)
+    );
+
+    let pkcs8 = EcdsaKeyPair::generate_pkcs8(
+        &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+        &SystemRandom::new(),
+    )
+    .unwrap();
+    let err = EcdsaKeyPair::from_pkcs8(&signature::ECDSA_P384_SHA384_ASN1_SIGNING, pkcs8.as_ref())
+        .unwrap_err();
+    assert_eq!(
+        CryptoError::KeyRejected(KeyRejectionReason::WrongAlgorithm),
+        CryptoError::from(err)
+    );
+}
+
+#[test]
+fn signature_verification_failed() {
+    let key_pair = Ed25519KeyPair::generate().unwrap();
+    let signature = key_pair.sign(b"message");
+    let public_key = UnparsedPublicKey::new(&signature::ED25519, key_pair.public_key().as_ref());
+
+    public_key
+        .verify_with_error(b"message", signature.as_ref())
+        .unwrap();
+    assert_eq!(
+        Err(CryptoError::SignatureVerificationFailed),
+        public_key.verify_with_error(b"other message", signature.as_ref())
+    );
+}
+
+#[test]
+fn agreement_algorithm_mismatch() {
+    let private_key = PrivateKey::generate(&X25519).unwrap();
+    let peer_private_key = PrivateKey::generate(&X25519).unwrap();
+    let peer_public_key = peer_private_key.compute_public_key().unwrap();
+
+    let secret = agree_with_error(
+        &private_key,
+        &AgreementPublicKey::new(&X25519, peer_public_key.as_ref()),
+        |secret| Ok(secret.to_vec()),
+    )
+    .unwrap();
+    let expected = agree(
+        &private_key,
+        &AgreementPublicKey::new(&X25519, peer_public_key.as_ref()),
+        Unspecified,
+        |secret| Ok(secret.to_vec()),
+    )
+    .unwrap();
+    assert_eq!(expected, secret);
+
+    assert_eq!(
+        Err(CryptoError::AlgorithmMismatch),
+        agree_with_error(
+            &private_key,
+            &AgreementPublicKey::new(&ECDH_P256, peer_public_key.as_ref()),
+            |_| Ok(()),
+        )
+    );
+
+    // The peer public key is not a valid X25519 key.
+    assert_eq!(
+        Err(CryptoError::Unspecified),
+        agree_with_error(
+            &private_key,
+            &AgreementPublicKey::new(&X25519, &[0u8; 31]),
+            |_| Ok(()),
+        )
+    );
+
+    // Errors from the KDF are returned unchanged.
+    assert_eq!(
+        Err(CryptoError::InvalidEncoding),
+        agree_with_error(
+            &private_key,
+            &AgreementPublicKey::new(&X25519, peer_public_key.as_ref()),
+            |_| Err::<(), _>(CryptoError::InvalidEncoding),
+        )
+    );
+}
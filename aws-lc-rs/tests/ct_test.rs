@@ -0,0 +1,106 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+use aws_lc_rs::ct::{self, LogEntry, SctInfo};
+use aws_lc_rs::signature::{
+    EcdsaKeyPair, Ed25519KeyPair, KeyPair, ECDSA_P256K1_SHA256_ASN1_SIGNING,
+    ECDSA_P256_SHA256_ASN1_SIGNING,
+};
+use aws_lc_rs::{test, test_file};
+
+const CERTIFICATE: &[u8] = include_bytes!("data/x509_must_staple.der");
+const TBS_CERTIFICATE: &[u8] = include_bytes!("data/ct_precert_tbs.der");
+
+fn ecdsa_log_key() -> EcdsaKeyPair {
+    EcdsaKeyPair::from_pkcs8(
+        &ECDSA_P256_SHA256_ASN1_SIGNING,
+        include_bytes!("data/ct_log_p256.p8"),
+    )
+    .unwrap()
+}
+
+fn ed25519_log_key() -> Ed25519KeyPair {
+    Ed25519KeyPair::from_pkcs8(include_bytes!("data/ct_log_ed25519.p8")).unwrap()
+}
+
+fn verify(
+    key: &str,
+    sct: &[u8],
+    entry: &LogEntry,
+) -> Result<SctInfo, aws_lc_rs::error::Unspecified> {
+    match key {
+        "ECDSA" => ct::verify_sct_ecdsa(ecdsa_log_key().public_key(), sct, entry),
+        "Ed25519" => ct::verify_sct(ed25519_log_key().public_key(), sct, entry),
+        _ => panic!("unsupported key: {key}"),
+    }
+}
+
+#[test]
+fn ct_sct_tests() {
+    test::run(test_file!("data/ct_sct_tests.txt"), |section, test_case| {
+        assert_eq!(section, "");
+        let key = test_case.consume_string("KEY");
+        let entry_type = test_case.consume_string("ENTRY");
+        let issuer_key_hash = test_case
+            .consume_optional_string("ISSUER_KEY_HASH")
+            .map(|hash| test::from_hex(&hash).unwrap());
+        let sct = test_case.consume_bytes("SCT");
+        let log_id = test_case.consume_bytes("LOG_ID");
+        let timestamp_ms: u64 = test_case.consume_string("TIMESTAMP").parse().unwrap();
+
+        let issuer_key_hash: Option<[u8; 32]> =
+            issuer_key_hash.map(|hash| hash.try_into().unwrap());
+        let entry = match (entry_type.as_str(), issuer_key_hash.as_ref()) {
+            ("X509", None) => LogEntry::X509Certificate(CERTIFICATE),
+            ("PRECERT", Some(issuer_key_hash)) => LogEntry::Precertificate {
+                issuer_key_hash,
+                tbs_certificate: TBS_CERTIFICATE,
+            },
+            _ => panic!("unsupported entry: {entry_type}"),
+        };
+
+        let info = verify(&key, &sct, &entry).unwrap();
+        assert_eq!(log_id, info.log_id);
+        assert_eq!(timestamp_ms, info.timestamp_ms);
+
+        // Modifying any byte of the SCT invalidates it.
+        for i in 0..sct.len() {
+            let mut modified = sct.clone();
+            modified[i] ^= 1;
+            assert!(verify(&key, &modified, &entry).is_err(), "byte {i}");
+        }
+        assert!(verify(&key, &sct[..sct.len() - 1], &entry).is_err());
+        let mut extended = sct.clone();
+        extended.push(0);
+        assert!(verify(&key, &extended, &entry).is_err());
+
+        // The SCT is bound to the entry and the log.
+        let other_entry = match entry {
+            LogEntry::X509Certificate(_) => LogEntry::Precertificate {
+                issuer_key_hash: &[0; 32],
+                tbs_certificate: TBS_CERTIFICATE,
+            },
+            _ => LogEntry::X509Certificate(CERTIFICATE),
+        };
+        assert!(verify(&key, &sct, &other_entry).is_err());
+        let other_key = if key == "ECDSA" { "Ed25519" } else { "ECDSA" };
+        assert!(verify(other_key, &sct, &entry).is_err());
+
+        Ok(())
+    });
+}
+
+#[test]
+fn ct_sct_ecdsa_requires_p256() {
+    let sct = test::from_hex(
+        "00a9e4ecbcdcee7fca8cdbc4d315e7a0af747e57a6034cf37ea4789d3431fd79f10000018bcfe5687b00000403\
+         0046304402201e3138ab178dde20116da57e770f9ebd820a017c432819a13b73d06541db89380220011892\
+         17e18d2668543aa64164eab03b323046f18d57de17820d2edd80deb6e7",
+    )
+    .unwrap();
+    let entry = LogEntry::X509Certificate(CERTIFICATE);
+    assert!(ct::verify_sct_ecdsa(ecdsa_log_key().public_key(), &sct, &entry).is_ok());
+
+    let secp256k1_key = EcdsaKeyPair::generate(&ECDSA_P256K1_SHA256_ASN1_SIGNING).unwrap();
+    assert!(ct::verify_sct_ecdsa(secp256k1_key.public_key(), &sct, &entry).is_err());
+}
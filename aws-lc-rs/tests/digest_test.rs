@@ -31,6 +31,97 @@ fn digest_misc() {
     });
 }
 
+#[cfg(not(feature = "fips"))]
+#[test]
+fn blake2_tests() {
+    test::run(test_file!("data/blake2_tests.txt"), |section, test_case| {
+        assert_eq!(section, "");
+        let digest_alg = test_case.consume_digest_alg("Hash").unwrap();
+        let input = test_case.consume_bytes("Input");
+        let expected = test_case.consume_bytes("Output");
+        assert_eq!(digest::BLAKE2B_256_OUTPUT_LEN, digest_alg.output_len());
+        assert_eq!(expected.len(), digest_alg.output_len());
+
+        let actual = digest::digest(digest_alg, &input);
+        assert_eq!(&expected, &actual.as_ref());
+
+        let mut ctx = digest::Context::new(digest_alg);
+        for chunk in input.chunks(7) {
+            ctx.update(chunk);
+        }
+        assert_eq!(&expected, &ctx.finish().as_ref());
+
+        Ok(())
+    });
+}
+
+#[cfg(all(feature = "legacy", not(feature = "fips")))]
+#[test]
+fn md5_tests() {
+    test::run(test_file!("data/md5_tests.txt"), |section, test_case| {
+        assert_eq!(section, "");
+        let digest_alg = test_case.consume_digest_alg("Hash").unwrap();
+        let input = test_case.consume_bytes("Input");
+        let expected = test_case.consume_bytes("Output");
+        assert_eq!(&digest::MD5_FOR_LEGACY_USE_ONLY, digest_alg);
+        assert_eq!(digest::MD5_OUTPUT_LEN, digest_alg.output_len());
+        assert_eq!(expected.len(), digest_alg.output_len());
+
+        let actual = digest::digest(digest_alg, &input);
+        assert_eq!(&expected, &actual.as_ref());
+
+        let mut ctx = digest::Context::new(digest_alg);
+        for chunk in input.chunks(7) {
+            ctx.update(chunk);
+        }
+        assert_eq!(&expected, &ctx.finish().as_ref());
+
+        Ok(())
+    });
+}
+
+#[test]
+fn shake_tests() {
+    test::run(test_file!("data/shake_tests.txt"), |section, test_case| {
+        assert_eq!(section, "");
+        let hash = test_case.consume_string("Hash");
+        let input = test_case.consume_bytes("Input");
+        let expected = test_case.consume_bytes("Output");
+
+        let (one_shot, mut ctx) = match hash.as_str() {
+            "SHAKE128" => (
+                digest::shake128 as fn(&[u8], &mut [u8]),
+                digest::ShakeContext::shake128(),
+            ),
+            "SHAKE256" => (
+                digest::shake256 as fn(&[u8], &mut [u8]),
+                digest::ShakeContext::shake256(),
+            ),
+            _ => panic!("unsupported hash: {hash}"),
+        };
+
+        let mut actual = vec![0u8; expected.len()];
+        one_shot(&input, &mut actual);
+        assert_eq!(expected, actual);
+
+        let mut actual = vec![0u8; expected.len()];
+        for chunk in input.chunks(7) {
+            ctx.update(chunk);
+        }
+        ctx.finish_xof(&mut actual);
+        assert_eq!(expected, actual);
+
+        // Every shorter output is a prefix of the longest one.
+        for len in [0, 1, 16, 31, 32, 33, 64, 167, 168, 169, 200] {
+            let mut actual = vec![0u8; len];
+            one_shot(&input, &mut actual);
+            assert_eq!(&expected[..len], actual.as_slice());
+        }
+
+        Ok(())
+    });
+}
+
 mod digest_shavs {
     use aws_lc_rs::{digest, test};
 
@@ -388,6 +479,37 @@ test_large_digest!(
 // TODO: test_large_digest!(digest_test_large_digest_sha512_256,
 //                            digest::SHA512_256, 256 / 8, [ ... ]);
 
+#[test]
+fn digest_sha512_224_output_len() {
+    assert_eq!(28, digest::SHA512_224_OUTPUT_LEN);
+    assert_eq!(28, digest::SHA512_224.output_len());
+    assert_eq!(28, digest::digest(&digest::SHA512_224, b"").as_ref().len());
+
+    let mut ctx = digest::Context::new(&digest::SHA512_224);
+    ctx.update(b"abc");
+    assert_eq!(28, ctx.finish().as_ref().len());
+}
+
+#[test]
+fn digest_context_clone_forks_state() {
+    let mut ctx = digest::Context::new(&digest::SHA256);
+    ctx.update(b"hello ");
+
+    let mut fork = ctx.clone();
+    fork.update(b"world");
+    assert_eq!(
+        digest::digest(&digest::SHA256, b"hello world").as_ref(),
+        fork.finish().as_ref()
+    );
+
+    // The original context is unaffected by updates to the clone.
+    ctx.update(b"there");
+    assert_eq!(
+        digest::digest(&digest::SHA256, b"hello there").as_ref(),
+        ctx.finish().as_ref()
+    );
+}
+
 #[test]
 fn test_fmt_algorithm() {
     assert_eq!("SHA1", &format!("{:?}", digest::SHA1_FOR_LEGACY_USE_ONLY));
@@ -395,6 +517,7 @@ fn test_fmt_algorithm() {
     assert_eq!("SHA256", &format!("{:?}", digest::SHA256));
     assert_eq!("SHA384", &format!("{:?}", digest::SHA384));
     assert_eq!("SHA512", &format!("{:?}", digest::SHA512));
+    assert_eq!("SHA512_224", &format!("{:?}", digest::SHA512_224));
     assert_eq!("SHA512_256", &format!("{:?}", digest::SHA512_256));
 }
 
@@ -429,6 +552,11 @@ fn digest_test_fmt() {
         &format!("{:?}", digest::digest(&digest::SHA512, b"hello, world"))
     );
 
+    assert_eq!(
+        "SHA512_224:7cbabb02ab4083f5b270bdd94705137aa3aaa6260a8e041b0f8f3046",
+        &format!("{:?}", digest::digest(&digest::SHA512_224, b"hello, world"))
+    );
+
     assert_eq!(
         "SHA512_256:11f2c88c04f0a9c3d0970894ad2472505e\
          0bc6e8c7ec46b5211cd1fa3e253e62",
@@ -3,10 +3,11 @@
 // Modifications copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0 OR ISC
 
-use aws_lc_rs::encoding::{AsBigEndian, AsDer, EcPrivateKeyRfc5915Der};
+use aws_lc_rs::encoding::pem::pem_decode;
+use aws_lc_rs::encoding::{AsBigEndian, AsDer, EcPrivateKeyPkcs8V2Der, EcPrivateKeyRfc5915Der};
 use aws_lc_rs::rand::SystemRandom;
 use aws_lc_rs::signature::{self, EcdsaKeyPair, KeyPair, Signature, UnparsedPublicKey};
-use aws_lc_rs::{test, test_file};
+use aws_lc_rs::{digest, test, test_file};
 
 #[test]
 fn ecdsa_traits() {
@@ -267,6 +268,25 @@ fn ecdsa_test_public_key_coverage() {
     );
 }
 
+#[test]
+fn ecdsa_public_key_x_y() {
+    for (alg, elem_len) in [
+        (&signature::ECDSA_P256_SHA256_ASN1_SIGNING, 32),
+        (&signature::ECDSA_P384_SHA384_ASN1_SIGNING, 48),
+        (&signature::ECDSA_P521_SHA512_ASN1_SIGNING, 66),
+        (&signature::ECDSA_P256K1_SHA256_ASN1_SIGNING, 32),
+    ] {
+        let key_pair = EcdsaKeyPair::generate(alg).unwrap();
+        let (x, y) = key_pair.public_key_x_y().unwrap();
+        assert_eq!(elem_len, x.len());
+        assert_eq!(elem_len, y.len());
+        assert_eq!(
+            key_pair.public_key().as_ref(),
+            [&[0x04][..], &x, &y].concat().as_slice()
+        );
+    }
+}
+
 #[test]
 fn signature_ecdsa_sign_fixed_sign_and_verify_test() {
     test_signature_ecdsa_sign_fixed_sign_and_verify(test_file!("data/ecdsa_sign_fixed_tests.txt"));
@@ -514,3 +534,339 @@ fn test_private_key() {
         }
     }
 }
+
+#[test]
+fn ecdsa_is_fips_approved_curve() {
+    for (alg, expected) in [
+        (&signature::ECDSA_P256_SHA256_ASN1_SIGNING, true),
+        (&signature::ECDSA_P384_SHA384_FIXED_SIGNING, true),
+        (&signature::ECDSA_P521_SHA512_ASN1_SIGNING, true),
+        (&signature::ECDSA_P256K1_SHA256_FIXED_SIGNING, false),
+    ] {
+        let key_pair = EcdsaKeyPair::generate(alg).unwrap();
+        assert_eq!(expected, key_pair.is_fips_approved_curve());
+    }
+}
+
+#[test]
+fn ecdsa_from_sec1_der() {
+    // Generated with `openssl ecparam -genkey -noout | openssl ec -outform DER`
+    const P256_KEY: &[u8] = include_bytes!("data/ecdsa_test_private_key_p256_sec1.der");
+    const P384_KEY: &[u8] = include_bytes!("data/ecdsa_test_private_key_p384_sec1.der");
+
+    let key_pair =
+        EcdsaKeyPair::from_sec1_der(&signature::ECDSA_P256_SHA256_ASN1_SIGNING, P256_KEY).unwrap();
+    let der: EcPrivateKeyRfc5915Der = key_pair.as_der().unwrap();
+    assert_eq!(P256_KEY, der.as_ref());
+
+    let key_pair =
+        EcdsaKeyPair::from_sec1_der(&signature::ECDSA_P384_SHA384_FIXED_SIGNING, P384_KEY).unwrap();
+    let der: EcPrivateKeyRfc5915Der = key_pair.as_der().unwrap();
+    assert_eq!(P384_KEY, der.as_ref());
+
+    // Embedded curve does not match the algorithm.
+    assert!(
+        EcdsaKeyPair::from_sec1_der(&signature::ECDSA_P384_SHA384_ASN1_SIGNING, P256_KEY).is_err()
+    );
+    assert!(
+        EcdsaKeyPair::from_sec1_der(&signature::ECDSA_P256_SHA256_ASN1_SIGNING, P384_KEY).is_err()
+    );
+
+    // PKCS#8 documents are not accepted.
+    let pkcs8 = key_pair.to_pkcs8v1().unwrap();
+    assert!(EcdsaKeyPair::from_sec1_der(
+        &signature::ECDSA_P384_SHA384_FIXED_SIGNING,
+        pkcs8.as_ref()
+    )
+    .is_err());
+}
+
+#[test]
+fn ecdsa_from_encrypted_pkcs8() {
+    const PASSWORD: &[u8] = b"password";
+    let pkcs8 = include_bytes!("data/ecdsa_test_private_key_p256.p8");
+    let (label, encrypted) = pem_decode(include_str!(
+        "data/ecdsa_test_private_key_p256_encrypted.pem"
+    ))
+    .unwrap();
+    assert_eq!("ENCRYPTED PRIVATE KEY", label);
+
+    let key_pair = EcdsaKeyPair::from_encrypted_pkcs8(
+        &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+        &encrypted,
+        PASSWORD,
+    )
+    .unwrap();
+    let expected =
+        EcdsaKeyPair::from_pkcs8(&signature::ECDSA_P256_SHA256_ASN1_SIGNING, pkcs8).unwrap();
+    assert_eq!(
+        expected.private_key().as_be_bytes().unwrap().as_ref(),
+        key_pair.private_key().as_be_bytes().unwrap().as_ref()
+    );
+    assert_eq!(
+        expected.public_key().as_ref(),
+        key_pair.public_key().as_ref()
+    );
+
+    let message = b"hello, world";
+    let sig = key_pair.sign(&SystemRandom::new(), message).unwrap();
+    UnparsedPublicKey::new(&signature::ECDSA_P256_SHA256_ASN1, key_pair.public_key())
+        .verify(message, sig.as_ref())
+        .unwrap();
+
+    assert!(EcdsaKeyPair::from_encrypted_pkcs8(
+        &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+        &encrypted,
+        b"wrong password",
+    )
+    .is_err());
+    assert!(EcdsaKeyPair::from_encrypted_pkcs8(
+        &signature::ECDSA_P384_SHA384_ASN1_SIGNING,
+        &encrypted,
+        PASSWORD,
+    )
+    .is_err());
+    assert!(EcdsaKeyPair::from_encrypted_pkcs8(
+        &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+        include_bytes!("data/rsa_test_private_key_2048_aes128.p8"),
+        PASSWORD,
+    )
+    .is_err());
+}
+
+#[test]
+fn ecdsa_pkcs8_v2() {
+    // `ecdsa_test_private_key_p256.p8` converted to a `OneAsymmetricKey` with the `publicKey`
+    // field set to the uncompressed public point.
+    const P256_KEY_V1: &[u8] = include_bytes!("data/ecdsa_test_private_key_p256.p8");
+    const P256_KEY_V2: &[u8] = include_bytes!("data/ecdsa_test_private_key_p256_pkcs8_v2.der");
+    let alg = &signature::ECDSA_P256_SHA256_ASN1_SIGNING;
+
+    let key_pair = EcdsaKeyPair::from_pkcs8_v2(alg, P256_KEY_V2).unwrap();
+    let key_pair_v1 = EcdsaKeyPair::from_pkcs8(alg, P256_KEY_V1).unwrap();
+    assert_eq!(
+        key_pair_v1.public_key().as_ref(),
+        key_pair.public_key().as_ref()
+    );
+    let der: EcPrivateKeyPkcs8V2Der = key_pair.as_der().unwrap();
+    assert_eq!("EcPrivateKeyPkcs8V2Der", format!("{der:?}"));
+    assert_eq!(P256_KEY_V2, der.as_ref());
+
+    // PKCS#8 v1 documents are also accepted.
+    let key_pair = EcdsaKeyPair::from_pkcs8_v2(alg, P256_KEY_V1).unwrap();
+    assert_eq!(
+        key_pair_v1.public_key().as_ref(),
+        key_pair.public_key().as_ref()
+    );
+
+    // The curve must match the algorithm.
+    assert!(
+        EcdsaKeyPair::from_pkcs8_v2(&signature::ECDSA_P384_SHA384_ASN1_SIGNING, P256_KEY_V2)
+            .is_err()
+    );
+
+    // A `publicKey` field is not allowed in a version 1 document.
+    let mut v1_with_public_key = P256_KEY_V2.to_vec();
+    assert_eq!(&[0x02, 0x01, 0x01], &v1_with_public_key[3..6]);
+    v1_with_public_key[5] = 0;
+    assert!(EcdsaKeyPair::from_pkcs8_v2(alg, &v1_with_public_key).is_err());
+
+    // The public key must match the private key.
+    let mut wrong_public_key = P256_KEY_V2.to_vec();
+    let other_public_key = EcdsaKeyPair::generate(alg).unwrap();
+    let other_public_key = other_public_key.public_key().as_ref();
+    let len = wrong_public_key.len();
+    wrong_public_key[len - other_public_key.len()..].copy_from_slice(other_public_key);
+    assert!(EcdsaKeyPair::from_pkcs8_v2(alg, &wrong_public_key).is_err());
+
+    // Trailing data is rejected.
+    let mut trailing_data = P256_KEY_V2.to_vec();
+    trailing_data.push(0);
+    assert!(EcdsaKeyPair::from_pkcs8_v2(alg, &trailing_data).is_err());
+}
+
+#[test]
+fn ecdsa_pkcs8_v2_round_trip() {
+    for (signing_alg, verification_alg) in [
+        (
+            &signature::ECDSA_P521_SHA512_ASN1_SIGNING,
+            &signature::ECDSA_P521_SHA512_ASN1,
+        ),
+        (
+            &signature::ECDSA_P384_SHA384_FIXED_SIGNING,
+            &signature::ECDSA_P384_SHA384_FIXED,
+        ),
+        (
+            &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+            &signature::ECDSA_P256_SHA256_ASN1,
+        ),
+        (
+            &signature::ECDSA_P256K1_SHA256_FIXED_SIGNING,
+            &signature::ECDSA_P256K1_SHA256_FIXED,
+        ),
+    ] {
+        let key_pair = EcdsaKeyPair::generate(signing_alg).unwrap();
+        let der: EcPrivateKeyPkcs8V2Der = key_pair.as_der().unwrap();
+
+        let parsed = EcdsaKeyPair::from_pkcs8_v2(signing_alg, der.as_ref()).unwrap();
+        assert_eq!(key_pair.public_key().as_ref(), parsed.public_key().as_ref());
+        let der_again: EcPrivateKeyPkcs8V2Der = parsed.as_der().unwrap();
+        assert_eq!(der.as_ref(), der_again.as_ref());
+
+        // The v1 parser does not accept the `publicKey` field.
+        assert!(EcdsaKeyPair::from_pkcs8(signing_alg, der.as_ref()).is_err());
+
+        let message = b"pkcs8 v2";
+        let signature = parsed.sign(&SystemRandom::new(), message).unwrap();
+        UnparsedPublicKey::new(verification_alg, key_pair.public_key().as_ref())
+            .verify(message, signature.as_ref())
+            .unwrap();
+    }
+}
+
+#[test]
+fn ecdsa_sign_digest() {
+    const MESSAGE: &[u8] = b"hello";
+    for (alg, verification_alg, digest_alg) in [
+        (
+            &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+            &signature::ECDSA_P256_SHA256_ASN1,
+            &digest::SHA256,
+        ),
+        (
+            &signature::ECDSA_P256_SHA256_FIXED_SIGNING,
+            &signature::ECDSA_P256_SHA256_FIXED,
+            &digest::SHA256,
+        ),
+        (
+            &signature::ECDSA_P384_SHA384_ASN1_SIGNING,
+            &signature::ECDSA_P384_SHA384_ASN1,
+            &digest::SHA384,
+        ),
+        (
+            &signature::ECDSA_P521_SHA512_FIXED_SIGNING,
+            &signature::ECDSA_P521_SHA512_FIXED,
+            &digest::SHA512,
+        ),
+        (
+            &signature::ECDSA_P256K1_SHA3_256_ASN1_SIGNING,
+            &signature::ECDSA_P256K1_SHA3_256_ASN1,
+            &digest::SHA3_256,
+        ),
+    ] {
+        let key_pair = EcdsaKeyPair::generate(alg).unwrap();
+        let public_key = UnparsedPublicKey::new(verification_alg, key_pair.public_key());
+        let hash = digest::digest(digest_alg, MESSAGE);
+
+        // Signatures over the digest and over the message are interchangeable.
+        let signature = key_pair.sign_digest(digest_alg, hash.as_ref()).unwrap();
+        public_key.verify(MESSAGE, signature.as_ref()).unwrap();
+        public_key
+            .verify_digest(digest_alg, hash.as_ref(), signature.as_ref())
+            .unwrap();
+
+        let signature = key_pair.sign(&SystemRandom::new(), MESSAGE).unwrap();
+        public_key
+            .verify_digest(digest_alg, hash.as_ref(), signature.as_ref())
+            .unwrap();
+
+        let other = digest::digest(digest_alg, b"other");
+        assert!(public_key
+            .verify_digest(digest_alg, other.as_ref(), signature.as_ref())
+            .is_err());
+
+        // The digest must have the length of the key's digest algorithm.
+        let truncated = &hash.as_ref()[1..];
+        assert!(key_pair.sign_digest(digest_alg, truncated).is_err());
+        assert!(public_key
+            .verify_digest(digest_alg, truncated, signature.as_ref())
+            .is_err());
+        assert!(key_pair
+            .sign_digest(digest_alg, &[hash.as_ref(), &[0]].concat())
+            .is_err());
+    }
+
+    // The digest algorithm must match the key's signing algorithm.
+    let key_pair = EcdsaKeyPair::generate(&signature::ECDSA_P256_SHA256_ASN1_SIGNING).unwrap();
+    let hash = digest::digest(&digest::SHA384, MESSAGE);
+    assert!(key_pair
+        .sign_digest(&digest::SHA384, hash.as_ref())
+        .is_err());
+    let hash = digest::digest(&digest::SHA3_256, MESSAGE);
+    assert!(key_pair
+        .sign_digest(&digest::SHA3_256, hash.as_ref())
+        .is_err());
+
+    // Pre-hashed messages are not supported by other algorithms.
+    let public_key = UnparsedPublicKey::new(&signature::ED25519, [0u8; 32]);
+    let hash = digest::digest(&digest::SHA512, MESSAGE);
+    assert!(public_key
+        .verify_digest(&digest::SHA512, hash.as_ref(), &[0u8; 64])
+        .is_err());
+}
+
+#[test]
+fn ecdsa_normalize_s() {
+    for signing_alg in [
+        &signature::ECDSA_P256K1_SHA256_FIXED_SIGNING,
+        &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+        &signature::ECDSA_P384_SHA384_FIXED_SIGNING,
+        &signature::ECDSA_P521_SHA512_ASN1_SIGNING,
+    ] {
+        let key_pair = EcdsaKeyPair::generate(signing_alg).unwrap();
+        let public_key = UnparsedPublicKey::new(&**signing_alg, key_pair.public_key());
+        let rng = SystemRandom::new();
+
+        let (mut found_high_s, mut found_low_s) = (false, false);
+        for i in 0u32..256 {
+            let msg = i.to_be_bytes();
+            let sig = key_pair.sign(&rng, &msg).unwrap();
+            let normalized = signing_alg.normalize_s(sig.as_ref()).unwrap();
+            public_key.verify(&msg, normalized.as_ref()).unwrap();
+
+            // Normalization is idempotent.
+            let renormalized = signing_alg.normalize_s(normalized.as_ref()).unwrap();
+            assert_eq!(normalized.as_ref(), renormalized.as_ref());
+
+            if normalized.as_ref() == sig.as_ref() {
+                found_low_s = true;
+            } else {
+                found_high_s = true;
+            }
+            if found_high_s && found_low_s {
+                break;
+            }
+        }
+        assert!(found_high_s && found_low_s);
+
+        let sig = key_pair.sign(&rng, b"hello").unwrap();
+        assert!(signing_alg.normalize_s(&sig.as_ref()[1..]).is_err());
+        assert!(signing_alg.normalize_s(&[]).is_err());
+    }
+
+    // `s` must be in the range [1, n-1].
+    let mut sig = [0u8; 64];
+    sig[31] = 1;
+    assert!(signature::ECDSA_P256K1_SHA256_FIXED
+        .normalize_s(&sig)
+        .is_err());
+    sig[32..].fill(0xff);
+    assert!(signature::ECDSA_P256K1_SHA256_FIXED
+        .normalize_s(&sig)
+        .is_err());
+
+    // `s` equal to half the order of secp256k1 is already normalized; one more is not.
+    let half_order =
+        test::from_hex("7fffffffffffffffffffffffffffffff5d576e7357a4501ddfe92f46681b20a0").unwrap();
+    sig[32..].copy_from_slice(&half_order);
+    let normalized = signature::ECDSA_P256K1_SHA256_FIXED
+        .normalize_s(&sig)
+        .unwrap();
+    assert_eq!(&sig[..], normalized.as_ref());
+    sig[63] += 1;
+    let normalized = signature::ECDSA_P256K1_SHA256_FIXED
+        .normalize_s(&sig)
+        .unwrap();
+    assert_eq!(&sig[..32], &normalized.as_ref()[..32]);
+    assert_eq!(&half_order[..], &normalized.as_ref()[32..]);
+}
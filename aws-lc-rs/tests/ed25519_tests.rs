@@ -259,3 +259,47 @@ fn test_seed() {
 
     assert_eq!(key_pair_doc.as_ref(), key_pair_seed_copy_doc.as_ref());
 }
+
+#[test]
+fn test_ed25519_batch_verify() {
+    let key_pairs: Vec<Ed25519KeyPair> = (0..10)
+        .map(|_| Ed25519KeyPair::generate().unwrap())
+        .collect();
+    let messages: Vec<Vec<u8>> = (0..100u8).map(|i| vec![i; usize::from(i)]).collect();
+    let public_keys: Vec<&signature::Ed25519PublicKey> = (0..100)
+        .map(|i| key_pairs[i % key_pairs.len()].public_key())
+        .collect();
+    let mut signatures: Vec<Vec<u8>> = messages
+        .iter()
+        .enumerate()
+        .map(|(i, message)| {
+            key_pairs[i % key_pairs.len()]
+                .sign(message)
+                .as_ref()
+                .to_vec()
+        })
+        .collect();
+
+    let message_refs: Vec<&[u8]> = messages.iter().map(Vec::as_slice).collect();
+    let signature_refs: Vec<&[u8]> = signatures.iter().map(Vec::as_slice).collect();
+    signature::ed25519_batch_verify(&message_refs, &public_keys, &signature_refs).unwrap();
+    signature::ed25519_batch_verify(&[], &[], &[]).unwrap();
+
+    // The slices must have the same length.
+    assert!(
+        signature::ed25519_batch_verify(&message_refs[1..], &public_keys, &signature_refs).is_err()
+    );
+    assert!(
+        signature::ed25519_batch_verify(&message_refs, &public_keys[1..], &signature_refs).is_err()
+    );
+
+    // A single invalid signature fails the batch.
+    signatures[57][10] ^= 1;
+    let signature_refs: Vec<&[u8]> = signatures.iter().map(Vec::as_slice).collect();
+    assert!(signature::ed25519_batch_verify(&message_refs, &public_keys, &signature_refs).is_err());
+
+    // As does a signature from the wrong key.
+    signatures[57] = key_pairs[0].sign(&messages[57]).as_ref().to_vec();
+    let signature_refs: Vec<&[u8]> = signatures.iter().map(Vec::as_slice).collect();
+    assert!(signature::ed25519_batch_verify(&message_refs, &public_keys, &signature_refs).is_err());
+}
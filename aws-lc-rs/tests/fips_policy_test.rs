@@ -0,0 +1,36 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+#![cfg(feature = "fips")]
+
+use aws_lc_rs::fips::{get_policy, set_policy, FipsPolicy};
+use aws_lc_rs::pbkdf2;
+use core::num::NonZeroU32;
+
+// The policy is process-wide, so this is the only test in this binary.
+#[test]
+#[should_panic(expected = "not FIPS approved")]
+fn panic_on_non_approved() {
+    let iterations = NonZeroU32::new(1000).unwrap();
+    let mut out = [0u8; 32];
+
+    // An approved use of PBKDF2 is unaffected by the policy.
+    set_policy(FipsPolicy::PanicOnNonApproved);
+    assert_eq!(FipsPolicy::PanicOnNonApproved, get_policy());
+    pbkdf2::derive(
+        pbkdf2::PBKDF2_HMAC_SHA256,
+        iterations,
+        &[42u8; 16],
+        &[42u8; 14],
+        &mut out,
+    );
+
+    // A salt shorter than 16 bytes is not approved.
+    pbkdf2::derive(
+        pbkdf2::PBKDF2_HMAC_SHA256,
+        iterations,
+        &[42u8; 15],
+        &[42u8; 14],
+        &mut out,
+    );
+}
@@ -227,3 +227,100 @@ impl From<hkdf::Okm<'_, My<usize>>> for My<Vec<u8>> {
         Self(r)
     }
 }
+
+/// Builds the `HkdfLabel` structure of RFC 8446, Section 7.1.
+fn tls13_label(label: &[u8], context: &[u8], len: u16) -> Vec<u8> {
+    let mut info = len.to_be_bytes().to_vec();
+    info.push(u8::try_from(b"tls13 ".len() + label.len()).unwrap());
+    info.extend_from_slice(b"tls13 ");
+    info.extend_from_slice(label);
+    info.push(u8::try_from(context.len()).unwrap());
+    info.extend_from_slice(context);
+    info
+}
+
+// RFC 8448, Section 3 (Simple 1-RTT Handshake) key schedule.
+#[test]
+fn hkdf_extract_expand_rfc8448() {
+    let alg = hkdf::HKDF_SHA256;
+    let empty_hash = digest::digest(&digest::SHA256, b"");
+    let derived_info = tls13_label(b"derived", empty_hash.as_ref(), 32);
+
+    let early_secret = hkdf::extract(alg, None, &[0u8; 32]).unwrap();
+    assert_prk_eq(
+        &early_secret,
+        "33ad0a1c607ec03b09e6cd9893680ce210adf300aa1f2660e1b22e10f170f92a",
+    );
+
+    let mut derived = [0u8; 32];
+    hkdf::expand(&early_secret, &derived_info, &mut derived).unwrap();
+    assert_eq!(
+        test::from_dirty_hex("6f2615a108c702c5678f54fc9dbab69716c076189c48250cebeac3576c3611ba"),
+        derived
+    );
+
+    let ecdhe =
+        test::from_dirty_hex("8bd4054fb55b9d63fdfbacf9f04b9f0d35e6d63f537563efd46272900f89492d");
+    let handshake_secret =
+        hkdf::extract(alg, Some(&hkdf::Salt::new(alg, &derived)), &ecdhe).unwrap();
+    assert_prk_eq(
+        &handshake_secret,
+        "1dc826e93606aa6fdc0aadc12f741b01046aa6b99f691ed221a9f0ca043fbeac",
+    );
+
+    hkdf::expand(&handshake_secret, &derived_info, &mut derived).unwrap();
+    assert_eq!(
+        test::from_dirty_hex("43de77e0c77713859a944db9db2590b53190a65b3ee2e4f12dd7a0bb7ce254b4"),
+        derived
+    );
+
+    let master_secret =
+        hkdf::extract(alg, Some(&hkdf::Salt::new(alg, &derived)), &[0u8; 32]).unwrap();
+    assert_prk_eq(
+        &master_secret,
+        "18df06843d13a08bf2a449844c5f8a478001bc4d4c627984d5a41da8d0402919",
+    );
+}
+
+/// Asserts that `prk` has the given value, by comparing its output with that of a `Prk`
+/// constructed directly from the value.
+fn assert_prk_eq(prk: &hkdf::Prk, expected: &str) {
+    let expected = hkdf::Prk::new_less_safe(hkdf::HKDF_SHA256, &test::from_dirty_hex(expected));
+    let mut out = [0u8; 64];
+    let mut expected_out = [0u8; 64];
+    hkdf::expand(prk, b"check", &mut out).unwrap();
+    hkdf::expand(&expected, b"check", &mut expected_out).unwrap();
+    assert_eq!(expected_out, out);
+}
+
+#[test]
+fn hkdf_expand_independent_info() {
+    let alg = hkdf::HKDF_SHA384;
+    let prk = hkdf::extract(alg, None, b"secret").unwrap();
+
+    let mut key = [0u8; 32];
+    let mut iv = [0u8; 32];
+    hkdf::expand(&prk, b"key", &mut key).unwrap();
+    hkdf::expand(&prk, b"iv", &mut iv).unwrap();
+    assert_ne!(key, iv);
+
+    // Expanding again with the same info yields the same output, and matches `Prk::expand`.
+    let mut key_again = [0u8; 32];
+    hkdf::expand(&prk, b"key", &mut key_again).unwrap();
+    assert_eq!(key, key_again);
+    let My(expected): My<Vec<u8>> = prk.expand(&[b"key"], My(32)).unwrap().into();
+    assert_eq!(expected, key);
+
+    // The output length is limited to 255 blocks.
+    let mut out = vec![0u8; 255 * 48];
+    assert!(hkdf::expand(&prk, b"", &mut out).is_ok());
+    out.push(0);
+    assert!(hkdf::expand(&prk, b"", &mut out).is_err());
+}
+
+#[test]
+fn hkdf_extract_salt_algorithm_mismatch() {
+    let salt = hkdf::Salt::new(hkdf::HKDF_SHA256, b"salt");
+    assert!(hkdf::extract(hkdf::HKDF_SHA384, Some(&salt), b"secret").is_err());
+    assert!(hkdf::extract(hkdf::HKDF_SHA256, Some(&salt), b"secret").is_ok());
+}
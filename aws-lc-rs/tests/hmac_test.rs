@@ -4,7 +4,7 @@
 // SPDX-License-Identifier: Apache-2.0 OR ISC
 
 use aws_lc_rs::hmac::sign;
-use aws_lc_rs::{digest, hmac, test, test_file};
+use aws_lc_rs::{digest, hkdf, hmac, rand, test, test_file};
 
 #[test]
 fn hmac_tests() {
@@ -87,7 +87,17 @@ fn hmac_test_case_inner(
 #[test]
 fn hmac_debug() {
     let key = hmac::Key::new(hmac::HMAC_SHA256, &[0; 32]);
-    assert_eq!("Key { algorithm: SHA256 }", format!("{:?}", &key));
+    assert_eq!(
+        "Key { algorithm: SHA256, key_len: 32 }",
+        format!("{:?}", &key)
+    );
+
+    // The key material is never included.
+    let key_bytes = [0xff, 0x0b, 0x0b, 0x0b, 0x0b, 0x0b, 0x0b, 0x0b];
+    let secret_key = hmac::Key::new(hmac::HMAC_SHA256, &key_bytes);
+    let debug = format!("{secret_key:?}");
+    assert!(!debug.contains("ff0b"));
+    assert!(!debug.contains("255"));
 
     let ctx = hmac::Context::with_key(&key);
     assert_eq!("Context { algorithm: SHA256 }", format!("{:?}", &ctx));
@@ -95,6 +105,39 @@ fn hmac_debug() {
     assert_eq!("Algorithm(SHA256)", format!("{:?}", hmac::HMAC_SHA256));
 }
 
+#[test]
+fn hmac_generate_key() {
+    let rng = rand::SystemRandom::new();
+    for algorithm in [hmac::HMAC_SHA256, hmac::HMAC_SHA384, hmac::HMAC_SHA512] {
+        let key = hmac::Key::generate(algorithm, &rng).unwrap();
+        let tag = hmac::sign(&key, b"hello, world");
+
+        #[cfg(not(feature = "fips"))]
+        {
+            let key_value = key.as_bytes().unwrap();
+            assert_eq!(algorithm.digest_algorithm().output_len(), key_value.len());
+            let key2 = hmac::Key::new(algorithm, key_value);
+            hmac::verify(&key2, b"hello, world", tag.as_ref()).unwrap();
+            assert!(hmac::verify(&key2, b"hello, world!", tag.as_ref()).is_err());
+        }
+        #[cfg(feature = "fips")]
+        assert!(key.as_bytes().is_none());
+
+        hmac::verify(&key, b"hello, world", tag.as_ref()).unwrap();
+    }
+
+    // Only generated keys retain their value.
+    let key = hmac::Key::new(hmac::HMAC_SHA256, b"this is a test!");
+    assert_eq!(None, key.as_bytes());
+    let key = hmac::Key::from(
+        hkdf::Salt::new(hkdf::HKDF_SHA256, b"salt")
+            .extract(b"secret")
+            .expand(&[], hmac::HMAC_SHA256)
+            .unwrap(),
+    );
+    assert_eq!(None, key.as_bytes());
+}
+
 #[test]
 fn hmac_traits() {
     test::compile_time_assert_send::<hmac::Key>();
@@ -0,0 +1,264 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+#![cfg(feature = "unstable")]
+
+use aws_lc_rs::test::from_hex;
+use aws_lc_rs::unstable::hpke::{
+    Aead, HpkeRecipientContext, HpkeSenderContext, PrivateKey, RecipientMode, SenderMode,
+    AES_128_GCM, AES_256_GCM, CHACHA20_POLY1305, DHKEM_X25519_HKDF_SHA256, HKDF_SHA256,
+};
+
+const INFO: &[u8] = b"Ode on a Grecian Urn";
+const PT: &[u8] = b"Beauty is truth, truth beauty";
+
+struct SenderKey {
+    sk_sm: &'static str,
+    pk_sm: &'static str,
+}
+
+struct TestVector {
+    aead: &'static Aead,
+    sk_rm: &'static str,
+    pk_rm: &'static str,
+    enc: &'static str,
+    sender: Option<SenderKey>,
+    ciphertexts: [&'static str; 2],
+    exports: [&'static str; 2],
+}
+
+// Test vectors from RFC 9180, Appendix A, for the sequence numbers 0 and 1 and the exporter
+// contexts "" and "TestContext". All use DHKEM(X25519, HKDF-SHA256) and HKDF-SHA256.
+const TEST_VECTORS: [TestVector; 3] = [
+    // A.1.1: Base mode, AES-128-GCM
+    TestVector {
+        aead: &AES_128_GCM,
+        sk_rm: "4612c550263fc8ad58375df3f557aac531d26850903e55a9f23f21d8534e8ac8",
+        pk_rm: "3948cfe0ad1ddb695d780e59077195da6c56506b027329794ab02bca80815c4d",
+        enc: "37fda3567bdbd628e88668c3c8d7e97d1d1253b6d4ea6d44c150f741f1bf4431",
+        sender: None,
+        ciphertexts: [
+            "f938558b5d72f1a23810b4be2ab4f84331acc02fc97babc53a52ae8218a355a96d8770ac83d07bea87e13c512a",
+            "af2d7e9ac9ae7e270f46ba1f975be53c09f8d875bdc8535458c2494e8a6eab251c03d0c22a56b8ca42c2063b84",
+        ],
+        exports: [
+            "3853fe2b4035195a573ffc53856e77058e15d9ea064de3e59f4961d0095250ee",
+            "e9e43065102c3836401bed8c3c3c75ae46be1639869391d62c61f1ec7af54931",
+        ],
+    },
+    // A.1.3: Auth mode, AES-128-GCM
+    TestVector {
+        aead: &AES_128_GCM,
+        sk_rm: "fdea67cf831f1ca98d8e27b1f6abeb5b7745e9d35348b80fa407ff6958f9137e",
+        pk_rm: "1632d5c2f71c2b38d0a8fcc359355200caa8b1ffdf28618080466c909cb69b2e",
+        enc: "23fb952571a14a25e3d678140cd0e5eb47a0961bb18afcf85896e5453c312e76",
+        sender: Some(SenderKey {
+            sk_sm: "dc4a146313cce60a278a5323d321f051c5707e9c45ba21a3479fecdf76fc69dd",
+            pk_sm: "8b0c70873dc5aecb7f9ee4e62406a397b350e57012be45cf53b7105ae731790b",
+        }),
+        ciphertexts: [
+            "5fd92cc9d46dbf8943e72a07e42f363ed5f721212cd90bcfd072bfd9f44e06b80fd17824947496e21b680c141b",
+            "d3736bb256c19bfa93d79e8f80b7971262cb7c887e35c26370cfed62254369a1b52e3d505b79dd699f002bc8ed",
+        ],
+        exports: [
+            "28c70088017d70c896a8420f04702c5a321d9cbf0279fba899b59e51bac72c85",
+            "5a0131813abc9a522cad678eb6bafaabc43389934adb8097d23c5ff68059eb64",
+        ],
+    },
+    // A.2.1: Base mode, ChaCha20Poly1305
+    TestVector {
+        aead: &CHACHA20_POLY1305,
+        sk_rm: "8057991eef8f1f1af18f4a9491d16a1ce333f695d4db8e38da75975c4478e0fb",
+        pk_rm: "4310ee97d88cc1f088a5576c77ab0cf5c3ac797f3d95139c6c84b5429c59662a",
+        enc: "1afa08d3dec047a643885163f1180476fa7ddb54c6a8029ea33f95796bf2ac4a",
+        sender: None,
+        ciphertexts: [
+            "1c5250d8034ec2b784ba2cfd69dbdb8af406cfe3ff938e131f0def8c8b60b4db21993c62ce81883d2dd1b51a28",
+            "6b53c051e4199c518de79594e1c4ab18b96f081549d45ce015be002090bb119e85285337cc95ba5f59992dc98c",
+        ],
+        exports: [
+            "4bbd6243b8bb54cec311fac9df81841b6fd61f56538a775e7c80a9f40160606e",
+            "5acb09211139c43b3090489a9da433e8a30ee7188ba8b0a9a1ccf0c229283e53",
+        ],
+    },
+];
+
+#[test]
+fn hpke_test_vectors() {
+    for vector in &TEST_VECTORS {
+        let recipient_key = PrivateKey::from_private_key(
+            &DHKEM_X25519_HKDF_SHA256,
+            &from_hex(vector.sk_rm).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(from_hex(vector.pk_rm).unwrap(), recipient_key.public_key());
+
+        let sender_public_key = vector.sender.as_ref().map(|sender| {
+            let sender_key = PrivateKey::from_private_key(
+                &DHKEM_X25519_HKDF_SHA256,
+                &from_hex(sender.sk_sm).unwrap(),
+            )
+            .unwrap();
+            let pk_sm = from_hex(sender.pk_sm).unwrap();
+            assert_eq!(pk_sm, sender_key.public_key());
+            pk_sm
+        });
+        let mode = match &sender_public_key {
+            None => RecipientMode::Base,
+            Some(pk) => RecipientMode::Auth(pk),
+        };
+        let mut recipient = HpkeRecipientContext::new(
+            mode,
+            &DHKEM_X25519_HKDF_SHA256,
+            &HKDF_SHA256,
+            vector.aead,
+            &recipient_key,
+            &from_hex(vector.enc).unwrap(),
+            INFO,
+        )
+        .unwrap();
+
+        for (seq, ciphertext) in vector.ciphertexts.iter().enumerate() {
+            let aad = format!("Count-{seq}");
+            let plaintext = recipient
+                .open(&from_hex(ciphertext).unwrap(), aad.as_bytes())
+                .unwrap();
+            assert_eq!(PT, plaintext.as_slice());
+        }
+
+        for (exporter_context, expected) in [b"".as_slice(), b"TestContext"]
+            .into_iter()
+            .zip(vector.exports)
+        {
+            let mut exported = [0u8; 32];
+            recipient.export(exporter_context, &mut exported).unwrap();
+            assert_eq!(from_hex(expected).unwrap(), exported);
+        }
+    }
+}
+
+#[test]
+fn hpke_round_trip() {
+    let recipient_key = PrivateKey::generate(&DHKEM_X25519_HKDF_SHA256).unwrap();
+    let sender_key = PrivateKey::generate(&DHKEM_X25519_HKDF_SHA256).unwrap();
+    let info = b"round trip";
+
+    for aead in [&AES_128_GCM, &AES_256_GCM, &CHACHA20_POLY1305] {
+        for auth in [false, true] {
+            let sender_mode = if auth {
+                SenderMode::Auth(&sender_key)
+            } else {
+                SenderMode::Base
+            };
+            let (mut sender, encapsulated_key) = HpkeSenderContext::new(
+                sender_mode,
+                &DHKEM_X25519_HKDF_SHA256,
+                &HKDF_SHA256,
+                aead,
+                recipient_key.public_key(),
+                info,
+            )
+            .unwrap();
+
+            let recipient_mode = if auth {
+                RecipientMode::Auth(sender_key.public_key())
+            } else {
+                RecipientMode::Base
+            };
+            let mut recipient = HpkeRecipientContext::new(
+                recipient_mode,
+                &DHKEM_X25519_HKDF_SHA256,
+                &HKDF_SHA256,
+                aead,
+                &recipient_key,
+                encapsulated_key.as_ref(),
+                info,
+            )
+            .unwrap();
+
+            for i in 0..3u8 {
+                let message = vec![i; 100 * usize::from(i)];
+                let ciphertext = sender.seal(&message, &[i]).unwrap();
+                assert_eq!(message, recipient.open(&ciphertext, &[i]).unwrap());
+            }
+
+            let mut sender_secret = [0u8; 48];
+            let mut recipient_secret = [0u8; 48];
+            sender.export(b"context", &mut sender_secret).unwrap();
+            recipient.export(b"context", &mut recipient_secret).unwrap();
+            assert_eq!(sender_secret, recipient_secret);
+
+            // Messages must be opened in order, with the same associated data.
+            let first = sender.seal(b"first", b"").unwrap();
+            let second = sender.seal(b"second", b"").unwrap();
+            assert!(recipient.open(&second, b"").is_err());
+            assert!(recipient.open(&first, b"aad").is_err());
+        }
+    }
+}
+
+#[test]
+fn hpke_invalid() {
+    let recipient_key = PrivateKey::generate(&DHKEM_X25519_HKDF_SHA256).unwrap();
+    let other_key = PrivateKey::generate(&DHKEM_X25519_HKDF_SHA256).unwrap();
+
+    assert!(PrivateKey::from_private_key(&DHKEM_X25519_HKDF_SHA256, &[0u8; 31]).is_err());
+    assert!(HpkeSenderContext::new(
+        SenderMode::Base,
+        &DHKEM_X25519_HKDF_SHA256,
+        &HKDF_SHA256,
+        &AES_128_GCM,
+        &recipient_key.public_key()[1..],
+        b"",
+    )
+    .is_err());
+
+    let (mut sender, encapsulated_key) = HpkeSenderContext::new(
+        SenderMode::Auth(&other_key),
+        &DHKEM_X25519_HKDF_SHA256,
+        &HKDF_SHA256,
+        &AES_128_GCM,
+        recipient_key.public_key(),
+        b"info",
+    )
+    .unwrap();
+    let ciphertext = sender.seal(b"message", b"").unwrap();
+
+    // The recipient must use the same mode, info and sender public key.
+    for (mode, info) in [
+        (RecipientMode::Base, b"info".as_slice()),
+        (RecipientMode::Auth(other_key.public_key()), b"other"),
+        (RecipientMode::Auth(recipient_key.public_key()), b"info"),
+    ] {
+        let mut recipient = HpkeRecipientContext::new(
+            mode,
+            &DHKEM_X25519_HKDF_SHA256,
+            &HKDF_SHA256,
+            &AES_128_GCM,
+            &recipient_key,
+            encapsulated_key.as_ref(),
+            info,
+        )
+        .unwrap();
+        assert!(recipient.open(&ciphertext, b"").is_err());
+    }
+
+    assert!(HpkeRecipientContext::new(
+        RecipientMode::Base,
+        &DHKEM_X25519_HKDF_SHA256,
+        &HKDF_SHA256,
+        &AES_128_GCM,
+        &recipient_key,
+        &encapsulated_key.as_ref()[1..],
+        b"info",
+    )
+    .is_err());
+
+    assert_eq!(0x0020, DHKEM_X25519_HKDF_SHA256.id());
+    assert_eq!(0x0001, HKDF_SHA256.id());
+    assert_eq!(0x0003, CHACHA20_POLY1305.id());
+    assert_eq!(
+        "PrivateKey { kem: Kem { id: 32 }, .. }",
+        format!("{recipient_key:?}")
+    );
+}
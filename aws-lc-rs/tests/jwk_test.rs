@@ -0,0 +1,163 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+use std::collections::BTreeMap;
+
+use aws_lc_rs::encoding::base64url;
+use aws_lc_rs::encoding::jwk::{AsJwk, JwkPublicKey};
+use aws_lc_rs::rsa::{KeyPair as RsaKeyPair, PublicEncryptingKey, PublicKeyComponents};
+use aws_lc_rs::signature::{
+    EcdsaKeyPair, EcdsaSigningAlgorithm, Ed25519KeyPair, KeyPair, ECDSA_P256K1_SHA256_ASN1_SIGNING,
+    ECDSA_P256_SHA256_ASN1_SIGNING, ECDSA_P384_SHA384_ASN1_SIGNING, ECDSA_P521_SHA512_ASN1_SIGNING,
+};
+
+// RFC 7517, Appendix A.1 and A.2
+const RFC7517_EC_X: &str = "MKBCTNIcKUSDii11ySs3526iDZ8AiTo7Tu6KPAqv7D4";
+const RFC7517_EC_Y: &str = "4Etl6SRW2YiLUrN5vfvVHuhp7x8PxltmWWlbbM4IFyM";
+const RFC7517_EC_D: &str = "870MB6gfuTJ4HtUnUvYMyJpr5eUZNP4Bk43bVdj3eAE";
+const RFC7517_RSA_N: &str = "0vx7agoebGcQSuuPiLJXZptN9nndrQmbXEps2aiAFbWhM78LhWx4cbbfAAtVT86zwu1RK7aPFFxuhDR1L6tSoc_BJECPebWKRXjBZCiFV4n3oknjhMstn64tZ_2W-5JsGY4Hc5n9yBXArwl93lqt7_RN5w6Cf0h4QyQ5v-65YGjQR0_FDW2QvzqY368QQMicAtaSqzs8KJZgnYb9c7d0zgdAZHzu6qMQvRL5hajrn1n91CbOpbISD08qNLyrdkt-bFTWhAI4vMQFh6WeZu0fM4lFd2NcRwr3XPksINHaQ-G_xBniIqbw0Ls1jF44-csFCur-kEgU8awapJzKnqDKgw";
+const RFC7517_RSA_E: &str = "AQAB";
+
+// RFC 8037, Appendix A.1 and A.2
+const RFC8037_ED25519_D: &str = "nWGxne_9WmC6hEr0kuwsxERJxWl7MmkZcDusAxyuf2A";
+const RFC8037_ED25519_X: &str = "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo";
+
+/// Parses a flat JSON object whose members all have string values.
+fn parse_json_object(json: &str) -> BTreeMap<String, String> {
+    let inner = json
+        .strip_prefix('{')
+        .and_then(|json| json.strip_suffix('}'))
+        .expect("JSON object");
+    let mut members = BTreeMap::new();
+    for member in inner.split(',') {
+        let (name, value) = member.split_once(':').expect("JSON member");
+        let unquote = |s: &str| {
+            s.strip_prefix('"')
+                .and_then(|s| s.strip_suffix('"'))
+                .expect("JSON string")
+                .to_string()
+        };
+        assert!(members.insert(unquote(name), unquote(value)).is_none());
+    }
+    members
+}
+
+fn assert_json(jwk: &JwkPublicKey, expected: &[(&str, &str)]) {
+    let members = parse_json_object(&jwk.to_string());
+    let expected: BTreeMap<String, String> = expected
+        .iter()
+        .map(|(name, value)| ((*name).to_string(), (*value).to_string()))
+        .collect();
+    assert_eq!(expected, members);
+}
+
+#[test]
+fn rfc7517_ec() {
+    let mut public_key = vec![0x04];
+    public_key.extend(base64url::decode(RFC7517_EC_X).unwrap());
+    public_key.extend(base64url::decode(RFC7517_EC_Y).unwrap());
+    let key_pair = EcdsaKeyPair::from_private_key_and_public_key(
+        &ECDSA_P256_SHA256_ASN1_SIGNING,
+        &base64url::decode(RFC7517_EC_D).unwrap(),
+        &public_key,
+    )
+    .unwrap();
+
+    let jwk = key_pair.public_key().as_jwk().unwrap();
+    assert_eq!("EC", jwk.kty());
+    assert_eq!(Some("P-256"), jwk.crv());
+    assert_eq!(Some(RFC7517_EC_X), jwk.x());
+    assert_eq!(Some(RFC7517_EC_Y), jwk.y());
+    assert_eq!(None, jwk.n());
+    assert_eq!(None, jwk.e());
+    assert_json(
+        &jwk,
+        &[
+            ("kty", "EC"),
+            ("crv", "P-256"),
+            ("x", RFC7517_EC_X),
+            ("y", RFC7517_EC_Y),
+        ],
+    );
+}
+
+#[test]
+fn rfc7517_rsa() {
+    let public_key: PublicEncryptingKey = PublicKeyComponents {
+        n: base64url::decode(RFC7517_RSA_N).unwrap(),
+        e: base64url::decode(RFC7517_RSA_E).unwrap(),
+    }
+    .try_into()
+    .unwrap();
+
+    let jwk = public_key.as_jwk().unwrap();
+    assert_eq!("RSA", jwk.kty());
+    assert_eq!(None, jwk.crv());
+    assert_eq!(Some(RFC7517_RSA_N), jwk.n());
+    assert_eq!(Some(RFC7517_RSA_E), jwk.e());
+    assert_eq!(None, jwk.x());
+    assert_eq!(None, jwk.y());
+    assert_json(
+        &jwk,
+        &[("kty", "RSA"), ("n", RFC7517_RSA_N), ("e", RFC7517_RSA_E)],
+    );
+}
+
+#[test]
+fn rfc8037_ed25519() {
+    let key_pair = Ed25519KeyPair::from_seed_and_public_key(
+        &base64url::decode(RFC8037_ED25519_D).unwrap(),
+        &base64url::decode(RFC8037_ED25519_X).unwrap(),
+    )
+    .unwrap();
+
+    let jwk = key_pair.public_key().as_jwk().unwrap();
+    assert_eq!("OKP", jwk.kty());
+    assert_eq!(Some("Ed25519"), jwk.crv());
+    assert_eq!(Some(RFC8037_ED25519_X), jwk.x());
+    assert_eq!(None, jwk.y());
+    assert_json(
+        &jwk,
+        &[("kty", "OKP"), ("crv", "Ed25519"), ("x", RFC8037_ED25519_X)],
+    );
+}
+
+#[test]
+fn rsa_key_pair_public_key() {
+    let key_pair =
+        RsaKeyPair::from_pkcs8(include_bytes!("data/rsa_test_private_key_2048.p8")).unwrap();
+    let public_key =
+        PublicEncryptingKey::from_der(include_bytes!("data/rsa_test_public_key_2048.x509"))
+            .unwrap();
+
+    let jwk = key_pair.public_key().as_jwk().unwrap();
+    assert_eq!(public_key.as_jwk().unwrap(), jwk);
+    assert_eq!(Some("AQAB"), jwk.e());
+    // A 2048-bit modulus without leading zeros.
+    assert_eq!(256, base64url::decode(jwk.n().unwrap()).unwrap().len());
+}
+
+#[test]
+fn ecdsa_curves() {
+    for (alg, crv, coordinate_len) in [
+        (&ECDSA_P256_SHA256_ASN1_SIGNING, "P-256", 32),
+        (&ECDSA_P384_SHA384_ASN1_SIGNING, "P-384", 48),
+        (&ECDSA_P521_SHA512_ASN1_SIGNING, "P-521", 66),
+        (&ECDSA_P256K1_SHA256_ASN1_SIGNING, "secp256k1", 32),
+    ] as [(&'static EcdsaSigningAlgorithm, &str, usize); 4]
+    {
+        let key_pair = EcdsaKeyPair::generate(alg).unwrap();
+        let jwk = key_pair.public_key().as_jwk().unwrap();
+        assert_eq!(Some(crv), jwk.crv());
+
+        // Coordinates are padded to the full field length.
+        let x = base64url::decode(jwk.x().unwrap()).unwrap();
+        let y = base64url::decode(jwk.y().unwrap()).unwrap();
+        assert_eq!(coordinate_len, x.len());
+        assert_eq!(coordinate_len, y.len());
+        assert_eq!(
+            key_pair.public_key().as_ref(),
+            [&[0x04], x.as_slice(), y.as_slice()].concat()
+        );
+    }
+}
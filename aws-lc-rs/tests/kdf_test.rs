@@ -6,11 +6,14 @@
 use std::error::Error;
 
 use aws_lc_rs::kdf::{
-    get_kbkdf_ctr_hmac_algorithm, get_sskdf_digest_algorithm, get_sskdf_hmac_algorithm,
-    kbkdf_ctr_hmac, sskdf_digest, sskdf_hmac, KbkdfCtrHmacAlgorithm, KbkdfCtrHmacAlgorithmId,
-    SskdfDigestAlgorithm, SskdfDigestAlgorithmId, SskdfHmacAlgorithm, SskdfHmacAlgorithmId,
+    get_kbkdf_ctr_hmac_algorithm, get_kbkdf_dp_hmac_algorithm, get_kbkdf_fb_hmac_algorithm,
+    get_sskdf_digest_algorithm, get_sskdf_hmac_algorithm, kbkdf_ctr_hmac,
+    kbkdf_double_pipeline_hmac, kbkdf_fb_hmac, scrypt, sp800_56c, sskdf_digest, sskdf_hmac,
+    KbkdfCtrHmacAlgorithm, KbkdfCtrHmacAlgorithmId, KbkdfDpHmacAlgorithm, KbkdfDpHmacAlgorithmId,
+    KbkdfFbHmacAlgorithm, KbkdfFbHmacAlgorithmId, ScryptParams, SskdfDigestAlgorithm,
+    SskdfDigestAlgorithmId, SskdfHmacAlgorithm, SskdfHmacAlgorithmId,
 };
-use aws_lc_rs::{test, test_file};
+use aws_lc_rs::{hmac, test, test_file};
 
 #[derive(Clone, Copy)]
 enum SskdfVariant {
@@ -88,6 +91,30 @@ impl From<Hash> for &'static KbkdfCtrHmacAlgorithm {
     }
 }
 
+impl From<Hash> for &'static KbkdfDpHmacAlgorithm {
+    fn from(value: Hash) -> Self {
+        get_kbkdf_dp_hmac_algorithm(match value {
+            Hash::Sha224 => KbkdfDpHmacAlgorithmId::Sha224,
+            Hash::Sha256 => KbkdfDpHmacAlgorithmId::Sha256,
+            Hash::Sha384 => KbkdfDpHmacAlgorithmId::Sha384,
+            Hash::Sha512 => KbkdfDpHmacAlgorithmId::Sha512,
+        })
+        .expect("hash supported")
+    }
+}
+
+impl From<Hash> for &'static KbkdfFbHmacAlgorithm {
+    fn from(value: Hash) -> Self {
+        get_kbkdf_fb_hmac_algorithm(match value {
+            Hash::Sha256 => KbkdfFbHmacAlgorithmId::Sha256,
+            Hash::Sha384 => KbkdfFbHmacAlgorithmId::Sha384,
+            Hash::Sha512 => KbkdfFbHmacAlgorithmId::Sha512,
+            Hash::Sha224 => panic!("hash not supported"),
+        })
+        .expect("hash supported")
+    }
+}
+
 #[test]
 fn sskdf() {
     test::run(test_file!("data/sskdf.txt"), |_section, tc| {
@@ -162,3 +189,230 @@ fn hkdf_ctr_hmac() {
         Ok(())
     });
 }
+
+impl From<Hash> for hmac::Algorithm {
+    fn from(value: Hash) -> Self {
+        match value {
+            Hash::Sha224 => hmac::HMAC_SHA224,
+            Hash::Sha256 => hmac::HMAC_SHA256,
+            Hash::Sha384 => hmac::HMAC_SHA384,
+            Hash::Sha512 => hmac::HMAC_SHA512,
+        }
+    }
+}
+
+#[test]
+fn sp800_56c_two_step() {
+    test::run(test_file!("data/sp800_56c_two_step.txt"), |_section, tc| {
+        let hash: Hash = tc.consume_string("HASH").try_into().unwrap();
+        let shared_secret = tc.consume_bytes("Z");
+        let salt = tc.consume_bytes("SALT");
+        let info = tc.consume_bytes("INFO");
+        let expect = tc.consume_bytes("EXPECT");
+
+        let hmac_alg: hmac::Algorithm = hash.into();
+        let prk = sp800_56c::extract(hmac_alg, &salt, &shared_secret);
+        assert_eq!(hmac_alg, prk.algorithm());
+        assert_eq!(hmac_alg.digest_algorithm().output_len(), prk.key_len());
+
+        let output = sp800_56c::expand(&prk, hash.into(), &info, expect.len()).unwrap();
+        assert_eq!(expect, output);
+
+        Ok(())
+    });
+}
+
+#[test]
+fn sp800_56c_two_step_defaults() {
+    let kdf_alg = get_kbkdf_ctr_hmac_algorithm(KbkdfCtrHmacAlgorithmId::Sha256).unwrap();
+    let shared_secret = [0x5au8; 32];
+
+    // An empty salt is equivalent to the default all-zero salt of the block length.
+    let prk = sp800_56c::extract(hmac::HMAC_SHA256, &[], &shared_secret);
+    let zero_salt_prk = sp800_56c::extract(hmac::HMAC_SHA256, &[0u8; 64], &shared_secret);
+    assert_eq!(
+        sp800_56c::expand(&prk, kdf_alg, b"info", 32).unwrap(),
+        sp800_56c::expand(&zero_salt_prk, kdf_alg, b"info", 32).unwrap()
+    );
+
+    assert!(sp800_56c::expand(&prk, kdf_alg, b"info", 0).is_err());
+    assert_eq!(
+        "sp800_56c::Prk { algorithm: SHA256, key_len: 32 }",
+        format!("{prk:?}")
+    );
+}
+
+#[cfg(not(feature = "fips"))]
+#[test]
+fn kbkdf_ctr_hmac_sha1() {
+    let algorithm = get_kbkdf_ctr_hmac_algorithm(KbkdfCtrHmacAlgorithmId::Sha1).unwrap();
+    assert_eq!(KbkdfCtrHmacAlgorithmId::Sha1, algorithm.id());
+
+    test::run(test_file!("data/kbkdf_counter_sha1.txt"), |_section, tc| {
+        assert_eq!("SHA-1", tc.consume_string("HASH"));
+        let secret = tc.consume_bytes("SECRET");
+        let info = tc.consume_bytes("INFO");
+        let expect = tc.consume_bytes("EXPECT");
+
+        let mut output = vec![0u8; expect.len()];
+        kbkdf_ctr_hmac(algorithm, &secret, &info, output.as_mut_slice()).unwrap();
+        assert_eq!(expect, output.as_slice());
+
+        Ok(())
+    });
+}
+
+#[test]
+fn kbkdf_fb_hmac_tests() {
+    test::run(test_file!("data/kbkdf_feedback.txt"), |_section, tc| {
+        let hash: Hash = tc.consume_string("HASH").try_into().unwrap();
+        let secret = tc.consume_bytes("SECRET");
+        let iv = tc.consume_optional_bytes("IV");
+        let info = tc.consume_bytes("INFO");
+        let expect = tc.consume_bytes("EXPECT");
+
+        let mut output = vec![0u8; expect.len()];
+        kbkdf_fb_hmac(
+            hash.into(),
+            &secret,
+            iv.as_deref(),
+            &info,
+            output.as_mut_slice(),
+        )
+        .unwrap();
+        assert_eq!(expect, output.as_slice());
+
+        Ok(())
+    });
+}
+
+#[test]
+fn kbkdf_fb_hmac_first_block() {
+    const SECRET: &[u8] = &[0x42; 32];
+    const INFO: &[u8] = b"kbkdf feedback mode";
+
+    let mut ctr_output = [0u8; 64];
+    kbkdf_ctr_hmac(Hash::Sha256.into(), SECRET, INFO, &mut ctr_output).unwrap();
+
+    let fb = |iv: Option<&[u8]>| {
+        let mut output = [0u8; 64];
+        kbkdf_fb_hmac(Hash::Sha256.into(), SECRET, iv, INFO, &mut output).unwrap();
+        output
+    };
+
+    // An all-zero IV is still K(0) and is hashed into the first block, so the output is
+    // distinct from counter mode.
+    let zero_iv = fb(Some(&[0u8; 32]));
+    assert_ne!(ctr_output[..32], zero_iv[..32]);
+    assert_ne!(ctr_output[32..], zero_iv[32..]);
+
+    // Only an absent (or empty) IV makes the first block match counter mode. Later blocks are
+    // still chained from the previous block.
+    let no_iv = fb(None);
+    assert_eq!(ctr_output[..32], no_iv[..32]);
+    assert_ne!(ctr_output[32..], no_iv[32..]);
+    assert_eq!(no_iv, fb(Some(&[])));
+    assert_ne!(no_iv, zero_iv);
+
+    let mut output = [0u8; 64];
+    assert!(kbkdf_fb_hmac(Hash::Sha256.into(), SECRET, None, INFO, &mut []).is_err());
+    assert!(kbkdf_fb_hmac(Hash::Sha256.into(), &[], None, INFO, &mut output).is_err());
+}
+
+#[test]
+fn kbkdf_double_pipeline_hmac_tests() {
+    test::run(
+        test_file!("data/kbkdf_double_pipeline.txt"),
+        |_section, tc| {
+            let hash: Hash = tc.consume_string("HASH").try_into().unwrap();
+            let secret = tc.consume_bytes("SECRET");
+            let info = tc.consume_bytes("INFO");
+            let expect = tc.consume_bytes("EXPECT");
+
+            let mut output = vec![0u8; expect.len()];
+            kbkdf_double_pipeline_hmac(hash.into(), &secret, &info, output.as_mut_slice()).unwrap();
+            assert_eq!(expect, output.as_slice());
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn kbkdf_double_pipeline_hmac_differs_from_other_modes() {
+    const SECRET: &[u8] = &[0x42; 32];
+    const INFO: &[u8] = b"kbkdf double-pipeline mode";
+
+    let mut ctr_output = [0u8; 64];
+    kbkdf_ctr_hmac(Hash::Sha256.into(), SECRET, INFO, &mut ctr_output).unwrap();
+    let mut fb_output = [0u8; 64];
+    kbkdf_fb_hmac(Hash::Sha256.into(), SECRET, None, INFO, &mut fb_output).unwrap();
+
+    let mut dp_output = [0u8; 64];
+    kbkdf_double_pipeline_hmac(Hash::Sha256.into(), SECRET, INFO, &mut dp_output).unwrap();
+    assert_ne!(ctr_output[..32], dp_output[..32]);
+    assert_ne!(ctr_output[32..], dp_output[32..]);
+    assert_ne!(fb_output[..32], dp_output[..32]);
+    assert_ne!(fb_output[32..], dp_output[32..]);
+
+    // A shorter output is a prefix of a longer one.
+    let mut dp_output_short = [0u8; 40];
+    kbkdf_double_pipeline_hmac(Hash::Sha256.into(), SECRET, INFO, &mut dp_output_short).unwrap();
+    assert_eq!(dp_output[..40], dp_output_short);
+
+    assert!(kbkdf_double_pipeline_hmac(Hash::Sha256.into(), SECRET, INFO, &mut []).is_err());
+    assert!(kbkdf_double_pipeline_hmac(Hash::Sha256.into(), &[], INFO, &mut dp_output).is_err());
+}
+
+#[test]
+fn scrypt_tests() {
+    test::run(test_file!("data/scrypt_tests.txt"), |_section, tc| {
+        let password = tc.consume_bytes("PASSWORD");
+        let salt = tc.consume_bytes("SALT");
+        let n = tc.consume_usize("N") as u64;
+        let r = tc.consume_usize("R") as u64;
+        let p = tc.consume_usize("P") as u64;
+        let expect = tc.consume_bytes("EXPECT");
+
+        let mut output = vec![0u8; expect.len()];
+        scrypt(&password, &salt, n, r, p, output.as_mut_slice()).unwrap();
+        assert_eq!(expect, output.as_slice());
+
+        output.fill(0);
+        ScryptParams::new(n, r, p)
+            .derive(&password, &salt, output.as_mut_slice())
+            .unwrap();
+        assert_eq!(expect, output.as_slice());
+
+        Ok(())
+    });
+}
+
+// The last test vector from RFC 7914 Section 12 needs about 1 GiB of memory, so it only runs
+// when ignored tests are requested.
+#[test]
+#[ignore = "requires about 1 GiB of memory"]
+fn scrypt_rfc7914_n_2_pow_20() {
+    let expect = test::from_dirty_hex(
+        "2101cb9b6a511aaeaddbbe09cf70f881ec568d574a2ffd4dabe5ee9820adaa47\
+         8e56fd8f4ba5d09ffa1c6d927c40f4c337304049e8a952fbcbf45c6fa77a41a4",
+    );
+    let mut output = vec![0u8; expect.len()];
+    scrypt(
+        b"pleaseletmein",
+        b"SodiumChloride",
+        1 << 20,
+        8,
+        1,
+        &mut output,
+    )
+    .unwrap();
+    assert_eq!(expect, output);
+}
+
+#[test]
+fn scrypt_excessive_memory() {
+    let mut output = [0u8; 32];
+    // Requires 128 * 2^30 * 2^24 bytes of memory.
+    assert!(scrypt(b"password", b"salt", 1 << 24, 1 << 30, 1, &mut output).is_err());
+}
@@ -0,0 +1,50 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+use aws_lc_rs::mac::{Poly1305Context, Poly1305Key, POLY1305_TAG_LEN};
+use aws_lc_rs::test::from_hex;
+
+// RFC 8439, Section 2.5.2
+const RFC8439_KEY: &str = "85d6be7857556d337f4452fe42d506a80103808afb0db2fd4abff6af4149f51b";
+const RFC8439_MSG: &[u8] = b"Cryptographic Forum Research Group";
+const RFC8439_TAG: &str = "a8061dc1305136c6c22b8baf0c0127a9";
+
+fn rfc8439_key() -> Poly1305Key {
+    Poly1305Key::new(&from_hex(RFC8439_KEY).unwrap().try_into().unwrap())
+}
+
+#[test]
+fn poly1305_rfc8439() {
+    let key = rfc8439_key();
+    let expected = from_hex(RFC8439_TAG).unwrap();
+
+    assert_eq!(expected, key.sign(RFC8439_MSG));
+
+    let mut ctx = Poly1305Context::with_key(&key);
+    for chunk in RFC8439_MSG.chunks(5) {
+        ctx.update(chunk);
+    }
+    assert_eq!(expected, ctx.sign());
+
+    assert!(key.verify(RFC8439_MSG, &expected).is_ok());
+}
+
+#[test]
+fn poly1305_verify_rejects() {
+    let key = rfc8439_key();
+    let tag = from_hex(RFC8439_TAG).unwrap();
+
+    let mut msg = RFC8439_MSG.to_vec();
+    msg[0] ^= 1;
+    assert!(key.verify(&msg, &tag).is_err());
+
+    for i in 0..POLY1305_TAG_LEN {
+        let mut bad_tag = tag.clone();
+        bad_tag[i] ^= 0x80;
+        assert!(key.verify(RFC8439_MSG, &bad_tag).is_err());
+    }
+    assert!(key
+        .verify(RFC8439_MSG, &tag[..POLY1305_TAG_LEN - 1])
+        .is_err());
+    assert!(key.verify(RFC8439_MSG, &[]).is_err());
+}
@@ -0,0 +1,214 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+#![cfg(all(feature = "unstable", not(feature = "fips")))]
+
+use aws_lc_rs::encoding::AsDer;
+use aws_lc_rs::signature::{KeyPair, UnparsedPublicKey};
+use aws_lc_rs::unstable::signature::{
+    MlDsaAlgorithmId, MlDsaKeyPair, MlDsaSigningAlgorithm, MlDsaVerificationAlgorithm, ML_DSA_44,
+    ML_DSA_44_SIGNING, ML_DSA_65, ML_DSA_65_SIGNING, ML_DSA_87, ML_DSA_87_SIGNING,
+};
+use aws_lc_rs::{error, test, test_file};
+
+const ALGORITHMS: [(
+    &MlDsaVerificationAlgorithm,
+    &MlDsaSigningAlgorithm,
+    MlDsaAlgorithmId,
+); 3] = [
+    (&ML_DSA_44, &ML_DSA_44_SIGNING, MlDsaAlgorithmId::MlDsa44),
+    (&ML_DSA_65, &ML_DSA_65_SIGNING, MlDsaAlgorithmId::MlDsa65),
+    (&ML_DSA_87, &ML_DSA_87_SIGNING, MlDsaAlgorithmId::MlDsa87),
+];
+
+#[test]
+fn test_mldsa_traits() {
+    test::compile_time_assert_send::<MlDsaKeyPair>();
+    test::compile_time_assert_sync::<MlDsaKeyPair>();
+}
+
+#[test]
+fn test_mldsa_tests() {
+    test::run(test_file!("data/mldsa_tests.txt"), |section, test_case| {
+        assert_eq!(section, "");
+        let (verification_alg, signing_alg) = match test_case.consume_string("Algorithm").as_str() {
+            "ML_DSA_44" => (&ML_DSA_44, &ML_DSA_44_SIGNING),
+            "ML_DSA_65" => (&ML_DSA_65, &ML_DSA_65_SIGNING),
+            "ML_DSA_87" => (&ML_DSA_87, &ML_DSA_87_SIGNING),
+            s => panic!("{s:?} is not a valid algorithm"),
+        };
+        let pkcs8 = test_case.consume_bytes("PKCS8");
+        let public_key = test_case.consume_bytes("PUB");
+        let msg = test_case.consume_bytes("MESSAGE");
+        let sig = test_case.consume_bytes("SIG");
+        let expected_result = match test_case.consume_string("Result").as_str() {
+            "P" => Ok(()),
+            "F" => Err(error::Unspecified),
+            s => panic!("{s:?} is not a valid result"),
+        };
+
+        let actual_result =
+            UnparsedPublicKey::new(verification_alg, &public_key).verify(&msg, &sig);
+        assert_eq!(expected_result, actual_result);
+
+        let key_pair = MlDsaKeyPair::from_pkcs8(signing_alg, &pkcs8).unwrap();
+        assert_eq!(public_key, key_pair.public_key().as_ref());
+
+        let spki = key_pair.public_key().as_der().unwrap();
+        let spki = spki.as_ref();
+        let actual_result = UnparsedPublicKey::new(verification_alg, spki).verify(&msg, &sig);
+        assert_eq!(expected_result, actual_result);
+
+        for (other_alg, other_signing_alg, _) in ALGORITHMS {
+            if other_alg != verification_alg {
+                assert!(UnparsedPublicKey::new(other_alg, &public_key)
+                    .verify(&msg, &sig)
+                    .is_err());
+                assert!(UnparsedPublicKey::new(other_alg, spki)
+                    .verify(&msg, &sig)
+                    .is_err());
+                assert!(MlDsaKeyPair::from_pkcs8(other_signing_alg, &pkcs8).is_err());
+            }
+        }
+
+        Ok(())
+    });
+}
+
+#[test]
+fn test_mldsa_keygen_tests() {
+    test::run(
+        test_file!("data/mldsa_keygen_tests.txt"),
+        |section, test_case| {
+            assert_eq!(section, "");
+            let signing_alg = match test_case.consume_string("Algorithm").as_str() {
+                "ML_DSA_44" => &ML_DSA_44_SIGNING,
+                "ML_DSA_65" => &ML_DSA_65_SIGNING,
+                "ML_DSA_87" => &ML_DSA_87_SIGNING,
+                s => panic!("{s:?} is not a valid algorithm"),
+            };
+            let seed: [u8; 32] = test_case.consume_bytes("SEED").try_into().unwrap();
+            let public_key = test_case.consume_bytes("PUB");
+
+            let key_pair = MlDsaKeyPair::from_seed(signing_alg, &seed).unwrap();
+            assert_eq!(public_key, key_pair.public_key().as_ref());
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn test_mldsa_sigver_tests() {
+    test::run(
+        test_file!("data/mldsa_sigver_tests.txt"),
+        |section, test_case| {
+            assert_eq!(section, "");
+            let verification_alg = match test_case.consume_string("Algorithm").as_str() {
+                "ML_DSA_44" => &ML_DSA_44,
+                "ML_DSA_65" => &ML_DSA_65,
+                "ML_DSA_87" => &ML_DSA_87,
+                s => panic!("{s:?} is not a valid algorithm"),
+            };
+            let public_key = test_case.consume_bytes("PUB");
+            let msg = test_case.consume_bytes("MESSAGE");
+            let sig = test_case.consume_bytes("SIG");
+            let expected_result = match test_case.consume_string("Result").as_str() {
+                "P" => Ok(()),
+                "F" => Err(error::Unspecified),
+                s => panic!("{s:?} is not a valid result"),
+            };
+
+            let actual_result =
+                UnparsedPublicKey::new(verification_alg, &public_key).verify(&msg, &sig);
+            assert_eq!(expected_result, actual_result);
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn test_mldsa_sign_verify() {
+    for (verification_alg, signing_alg, id) in ALGORITHMS {
+        let key_pair = MlDsaKeyPair::generate(signing_alg).unwrap();
+        assert_eq!(id, key_pair.algorithm().id());
+        assert_eq!(
+            verification_alg.public_key_len(),
+            key_pair.public_key().as_ref().len()
+        );
+
+        let msg = b"hello, world";
+        let mut sig = vec![0u8; verification_alg.signature_len() + 1];
+        assert!(key_pair
+            .sign(msg, &mut sig[..verification_alg.signature_len() - 1])
+            .is_err());
+        let sig_len = key_pair.sign(msg, &mut sig).unwrap();
+        assert_eq!(verification_alg.signature_len(), sig_len);
+        let sig = &sig[..sig_len];
+
+        let public_key = UnparsedPublicKey::new(verification_alg, key_pair.public_key());
+        public_key.verify(msg, sig).unwrap();
+        assert!(public_key.verify(b"hello, world!", sig).is_err());
+
+        let pkcs8 = key_pair.as_der().unwrap();
+        let key_pair2 = MlDsaKeyPair::from_pkcs8(signing_alg, pkcs8.as_ref()).unwrap();
+        assert_eq!(
+            key_pair.public_key().as_ref(),
+            key_pair2.public_key().as_ref()
+        );
+        let mut sig2 = vec![0u8; verification_alg.signature_len()];
+        key_pair2.sign(msg, &mut sig2).unwrap();
+        public_key.verify(msg, &sig2).unwrap();
+
+        assert!(MlDsaKeyPair::from_pkcs8(signing_alg, &pkcs8.as_ref()[1..]).is_err());
+    }
+}
+
+#[test]
+fn test_mldsa_from_seed() {
+    let seed = [0x2au8; 32];
+    for (verification_alg, signing_alg, id) in ALGORITHMS {
+        let key_pair = MlDsaKeyPair::from_seed(signing_alg, &seed).unwrap();
+        assert_eq!(id, key_pair.algorithm().id());
+        let key_pair2 = MlDsaKeyPair::from_seed(signing_alg, &seed).unwrap();
+        assert_eq!(
+            key_pair.public_key().as_ref(),
+            key_pair2.public_key().as_ref()
+        );
+        assert_eq!(
+            key_pair.as_der().unwrap().as_ref(),
+            key_pair2.as_der().unwrap().as_ref()
+        );
+
+        let other = MlDsaKeyPair::from_seed(signing_alg, &[0x2bu8; 32]).unwrap();
+        assert_ne!(key_pair.public_key().as_ref(), other.public_key().as_ref());
+
+        let pkcs8 = key_pair.as_der().unwrap();
+        let key_pair3 = MlDsaKeyPair::from_pkcs8(signing_alg, pkcs8.as_ref()).unwrap();
+        assert_eq!(
+            key_pair.public_key().as_ref(),
+            key_pair3.public_key().as_ref()
+        );
+
+        let msg = b"hello, world";
+        let mut sig = vec![0u8; verification_alg.signature_len()];
+        let sig_len = key_pair.sign(msg, &mut sig).unwrap();
+        UnparsedPublicKey::new(verification_alg, key_pair2.public_key())
+            .verify(msg, &sig[..sig_len])
+            .unwrap();
+    }
+}
+
+#[test]
+fn test_mldsa_debug() {
+    assert_eq!("MlDsa65", format!("{:?}", ML_DSA_65));
+    assert_eq!(
+        "MlDsaSigningAlgorithm(MlDsa65)",
+        format!("{:?}", ML_DSA_65_SIGNING)
+    );
+
+    let key_pair = MlDsaKeyPair::generate(&ML_DSA_44_SIGNING).unwrap();
+    let debug = format!("{key_pair:?}");
+    assert!(debug.starts_with("MlDsaKeyPair { algorithm: MlDsa44, public_key: MlDsaPublicKey(\""));
+}
@@ -0,0 +1,197 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+use aws_lc_rs::encoding::openssh::{openssh_public_key, OPENSSH_PRIVATE_KEY_LABEL};
+use aws_lc_rs::encoding::pem::{pem_decode, pem_encode};
+use aws_lc_rs::encoding::AsBigEndian;
+use aws_lc_rs::rand::SystemRandom;
+use aws_lc_rs::signature::{
+    self, EcdsaKeyPair, EcdsaSigningAlgorithm, Ed25519KeyPair, KeyPair, UnparsedPublicKey,
+    VerificationAlgorithm,
+};
+
+// Generated with `ssh-keygen -t <type> -N "" -f <file>`.
+const ED25519_PRIVATE_KEY: &str = include_str!("data/openssh_ed25519_private_key.txt");
+const ED25519_PUBLIC_KEY: &str = include_str!("data/openssh_ed25519_public_key.txt");
+const ECDSA_P256_PRIVATE_KEY: &str = include_str!("data/openssh_ecdsa_p256_private_key.txt");
+const ECDSA_P256_PUBLIC_KEY: &str = include_str!("data/openssh_ecdsa_p256_public_key.txt");
+const ECDSA_P521_PRIVATE_KEY: &str = include_str!("data/openssh_ecdsa_p521_private_key.txt");
+const ECDSA_P521_PUBLIC_KEY: &str = include_str!("data/openssh_ecdsa_p521_public_key.txt");
+// Generated with `ssh-keygen -t ed25519 -N passphrase -f <file>`.
+const ED25519_ENCRYPTED_PRIVATE_KEY: &str =
+    include_str!("data/openssh_ed25519_encrypted_private_key.txt");
+
+fn decode_private_key(pem: &str) -> Vec<u8> {
+    let (label, openssh) = pem_decode(pem).unwrap();
+    assert_eq!(OPENSSH_PRIVATE_KEY_LABEL, label);
+    openssh
+}
+
+/// Decodes the Base64 public key blob of an `authorized_keys` style line.
+fn decode_public_key(line: &str) -> Vec<u8> {
+    let base64 = line.split(' ').nth(1).unwrap();
+    // The PEM decoder is reused for standard Base64.
+    pem_decode(&format!("-----BEGIN A-----\n{base64}\n-----END A-----\n"))
+        .unwrap()
+        .1
+}
+
+fn assert_signs(
+    sign: impl Fn(&[u8]) -> Vec<u8>,
+    verification_alg: &'static dyn VerificationAlgorithm,
+    public_key: &[u8],
+) {
+    const MESSAGE: &[u8] = b"openssh";
+    let signature = sign(MESSAGE);
+    UnparsedPublicKey::new(verification_alg, public_key)
+        .verify(MESSAGE, &signature)
+        .unwrap();
+}
+
+#[test]
+fn ed25519_from_ssh_keygen() {
+    let openssh = decode_private_key(ED25519_PRIVATE_KEY);
+    let key_pair = Ed25519KeyPair::from_openssh(&openssh).unwrap();
+
+    let public_key_blob = decode_public_key(ED25519_PUBLIC_KEY);
+    assert_eq!(public_key_blob, openssh_public_key(&openssh).unwrap());
+    assert_eq!(&public_key_blob[19..], key_pair.public_key().as_ref());
+
+    assert_signs(
+        |message| key_pair.sign(message).as_ref().to_vec(),
+        &signature::ED25519,
+        key_pair.public_key().as_ref(),
+    );
+
+    // The key is not an ECDSA key.
+    assert!(
+        EcdsaKeyPair::from_openssh(&signature::ECDSA_P256_SHA256_ASN1_SIGNING, &openssh).is_err()
+    );
+}
+
+#[test]
+fn ed25519_round_trip() {
+    let key_pair = Ed25519KeyPair::generate().unwrap();
+    let openssh = key_pair.to_openssh().unwrap();
+
+    let pem = pem_encode(OPENSSH_PRIVATE_KEY_LABEL, &openssh);
+    let parsed = Ed25519KeyPair::from_openssh(&decode_private_key(&pem)).unwrap();
+    assert_eq!(key_pair.public_key().as_ref(), parsed.public_key().as_ref());
+    assert_eq!(
+        key_pair.seed().unwrap().as_be_bytes().unwrap().as_ref(),
+        parsed.seed().unwrap().as_be_bytes().unwrap().as_ref()
+    );
+
+    let public_key_blob = openssh_public_key(&openssh).unwrap();
+    assert_eq!(&public_key_blob[..19], b"\0\0\0\x0bssh-ed25519\0\0\0\x20");
+    assert_eq!(&public_key_blob[19..], key_pair.public_key().as_ref());
+}
+
+#[test]
+fn ecdsa_from_ssh_keygen() {
+    for (alg, verification_alg, private_key, public_key) in [
+        (
+            &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+            &signature::ECDSA_P256_SHA256_ASN1,
+            ECDSA_P256_PRIVATE_KEY,
+            ECDSA_P256_PUBLIC_KEY,
+        ),
+        (
+            &signature::ECDSA_P521_SHA512_ASN1_SIGNING,
+            &signature::ECDSA_P521_SHA512_ASN1,
+            ECDSA_P521_PRIVATE_KEY,
+            ECDSA_P521_PUBLIC_KEY,
+        ),
+    ] {
+        let openssh = decode_private_key(private_key);
+        let key_pair = EcdsaKeyPair::from_openssh(alg, &openssh).unwrap();
+
+        let public_key_blob = decode_public_key(public_key);
+        assert_eq!(public_key_blob, openssh_public_key(&openssh).unwrap());
+        let public_point = key_pair.public_key().as_ref();
+        assert!(public_key_blob.ends_with(public_point));
+
+        assert_signs(
+            |message| {
+                key_pair
+                    .sign(&SystemRandom::new(), message)
+                    .unwrap()
+                    .as_ref()
+                    .to_vec()
+            },
+            verification_alg,
+            public_point,
+        );
+
+        // Exporting and parsing again yields the same key.
+        let exported = key_pair.to_openssh().unwrap();
+        assert_eq!(public_key_blob, openssh_public_key(&exported).unwrap());
+        let parsed = EcdsaKeyPair::from_openssh(alg, &exported).unwrap();
+        assert_eq!(public_point, parsed.public_key().as_ref());
+
+        // The key is not an Ed25519 key.
+        assert!(Ed25519KeyPair::from_openssh(&openssh).is_err());
+    }
+
+    // The curve must match the algorithm.
+    let openssh = decode_private_key(ECDSA_P256_PRIVATE_KEY);
+    assert!(
+        EcdsaKeyPair::from_openssh(&signature::ECDSA_P384_SHA384_ASN1_SIGNING, &openssh).is_err()
+    );
+}
+
+#[test]
+fn ecdsa_round_trip() {
+    for alg in [
+        &signature::ECDSA_P256_SHA256_FIXED_SIGNING,
+        &signature::ECDSA_P384_SHA384_ASN1_SIGNING,
+        &signature::ECDSA_P521_SHA512_FIXED_SIGNING,
+    ] as [&'static EcdsaSigningAlgorithm; 3]
+    {
+        // Repeat to cover private keys with and without the high bit set.
+        for _ in 0..8 {
+            let key_pair = EcdsaKeyPair::generate(alg).unwrap();
+            let openssh = key_pair.to_openssh().unwrap();
+            let parsed = EcdsaKeyPair::from_openssh(alg, &openssh).unwrap();
+            assert_eq!(key_pair.public_key().as_ref(), parsed.public_key().as_ref());
+        }
+    }
+
+    // OpenSSH does not support secp256k1.
+    let key_pair = EcdsaKeyPair::generate(&signature::ECDSA_P256K1_SHA256_ASN1_SIGNING).unwrap();
+    assert!(key_pair.to_openssh().is_err());
+}
+
+#[test]
+fn invalid() {
+    // Encrypted keys are not supported.
+    let encrypted = decode_private_key(ED25519_ENCRYPTED_PRIVATE_KEY);
+    assert!(Ed25519KeyPair::from_openssh(&encrypted).is_err());
+    assert!(openssh_public_key(&encrypted).is_err());
+
+    let openssh = decode_private_key(ED25519_PRIVATE_KEY);
+    assert!(Ed25519KeyPair::from_openssh(&openssh[..openssh.len() - 1]).is_err());
+    assert!(Ed25519KeyPair::from_openssh(&[openssh.as_slice(), &[0]].concat()).is_err());
+    assert!(Ed25519KeyPair::from_openssh(&openssh[1..]).is_err());
+
+    // The outer public key must match the private key.
+    let other = Ed25519KeyPair::generate().unwrap().to_openssh().unwrap();
+    let mut mismatched = openssh.clone();
+    mismatched[62..94].copy_from_slice(&other[62..94]);
+    assert_eq!(
+        openssh_public_key(&other).unwrap(),
+        openssh_public_key(&mismatched).unwrap()
+    );
+    assert!(Ed25519KeyPair::from_openssh(&mismatched).is_err());
+
+    // The check integers must match.
+    let mut bad_check = openssh.clone();
+    bad_check[98] ^= 1;
+    assert!(Ed25519KeyPair::from_openssh(&bad_check).is_err());
+
+    // The padding must be 1, 2, 3, ...
+    let mut bad_padding = openssh;
+    let last = bad_padding.len() - 1;
+    bad_padding[last] ^= 0x10;
+    assert!(Ed25519KeyPair::from_openssh(&bad_padding).is_err());
+}
@@ -52,3 +52,48 @@ fn pbkdf2_tests() {
         Ok(())
     });
 }
+
+#[test]
+fn pbkdf2_params() {
+    const SALT: &[u8] = b"saltSALTsaltSALT";
+    const PASSWORD: &[u8] = b"password";
+
+    for (params, expected) in [
+        (
+            pbkdf2::Params::interactive(),
+            "9df51505a3e15f65f0f9e1cd0ddc9c55a8594e5da84ea558ccf33feb6f164910",
+        ),
+        (
+            pbkdf2::Params::sensitive(),
+            "d435844bb473a6c625ad9a7d3381337b52d71791933a4b7f5ab36740baf4853d",
+        ),
+    ] {
+        let key = pbkdf2::derive_with_params(pbkdf2::PBKDF2_HMAC_SHA256, &params, SALT, PASSWORD)
+            .unwrap();
+        assert_eq!(test::from_hex(expected).unwrap(), key.as_ref());
+
+        let mut out = vec![0u8; params.output_len().get()];
+        pbkdf2::derive(
+            pbkdf2::PBKDF2_HMAC_SHA256,
+            params.iterations(),
+            SALT,
+            PASSWORD,
+            &mut out,
+        );
+        assert_eq!(out, key.as_ref());
+    }
+
+    let params = pbkdf2::Params::new(1000, 64).unwrap();
+    assert_eq!(1000, params.iterations().get());
+    assert_eq!(64, params.output_len().get());
+    let key =
+        pbkdf2::derive_with_params(pbkdf2::PBKDF2_HMAC_SHA512, &params, SALT, PASSWORD).unwrap();
+    assert_eq!(64, key.as_ref().len());
+
+    assert!(pbkdf2::Params::new(0, 32).is_err());
+    assert!(pbkdf2::Params::new(600_000, 0).is_err());
+    assert_eq!(
+        cfg!(debug_assertions),
+        pbkdf2::Params::new(999, 32).is_err()
+    );
+}
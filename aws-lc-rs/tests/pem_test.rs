@@ -0,0 +1,235 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+use aws_lc_rs::encoding::pem::{pem_decode, pem_encode};
+use aws_lc_rs::encoding::{
+    AsDer, EcPrivateKeyRfc5915Der, Pkcs8V1Der, Pkcs8V2Der, PublicKeyX509Der,
+};
+use aws_lc_rs::rsa::{KeyPair, KeySize, PrivateDecryptingKey, PublicEncryptingKey, PublicKey};
+use aws_lc_rs::signature::{
+    EcdsaKeyPair, Ed25519KeyPair, KeyPair as _, ECDSA_P256_SHA256_ASN1_SIGNING,
+    ECDSA_P384_SHA384_ASN1_SIGNING,
+};
+use aws_lc_rs::{agreement, rand};
+
+// The PEM files were generated from the corresponding DER files with OpenSSL.
+const RSA_PRIVATE_KEY_PKCS8: &[u8] = include_bytes!("data/rsa_test_private_key_2048.p8");
+const RSA_PRIVATE_KEY_PKCS8_PEM: &str = include_str!("data/rsa_test_private_key_2048_pkcs8.pem");
+const RSA_PRIVATE_KEY: &[u8] = include_bytes!("data/rsa_test_private_key_2048.der");
+const RSA_PRIVATE_KEY_PEM: &str = include_str!("data/rsa_test_private_key_2048.pem");
+const RSA_PUBLIC_KEY_X509: &[u8] = include_bytes!("data/rsa_test_public_key_2048.x509");
+const RSA_PUBLIC_KEY_X509_PEM: &str = include_str!("data/rsa_test_public_key_2048_x509.pem");
+const RSA_PUBLIC_KEY: &[u8] = include_bytes!("data/rsa_test_public_key_2048.der");
+const RSA_PUBLIC_KEY_PEM: &str = include_str!("data/rsa_test_public_key_2048.pem");
+const EC_PRIVATE_KEY_PKCS8: &[u8] = include_bytes!("data/ecdsa_test_private_key_p256.p8");
+const EC_PRIVATE_KEY_PKCS8_PEM: &str = include_str!("data/ecdsa_test_private_key_p256_pkcs8.pem");
+const EC_PRIVATE_KEY_SEC1: &[u8] = include_bytes!("data/ecdsa_test_private_key_p256_sec1.der");
+const EC_PRIVATE_KEY_SEC1_PEM: &str = include_str!("data/ecdsa_test_private_key_p256_sec1.pem");
+
+#[test]
+fn openssl_compatibility() {
+    for (label, der, pem) in [
+        (
+            "PRIVATE KEY",
+            RSA_PRIVATE_KEY_PKCS8,
+            RSA_PRIVATE_KEY_PKCS8_PEM,
+        ),
+        ("RSA PRIVATE KEY", RSA_PRIVATE_KEY, RSA_PRIVATE_KEY_PEM),
+        ("PUBLIC KEY", RSA_PUBLIC_KEY_X509, RSA_PUBLIC_KEY_X509_PEM),
+        ("RSA PUBLIC KEY", RSA_PUBLIC_KEY, RSA_PUBLIC_KEY_PEM),
+        (
+            "PRIVATE KEY",
+            EC_PRIVATE_KEY_PKCS8,
+            EC_PRIVATE_KEY_PKCS8_PEM,
+        ),
+        (
+            "EC PRIVATE KEY",
+            EC_PRIVATE_KEY_SEC1,
+            EC_PRIVATE_KEY_SEC1_PEM,
+        ),
+    ] {
+        assert_eq!(pem, pem_encode(label, der));
+        assert_eq!((label.to_string(), der.to_vec()), pem_decode(pem).unwrap());
+    }
+}
+
+#[test]
+fn rsa_key_pair_from_pem() {
+    let from_pkcs8 = KeyPair::from_pem(RSA_PRIVATE_KEY_PKCS8_PEM).unwrap();
+    let from_der = KeyPair::from_pem(RSA_PRIVATE_KEY_PEM).unwrap();
+    assert_eq!(RSA_PUBLIC_KEY, from_pkcs8.public_key().as_ref());
+    assert_eq!(RSA_PUBLIC_KEY, from_der.public_key().as_ref());
+
+    let key_pair = KeyPair::generate(KeySize::Rsa2048).unwrap();
+    let der: Pkcs8V1Der = key_pair.as_der().unwrap();
+    let pem = pem_encode("PRIVATE KEY", der.as_ref());
+    let parsed = KeyPair::from_pem(&pem).unwrap();
+    assert_eq!(key_pair.public_key().as_ref(), parsed.public_key().as_ref());
+
+    // Only the first document is parsed.
+    let multiple = format!("{RSA_PRIVATE_KEY_PEM}{pem}");
+    let parsed = KeyPair::from_pem(&multiple).unwrap();
+    assert_eq!(RSA_PUBLIC_KEY, parsed.public_key().as_ref());
+
+    for pem in [
+        RSA_PUBLIC_KEY_PEM,
+        EC_PRIVATE_KEY_SEC1_PEM,
+        EC_PRIVATE_KEY_PKCS8_PEM,
+        "",
+        &pem[..pem.len() / 2],
+    ] {
+        assert!(KeyPair::from_pem(pem).is_err());
+    }
+}
+
+#[test]
+fn rsa_public_key_from_pem() {
+    let from_x509 = PublicKey::from_pem(RSA_PUBLIC_KEY_X509_PEM).unwrap();
+    let from_der = PublicKey::from_pem(RSA_PUBLIC_KEY_PEM).unwrap();
+    assert_eq!(RSA_PUBLIC_KEY, from_x509.as_ref());
+    assert_eq!(RSA_PUBLIC_KEY, from_der.as_ref());
+
+    let key_pair = KeyPair::generate(KeySize::Rsa2048).unwrap();
+    let pem = pem_encode("RSA PUBLIC KEY", key_pair.public_key().as_ref());
+    let parsed = PublicKey::from_pem(&pem).unwrap();
+    assert_eq!(key_pair.public_key().as_ref(), parsed.as_ref());
+
+    for pem in [
+        RSA_PRIVATE_KEY_PEM,
+        &pem_encode("PUBLIC KEY", RSA_PUBLIC_KEY),
+        &pem_encode(
+            "PUBLIC KEY",
+            EcdsaKeyPair::from_pkcs8(&ECDSA_P256_SHA256_ASN1_SIGNING, EC_PRIVATE_KEY_PKCS8)
+                .unwrap()
+                .public_key()
+                .as_der()
+                .unwrap()
+                .as_ref(),
+        ),
+    ] {
+        assert!(PublicKey::from_pem(pem).is_err());
+    }
+}
+
+#[test]
+fn rsa_encryption_keys_pem_round_trip() {
+    let private_key = PrivateDecryptingKey::generate(aws_lc_rs::rsa::KeySize::Rsa2048).unwrap();
+    let der: Pkcs8V1Der = private_key.as_der().unwrap();
+    let (label, der) = pem_decode(&pem_encode("PRIVATE KEY", der.as_ref())).unwrap();
+    assert_eq!("PRIVATE KEY", label);
+    let parsed = PrivateDecryptingKey::from_pkcs8(&der).unwrap();
+
+    let public_key = private_key.public_key();
+    let der: PublicKeyX509Der = public_key.as_der().unwrap();
+    let (label, der) = pem_decode(&pem_encode("PUBLIC KEY", der.as_ref())).unwrap();
+    assert_eq!("PUBLIC KEY", label);
+    let parsed_public_key = PublicEncryptingKey::from_der(&der).unwrap();
+    assert_eq!(
+        parsed.public_key().as_der().unwrap().as_ref(),
+        parsed_public_key.as_der().unwrap().as_ref()
+    );
+}
+
+#[test]
+fn ecdsa_key_pair_from_pem() {
+    let alg = &ECDSA_P256_SHA256_ASN1_SIGNING;
+    let from_pkcs8 = EcdsaKeyPair::from_pem(alg, EC_PRIVATE_KEY_PKCS8_PEM).unwrap();
+    let expected = EcdsaKeyPair::from_pkcs8(alg, EC_PRIVATE_KEY_PKCS8).unwrap();
+    assert_eq!(
+        expected.public_key().as_ref(),
+        from_pkcs8.public_key().as_ref()
+    );
+    let from_sec1 = EcdsaKeyPair::from_pem(alg, EC_PRIVATE_KEY_SEC1_PEM).unwrap();
+    let expected = EcdsaKeyPair::from_sec1_der(alg, EC_PRIVATE_KEY_SEC1).unwrap();
+    assert_eq!(
+        expected.public_key().as_ref(),
+        from_sec1.public_key().as_ref()
+    );
+
+    let key_pair = EcdsaKeyPair::generate(alg).unwrap();
+    let pkcs8 = key_pair.to_pkcs8v1().unwrap();
+    let parsed = EcdsaKeyPair::from_pem(alg, &pem_encode("PRIVATE KEY", pkcs8.as_ref())).unwrap();
+    assert_eq!(key_pair.public_key().as_ref(), parsed.public_key().as_ref());
+    let der: EcPrivateKeyRfc5915Der = key_pair.private_key().as_der().unwrap();
+    let parsed = EcdsaKeyPair::from_pem(alg, &pem_encode("EC PRIVATE KEY", der.as_ref())).unwrap();
+    assert_eq!(key_pair.public_key().as_ref(), parsed.public_key().as_ref());
+
+    let der: PublicKeyX509Der = key_pair.public_key().as_der().unwrap();
+    let (label, parsed) = pem_decode(&pem_encode("PUBLIC KEY", der.as_ref())).unwrap();
+    assert_eq!("PUBLIC KEY", label);
+    assert_eq!(der.as_ref(), parsed.as_slice());
+
+    for pem in [
+        RSA_PRIVATE_KEY_PKCS8_PEM,
+        RSA_PRIVATE_KEY_PEM,
+        RSA_PUBLIC_KEY_X509_PEM,
+    ] {
+        assert!(EcdsaKeyPair::from_pem(alg, pem).is_err());
+    }
+    // The curve must match the algorithm.
+    assert!(
+        EcdsaKeyPair::from_pem(&ECDSA_P384_SHA384_ASN1_SIGNING, EC_PRIVATE_KEY_PKCS8_PEM).is_err()
+    );
+    assert!(
+        EcdsaKeyPair::from_pem(&ECDSA_P384_SHA384_ASN1_SIGNING, EC_PRIVATE_KEY_SEC1_PEM).is_err()
+    );
+}
+
+#[test]
+fn ed25519_pem_round_trip() {
+    let rng = rand::SystemRandom::new();
+    let key_pair =
+        Ed25519KeyPair::from_pkcs8(Ed25519KeyPair::generate_pkcs8(&rng).unwrap().as_ref()).unwrap();
+
+    let v1: Pkcs8V1Der = key_pair.as_der().unwrap();
+    let v2: Pkcs8V2Der = key_pair.as_der().unwrap();
+    for der in [v1.as_ref(), v2.as_ref()] {
+        let (label, der) = pem_decode(&pem_encode("PRIVATE KEY", der)).unwrap();
+        assert_eq!("PRIVATE KEY", label);
+        let parsed = Ed25519KeyPair::from_pkcs8_maybe_unchecked(&der).unwrap();
+        assert_eq!(key_pair.public_key().as_ref(), parsed.public_key().as_ref());
+    }
+
+    let der: PublicKeyX509Der = key_pair.public_key().as_der().unwrap();
+    let (label, parsed) = pem_decode(&pem_encode("PUBLIC KEY", der.as_ref())).unwrap();
+    assert_eq!("PUBLIC KEY", label);
+    assert_eq!(der.as_ref(), parsed.as_slice());
+}
+
+#[test]
+fn agreement_pem_round_trip() {
+    for alg in [
+        &agreement::ECDH_P256,
+        &agreement::ECDH_P384,
+        &agreement::ECDH_P521,
+        &agreement::X25519,
+    ] {
+        let private_key = agreement::PrivateKey::generate(alg).unwrap();
+        let public_key = private_key.compute_public_key().unwrap();
+
+        let der: Pkcs8V1Der = private_key.as_der().unwrap();
+        let (label, der) = pem_decode(&pem_encode("PRIVATE KEY", der.as_ref())).unwrap();
+        assert_eq!("PRIVATE KEY", label);
+        let parsed = agreement::PrivateKey::from_private_key_der(alg, &der).unwrap();
+        assert_eq!(
+            public_key.as_ref(),
+            parsed.compute_public_key().unwrap().as_ref()
+        );
+
+        if alg != &agreement::X25519 {
+            let der: EcPrivateKeyRfc5915Der = private_key.as_der().unwrap();
+            let (label, der) = pem_decode(&pem_encode("EC PRIVATE KEY", der.as_ref())).unwrap();
+            assert_eq!("EC PRIVATE KEY", label);
+            let parsed = agreement::PrivateKey::from_private_key_der(alg, &der).unwrap();
+            assert_eq!(
+                public_key.as_ref(),
+                parsed.compute_public_key().unwrap().as_ref()
+            );
+        }
+
+        let der: PublicKeyX509Der = public_key.as_der().unwrap();
+        let (label, parsed) = pem_decode(&pem_encode("PUBLIC KEY", der.as_ref())).unwrap();
+        assert_eq!("PUBLIC KEY", label);
+        assert_eq!(der.as_ref(), parsed.as_slice());
+    }
+}
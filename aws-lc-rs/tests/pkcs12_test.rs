@@ -0,0 +1,100 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+#![cfg(not(feature = "fips"))]
+
+use aws_lc_rs::encoding::AsDer;
+use aws_lc_rs::rand::SystemRandom;
+use aws_lc_rs::signature::{self, EcdsaKeyPair, KeyPair, UnparsedPublicKey};
+use aws_lc_rs::{pkcs12, rsa};
+
+const MESSAGE: &[u8] = b"hello, world";
+
+#[test]
+fn pkcs12_rsa() {
+    let p12 = include_bytes!("data/pkcs12_rsa.p12");
+    let bundle = pkcs12::parse(p12, b"password").unwrap();
+    assert_eq!(
+        vec![include_bytes!("data/pkcs12_rsa_cert.der").to_vec()],
+        bundle.certificates
+    );
+
+    let key_pair = rsa::KeyPair::from_pkcs8(bundle.private_key.as_ref().unwrap()).unwrap();
+    let mut sig = vec![0u8; key_pair.public_modulus_len()];
+    key_pair
+        .sign(
+            &signature::RSA_PKCS1_SHA256,
+            &SystemRandom::new(),
+            MESSAGE,
+            &mut sig,
+        )
+        .unwrap();
+    UnparsedPublicKey::new(
+        &signature::RSA_PKCS1_2048_8192_SHA256,
+        key_pair.public_key(),
+    )
+    .verify(MESSAGE, &sig)
+    .unwrap();
+
+    assert!(pkcs12::parse(p12, b"wrong password").is_err());
+    assert!(pkcs12::parse(p12, b"").is_err());
+}
+
+#[test]
+fn pkcs12_ecdsa() {
+    for (p12, password) in [
+        (&include_bytes!("data/pkcs12_ecdsa_p256.p12")[..], &b""[..]),
+        (
+            &include_bytes!("data/pkcs12_ecdsa_p256_legacy.p12")[..],
+            &b"password"[..],
+        ),
+    ] {
+        let bundle = pkcs12::parse(p12, password).unwrap();
+        assert_eq!(
+            vec![
+                include_bytes!("data/pkcs12_ecdsa_p256_cert.der").to_vec(),
+                include_bytes!("data/pkcs12_ca_cert.der").to_vec(),
+            ],
+            bundle.certificates
+        );
+
+        let key_pair = EcdsaKeyPair::from_pkcs8(
+            &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+            bundle.private_key.as_ref().unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            &include_bytes!("data/pkcs12_ecdsa_p256_spki.der")[..],
+            key_pair.public_key().as_der().unwrap().as_ref()
+        );
+        let sig = key_pair.sign(&SystemRandom::new(), MESSAGE).unwrap();
+        UnparsedPublicKey::new(&signature::ECDSA_P256_SHA256_ASN1, key_pair.public_key())
+            .verify(MESSAGE, sig.as_ref())
+            .unwrap();
+
+        assert!(pkcs12::parse(p12, b"wrong password").is_err());
+    }
+}
+
+#[test]
+fn pkcs12_certificates_only() {
+    let p12 = include_bytes!("data/pkcs12_certs_only.p12");
+    let bundle = pkcs12::parse(p12, b"password").unwrap();
+    assert!(bundle.private_key.is_none());
+    assert_eq!(
+        vec![include_bytes!("data/pkcs12_ca_cert.der").to_vec()],
+        bundle.certificates
+    );
+    assert_eq!(
+        "Pkcs12Bundle { has_private_key: false, certificates: 1 }",
+        format!("{bundle:?}")
+    );
+}
+
+#[test]
+fn pkcs12_invalid() {
+    let p12 = include_bytes!("data/pkcs12_rsa.p12");
+    assert!(pkcs12::parse(&p12[..p12.len() - 1], b"password").is_err());
+    assert!(pkcs12::parse(&[], b"password").is_err());
+    assert!(pkcs12::parse(p12, b"pass\0word").is_err());
+}
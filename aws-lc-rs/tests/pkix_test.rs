@@ -0,0 +1,200 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+use aws_lc_rs::digest;
+use aws_lc_rs::encoding::der::{DerReader, TAG_BIT_STRING, TAG_SEQUENCE};
+use aws_lc_rs::pkix::{CsrBuilder, DistinguishedName, SubjectAltName};
+use aws_lc_rs::signature::{
+    self, EcdsaKeyPair, EcdsaSigningAlgorithm, KeyPair, RsaKeyPair, UnparsedPublicKey,
+    VerificationAlgorithm,
+};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+const TAG_SET: u8 = 0x31;
+const TAG_ATTRIBUTES: u8 = 0xa0;
+
+// 1.2.840.113549.1.9.14
+const OID_EXTENSION_REQUEST: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x09, 0x0e];
+
+fn subject() -> DistinguishedName {
+    DistinguishedName::new()
+        .country("US")
+        .state_or_province("Washington")
+        .organization("Example Corp")
+        .common_name("example.com")
+}
+
+fn subject_alt_names() -> Vec<SubjectAltName> {
+    vec![
+        SubjectAltName::DnsName("example.com".to_string()),
+        SubjectAltName::DnsName("www.example.com".to_string()),
+        SubjectAltName::IpAddress(IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1))),
+        SubjectAltName::IpAddress(IpAddr::V6(Ipv6Addr::LOCALHOST)),
+        SubjectAltName::Email("admin@example.com".to_string()),
+    ]
+}
+
+/// Returns the length of the DER header of an element with `content_len` bytes of content.
+fn header_len(content_len: usize) -> usize {
+    match content_len {
+        0..=0x7f => 2,
+        0x80..=0xff => 3,
+        _ => 4,
+    }
+}
+
+/// Checks the structure of a `CertificationRequest`, returning the DER-encoded
+/// `CertificationRequestInfo`, the signature algorithm OID, and the signature.
+///
+/// For both RSA and EC keys, the `SubjectPublicKeyInfo` ends with the key's `public_key()`.
+fn parse_csr<'a>(csr: &'a [u8], public_key: &[u8], sans: bool) -> (&'a [u8], &'a [u8], &'a [u8]) {
+    let mut reader = DerReader::new(csr);
+    let content = reader.read_tagged(TAG_SEQUENCE).unwrap();
+    assert!(reader.is_empty());
+
+    let mut request = DerReader::new(content);
+    let info_content = request.read_tagged(TAG_SEQUENCE).unwrap();
+    let info = &content[..header_len(info_content.len()) + info_content.len()];
+
+    let mut algorithm = request.read_sequence().unwrap();
+    let oid = algorithm.read_oid().unwrap();
+    let signature = request.read_tagged(TAG_BIT_STRING).unwrap();
+    assert!(request.is_empty());
+    assert_eq!(0, signature[0]);
+
+    let mut info_reader = DerReader::new(info_content);
+    assert_eq!(&[0], info_reader.read_integer().unwrap());
+    let mut name = info_reader.read_sequence().unwrap();
+    let mut rdns = 0;
+    while !name.is_empty() {
+        let (tag, _) = name.next_tag_len().unwrap();
+        assert_eq!(TAG_SET, tag);
+        rdns += 1;
+    }
+    assert_eq!(4, rdns);
+
+    let spki = info_reader.read_tagged(TAG_SEQUENCE).unwrap();
+    assert!(spki.ends_with(public_key));
+    let attributes = info_reader.read_tagged(TAG_ATTRIBUTES).unwrap();
+    assert!(info_reader.is_empty());
+    if sans {
+        let mut attribute = DerReader::new(attributes).read_sequence().unwrap();
+        assert_eq!(OID_EXTENSION_REQUEST, attribute.read_oid().unwrap());
+    } else {
+        assert!(attributes.is_empty());
+    }
+
+    (info, oid, &signature[1..])
+}
+
+#[test]
+fn csr_ecdsa() {
+    for (alg, verification_alg, oid_last) in [
+        (
+            &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+            &signature::ECDSA_P256_SHA256_ASN1,
+            0x02,
+        ),
+        (
+            &signature::ECDSA_P384_SHA384_FIXED_SIGNING,
+            &signature::ECDSA_P384_SHA384_ASN1,
+            0x03,
+        ),
+        (
+            &signature::ECDSA_P521_SHA512_ASN1_SIGNING,
+            &signature::ECDSA_P521_SHA512_ASN1,
+            0x04,
+        ),
+    ]
+        as [(&EcdsaSigningAlgorithm, &dyn VerificationAlgorithm, u8); 3]
+    {
+        let key_pair = EcdsaKeyPair::generate(alg).unwrap();
+
+        for sans in [false, true] {
+            let mut builder = CsrBuilder::new().subject(&subject());
+            if sans {
+                builder = builder.subject_alt_names(&subject_alt_names());
+            }
+            let csr = builder.sign_with_ecdsa(&key_pair).unwrap();
+
+            let (info, oid, sig) = parse_csr(&csr, key_pair.public_key().as_ref(), sans);
+            assert_eq!(&[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x03, oid_last], oid);
+            UnparsedPublicKey::new(verification_alg, key_pair.public_key().as_ref())
+                .verify(info, sig)
+                .unwrap();
+        }
+    }
+}
+
+#[test]
+fn csr_rsa() {
+    let key_pair =
+        RsaKeyPair::from_pkcs8(include_bytes!("data/rsa_test_private_key_2048.p8")).unwrap();
+
+    for (digest_alg, verification_alg, oid_last) in [
+        (
+            &digest::SHA256,
+            &signature::RSA_PKCS1_2048_8192_SHA256,
+            0x0b,
+        ),
+        (
+            &digest::SHA384,
+            &signature::RSA_PKCS1_2048_8192_SHA384,
+            0x0c,
+        ),
+        (
+            &digest::SHA512,
+            &signature::RSA_PKCS1_2048_8192_SHA512,
+            0x0d,
+        ),
+    ] {
+        let csr = CsrBuilder::new()
+            .subject(&subject())
+            .subject_alt_names(&subject_alt_names())
+            .sign_with_rsa(&key_pair, digest_alg)
+            .unwrap();
+
+        let (info, oid, sig) = parse_csr(&csr, key_pair.public_key().as_ref(), true);
+        assert_eq!(
+            &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, oid_last],
+            oid
+        );
+        UnparsedPublicKey::new(verification_alg, key_pair.public_key().as_ref())
+            .verify(info, sig)
+            .unwrap();
+    }
+
+    assert!(CsrBuilder::new()
+        .subject(&subject())
+        .sign_with_rsa(&key_pair, &digest::SHA1_FOR_LEGACY_USE_ONLY)
+        .is_err());
+}
+
+#[test]
+fn csr_invalid() {
+    let key_pair = EcdsaKeyPair::generate(&signature::ECDSA_P256_SHA256_ASN1_SIGNING).unwrap();
+
+    for name in [
+        DistinguishedName::new().country("USA"),
+        DistinguishedName::new().country("1A"),
+    ] {
+        assert!(CsrBuilder::new()
+            .subject(&name)
+            .sign_with_ecdsa(&key_pair)
+            .is_err());
+    }
+
+    for san in [
+        SubjectAltName::DnsName(String::new()),
+        SubjectAltName::DnsName("bücher.example".to_string()),
+    ] {
+        assert!(CsrBuilder::new()
+            .subject_alt_names(&[san])
+            .sign_with_ecdsa(&key_pair)
+            .is_err());
+    }
+
+    // There is no X.509 signature algorithm identifier for ECDSA with SHA3 in use.
+    let key_pair = EcdsaKeyPair::generate(&signature::ECDSA_P256K1_SHA3_256_ASN1_SIGNING).unwrap();
+    assert!(CsrBuilder::new().sign_with_ecdsa(&key_pair).is_err());
+}
@@ -55,6 +55,26 @@ fn test_system_random_lengths() {
     }
 }
 
+#[test]
+fn test_generate_bytes_and_integers() {
+    let rng = rand::SystemRandom::new();
+
+    let mut buf = vec![0u8; 1024 * 1024];
+    rand::generate_bytes(&rng, &mut buf).unwrap();
+    assert!(buf.iter().any(|x| *x != 0));
+    rand::generate_bytes(&rng, &mut []).unwrap();
+
+    // The likelihood of a false positive is too low to worry about.
+    assert_ne!(
+        rand::generate_u64(&rng).unwrap(),
+        rand::generate_u64(&rng).unwrap()
+    );
+    assert_ne!(
+        rand::generate_u128(&rng).unwrap(),
+        rand::generate_u128(&rng).unwrap()
+    );
+}
+
 #[test]
 fn test_system_random_traits() {
     test::compile_time_assert_clone::<rand::SystemRandom>();
@@ -0,0 +1,118 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+#![cfg(feature = "ring-compat")]
+
+// The tests below only name items through `ring::` paths, so they compile unchanged against the
+// `ring` crate when this import is replaced with `extern crate ring;`.
+use aws_lc_rs::ring;
+
+use ring::signature::KeyPair;
+
+#[test]
+fn ring_compat_aead() {
+    let rng = ring::rand::SystemRandom::new();
+    let mut key_bytes = [0u8; 32];
+    ring::rand::SecureRandom::fill(&rng, &mut key_bytes).unwrap();
+
+    let key = ring::aead::LessSafeKey::new(
+        ring::aead::UnboundKey::new(&ring::aead::AES_256_GCM, &key_bytes).unwrap(),
+    );
+    let nonce = [0x42u8; ring::aead::NONCE_LEN];
+    let mut in_out = b"hello, world".to_vec();
+    key.seal_in_place_append_tag(
+        ring::aead::Nonce::assume_unique_for_key(nonce),
+        ring::aead::Aad::from(b"aad"),
+        &mut in_out,
+    )
+    .unwrap();
+    let plaintext = key
+        .open_in_place(
+            ring::aead::Nonce::assume_unique_for_key(nonce),
+            ring::aead::Aad::from(b"aad"),
+            &mut in_out,
+        )
+        .unwrap();
+    assert_eq!(b"hello, world", plaintext);
+}
+
+#[test]
+fn ring_compat_digest_hmac_hkdf_pbkdf2() {
+    let digest = ring::digest::digest(&ring::digest::SHA256, b"hello, world");
+    assert_eq!(ring::digest::SHA256_OUTPUT_LEN, digest.as_ref().len());
+
+    let key = ring::hmac::Key::new(ring::hmac::HMAC_SHA256, b"key");
+    let tag = ring::hmac::sign(&key, b"hello, world");
+    ring::hmac::verify(&key, b"hello, world", tag.as_ref()).unwrap();
+
+    let salt = ring::hkdf::Salt::new(ring::hkdf::HKDF_SHA256, b"salt");
+    let prk = salt.extract(b"secret");
+    let mut okm = [0u8; 32];
+    prk.expand(&[b"info"], ring::hkdf::HKDF_SHA256)
+        .unwrap()
+        .fill(&mut okm)
+        .unwrap();
+
+    let iterations = core::num::NonZeroU32::new(1000).unwrap();
+    let mut derived = [0u8; ring::digest::SHA256_OUTPUT_LEN];
+    ring::pbkdf2::derive(
+        ring::pbkdf2::PBKDF2_HMAC_SHA256,
+        iterations,
+        b"salt",
+        b"password",
+        &mut derived,
+    );
+    ring::pbkdf2::verify(
+        ring::pbkdf2::PBKDF2_HMAC_SHA256,
+        iterations,
+        b"salt",
+        b"password",
+        &derived,
+    )
+    .unwrap();
+    assert!(ring::constant_time::verify_slices_are_equal(&derived, &okm).is_err());
+}
+
+#[test]
+fn ring_compat_signature() {
+    let rng = ring::rand::SystemRandom::new();
+    let pkcs8 = ring::signature::Ed25519KeyPair::generate_pkcs8(&rng).unwrap();
+    let key_pair = ring::signature::Ed25519KeyPair::from_pkcs8(pkcs8.as_ref()).unwrap();
+    let signature = key_pair.sign(b"hello, world");
+    ring::signature::UnparsedPublicKey::new(&ring::signature::ED25519, key_pair.public_key())
+        .verify(b"hello, world", signature.as_ref())
+        .unwrap();
+}
+
+#[test]
+fn ring_compat_agreement() {
+    let rng = ring::rand::SystemRandom::new();
+    let my_private_key =
+        ring::agreement::EphemeralPrivateKey::generate(&ring::agreement::X25519, &rng).unwrap();
+    let peer_private_key =
+        ring::agreement::EphemeralPrivateKey::generate(&ring::agreement::X25519, &rng).unwrap();
+    let peer_public_key = ring::agreement::UnparsedPublicKey::new(
+        &ring::agreement::X25519,
+        peer_private_key.compute_public_key().unwrap(),
+    );
+    let my_public_key = ring::agreement::UnparsedPublicKey::new(
+        &ring::agreement::X25519,
+        my_private_key.compute_public_key().unwrap(),
+    );
+
+    let mine = ring::agreement::agree_ephemeral(
+        my_private_key,
+        &peer_public_key,
+        ring::error::Unspecified,
+        |key| Ok(key.to_vec()),
+    )
+    .unwrap();
+    let theirs = ring::agreement::agree_ephemeral(
+        peer_private_key,
+        &my_public_key,
+        ring::error::Unspecified,
+        |key| Ok(key.to_vec()),
+    )
+    .unwrap();
+    assert_eq!(mine, theirs);
+}
@@ -3,17 +3,21 @@
 // Modifications copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0 OR ISC
 
+use aws_lc_rs::encoding::pem::pem_decode;
 use aws_lc_rs::encoding::{AsDer, Pkcs8V1Der, PublicKeyX509Der};
+use aws_lc_rs::error::KeyRejectionReason;
 use aws_lc_rs::rsa::{
-    EncryptionAlgorithmId, KeySize, OaepPrivateDecryptingKey, OaepPublicEncryptingKey,
+    kem, EncryptionAlgorithmId, KeySize, OaepPrivateDecryptingKey, OaepPublicEncryptingKey,
     Pkcs1PrivateDecryptingKey, Pkcs1PublicEncryptingKey, PrivateDecryptingKey, PublicEncryptingKey,
-    OAEP_SHA1_MGF1SHA1, OAEP_SHA256_MGF1SHA256, OAEP_SHA384_MGF1SHA384, OAEP_SHA512_MGF1SHA512,
+    PublicKey, RsaPssKeyPair, RsaPssSaltLength, OAEP_SHA1_MGF1SHA1, OAEP_SHA256_MGF1SHA256,
+    OAEP_SHA384_MGF1SHA384, OAEP_SHA512_MGF1SHA512,
 };
 use aws_lc_rs::signature::{
-    KeyPair, RsaKeyPair, RsaParameters, RsaPublicKeyComponents, RsaSubjectPublicKey,
+    KeyPair, RsaKeyPair, RsaParameters, RsaPrivateKeyComponents, RsaPublicKeyComponents,
+    RsaSubjectPublicKey,
 };
 use aws_lc_rs::test::to_hex_upper;
-use aws_lc_rs::{rand, signature, test, test_file};
+use aws_lc_rs::{digest, rand, rsa, signature, test, test_file};
 
 #[test]
 fn rsa_traits() {
@@ -25,6 +29,8 @@ fn rsa_traits() {
     test::compile_time_assert_sync::<RsaPublicKeyComponents<&[u8]>>();
     test::compile_time_assert_send::<RsaPublicKeyComponents<Vec<u8>>>();
     test::compile_time_assert_sync::<RsaPublicKeyComponents<Vec<u8>>>();
+    test::compile_time_assert_send::<RsaPrivateKeyComponents<Vec<u8>>>();
+    test::compile_time_assert_sync::<RsaPrivateKeyComponents<Vec<u8>>>();
 }
 
 #[test]
@@ -54,6 +60,61 @@ fn rsa_from_pkcs8_test() {
     );
 }
 
+#[test]
+fn rsa_from_encrypted_pkcs8() {
+    const PASSWORD: &[u8] = b"password";
+    let pkcs8 = include_bytes!("data/rsa_test_private_key_2048.p8");
+    let ecdsa_pkcs8 = pem_decode(include_str!(
+        "data/ecdsa_test_private_key_p256_encrypted.pem"
+    ))
+    .unwrap()
+    .1;
+
+    for encrypted in [
+        &include_bytes!("data/rsa_test_private_key_2048_aes128.p8")[..],
+        &include_bytes!("data/rsa_test_private_key_2048_aes256.p8")[..],
+        &include_bytes!("data/rsa_test_private_key_2048_3des.p8")[..],
+    ] {
+        let key_pair = RsaKeyPair::from_encrypted_pkcs8(encrypted, PASSWORD).unwrap();
+        let der: Pkcs8V1Der = key_pair.as_der().unwrap();
+        assert_eq!(&pkcs8[..], der.as_ref());
+
+        let message = b"hello, world";
+        let mut sig = vec![0u8; key_pair.public_modulus_len()];
+        key_pair
+            .sign(
+                &signature::RSA_PKCS1_SHA256,
+                &rand::SystemRandom::new(),
+                message,
+                &mut sig,
+            )
+            .unwrap();
+        signature::UnparsedPublicKey::new(
+            &signature::RSA_PKCS1_2048_8192_SHA256,
+            key_pair.public_key(),
+        )
+        .verify(message, &sig)
+        .unwrap();
+
+        assert_eq!(
+            KeyRejectionReason::InvalidEncoding,
+            RsaKeyPair::from_encrypted_pkcs8(encrypted, b"wrong password")
+                .unwrap_err()
+                .reason()
+        );
+        assert!(RsaKeyPair::from_encrypted_pkcs8(encrypted, b"").is_err());
+        assert!(RsaKeyPair::from_encrypted_pkcs8(&encrypted[1..], PASSWORD).is_err());
+    }
+
+    assert!(RsaKeyPair::from_encrypted_pkcs8(pkcs8, PASSWORD).is_err());
+    assert_eq!(
+        KeyRejectionReason::WrongAlgorithm,
+        RsaKeyPair::from_encrypted_pkcs8(&ecdsa_pkcs8, PASSWORD)
+            .unwrap_err()
+            .reason()
+    );
+}
+
 #[test]
 fn test_signature_rsa_pkcs1_sign() {
     let rng = rand::SystemRandom::new();
@@ -284,6 +345,29 @@ fn keysize_len() {
     assert_eq!(KeySize::Rsa8192.len(), 1024);
 }
 
+#[test]
+fn key_size_non_standard() {
+    let key_pair =
+        RsaKeyPair::from_pkcs8(include_bytes!("data/rsa_test_private_key_2050.p8")).unwrap();
+    assert_eq!(None, key_pair.key_size());
+    assert_eq!(2050, key_pair.key_size_bits());
+    assert_eq!(2050, key_pair.public_key().key_size_bits());
+    assert_eq!(257, key_pair.public_modulus_len());
+
+    let components = RsaPublicKeyComponents::<Vec<u8>>::try_from(key_pair.public_key()).unwrap();
+    assert_eq!(2050, components.key_size_bits());
+    let padded_n = [&[0u8, 0][..], &components.n].concat();
+    let padded = RsaPublicKeyComponents {
+        n: padded_n.as_slice(),
+        e: &components.e,
+    };
+    assert_eq!(2050, padded.key_size_bits());
+    assert_eq!(
+        0,
+        RsaPublicKeyComponents::<&[u8]> { n: &[], e: &[] }.key_size_bits()
+    );
+}
+
 macro_rules! generate_encode_decode {
     ($name:ident, $size:expr) => {
         #[test]
@@ -293,10 +377,15 @@ macro_rules! generate_encode_decode {
             let pkcs8v1 = private_key.as_der().expect("encoded");
 
             let private_key = RsaKeyPair::from_pkcs8(pkcs8v1.as_ref()).expect("decoded");
+            assert_eq!(Some($size), private_key.key_size());
+            assert_eq!($size.len() * 8, private_key.key_size_bits());
 
             let public_key = crate::signature::KeyPair::public_key(&private_key);
 
             let _ = public_key.as_ref();
+            assert_eq!($size.len() * 8, public_key.key_size_bits());
+            let components = RsaPublicKeyComponents::<Vec<u8>>::try_from(public_key).unwrap();
+            assert_eq!($size.len() * 8, components.key_size_bits());
         }
     };
 }
@@ -324,10 +413,15 @@ macro_rules! generate_fips_encode_decode {
             let pkcs8v1 = private_key.as_der().expect("encoded");
 
             let private_key = RsaKeyPair::from_pkcs8(pkcs8v1.as_ref()).expect("decoded");
+            assert_eq!(Some($size), private_key.key_size());
+            assert_eq!($size.len() * 8, private_key.key_size_bits());
 
             let public_key = crate::signature::KeyPair::public_key(&private_key);
 
             let _ = public_key.as_ref();
+            assert_eq!($size.len() * 8, public_key.key_size_bits());
+            let components = RsaPublicKeyComponents::<Vec<u8>>::try_from(public_key).unwrap();
+            assert_eq!($size.len() * 8, components.key_size_bits());
         }
     };
     ($name:ident, $size:expr, false) => {
@@ -466,6 +560,10 @@ fn encryption_algorithm_id() {
         OAEP_SHA512_MGF1SHA512.id(),
         EncryptionAlgorithmId::OaepSha512Mgf1sha512
     );
+    assert_eq!(
+        rsa::OAEP_SHA256_MGF1SHA512.id(),
+        EncryptionAlgorithmId::OaepSha256Mgf1sha512
+    );
 }
 
 #[test]
@@ -813,6 +911,22 @@ fn too_small_encrypt_key() {
     PrivateDecryptingKey::from_pkcs8(PRIVATE_KEY).expect_err("key too small");
 }
 
+#[test]
+fn too_small_signing_key() {
+    const PRIVATE_KEY: &[u8] = include_bytes!("data/rsa_test_private_key_1024.p8");
+    let err = RsaKeyPair::from_pkcs8(PRIVATE_KEY).expect_err("key too small");
+    #[cfg(feature = "fips")]
+    assert_eq!(
+        aws_lc_rs::error::KeyRejected::fips_key_size_violation(),
+        err
+    );
+    #[cfg(not(feature = "fips"))]
+    assert_ne!(
+        aws_lc_rs::error::KeyRejected::fips_key_size_violation(),
+        err
+    );
+}
+
 #[test]
 fn min_encrypt_key() {
     const PRIVATE_KEY: &[u8] = include_bytes!("data/rsa_test_private_key_2048.p8");
@@ -1052,3 +1166,472 @@ fn rsa2048_pkcs1_openssl_kat() {
 
     assert_eq!(EXPECTED_MESSAGE, plaintext);
 }
+
+#[test]
+fn rsa2048_oaep_sha256_convenience_round_trip() {
+    const PKCS8: &[u8] = include_bytes!("data/rsa_test_private_key_2048.p8");
+    const RSA_PRIVATE_KEY_DER: &[u8] = include_bytes!("data/rsa_test_private_key_2048.der");
+    const MESSAGE: &[u8] = b"Hello World!";
+    const LABEL: &[u8] = b"aws-lc-rs";
+
+    let pkcs8_key = PrivateDecryptingKey::from_pkcs8(PKCS8).expect("private key");
+    let der_key = PrivateDecryptingKey::from_der(RSA_PRIVATE_KEY_DER).expect("private key");
+    assert!(PrivateDecryptingKey::from_der(PKCS8).is_err());
+
+    let public_key = pkcs8_key.public_key();
+    let ciphertext = public_key
+        .oaep_sha256_encrypt(MESSAGE, LABEL)
+        .expect("encrypt");
+    assert_eq!(public_key.key_size_bytes(), ciphertext.len());
+
+    for private_key in [&pkcs8_key, &der_key] {
+        let plaintext = private_key
+            .decrypt_oaep(&ciphertext, LABEL)
+            .expect("decrypt");
+        assert_eq!(MESSAGE, plaintext);
+        assert!(private_key.decrypt_oaep(&ciphertext, b"wrong").is_err());
+    }
+
+    // Interoperates with the explicit OAEP key types.
+    let oaep_private_key = OaepPrivateDecryptingKey::new(der_key).expect("private key");
+    let mut plaintext = vec![0u8; oaep_private_key.min_output_size()];
+    let plaintext = oaep_private_key
+        .decrypt(
+            &OAEP_SHA256_MGF1SHA256,
+            &ciphertext,
+            &mut plaintext,
+            Some(LABEL),
+        )
+        .expect("decrypt");
+    assert_eq!(MESSAGE, plaintext);
+
+    let too_long = vec![0u8; public_key.key_size_bytes()];
+    assert!(public_key.oaep_sha256_encrypt(&too_long, LABEL).is_err());
+}
+
+#[test]
+fn oaep_decrypt_tests() {
+    const PRIVATE_KEY: &[u8] = include_bytes!("data/rsa_test_private_key_2048.p8");
+
+    let private_key = PrivateDecryptingKey::from_pkcs8(PRIVATE_KEY).expect("key supported");
+    let public_key = OaepPublicEncryptingKey::new(private_key.public_key()).expect("supported key");
+    let private_key = OaepPrivateDecryptingKey::new(private_key).expect("supported key");
+
+    test::run(
+        test_file!("data/rsa_oaep_decrypt_tests.txt"),
+        |section, test_case| {
+            assert_eq!(section, "");
+            let algorithm = match test_case.consume_string("Algorithm").as_str() {
+                "OAEP_SHA256_MGF1SHA256" => &OAEP_SHA256_MGF1SHA256,
+                "OAEP_SHA256_MGF1SHA384" => &rsa::OAEP_SHA256_MGF1SHA384,
+                "OAEP_SHA256_MGF1SHA512" => &rsa::OAEP_SHA256_MGF1SHA512,
+                "OAEP_SHA384_MGF1SHA256" => &rsa::OAEP_SHA384_MGF1SHA256,
+                "OAEP_SHA384_MGF1SHA384" => &OAEP_SHA384_MGF1SHA384,
+                "OAEP_SHA384_MGF1SHA512" => &rsa::OAEP_SHA384_MGF1SHA512,
+                "OAEP_SHA512_MGF1SHA256" => &rsa::OAEP_SHA512_MGF1SHA256,
+                "OAEP_SHA512_MGF1SHA384" => &rsa::OAEP_SHA512_MGF1SHA384,
+                "OAEP_SHA512_MGF1SHA512" => &OAEP_SHA512_MGF1SHA512,
+                name => panic!("Unsupported algorithm: {name}"),
+            };
+            let label = test_case.consume_optional_bytes("Label");
+            let label = label.as_deref();
+            let ciphertext = test_case.consume_bytes("Ciphertext");
+            let expected = test_case.consume_bytes("Plaintext");
+
+            let mut plaintext = vec![0u8; private_key.min_output_size()];
+            let plaintext = private_key
+                .decrypt(algorithm, &ciphertext, &mut plaintext, label)
+                .expect("decrypted");
+            assert_eq!(expected.as_slice(), plaintext);
+
+            // The label is bound to the ciphertext.
+            let mut plaintext = vec![0u8; private_key.min_output_size()];
+            let wrong_label = if label.is_some() {
+                None
+            } else {
+                Some(&b"label"[..])
+            };
+            assert!(private_key
+                .decrypt(algorithm, &ciphertext, &mut plaintext, wrong_label)
+                .is_err());
+
+            let mut ciphertext = vec![0u8; public_key.ciphertext_size()];
+            let ciphertext = public_key
+                .encrypt(algorithm, &expected, &mut ciphertext, label)
+                .expect("encrypted");
+            let mut plaintext = vec![0u8; private_key.min_output_size()];
+            let plaintext = private_key
+                .decrypt(algorithm, ciphertext, &mut plaintext, label)
+                .expect("decrypted");
+            assert_eq!(expected.as_slice(), plaintext);
+
+            // The maximum plaintext size only depends on the OAEP hash function.
+            let mut ciphertext = vec![0u8; public_key.ciphertext_size()];
+            let message = vec![1u8; public_key.max_plaintext_size(algorithm)];
+            assert!(public_key
+                .encrypt(algorithm, &message, &mut ciphertext, label)
+                .is_ok());
+            let message = vec![1u8; public_key.max_plaintext_size(algorithm) + 1];
+            assert!(public_key
+                .encrypt(algorithm, &message, &mut ciphertext, label)
+                .is_err());
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn pkcs1_decrypt_tests() {
+    const PRIVATE_KEY: &[u8] = include_bytes!("data/rsa_test_private_key_2048.p8");
+
+    let private_key = Pkcs1PrivateDecryptingKey::new(
+        PrivateDecryptingKey::from_pkcs8(PRIVATE_KEY).expect("key supported"),
+    )
+    .expect("supported key");
+
+    test::run(
+        test_file!("data/rsa_pkcs1_decrypt_tests.txt"),
+        |section, test_case| {
+            assert_eq!(section, "");
+            let ciphertext = test_case.consume_bytes("Ciphertext");
+            let expected = test_case.consume_optional_bytes("Plaintext");
+
+            let mut plaintext = vec![0u8; private_key.min_output_size()];
+            let result = private_key.decrypt(&ciphertext, &mut plaintext);
+            match expected {
+                Some(expected) => assert_eq!(expected.as_slice(), result.unwrap()),
+                None => assert!(result.is_err()),
+            }
+
+            // Ciphertexts that are not exactly the size of the modulus are rejected.
+            let mut plaintext = vec![0u8; private_key.min_output_size()];
+            assert!(private_key
+                .decrypt(&ciphertext[1..], &mut plaintext)
+                .is_err());
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn private_key_components_round_trip() {
+    const MESSAGE: &[u8] = b"private key components";
+    let rng = rand::SystemRandom::new();
+    let key_pair = RsaKeyPair::generate(KeySize::Rsa2048).unwrap();
+
+    let components = key_pair.private_key_components().unwrap();
+    // The private components are not shown.
+    let debug = format!("{components:?}");
+    assert!(debug.starts_with("RsaPrivateKeyComponents { n: "));
+    assert!(debug.ends_with(", .. }"));
+    assert!(!debug.contains("d: "));
+
+    let reconstructed = RsaKeyPair::try_from(components.clone()).unwrap();
+    assert_eq!(
+        key_pair.public_key().as_ref(),
+        reconstructed.public_key().as_ref()
+    );
+
+    let mut signature = vec![0u8; reconstructed.public_modulus_len()];
+    reconstructed
+        .sign(&signature::RSA_PKCS1_SHA256, &rng, MESSAGE, &mut signature)
+        .unwrap();
+    signature::UnparsedPublicKey::new(
+        &signature::RSA_PKCS1_2048_8192_SHA256,
+        key_pair.public_key().as_ref(),
+    )
+    .verify(MESSAGE, &signature)
+    .unwrap();
+
+    // Borrowed components, with leading zeros.
+    let padded_p = [&[0u8, 0][..], &components.p].concat();
+    let borrowed = RsaPrivateKeyComponents {
+        n: components.n.as_slice(),
+        e: &components.e,
+        d: &components.d,
+        p: &padded_p,
+        q: &components.q,
+        dp: &components.dp,
+        dq: &components.dq,
+        qi: &components.qi,
+    };
+    let reconstructed = RsaKeyPair::try_from(borrowed).unwrap();
+    assert_eq!(
+        key_pair.public_key().as_ref(),
+        reconstructed.public_key().as_ref()
+    );
+}
+
+#[test]
+fn private_key_components_invalid() {
+    let components = RsaKeyPair::generate(KeySize::Rsa2048)
+        .unwrap()
+        .private_key_components()
+        .unwrap();
+    let other = RsaKeyPair::generate(KeySize::Rsa2048)
+        .unwrap()
+        .private_key_components()
+        .unwrap();
+
+    // p * q != n
+    let mut mismatched = components.clone();
+    mismatched.p = other.p;
+    assert!(RsaKeyPair::try_from(mismatched).is_err());
+
+    let mut bad_d = components.clone();
+    bad_d.d = other.d;
+    assert!(RsaKeyPair::try_from(bad_d).is_err());
+
+    let mut bad_qi = components.clone();
+    bad_qi.qi = other.qi;
+    assert!(RsaKeyPair::try_from(bad_qi).is_err());
+
+    let mut zero = components.clone();
+    zero.dq = vec![0; 4];
+    assert!(RsaKeyPair::try_from(zero).is_err());
+
+    let mut empty = components;
+    empty.e = vec![];
+    assert!(RsaKeyPair::try_from(empty).is_err());
+}
+
+#[test]
+fn rsa_pss_key_pair_openssl_signature() {
+    // An `id-RSASSA-PSS` key with SHA-256, MGF1-SHA-256 and a 32-byte salt, and a signature of
+    // `MESSAGE` generated with that key by OpenSSL 3. This is an interoperability check, not a
+    // published test vector: the RSA Laboratories `pss-vect.txt` vectors use SHA-1 and 1024-bit
+    // keys, which `RsaPssKeyPair` rejects.
+    const MESSAGE: &[u8] = b"RSASSA-PSS signature generated by OpenSSL";
+    let signature = test::from_dirty_hex(
+        "3bb0d91ffbf9cc6824f43abe6401a0993b2e8971caa694bb1b8ec59042b58370a503f336f35b4e2f3d1d1ef1c30d44b7\
+         d0080e6b526fd6f4887be39467fbfa60187845f3165a1285bdd86d478cb20e3d54255ddec46c1aa29d8c2d7b76481d32\
+         e465ba4afaf43d599dcbf3d8f45d7cacd149210032242c3edf41f79cb76ed461bbd5776be94e0454508c9c45c9914154\
+         229726e19aca4c59c62a950670c430eae81779afce352b3a9434a33218ab2b6572c1bc01d36ed59cf83440f3fba395f2\
+         9683fe16a0efbe025a60981e09600a339081de962385f1a5478da0674a35246f3c87a2c38af3e95dcb89856ccc164b28\
+         fea4002f8f257f499af0fb8103f13855",
+    );
+
+    let key_pair =
+        RsaPssKeyPair::from_pkcs8(include_bytes!("data/rsa_pss_test_private_key_2048.p8")).unwrap();
+    let public_key = key_pair.public_key();
+    assert_eq!(&digest::SHA256, public_key.digest_algorithm());
+    assert_eq!(32, public_key.salt_len());
+    public_key.verify(MESSAGE, &signature).unwrap();
+    assert!(public_key.verify(b"other", &signature).is_err());
+
+    let mut our_signature = vec![0u8; key_pair.public_modulus_len()];
+    key_pair
+        .sign(&rand::SystemRandom::new(), MESSAGE, &mut our_signature)
+        .unwrap();
+    public_key.verify(MESSAGE, &our_signature).unwrap();
+
+    // A salt of the digest length is what the `RSA_PSS_*` verification algorithms expect.
+    signature::UnparsedPublicKey::new(&signature::RSA_PSS_2048_8192_SHA256, public_key.as_ref())
+        .verify(MESSAGE, &our_signature)
+        .unwrap();
+}
+
+#[test]
+fn rsa_pss_key_pair_round_trip() {
+    const MESSAGE: &[u8] = b"message";
+    let rng = rand::SystemRandom::new();
+    for (digest_alg, salt_len, expected_salt_len) in [
+        (&digest::SHA256, RsaPssSaltLength::DigestLength, 32),
+        (&digest::SHA384, RsaPssSaltLength::Bytes(0), 0),
+        (&digest::SHA512, RsaPssSaltLength::Bytes(20), 20),
+    ] {
+        let key_pair = RsaPssKeyPair::generate(KeySize::Rsa2048, digest_alg, salt_len).unwrap();
+        let public_key = key_pair.public_key();
+        assert_eq!(digest_alg, public_key.digest_algorithm());
+        assert_eq!(expected_salt_len, public_key.salt_len());
+
+        let mut signature = vec![0u8; key_pair.public_modulus_len()];
+        key_pair.sign(&rng, MESSAGE, &mut signature).unwrap();
+        public_key.verify(MESSAGE, &signature).unwrap();
+        assert!(public_key.verify(b"other", &signature).is_err());
+
+        assert!(key_pair.sign(&rng, MESSAGE, &mut signature[1..]).is_err());
+    }
+
+    // The parameters must be FIPS 186-5 approved.
+    for (digest_alg, salt_len) in [
+        (
+            &digest::SHA1_FOR_LEGACY_USE_ONLY,
+            RsaPssSaltLength::DigestLength,
+        ),
+        (&digest::SHA3_256, RsaPssSaltLength::DigestLength),
+        (&digest::SHA256, RsaPssSaltLength::Bytes(33)),
+    ] {
+        assert!(RsaPssKeyPair::generate(KeySize::Rsa2048, digest_alg, salt_len).is_err());
+    }
+}
+
+#[test]
+fn rsa_pss_key_pair_rejects_rsa_keys() {
+    let key = include_bytes!("data/rsa_test_private_key_2048.p8");
+    assert!(RsaPssKeyPair::from_pkcs8(key).is_err());
+    assert!(RsaKeyPair::from_pkcs8(key).is_ok());
+}
+
+#[test]
+fn public_key_components_round_trip() {
+    let key_pair = RsaKeyPair::generate(KeySize::Rsa2048).unwrap();
+    let public_key = key_pair.public_key();
+
+    let components = RsaPublicKeyComponents::<Vec<u8>>::try_from(public_key).unwrap();
+    let reconstructed = PublicKey::try_from(components.clone()).unwrap();
+    assert_eq!(public_key.as_ref(), reconstructed.as_ref());
+
+    let borrowed = RsaPublicKeyComponents {
+        n: components.n.as_slice(),
+        e: components.e.as_slice(),
+    };
+    let reconstructed = PublicKey::try_from(borrowed).unwrap();
+    assert_eq!(public_key.as_ref(), reconstructed.as_ref());
+}
+
+#[test]
+fn public_key_components_invalid() {
+    let components = RsaPublicKeyComponents::<Vec<u8>>::try_from(
+        RsaKeyPair::generate(KeySize::Rsa2048).unwrap().public_key(),
+    )
+    .unwrap();
+
+    let mut leading_zero = components.clone();
+    leading_zero.n.insert(0, 0);
+    assert!(PublicKey::try_from(leading_zero).is_err());
+
+    let mut empty = components.clone();
+    empty.e.clear();
+    assert!(PublicKey::try_from(empty).is_err());
+
+    // An RSA modulus is odd.
+    let mut even = components.clone();
+    *even.n.last_mut().unwrap() &= 0xfe;
+    assert!(PublicKey::try_from(even).is_err());
+
+    // The public exponent is odd and greater than one.
+    for e in [&[1u8][..], &[2], &[0x01, 0x00, 0x00]] {
+        let mut invalid = components.clone();
+        invalid.e = e.to_vec();
+        assert!(PublicKey::try_from(invalid).is_err());
+    }
+}
+
+#[test]
+fn public_key_components_from_der() {
+    const PKCS1: &[u8] = include_bytes!("data/rsa_test_public_key_2048.der");
+    const SPKI: &[u8] = include_bytes!("data/rsa_test_public_key_2048.x509");
+
+    let pkcs1 = RsaPublicKeyComponents::from_pkcs1_der(PKCS1).unwrap();
+    assert_eq!(2048, pkcs1.key_size_bits());
+    assert_eq!(&[0x01, 0x00, 0x01], pkcs1.e.as_slice());
+
+    let spki = RsaPublicKeyComponents::from_spki_der(SPKI).unwrap();
+    assert_eq!(pkcs1.n, spki.n);
+    assert_eq!(pkcs1.e, spki.e);
+
+    let public_key = PublicKey::try_from(pkcs1).unwrap();
+    assert_eq!(PKCS1, public_key.as_ref());
+
+    assert!(RsaPublicKeyComponents::from_pkcs1_der(SPKI).is_err());
+    assert!(RsaPublicKeyComponents::from_spki_der(PKCS1).is_err());
+    assert!(RsaPublicKeyComponents::from_pkcs1_der(&PKCS1[..PKCS1.len() - 1]).is_err());
+    assert!(RsaPublicKeyComponents::from_spki_der(&SPKI[..SPKI.len() - 1]).is_err());
+
+    // RSAPublicKey ::= SEQUENCE { modulus 0, publicExponent 65537 }
+    let zero_modulus = [0x30, 0x08, 0x02, 0x01, 0x00, 0x02, 0x03, 0x01, 0x00, 0x01];
+    assert!(RsaPublicKeyComponents::from_pkcs1_der(&zero_modulus).is_err());
+}
+
+#[test]
+fn rsa_kem_round_trip() {
+    let rng = rand::SystemRandom::new();
+    let private_key = PrivateDecryptingKey::generate(KeySize::Rsa2048).unwrap();
+    let public_key = private_key.public_key();
+
+    let (sender_secret, ciphertext) = kem::encapsulate(&public_key, &rng).unwrap();
+    assert_eq!(public_key.key_size_bytes(), ciphertext.len());
+    assert_eq!("SharedSecret { .. }", format!("{sender_secret:?}"));
+    let recipient_secret = kem::decapsulate(&private_key, &ciphertext).unwrap();
+
+    let sender_secret = sender_secret.derive(<[u8]>::to_vec);
+    assert_eq!(kem::SHARED_SECRET_LEN, sender_secret.len());
+    assert_eq!(sender_secret, recipient_secret.derive(<[u8]>::to_vec));
+
+    // Each encapsulation establishes a fresh secret.
+    let (other_secret, other_ciphertext) = kem::encapsulate(&public_key, &rng).unwrap();
+    assert_ne!(ciphertext, other_ciphertext);
+    assert_ne!(sender_secret, other_secret.derive(<[u8]>::to_vec));
+
+    // The ciphertext is RSA-OAEP with SHA-256.
+    let oaep_key = OaepPrivateDecryptingKey::new(private_key.clone()).unwrap();
+    let mut plaintext = vec![0u8; oaep_key.min_output_size()];
+    let plaintext = oaep_key
+        .decrypt(&OAEP_SHA256_MGF1SHA256, &ciphertext, &mut plaintext, None)
+        .unwrap();
+    assert_eq!(sender_secret.as_slice(), plaintext);
+}
+
+#[test]
+fn rsa_kem_invalid_ciphertext() {
+    let rng = rand::SystemRandom::new();
+    let private_key = PrivateDecryptingKey::generate(KeySize::Rsa2048).unwrap();
+    let (_, ciphertext) = kem::encapsulate(&private_key.public_key(), &rng).unwrap();
+
+    let mut corrupted = ciphertext.clone();
+    corrupted[10] ^= 0x01;
+    assert!(kem::decapsulate(&private_key, &corrupted).is_err());
+    assert!(kem::decapsulate(&private_key, &ciphertext[1..]).is_err());
+    assert!(kem::decapsulate(&private_key, &[]).is_err());
+
+    // A ciphertext for another key is rejected.
+    let other_key = PrivateDecryptingKey::generate(KeySize::Rsa2048).unwrap();
+    assert!(kem::decapsulate(&other_key, &ciphertext).is_err());
+
+    // A plaintext of the wrong length is rejected.
+    let oaep_key = OaepPublicEncryptingKey::new(private_key.public_key()).unwrap();
+    let mut ciphertext = vec![0u8; oaep_key.ciphertext_size()];
+    let ciphertext = oaep_key
+        .encrypt(&OAEP_SHA256_MGF1SHA256, &[0u8; 16], &mut ciphertext, None)
+        .unwrap();
+    assert!(kem::decapsulate(&private_key, ciphertext).is_err());
+}
+
+#[test]
+fn public_key_spki_der_round_trip() {
+    const MESSAGE: &[u8] = b"hello, world";
+
+    let key_pair = RsaKeyPair::generate(KeySize::Rsa2048).unwrap();
+    let spki = AsDer::<PublicKeyX509Der>::as_der(key_pair.public_key()).unwrap();
+    let public_key = PublicKey::from_der(spki.as_ref()).unwrap();
+    assert_eq!(key_pair.public_key().as_ref(), public_key.as_ref());
+    assert_eq!(2048, public_key.key_size_bits());
+    assert_eq!(
+        spki.as_ref(),
+        AsDer::<PublicKeyX509Der>::as_der(&public_key)
+            .unwrap()
+            .as_ref()
+    );
+
+    let mut sig = vec![0u8; key_pair.public_modulus_len()];
+    key_pair
+        .sign(
+            &signature::RSA_PKCS1_SHA256,
+            &rand::SystemRandom::new(),
+            MESSAGE,
+            &mut sig,
+        )
+        .unwrap();
+    signature::UnparsedPublicKey::new(&signature::RSA_PKCS1_2048_8192_SHA256, public_key)
+        .verify(MESSAGE, &sig)
+        .unwrap();
+
+    // An `RSAPublicKey` is not a `SubjectPublicKeyInfo`.
+    assert!(PublicKey::from_der(key_pair.public_key().as_ref()).is_err());
+    assert!(PublicKey::from_der(&spki.as_ref()[..spki.as_ref().len() - 1]).is_err());
+    let ec_spki = include_bytes!("data/pkcs12_ecdsa_p256_spki.der");
+    assert!(PublicKey::from_der(ec_spki).is_err());
+}
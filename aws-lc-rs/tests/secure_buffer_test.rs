@@ -0,0 +1,102 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+use aws_lc_rs::hkdf::{Salt, HKDF_SHA256};
+use aws_lc_rs::{digest, pbkdf2};
+use core::num::NonZeroU32;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicU8, AtomicUsize, Ordering};
+
+// An allocator that inspects the contents of one tracked allocation when it is freed.
+struct InspectingAllocator;
+
+static TRACKED: AtomicUsize = AtomicUsize::new(0);
+
+const NOT_FREED: u8 = 0;
+const FREED_ZEROED: u8 = 1;
+const FREED_NOT_ZEROED: u8 = 2;
+static TRACKED_STATE: AtomicU8 = AtomicU8::new(NOT_FREED);
+
+unsafe impl GlobalAlloc for InspectingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        if TRACKED
+            .compare_exchange(ptr as usize, 0, Ordering::SeqCst, Ordering::SeqCst)
+            .is_ok()
+        {
+            let contents = core::slice::from_raw_parts(ptr, layout.size());
+            let state = if contents.iter().all(|b| *b == 0) {
+                FREED_ZEROED
+            } else {
+                FREED_NOT_ZEROED
+            };
+            TRACKED_STATE.store(state, Ordering::SeqCst);
+        }
+        System.dealloc(ptr, layout);
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: InspectingAllocator = InspectingAllocator;
+
+fn assert_zeroed_on_drop(buffer: pbkdf2::SecureBuffer) {
+    assert!(buffer.as_ref().iter().any(|b| *b != 0));
+    TRACKED_STATE.store(NOT_FREED, Ordering::SeqCst);
+    TRACKED.store(buffer.as_ref().as_ptr() as usize, Ordering::SeqCst);
+    drop(buffer);
+    assert_eq!(FREED_ZEROED, TRACKED_STATE.load(Ordering::SeqCst));
+}
+
+#[test]
+fn secure_buffer_zeroized_on_drop() {
+    const SALT: &[u8] = b"saltSALTsaltSALTsaltSALTsaltSALTsalt";
+    const SECRET: &[u8] = b"passwordPASSWORDpassword";
+    let iterations = NonZeroU32::new(4096).unwrap();
+
+    let derived = pbkdf2::derive_secure(
+        pbkdf2::PBKDF2_HMAC_SHA256,
+        iterations,
+        SALT,
+        SECRET,
+        digest::SHA256_OUTPUT_LEN,
+    )
+    .unwrap();
+    let mut expected = [0u8; digest::SHA256_OUTPUT_LEN];
+    pbkdf2::derive(
+        pbkdf2::PBKDF2_HMAC_SHA256,
+        iterations,
+        SALT,
+        SECRET,
+        &mut expected,
+    );
+    assert_eq!(expected, derived.as_ref());
+    assert_eq!("SecureBuffer(<derived key>)", format!("{derived:?}"));
+    assert_zeroed_on_drop(derived);
+
+    let prk = Salt::new(HKDF_SHA256, SALT).extract(SECRET);
+    let okm = prk.expand(&[b"info"], HKDF_SHA256).unwrap();
+    let derived = okm.into_secure_buffer().unwrap();
+    let mut expected = [0u8; digest::SHA256_OUTPUT_LEN];
+    prk.expand(&[b"info"], HKDF_SHA256)
+        .unwrap()
+        .fill(&mut expected)
+        .unwrap();
+    assert_eq!(expected, derived.as_ref());
+    assert_zeroed_on_drop(derived);
+}
+
+#[cfg(target_pointer_width = "64")]
+#[test]
+fn derive_secure_output_too_long() {
+    assert!(pbkdf2::derive_secure(
+        pbkdf2::PBKDF2_HMAC_SHA1,
+        NonZeroU32::new(1).unwrap(),
+        b"salt",
+        b"password",
+        usize::MAX,
+    )
+    .is_err());
+}
@@ -0,0 +1,133 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+#![cfg(feature = "serde")]
+
+use aws_lc_rs::aead::nonce_sequence::{
+    NonceSequenceState, NsAlgorithm, UnpredictableNonceSequence,
+};
+use aws_lc_rs::aead::NonceSequence;
+use aws_lc_rs::encoding::{base64url, AsDer};
+use aws_lc_rs::rsa::{KeyPair as RsaKeyPair, PublicKey as RsaPublicKey};
+use aws_lc_rs::signature::{
+    self, EcdsaKeyPair, EcdsaSigningAlgorithm, Ed25519KeyPair, KeyPair, UnparsedPublicKey,
+};
+
+/// Returns the base64url string of a JSON string.
+fn json_base64url(json: &str) -> &str {
+    json.strip_prefix('"')
+        .and_then(|json| json.strip_suffix('"'))
+        .expect("JSON string")
+}
+
+#[test]
+fn rsa_public_key() {
+    let key_pair =
+        RsaKeyPair::from_pkcs8(include_bytes!("data/rsa_test_private_key_2048.p8")).unwrap();
+    let public_key = key_pair.public_key();
+
+    let json = serde_json::to_string(public_key).unwrap();
+    assert_eq!(
+        public_key.as_ref(),
+        base64url::decode(json_base64url(&json)).unwrap()
+    );
+
+    let parsed: RsaPublicKey = serde_json::from_str(&json).unwrap();
+    assert_eq!(public_key.as_ref(), parsed.as_ref());
+}
+
+#[test]
+fn ecdsa_public_key() {
+    for alg in [
+        &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+        &signature::ECDSA_P384_SHA384_FIXED_SIGNING,
+        &signature::ECDSA_P521_SHA512_ASN1_SIGNING,
+        &signature::ECDSA_P256K1_SHA256_ASN1_SIGNING,
+    ] as [&'static EcdsaSigningAlgorithm; 4]
+    {
+        let key_pair = EcdsaKeyPair::generate(alg).unwrap();
+        let public_key = key_pair.public_key();
+
+        let json = serde_json::to_string(public_key).unwrap();
+        assert_eq!(
+            public_key.as_der().unwrap().as_ref(),
+            base64url::decode(json_base64url(&json)).unwrap()
+        );
+
+        let parsed: signature::EcdsaPublicKey = serde_json::from_str(&json).unwrap();
+        assert_eq!(public_key.as_ref(), parsed.as_ref());
+    }
+}
+
+#[test]
+fn ed25519_public_key() {
+    let key_pair = Ed25519KeyPair::generate().unwrap();
+    let public_key = key_pair.public_key();
+
+    let json = serde_json::to_string(public_key).unwrap();
+    assert_eq!(
+        public_key.as_der().unwrap().as_ref(),
+        base64url::decode(json_base64url(&json)).unwrap()
+    );
+
+    let parsed: signature::Ed25519PublicKey = serde_json::from_str(&json).unwrap();
+    assert_eq!(public_key.as_ref(), parsed.as_ref());
+}
+
+#[test]
+fn unparsed_public_key() {
+    let key_pair = Ed25519KeyPair::generate().unwrap();
+    let public_key = UnparsedPublicKey::new(&signature::ED25519, key_pair.public_key().as_ref());
+
+    let json = serde_json::to_string(&public_key).unwrap();
+    assert_eq!(
+        key_pair.public_key().as_ref(),
+        base64url::decode(json_base64url(&json)).unwrap()
+    );
+}
+
+#[test]
+fn nonce_sequence_state() {
+    let key = [0x42u8; 32];
+    let mut ns = UnpredictableNonceSequence::with_chacha20(&key, 0);
+    for _ in 0..1000 {
+        ns.advance().unwrap();
+    }
+
+    let json = serde_json::to_string(&ns.state()).unwrap();
+    assert_eq!("1000", json);
+
+    let state: NonceSequenceState = serde_json::from_str(&json).unwrap();
+    let mut resumed =
+        UnpredictableNonceSequence::from_state(NsAlgorithm::ChaCha20, &key, state).unwrap();
+    assert_eq!(
+        ns.advance().unwrap().as_ref(),
+        resumed.advance().unwrap().as_ref()
+    );
+
+    assert!(serde_json::from_str::<NonceSequenceState>("-1").is_err());
+    assert!(serde_json::from_str::<NonceSequenceState>("\"1000\"").is_err());
+}
+
+#[test]
+fn invalid() {
+    let key_pair = Ed25519KeyPair::generate().unwrap();
+    let json = serde_json::to_string(key_pair.public_key()).unwrap();
+    let base64 = json_base64url(&json);
+
+    // Not base64url.
+    let not_base64url = format!("\"{}+\"", &base64[1..]);
+    assert!(serde_json::from_str::<signature::Ed25519PublicKey>(&not_base64url).is_err());
+
+    // Truncated DER.
+    let truncated = format!("\"{}\"", &base64[..base64.len() - 4]);
+    assert!(serde_json::from_str::<signature::Ed25519PublicKey>(&truncated).is_err());
+
+    // Keys of the wrong type.
+    assert!(serde_json::from_str::<signature::EcdsaPublicKey>(&json).is_err());
+    assert!(serde_json::from_str::<RsaPublicKey>(&json).is_err());
+
+    let key_pair = EcdsaKeyPair::generate(&signature::ECDSA_P256_SHA256_ASN1_SIGNING).unwrap();
+    let json = serde_json::to_string(key_pair.public_key()).unwrap();
+    assert!(serde_json::from_str::<signature::Ed25519PublicKey>(&json).is_err());
+}